@@ -1,17 +1,31 @@
 use crate::{
+    data::{BPMNDataAssociation, BPMNDataElement, DataAssociationDirection},
+    diagram::BPMNDiagram,
     element::BPMNElement,
-    elements::{collapsed_sub_process::BPMNCollapsedSubProcess, task::BPMNTask},
+    elements::{
+        event_sub_process::BPMNEventSubProcess, expanded_sub_process::BPMNExpandedSubProcess,
+        process::BPMNProcess, signal_end_event::BPMNSignalEndEvent,
+        signal_intermediate_throw_event::BPMNSignalIntermediateThrowEvent,
+    },
+    import::BPMNImport,
+    lane::BPMNLane,
+    message::BPMNMessage,
     message_flow::BPMNMessageFlow,
     parser::parser_state::GlobalIndex,
     sequence_flow::BPMNSequenceFlow,
+    signal::BPMNSignal,
     traits::{objectable::BPMNObject, processable::Processable, searchable::Searchable},
+    unrecognised_xml::BPMNUnrecognisedXml,
 };
 use anyhow::{Result, anyhow};
 #[cfg(any(test, feature = "testactivities"))]
 use ebi_activity_key::TestActivityKey;
 use ebi_activity_key::{ActivityKey, ActivityKeyTranslator, TranslateActivityKey};
 use ebi_derive::ActivityKey;
-use std::fmt::{Display, Formatter};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
 
 /// A struct with a Business Process Model and Notation (BPMN) model.
 ///
@@ -51,7 +65,24 @@ pub struct BusinessProcessModelAndNotation {
     pub definitions_id: String,
 
     pub elements: Vec<BPMNElement>,
+
+    /// Other files whose elements can be referred to, such as processes called by call activities.
+    pub imports: Vec<BPMNImport>,
     pub message_flows: Vec<BPMNMessageFlow>,
+    pub messages: Vec<BPMNMessage>,
+    pub signals: Vec<BPMNSignal>,
+
+    /// Data objects, data stores, references to them and properties of activities (recurses).
+    pub data_elements: Vec<BPMNDataElement>,
+    pub data_associations: Vec<BPMNDataAssociation>,
+
+    /// Layout information (BPMN DI), if present.
+    pub diagrams: Vec<BPMNDiagram>,
+
+    /// XML that was imported but is not interpreted, by the id of the element it belongs to.
+    pub(crate) unrecognised_xml: HashMap<String, BPMNUnrecognisedXml>,
+    /// XML that was imported outside of the definitions, such as comments, anchored to the definitions.
+    pub(crate) unrecognised_document_xml: BPMNUnrecognisedXml,
 }
 
 impl BusinessProcessModelAndNotation {
@@ -136,6 +167,147 @@ impl BusinessProcessModelAndNotation {
             .find(|message_flow| message_flow.global_index == message_flow_global_index)
     }
 
+    /// Returns the message with the given id.
+    pub fn message(&self, message_id: &str) -> Option<&BPMNMessage> {
+        self.messages
+            .iter()
+            .find(|message| message.id == message_id)
+    }
+
+    /// Returns the local index of the signal with the given id.
+    pub fn signal_id_2_local_index(&self, signal_id: &str) -> Option<usize> {
+        self.signals.iter().position(|signal| signal.id == signal_id)
+    }
+
+    /// Returns whether an element in the model throws the given signal (recurses).
+    /// If not, the signal can only come from the environment.
+    pub fn signal_is_thrown(&self, signal_ref: &Option<String>) -> bool {
+        if signal_ref.is_none() {
+            return false;
+        }
+        self.elements().into_iter().any(|element| match element {
+            BPMNElement::SignalEndEvent(BPMNSignalEndEvent {
+                signal_ref: thrown, ..
+            })
+            | BPMNElement::SignalIntermediateThrowEvent(BPMNSignalIntermediateThrowEvent {
+                signal_ref: thrown,
+                ..
+            }) => thrown == signal_ref,
+            _ => false,
+        })
+    }
+
+    /// Returns the data element with the given id.
+    pub fn data_element(&self, data_id: &str) -> Option<&BPMNDataElement> {
+        self.data_elements
+            .iter()
+            .find(|data_element| data_element.id == data_id)
+    }
+
+    /// Returns the data input and data output associations of the activity with the given index.
+    pub fn data_associations_of(
+        &self,
+        activity_global_index: GlobalIndex,
+    ) -> Vec<&BPMNDataAssociation> {
+        self.data_associations
+            .iter()
+            .filter(|association| association.activity_global_index == activity_global_index)
+            .collect()
+    }
+
+    /// Returns the data elements that the activity with the given index reads.
+    pub fn data_read_by(&self, activity_global_index: GlobalIndex) -> Vec<&BPMNDataElement> {
+        self.data_accessed_by(activity_global_index, DataAssociationDirection::Input)
+    }
+
+    /// Returns the data elements that the activity with the given index writes.
+    pub fn data_written_by(&self, activity_global_index: GlobalIndex) -> Vec<&BPMNDataElement> {
+        self.data_accessed_by(activity_global_index, DataAssociationDirection::Output)
+    }
+
+    fn data_accessed_by(
+        &self,
+        activity_global_index: GlobalIndex,
+        direction: DataAssociationDirection,
+    ) -> Vec<&BPMNDataElement> {
+        self.data_associations_of(activity_global_index)
+            .into_iter()
+            .filter(|association| association.direction == direction)
+            .flat_map(|association| association.data_refs())
+            .filter_map(|data_ref| self.data_element(data_ref))
+            .collect()
+    }
+
+    /// Returns the activities that read the data object or data store with the given id, either directly or through a reference (recurses).
+    pub fn activities_reading(&self, data_id: &str) -> Vec<&BPMNElement> {
+        self.activities_accessing(data_id, DataAssociationDirection::Input)
+    }
+
+    /// Returns the activities that write the data object or data store with the given id, either directly or through a reference (recurses).
+    pub fn activities_writing(&self, data_id: &str) -> Vec<&BPMNElement> {
+        self.activities_accessing(data_id, DataAssociationDirection::Output)
+    }
+
+    fn activities_accessing(
+        &self,
+        data_id: &str,
+        direction: DataAssociationDirection,
+    ) -> Vec<&BPMNElement> {
+        self.elements()
+            .into_iter()
+            .filter(|element| {
+                self.data_accessed_by(element.global_index(), direction)
+                    .into_iter()
+                    .any(|data_element| data_element.is_or_refers_to(data_id))
+            })
+            .collect()
+    }
+
+    /// Returns all lanes of all processes (recurses).
+    pub fn lanes(&self) -> Vec<&BPMNLane> {
+        self.elements
+            .iter()
+            .filter_map(|element| {
+                if let BPMNElement::Process(process) = element {
+                    Some(process)
+                } else {
+                    None
+                }
+            })
+            .flat_map(|process| &process.lane_sets)
+            .flat_map(|lane_set| lane_set.all_lanes())
+            .collect()
+    }
+
+    /// Returns the most specific lane that contains the element with the given index, if any.
+    pub fn lane_of(&self, element_global_index: GlobalIndex) -> Option<&BPMNLane> {
+        self.elements
+            .iter()
+            .filter_map(|element| {
+                if let BPMNElement::Process(process) = element {
+                    Some(process)
+                } else {
+                    None
+                }
+            })
+            .flat_map(|process| &process.lane_sets)
+            .find_map(|lane_set| lane_set.lane_of(element_global_index))
+    }
+
+    /// Returns the elements in the lane with the given index, including the elements in its child lanes.
+    pub fn elements_in_lane(&self, lane_global_index: GlobalIndex) -> Vec<&BPMNElement> {
+        self.lanes()
+            .into_iter()
+            .find(|lane| lane.global_index == lane_global_index)
+            .map(|lane| {
+                lane.all_flow_node_refs()
+                    .into_iter()
+                    .filter_map(|flow_node_ref| self.global_index_2_element(flow_node_ref))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Returns the sequence flow with the given global index.
     pub fn global_index_2_sequence_flow_and_parent(
         &self,
@@ -179,32 +351,35 @@ impl Display for BusinessProcessModelAndNotation {
 impl TranslateActivityKey for BusinessProcessModelAndNotation {
     fn translate_using_activity_key(&mut self, to_activity_key: &mut ActivityKey) {
         let translator = ActivityKeyTranslator::new(&self.activity_key, to_activity_key);
-
-        //gather indices of elements
-        let mut indices = vec![];
-        for element in self.elements() {
-            if element.is_task() || element.is_collapsed_sub_process() {
-                indices.push(element.global_index());
+        translate_elements(&mut self.elements, &translator);
+        for import in self.imports.iter_mut() {
+            if let Some(definitions) = &mut import.definitions {
+                definitions.translate_using_activity_key(to_activity_key);
             }
         }
+        self.activity_key = to_activity_key.clone();
+    }
+}
 
-        //adjust activities
-        for index in indices {
-            match self.elements.global_index_2_element_mut(index) {
-                Some(BPMNElement::Task(BPMNTask { activity, .. }))
-                | Some(BPMNElement::CollapsedSubProcess(BPMNCollapsedSubProcess {
-                    activity,
-                    ..
-                })) => {
-                    *activity = translator.translate_activity(&activity);
-                }
-                element => {
-                    panic!("Cannot translate {:?}", element);
+/// Translates the activities of the elements recursively, including those of the processes that call activities have been linked to.
+fn translate_elements(elements: &mut [BPMNElement], translator: &ActivityKeyTranslator) {
+    for element in elements {
+        if let Some(activity) = element.activity_mut() {
+            *activity = translator.translate_activity(&activity);
+        }
+        match element {
+            BPMNElement::EventSubProcess(BPMNEventSubProcess { elements, .. })
+            | BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })
+            | BPMNElement::Process(BPMNProcess { elements, .. }) => {
+                translate_elements(elements, translator)
+            }
+            BPMNElement::CallActivity(call_activity) => {
+                if let Some(called_process) = &mut call_activity.called_process {
+                    translate_elements(&mut called_process.elements, translator);
                 }
             }
+            _ => {}
         }
-
-        self.activity_key = to_activity_key.clone();
     }
 }
 
@@ -212,8 +387,8 @@ impl TranslateActivityKey for BusinessProcessModelAndNotation {
 impl TestActivityKey for BusinessProcessModelAndNotation {
     fn test_activity_key(&self) {
         for element in self.elements() {
-            if let BPMNElement::Task(BPMNTask { activity, .. }) = element {
-                self.activity_key.assert_activity_is_of_key(activity);
+            if let Some(activity) = element.activity() {
+                self.activity_key.assert_activity_is_of_key(&activity);
             }
         }
     }
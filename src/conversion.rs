@@ -1,8 +1,8 @@
 use crate::{
-    BusinessProcessModelAndNotation,
+    BusinessProcessModelAndNotation, error::BPMNError,
     stochastic_business_process_model_and_notation::StochasticBusinessProcessModelAndNotation,
 };
-use anyhow::{Context, Error, anyhow};
+use anyhow::{Context, anyhow};
 
 impl From<StochasticBusinessProcessModelAndNotation> for BusinessProcessModelAndNotation {
     fn from(value: StochasticBusinessProcessModelAndNotation) -> Self {
@@ -11,7 +11,7 @@ impl From<StochasticBusinessProcessModelAndNotation> for BusinessProcessModelAnd
 }
 
 impl TryFrom<BusinessProcessModelAndNotation> for StochasticBusinessProcessModelAndNotation {
-    type Error = Error;
+    type Error = BPMNError;
 
     /// Attempt to transform a BPMN model into an SBPMN model.
     /// This is possible if the appropriate sequence flows are annotated with weights.
@@ -2,26 +2,56 @@ use crate::{
     BusinessProcessModelAndNotation,
     element::{BPMNElement, BPMNElementTrait},
     elements::{
-        end_event::BPMNEndEvent, event_based_gateway::BPMNEventBasedGateway,
-        exclusive_gateway::BPMNExclusiveGateway, expanded_sub_process::BPMNExpandedSubProcess,
+        boundary_event::{BPMNBoundaryEvent, BoundaryEventType},
+        business_rule_task::BPMNBusinessRuleTask,
+        complex_gateway::BPMNComplexGateway,
+        end_event::BPMNEndEvent,
+        error_end_event::BPMNErrorEndEvent,
+        event_based_gateway::BPMNEventBasedGateway,
+        exclusive_gateway::BPMNExclusiveGateway,
+        expanded_sub_process::BPMNExpandedSubProcess,
         inclusive_gateway::BPMNInclusiveGateway,
         intermediate_catch_event::BPMNIntermediateCatchEvent,
         intermediate_throw_event::BPMNIntermediateThrowEvent,
+        manual_task::BPMNManualTask,
         message_end_event::BPMNMessageEndEvent,
         message_intermediate_catch_event::BPMNMessageIntermediateCatchEvent,
-        message_start_event::BPMNMessageStartEvent, parallel_gateway::BPMNParallelGateway,
-        process::BPMNProcess, start_event::BPMNStartEvent, task::BPMNTask,
+        message_start_event::BPMNMessageStartEvent,
+        parallel_gateway::BPMNParallelGateway,
+        process::BPMNProcess,
+        receive_task::BPMNReceiveTask,
+        script_task::BPMNScriptTask,
+        send_task::BPMNSendTask,
+        service_task::BPMNServiceTask,
+        start_event::BPMNStartEvent,
+        task::BPMNTask,
+        terminate_end_event::BPMNTerminateEndEvent,
         timer_intermediate_catch_event::BPMNTimerIntermediateCatchEvent,
         timer_start_event::BPMNTimerStartEvent,
+        user_task::BPMNUserTask,
     },
+    error::BPMNError,
     if_not::IfNot,
+    lane::{BPMNLane, BPMNLaneSet},
     parser::parser_state::GlobalIndex,
     sequence_flow::BPMNSequenceFlow,
+    timer::BPMNTimer,
     traits::{objectable::BPMNObject, searchable::Searchable},
+    unrecognised_xml::BPMNUnrecognisedXml,
 };
-use anyhow::{Result, anyhow};
 use ebi_activity_key::{Activity, ActivityKey, HasActivityKey, TranslateActivityKey};
-use std::fmt::{Debug, Display};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+};
+
+/// Returns the error for a global index that does not refer to anything of the expected kind in the model.
+fn unknown_index(global_index: GlobalIndex, message: &str) -> BPMNError {
+    BPMNError::UnknownId {
+        message: message.to_string(),
+        id: global_index.to_string(),
+    }
+}
 
 /// A helper struct that assists with creating BPMN models programmatically.
 /// The advantage of a [BPMNCreator] over editing a [BusinessProcessModelAndNotation] struct directly is that the methods of a [BPMNCreator] are guaranteed to leave the model in a valid state.
@@ -41,7 +71,15 @@ impl BPMNCreator {
             definitions_index: GlobalIndex(0),
             definitions_id: "definitions".to_string(),
             elements: vec![],
+            imports: vec![],
             message_flows: vec![],
+            messages: vec![],
+            signals: vec![],
+            data_elements: vec![],
+            data_associations: vec![],
+            diagrams: vec![],
+            unrecognised_xml: HashMap::new(),
+            unrecognised_document_xml: BPMNUnrecognisedXml::default(),
         };
         Self { bpmn, max_id: 0 }
     }
@@ -55,13 +93,21 @@ impl BPMNCreator {
             definitions_index: GlobalIndex(0),
             definitions_id: "definitions".to_string(),
             elements: vec![],
+            imports: vec![],
             message_flows: vec![],
+            messages: vec![],
+            signals: vec![],
+            data_elements: vec![],
+            data_associations: vec![],
+            diagrams: vec![],
+            unrecognised_xml: HashMap::new(),
+            unrecognised_document_xml: BPMNUnrecognisedXml::default(),
         };
         Self { bpmn, max_id: 0 }
     }
 
     /// Checks the model for structural correctness, and returns the model.
-    pub fn to_bpmn(self) -> Result<BusinessProcessModelAndNotation> {
+    pub fn to_bpmn(self) -> Result<BusinessProcessModelAndNotation, BPMNError> {
         self.bpmn.is_structurally_correct()?;
         Ok(self.bpmn)
     }
@@ -87,6 +133,8 @@ impl BPMNCreator {
             participant_id: None,
             elements: vec![],
             sequence_flows: vec![],
+            lane_sets: vec![],
+            is_called: false,
         });
         self.bpmn.elements.push(process);
         Container { global_index }
@@ -96,7 +144,7 @@ impl BPMNCreator {
         &mut self,
         parent: Container,
         start_event_type: StartEventType,
-    ) -> Result<GlobalIndex> {
+    ) -> Result<GlobalIndex, BPMNError> {
         let global_index = self.new_global_index();
         match self.bpmn.global_index_2_element_mut(parent.global_index) {
             Some(BPMNElement::Process(BPMNProcess { elements, .. }))
@@ -105,7 +153,7 @@ impl BPMNCreator {
                 elements.push(start_event_type.to_element(global_index, local_index));
                 Ok(global_index)
             }
-            _ => Err(anyhow!("parent not found")),
+            _ => Err(BPMNError::SemanticMisuse("parent not found".to_string())),
         }
     }
 
@@ -130,7 +178,7 @@ impl BPMNCreator {
         &mut self,
         parent: Container,
         intermediate_event_type: IntermediateEventType,
-    ) -> Result<GlobalIndex> {
+    ) -> Result<GlobalIndex, BPMNError> {
         let global_index = self.new_global_index();
         match self.bpmn.global_index_2_element_mut(parent.global_index) {
             Some(BPMNElement::Process(BPMNProcess { elements, .. }))
@@ -139,7 +187,7 @@ impl BPMNCreator {
                 elements.push(intermediate_event_type.to_element(global_index, local_index));
                 Ok(global_index)
             }
-            _ => Err(anyhow!("parent not found")),
+            _ => Err(BPMNError::SemanticMisuse("parent not found".to_string())),
         }
     }
 
@@ -164,7 +212,7 @@ impl BPMNCreator {
         &mut self,
         parent: Container,
         end_event_type: EndEventType,
-    ) -> Result<GlobalIndex> {
+    ) -> Result<GlobalIndex, BPMNError> {
         let global_index = self.new_global_index();
         match self.bpmn.global_index_2_element_mut(parent.global_index) {
             Some(BPMNElement::Process(BPMNProcess { elements, .. }))
@@ -173,7 +221,7 @@ impl BPMNCreator {
                 elements.push(end_event_type.to_element(global_index, local_index));
                 Ok(global_index)
             }
-            _ => Err(anyhow!("parent not found")),
+            _ => Err(BPMNError::SemanticMisuse("parent not found".to_string())),
         }
     }
 
@@ -194,44 +242,107 @@ impl BPMNCreator {
         }
     }
 
-    pub fn add_task(&mut self, parent: Container, activity: Activity) -> Result<GlobalIndex> {
+    /// Adds a boundary event to the activity `host`, in the same parent as the host.
+    /// If `cancel_activity` is true, the boundary event is interrupting.
+    pub fn add_boundary_event(
+        &mut self,
+        host: GlobalIndex,
+        boundary_event_type: BoundaryEventType,
+        cancel_activity: bool,
+    ) -> Result<GlobalIndex, BPMNError> {
         let global_index = self.new_global_index();
-        match self.bpmn.global_index_2_element_mut(parent.global_index) {
+        let parent = self
+            .bpmn
+            .parent_of(host)
+            .ok_or_else(|| unknown_index(host, "Element not found."))?;
+
+        match self.bpmn.global_index_2_element_mut(parent.global_index()) {
             Some(BPMNElement::Process(BPMNProcess { elements, .. }))
             | Some(BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })) => {
-                let local_index = elements.len();
-                elements.push(BPMNElement::Task(BPMNTask {
+                let host = elements
+                    .global_index_2_element(host)
+                    .ok_or_else(|| BPMNError::SemanticMisuse("host not found".to_string()))?;
+                let element = boundary_event_type.to_element(
                     global_index,
-                    id: format!("task_{}", global_index.0),
-                    local_index,
-                    activity,
-                    incoming_sequence_flows: vec![],
-                    outgoing_sequence_flows: vec![],
-                    incoming_message_flow: None,
-                    outgoing_message_flow: None,
-                }));
+                    elements.len(),
+                    host,
+                    cancel_activity,
+                );
+                elements.push(element);
                 Ok(global_index)
             }
-            _ => Err(anyhow!("parent not found")),
+            _ => Err(BPMNError::SemanticMisuse("parent not found".to_string())),
+        }
+    }
+
+    pub fn add_boundary_event_unchecked(
+        &mut self,
+        host: GlobalIndex,
+        boundary_event_type: BoundaryEventType,
+        cancel_activity: bool,
+    ) -> GlobalIndex {
+        let global_index = self.new_global_index();
+        let parent = self.bpmn.parent_of(host).unwrap();
+
+        match self.bpmn.global_index_2_element_mut(parent.global_index()) {
+            Some(BPMNElement::Process(BPMNProcess { elements, .. }))
+            | Some(BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })) => {
+                let host = elements.global_index_2_element(host).unwrap();
+                let element = boundary_event_type.to_element(
+                    global_index,
+                    elements.len(),
+                    host,
+                    cancel_activity,
+                );
+                elements.push(element);
+                global_index
+            }
+            _ => panic!("parent not found"),
         }
     }
 
+    pub fn add_task(
+        &mut self,
+        parent: Container,
+        activity: Activity,
+    ) -> Result<GlobalIndex, BPMNError> {
+        self.add_task_of_type(parent, TaskType::Task, activity)
+    }
+
     pub fn add_task_unchecked(&mut self, parent: Container, activity: Activity) -> GlobalIndex {
+        self.add_task_of_type_unchecked(parent, TaskType::Task, activity)
+    }
+
+    pub fn add_task_of_type(
+        &mut self,
+        parent: Container,
+        task_type: TaskType,
+        activity: Activity,
+    ) -> Result<GlobalIndex, BPMNError> {
         let global_index = self.new_global_index();
         match self.bpmn.global_index_2_element_mut(parent.global_index) {
             Some(BPMNElement::Process(BPMNProcess { elements, .. }))
             | Some(BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })) => {
                 let local_index = elements.len();
-                elements.push(BPMNElement::Task(BPMNTask {
-                    global_index,
-                    id: format!("task_{}", global_index.0),
-                    local_index,
-                    activity,
-                    incoming_sequence_flows: vec![],
-                    outgoing_sequence_flows: vec![],
-                    incoming_message_flow: None,
-                    outgoing_message_flow: None,
-                }));
+                elements.push(task_type.to_element(global_index, local_index, activity));
+                Ok(global_index)
+            }
+            _ => Err(BPMNError::SemanticMisuse("parent not found".to_string())),
+        }
+    }
+
+    pub fn add_task_of_type_unchecked(
+        &mut self,
+        parent: Container,
+        task_type: TaskType,
+        activity: Activity,
+    ) -> GlobalIndex {
+        let global_index = self.new_global_index();
+        match self.bpmn.global_index_2_element_mut(parent.global_index) {
+            Some(BPMNElement::Process(BPMNProcess { elements, .. }))
+            | Some(BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })) => {
+                let local_index = elements.len();
+                elements.push(task_type.to_element(global_index, local_index, activity));
                 global_index
             }
             _ => panic!("parent not found"),
@@ -242,7 +353,7 @@ impl BPMNCreator {
         &mut self,
         parent: Container,
         gateway_type: GatewayType,
-    ) -> Result<GlobalIndex> {
+    ) -> Result<GlobalIndex, BPMNError> {
         let global_index = self.new_global_index();
         match self.bpmn.global_index_2_element_mut(parent.global_index) {
             Some(BPMNElement::Process(BPMNProcess { elements, .. }))
@@ -251,7 +362,7 @@ impl BPMNCreator {
                 elements.push(gateway_type.to_element(global_index, local_index));
                 Ok(global_index)
             }
-            _ => Err(anyhow!("parent not found")),
+            _ => Err(BPMNError::SemanticMisuse("parent not found".to_string())),
         }
     }
 
@@ -276,20 +387,22 @@ impl BPMNCreator {
         &mut self,
         source: GlobalIndex,
         target: GlobalIndex,
-    ) -> Result<GlobalIndex> {
+    ) -> Result<GlobalIndex, BPMNError> {
         let global_index = self.new_global_index();
         let parent_a = self
             .bpmn
             .parent_of(source)
-            .and_if_not("Parent not found.")?;
+            .ok_or_else(|| unknown_index(source, "Element not found."))?;
 
         let parent_b = self
             .bpmn
             .parent_of(target)
-            .and_if_not("Parent not found.")?;
+            .ok_or_else(|| unknown_index(target, "Element not found."))?;
 
         if parent_a.global_index() != parent_b.global_index() {
-            return Err(anyhow!("Elements have different parents."));
+            return Err(BPMNError::SemanticMisuse(
+                "Elements have different parents.".to_string(),
+            ));
         }
 
         match self
@@ -309,16 +422,22 @@ impl BPMNCreator {
                 let local_index = sequence_flows.len();
 
                 //find source
-                let source = elements
-                    .global_index_2_element_mut(source)
-                    .ok_or_else(|| anyhow!("source not found"))?;
+                let source = elements.global_index_2_element_mut(source).ok_or_else(|| {
+                    BPMNError::UnknownId {
+                        message: "source not found".to_string(),
+                        id: source.to_string(),
+                    }
+                })?;
                 source.add_outgoing_sequence_flow(local_index)?;
                 let source_global_index = source.global_index();
                 let source_local_index = source.local_index();
 
-                let target = elements
-                    .global_index_2_element_mut(target)
-                    .ok_or_else(|| anyhow!("target not found"))?;
+                let target = elements.global_index_2_element_mut(target).ok_or_else(|| {
+                    BPMNError::UnknownId {
+                        message: "target not found".to_string(),
+                        id: target.to_string(),
+                    }
+                })?;
                 target.add_incoming_sequence_flow(local_index)?;
                 let target_global_index = target.global_index();
                 let target_local_index = target.local_index();
@@ -327,15 +446,17 @@ impl BPMNCreator {
                     global_index,
                     id: format!("sequenceflow_{}", global_index.0),
                     local_index,
+                    name: None,
                     source_global_index,
                     source_local_index,
                     target_global_index,
                     target_local_index,
                     weight: None,
+                    condition_expression: None,
                 });
                 Ok(global_index)
             }
-            _ => Err(anyhow!("parent not found")),
+            _ => Err(BPMNError::SemanticMisuse("parent not found".to_string())),
         }
     }
 
@@ -374,11 +495,13 @@ impl BPMNCreator {
                     global_index,
                     id: format!("sequenceflow_{}", global_index.0),
                     local_index,
+                    name: None,
                     source_global_index,
                     source_local_index,
                     target_global_index,
                     target_local_index,
                     weight: None,
+                    condition_expression: None,
                 });
                 global_index
             }
@@ -388,11 +511,11 @@ impl BPMNCreator {
 
     /// Removes a sequence flow with the given index `sequence_flow` from the `parent`.
     /// Returns an error if the parent cannot be found.
-    pub fn remove_sequence_flow(&mut self, sequence_flow: GlobalIndex) -> Result<()> {
+    pub fn remove_sequence_flow(&mut self, sequence_flow: GlobalIndex) -> Result<(), BPMNError> {
         let parent = self
             .bpmn
             .parent_of(sequence_flow)
-            .and_if_not("Parent not found.")?;
+            .ok_or_else(|| unknown_index(sequence_flow, "Sequence flow not found."))?;
 
         match self.bpmn.global_index_2_element_mut(parent.global_index()) {
             Some(BPMNElement::Process(BPMNProcess {
@@ -409,8 +532,25 @@ impl BPMNCreator {
                     .iter()
                     .position(|f| f.global_index == sequence_flow)
                 {
+                    let sequence_flow_id = sequence_flows[local_index].id.clone();
+
                     //we need to update every local index of every sequence flow that is >= local_index
                     for element in elements.iter_mut() {
+                        //a removed sequence flow can no longer be the default of its source
+                        match element {
+                            BPMNElement::ExclusiveGateway(BPMNExclusiveGateway {
+                                default_sequence_flow,
+                                ..
+                            })
+                            | BPMNElement::InclusiveGateway(BPMNInclusiveGateway {
+                                default_sequence_flow,
+                                ..
+                            }) if default_sequence_flow.as_ref() == Some(&sequence_flow_id) => {
+                                *default_sequence_flow = None;
+                            }
+                            _ => {}
+                        }
+
                         let new_incoming_sequence_flows = element
                             .incoming_sequence_flows()
                             .iter()
@@ -451,49 +591,53 @@ impl BPMNCreator {
                     sequence_flows.retain(|f| f.global_index != sequence_flow);
                     Ok(())
                 } else {
-                    Err(anyhow!("Sequence flow not found."))
+                    Err(BPMNError::SemanticMisuse(
+                        "Sequence flow not found.".to_string(),
+                    ))
                 }
             }
-            _ => Err(anyhow!("parent not found")),
+            _ => Err(BPMNError::SemanticMisuse("parent not found".to_string())),
         }
     }
 
     pub fn incoming_sequence_flows_of_element(
         &self,
         element: GlobalIndex,
-    ) -> Result<impl Iterator<Item = GlobalIndex>> {
+    ) -> Result<impl Iterator<Item = GlobalIndex>, BPMNError> {
         let parent = self
             .bpmn
             .parent_of(element)
-            .and_if_not("Parent not found.")?;
+            .ok_or_else(|| unknown_index(element, "Element not found."))?;
         match self.bpmn.global_index_2_element(parent.global_index()) {
             Some(BPMNElement::Process(BPMNProcess { sequence_flows, .. }))
             | Some(BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess {
                 sequence_flows,
                 ..
             })) => {
-                let element = self
-                    .bpmn
-                    .global_index_2_element(element)
-                    .ok_or_else(|| anyhow!("Element not found."))?;
+                let element = self.bpmn.global_index_2_element(element).ok_or_else(|| {
+                    BPMNError::UnknownId {
+                        message: "Element not found.".to_string(),
+                        id: element.to_string(),
+                    }
+                })?;
 
                 Ok(element
                     .incoming_sequence_flows()
                     .iter()
                     .map(|local_id| sequence_flows[*local_id].global_index))
             }
-            _ => Err(anyhow!("parent not found")),
+            _ => Err(BPMNError::SemanticMisuse("parent not found".to_string())),
         }
     }
 
     pub fn outgoing_sequence_flows_of_element(
         &self,
         element: GlobalIndex,
-    ) -> Result<impl Iterator<Item = GlobalIndex>> {
+    ) -> Result<impl Iterator<Item = GlobalIndex>, BPMNError> {
         let parent = self
             .bpmn
             .parent_of(element)
-            .and_if_not("Parent not found.")?;
+            .ok_or_else(|| unknown_index(element, "Element not found."))?;
 
         match self.bpmn.global_index_2_element(parent.global_index()) {
             Some(BPMNElement::Process(BPMNProcess { sequence_flows, .. }))
@@ -501,17 +645,19 @@ impl BPMNCreator {
                 sequence_flows,
                 ..
             })) => {
-                let element = self
-                    .bpmn
-                    .global_index_2_element(element)
-                    .ok_or_else(|| anyhow!("Element not found."))?;
+                let element = self.bpmn.global_index_2_element(element).ok_or_else(|| {
+                    BPMNError::UnknownId {
+                        message: "Element not found.".to_string(),
+                        id: element.to_string(),
+                    }
+                })?;
 
                 Ok(element
                     .outgoing_sequence_flows()
                     .iter()
                     .map(|local_id| sequence_flows[*local_id].global_index))
             }
-            _ => Err(anyhow!("parent not found")),
+            _ => Err(BPMNError::SemanticMisuse("parent not found".to_string())),
         }
     }
 
@@ -529,6 +675,239 @@ impl BPMNCreator {
         Some(sequence_flow.target_global_index())
     }
 
+    /// Makes the given sequence flow the default sequence flow of an exclusive or inclusive gateway.
+    /// Returns an error if the gateway is not an exclusive or inclusive gateway, or if the sequence flow does not leave the gateway.
+    pub fn set_default_sequence_flow(
+        &mut self,
+        gateway: GlobalIndex,
+        sequence_flow: GlobalIndex,
+    ) -> Result<(), BPMNError> {
+        let (sequence_flow, _) = self
+            .bpmn
+            .global_index_2_sequence_flow_and_parent(sequence_flow)
+            .ok_or_else(|| unknown_index(sequence_flow, "Sequence flow not found."))?;
+        if sequence_flow.source_global_index() != gateway {
+            return Err(BPMNError::SemanticMisuse(
+                "Sequence flow does not leave the gateway.".to_string(),
+            ));
+        }
+        let sequence_flow_id = sequence_flow.id.clone();
+
+        match self.bpmn.global_index_2_element_mut(gateway) {
+            Some(BPMNElement::ExclusiveGateway(BPMNExclusiveGateway {
+                default_sequence_flow,
+                ..
+            }))
+            | Some(BPMNElement::InclusiveGateway(BPMNInclusiveGateway {
+                default_sequence_flow,
+                ..
+            })) => {
+                *default_sequence_flow = Some(sequence_flow_id);
+                Ok(())
+            }
+            _ => Err(BPMNError::SemanticMisuse(
+                "Only exclusive and inclusive gateways can have a default sequence flow."
+                    .to_string(),
+            )),
+        }
+    }
+
+    /// Sets the name of the given element.
+    /// For tasks and collapsed sub-processes, the name is the label of their activity.
+    pub fn set_name(
+        &mut self,
+        element: GlobalIndex,
+        name: Option<String>,
+    ) -> Result<(), BPMNError> {
+        let bpmn_element = self
+            .bpmn
+            .global_index_2_element_mut(element)
+            .ok_or_else(|| unknown_index(element, "Element not found."))?;
+        if let Some(element_name) = bpmn_element.name_mut() {
+            *element_name = name;
+            return Ok(());
+        }
+        if bpmn_element.activity_mut().is_none() {
+            return Err(BPMNError::SemanticMisuse(
+                "Element cannot have a name.".to_string(),
+            ));
+        }
+
+        let activity = self
+            .bpmn
+            .activity_key
+            .process_activity(name.as_deref().unwrap_or(""));
+        if let Some(element_activity) = self
+            .bpmn
+            .global_index_2_element_mut(element)
+            .and_then(|bpmn_element| bpmn_element.activity_mut())
+        {
+            *element_activity = activity;
+        }
+        Ok(())
+    }
+
+    /// Sets the name of the given sequence flow.
+    pub fn set_sequence_flow_name(
+        &mut self,
+        sequence_flow: GlobalIndex,
+        name: Option<String>,
+    ) -> Result<(), BPMNError> {
+        let sequence_flow = self
+            .bpmn
+            .elements
+            .global_index_2_sequence_flow_mut(sequence_flow)
+            .ok_or_else(|| unknown_index(sequence_flow, "Sequence flow not found."))?;
+        sequence_flow.name = name;
+        Ok(())
+    }
+
+    /// Sets the timer of the given timer start event, timer intermediate catch event or timer boundary event.
+    pub fn set_timer(
+        &mut self,
+        event: GlobalIndex,
+        timer: Option<BPMNTimer>,
+    ) -> Result<(), BPMNError> {
+        match self.bpmn.global_index_2_element_mut(event) {
+            Some(BPMNElement::TimerStartEvent(BPMNTimerStartEvent {
+                timer: event_timer, ..
+            }))
+            | Some(BPMNElement::TimerIntermediateCatchEvent(BPMNTimerIntermediateCatchEvent {
+                timer: event_timer,
+                ..
+            }))
+            | Some(BPMNElement::BoundaryEvent(BPMNBoundaryEvent {
+                event_type: BoundaryEventType::Timer,
+                timer: event_timer,
+                ..
+            })) => {
+                *event_timer = timer;
+                Ok(())
+            }
+            Some(_) => Err(BPMNError::SemanticMisuse(
+                "Only timer events can have a timer.".to_string(),
+            )),
+            None => Err(BPMNError::UnknownId {
+                message: "Element not found.".to_string(),
+                id: event.to_string(),
+            }),
+        }
+    }
+
+    /// Adds a lane to the given process, in its first lane set. If the process has no lane set yet, one is created.
+    pub fn add_lane(
+        &mut self,
+        process: Container,
+        name: Option<String>,
+    ) -> Result<GlobalIndex, BPMNError> {
+        let global_index = self.new_global_index();
+        let lane_set_global_index = self.new_global_index();
+        match self.bpmn.global_index_2_element_mut(process.global_index) {
+            Some(BPMNElement::Process(BPMNProcess { lane_sets, .. })) => {
+                if lane_sets.is_empty() {
+                    lane_sets.push(BPMNLaneSet {
+                        id: Some(format!("laneset_{}", lane_set_global_index.0)),
+                        name: None,
+                        lanes: vec![],
+                    });
+                }
+                lane_sets[0].lanes.push(BPMNLane {
+                    global_index,
+                    id: format!("lane_{}", global_index.0),
+                    name,
+                    flow_node_refs: vec![],
+                    child_lane_set: None,
+                });
+                Ok(global_index)
+            }
+            _ => Err(BPMNError::SemanticMisuse(
+                "Lanes can only be added to processes.".to_string(),
+            )),
+        }
+    }
+
+    /// Adds a lane to the child lane set of the given lane. If the lane has no child lane set yet, one is created.
+    pub fn add_child_lane(
+        &mut self,
+        lane: GlobalIndex,
+        name: Option<String>,
+    ) -> Result<GlobalIndex, BPMNError> {
+        let global_index = self.new_global_index();
+        let lane_set_global_index = self.new_global_index();
+        let parent_lane = self
+            .lane_mut(lane)
+            .ok_or_else(|| unknown_index(lane, "Lane not found."))?;
+        parent_lane
+            .child_lane_set
+            .get_or_insert_with(|| BPMNLaneSet {
+                id: Some(format!("laneset_{}", lane_set_global_index.0)),
+                name: None,
+                lanes: vec![],
+            })
+            .lanes
+            .push(BPMNLane {
+                global_index,
+                id: format!("lane_{}", global_index.0),
+                name,
+                flow_node_refs: vec![],
+                child_lane_set: None,
+            });
+        Ok(global_index)
+    }
+
+    /// Assigns an element to a lane of the process that contains the element, and removes it from all other lanes of that process.
+    pub fn assign_to_lane(
+        &mut self,
+        element: GlobalIndex,
+        lane: GlobalIndex,
+    ) -> Result<(), BPMNError> {
+        for process_element in &mut self.bpmn.elements {
+            if let BPMNElement::Process(process) = process_element {
+                if process
+                    .lane_sets
+                    .iter()
+                    .any(|lane_set| lane_set.lane(lane).is_some())
+                {
+                    if process.global_index_2_element(element).is_none() {
+                        return Err(BPMNError::SemanticMisuse(
+                            "The element is not in the process of the lane.".to_string(),
+                        ));
+                    }
+                    for lane_set in &mut process.lane_sets {
+                        lane_set.remove_element(element);
+                    }
+                    process
+                        .lane_sets
+                        .iter_mut()
+                        .find_map(|lane_set| lane_set.lane_mut(lane))
+                        .and_if_not("Lane not found.")?
+                        .flow_node_refs
+                        .push(element);
+                    return Ok(());
+                }
+            }
+        }
+        Err(BPMNError::UnknownId {
+            message: "Lane not found.".to_string(),
+            id: lane.to_string(),
+        })
+    }
+
+    fn lane_mut(&mut self, lane: GlobalIndex) -> Option<&mut BPMNLane> {
+        self.bpmn
+            .elements
+            .iter_mut()
+            .filter_map(|element| {
+                if let BPMNElement::Process(process) = element {
+                    Some(process)
+                } else {
+                    None
+                }
+            })
+            .flat_map(|process| &mut process.lane_sets)
+            .find_map(|lane_set| lane_set.lane_mut(lane))
+    }
+
     /// Returns a list of all elements; an element is anything that is not a flow.
     pub fn elements(&self) -> Vec<GlobalIndex> {
         self.bpmn
@@ -544,12 +923,12 @@ impl BPMNCreator {
         &mut self,
         element_a: GlobalIndex,
         element_b: GlobalIndex,
-    ) -> Result<()> {
+    ) -> Result<(), BPMNError> {
         let parent_a = Container {
             global_index: self
                 .bpmn
                 .parent_of(element_a)
-                .and_if_not("Parent not found.")?
+                .ok_or_else(|| unknown_index(element_a, "Element not found."))?
                 .global_index(),
         };
 
@@ -557,12 +936,14 @@ impl BPMNCreator {
             global_index: self
                 .bpmn
                 .parent_of(element_b)
-                .and_if_not("Parent not found.")?
+                .ok_or_else(|| unknown_index(element_b, "Element not found."))?
                 .global_index(),
         };
 
         if parent_a != parent_b {
-            return Err(anyhow!("Elements have different parents."));
+            return Err(BPMNError::SemanticMisuse(
+                "Elements have different parents.".to_string(),
+            ));
         }
 
         let element_a_global_index = element_a;
@@ -613,7 +994,7 @@ impl BPMNCreator {
                     sequence_flow.target_local_index = element_a_local_index;
                 }
             }
-            _ => return Err(anyhow!("Parent not found.")),
+            _ => return Err(BPMNError::SemanticMisuse("Parent not found.".to_string())),
         }
         Ok(())
     }
@@ -624,12 +1005,12 @@ impl BPMNCreator {
         &mut self,
         element_a: GlobalIndex,
         element_b: GlobalIndex,
-    ) -> Result<()> {
+    ) -> Result<(), BPMNError> {
         let parent_a = Container {
             global_index: self
                 .bpmn
                 .parent_of(element_a)
-                .and_if_not("Parent not found.")?
+                .ok_or_else(|| unknown_index(element_a, "Element not found."))?
                 .global_index(),
         };
 
@@ -637,12 +1018,14 @@ impl BPMNCreator {
             global_index: self
                 .bpmn
                 .parent_of(element_b)
-                .and_if_not("Parent not found.")?
+                .ok_or_else(|| unknown_index(element_b, "Element not found."))?
                 .global_index(),
         };
 
         if parent_a != parent_b {
-            return Err(anyhow!("Elements have different parents."));
+            return Err(BPMNError::SemanticMisuse(
+                "Elements have different parents.".to_string(),
+            ));
         }
 
         let element_a_global_index = element_a;
@@ -693,7 +1076,7 @@ impl BPMNCreator {
                     sequence_flow.source_local_index = element_a_local_index;
                 }
             }
-            _ => return Err(anyhow!("Parent not found.")),
+            _ => return Err(BPMNError::SemanticMisuse("Parent not found.".to_string())),
         }
         Ok(())
     }
@@ -735,6 +1118,10 @@ pub struct Container {
 }
 
 pub enum GatewayType {
+    /// A complex gateway, of which the join fires once `activation_count` incoming sequence flows have a token.
+    Complex {
+        activation_count: u64,
+    },
     EventBased,
     Exclusive,
     Inclusive,
@@ -744,10 +1131,22 @@ pub enum GatewayType {
 impl GatewayType {
     fn to_element(self, global_index: GlobalIndex, local_index: usize) -> BPMNElement {
         match self {
+            GatewayType::Complex { activation_count } => {
+                BPMNElement::ComplexGateway(BPMNComplexGateway {
+                    global_index,
+                    id: format!("gateway_{}", global_index.0),
+                    local_index,
+                    name: None,
+                    activation_count: Some(activation_count),
+                    incoming_sequence_flows: vec![],
+                    outgoing_sequence_flows: vec![],
+                })
+            }
             GatewayType::EventBased => BPMNElement::EventBasedGateway(BPMNEventBasedGateway {
                 global_index,
                 id: format!("gateway_{}", global_index.0),
                 local_index,
+                name: None,
                 incoming_sequence_flows: vec![],
                 outgoing_sequence_flows: vec![],
             }),
@@ -755,6 +1154,8 @@ impl GatewayType {
                 global_index,
                 id: format!("gateway_{}", global_index.0),
                 local_index,
+                name: None,
+                default_sequence_flow: None,
                 incoming_sequence_flows: vec![],
                 outgoing_sequence_flows: vec![],
             }),
@@ -762,6 +1163,8 @@ impl GatewayType {
                 global_index,
                 id: format!("gateway_{}", global_index.0),
                 local_index,
+                name: None,
+                default_sequence_flow: None,
                 incoming_sequence_flows: vec![],
                 outgoing_sequence_flows: vec![],
             }),
@@ -769,6 +1172,7 @@ impl GatewayType {
                 global_index,
                 id: format!("gateway_{}", global_index.0),
                 local_index,
+                name: None,
                 incoming_sequence_flows: vec![],
                 outgoing_sequence_flows: vec![],
             }),
@@ -789,22 +1193,29 @@ impl StartEventType {
                 global_index,
                 id: format!("startevent_{}", global_index.0),
                 local_index,
+                name: None,
                 outgoing_sequence_flows: vec![],
             }),
             StartEventType::Message => BPMNElement::MessageStartEvent(BPMNMessageStartEvent {
                 global_index,
                 id: format!("messagestartevent_{}", global_index.0),
                 local_index,
+                name: None,
                 incoming_message_flow: None,
                 message_marker_id: Some(format!("messagemarker_{}", global_index.0)),
+                message_ref: None,
                 outgoing_sequence_flows: vec![],
+                is_interrupting: true,
             }),
             StartEventType::Timer => BPMNElement::TimerStartEvent(BPMNTimerStartEvent {
                 global_index,
                 id: format!("timerstartevent_{}", global_index.0),
                 local_index,
+                name: None,
                 timer_marker_id: Some(format!("timermarker_{}", global_index.0)),
+                timer: None,
                 outgoing_sequence_flows: vec![],
+                is_interrupting: true,
             }),
         }
     }
@@ -812,7 +1223,9 @@ impl StartEventType {
 
 pub enum EndEventType {
     None,
+    Error,
     Message,
+    Terminate,
 }
 
 impl EndEventType {
@@ -822,20 +1235,72 @@ impl EndEventType {
                 global_index,
                 id: format!("endevent_{}", global_index.0),
                 local_index,
+                name: None,
+                incoming_sequence_flows: vec![],
+            }),
+            EndEventType::Error => BPMNElement::ErrorEndEvent(BPMNErrorEndEvent {
+                global_index,
+                id: format!("endevent_{}", global_index.0),
+                local_index,
+                name: None,
+                error_marker_id: Some(format!("errormarker_{}", global_index.0)),
+                error_ref: None,
                 incoming_sequence_flows: vec![],
             }),
             EndEventType::Message => BPMNElement::MessageEndEvent(BPMNMessageEndEvent {
                 global_index,
                 id: format!("endevent_{}", global_index.0),
                 local_index,
+                name: None,
                 incoming_sequence_flows: vec![],
                 message_marker_id: Some(format!("messagemarker_{}", global_index.0)),
+                message_ref: None,
                 outgoing_message_flow: None,
             }),
+            EndEventType::Terminate => BPMNElement::TerminateEndEvent(BPMNTerminateEndEvent {
+                global_index,
+                id: format!("endevent_{}", global_index.0),
+                local_index,
+                name: None,
+                terminate_marker_id: Some(format!("terminatemarker_{}", global_index.0)),
+                incoming_sequence_flows: vec![],
+            }),
         }
     }
 }
 
+impl BoundaryEventType {
+    fn to_element(
+        self,
+        global_index: GlobalIndex,
+        local_index: usize,
+        host: &BPMNElement,
+        cancel_activity: bool,
+    ) -> BPMNElement {
+        let marker_id = match self {
+            BoundaryEventType::Error => format!("errormarker_{}", global_index.0),
+            BoundaryEventType::Message => format!("messagemarker_{}", global_index.0),
+            BoundaryEventType::Timer => format!("timermarker_{}", global_index.0),
+        };
+        BPMNElement::BoundaryEvent(BPMNBoundaryEvent {
+            global_index,
+            id: format!("boundaryevent_{}", global_index.0),
+            local_index,
+            name: None,
+            event_type: self,
+            marker_id: Some(marker_id),
+            error_ref: None,
+            message_ref: None,
+            timer: None,
+            cancel_activity,
+            attached_to_id: host.id().to_string(),
+            attached_to_local_index: host.local_index(),
+            outgoing_sequence_flows: vec![],
+            incoming_message_flow: None,
+        })
+    }
+}
+
 pub enum IntermediateEventType {
     NoneCatch,
     NoneThrow,
@@ -852,6 +1317,7 @@ impl IntermediateEventType {
                     global_index,
                     id: format!("intermediatecatchevent_{}", global_index.0),
                     local_index,
+                    name: None,
                     incoming_sequence_flows: vec![],
                     outgoing_sequence_flows: vec![],
                 })
@@ -861,6 +1327,7 @@ impl IntermediateEventType {
                     global_index,
                     id: format!("intermediatethrowevent_{}", global_index.0),
                     local_index,
+                    name: None,
                     incoming_sequence_flows: vec![],
                     outgoing_sequence_flows: vec![],
                 })
@@ -870,9 +1337,11 @@ impl IntermediateEventType {
                     global_index,
                     id: format!("messageintermediatecatchevent_{}", global_index.0),
                     local_index,
+                    name: None,
                     incoming_sequence_flows: vec![],
                     outgoing_sequence_flows: vec![],
                     message_marker_id: Some(format!("messagemarker_{}", global_index.0)),
+                    message_ref: None,
                     incoming_message_flow: None,
                 })
             }
@@ -881,9 +1350,11 @@ impl IntermediateEventType {
                     global_index,
                     id: format!("messageintermediatethrowevent_{}", global_index.0),
                     local_index,
+                    name: None,
                     incoming_sequence_flows: vec![],
                     outgoing_sequence_flows: vec![],
                     message_marker_id: Some(format!("messagemarker_{}", global_index.0)),
+                    message_ref: None,
                     incoming_message_flow: None,
                 })
             }
@@ -892,7 +1363,9 @@ impl IntermediateEventType {
                     global_index,
                     id: format!("timerintermediatethrowevent_{}", global_index.0),
                     local_index,
+                    name: None,
                     timer_marker_id: Some(format!("timermarker_{}", global_index.0)),
+                    timer: None,
                     incoming_sequence_flows: vec![],
                     outgoing_sequence_flows: vec![],
                 })
@@ -900,3 +1373,167 @@ impl IntermediateEventType {
         }
     }
 }
+
+pub enum TaskType {
+    BusinessRule,
+    Manual,
+    Receive,
+    Script,
+    Send,
+    Service,
+    Task,
+    User,
+}
+
+impl TaskType {
+    fn to_element(
+        self,
+        global_index: GlobalIndex,
+        local_index: usize,
+        activity: Activity,
+    ) -> BPMNElement {
+        match self {
+            TaskType::BusinessRule => BPMNElement::BusinessRuleTask(BPMNBusinessRuleTask {
+                global_index,
+                id: format!("businessruletask_{}", global_index.0),
+                local_index,
+                activity,
+                incoming_sequence_flows: vec![],
+                outgoing_sequence_flows: vec![],
+                incoming_message_flow: None,
+                outgoing_message_flow: None,
+                multi_instance: None,
+                standard_loop: None,
+            }),
+            TaskType::Manual => BPMNElement::ManualTask(BPMNManualTask {
+                global_index,
+                id: format!("manualtask_{}", global_index.0),
+                local_index,
+                activity,
+                incoming_sequence_flows: vec![],
+                outgoing_sequence_flows: vec![],
+                incoming_message_flow: None,
+                outgoing_message_flow: None,
+                multi_instance: None,
+                standard_loop: None,
+            }),
+            TaskType::Receive => BPMNElement::ReceiveTask(BPMNReceiveTask {
+                global_index,
+                id: format!("receivetask_{}", global_index.0),
+                local_index,
+                activity,
+                incoming_sequence_flows: vec![],
+                outgoing_sequence_flows: vec![],
+                incoming_message_flow: None,
+                multi_instance: None,
+                standard_loop: None,
+            }),
+            TaskType::Script => BPMNElement::ScriptTask(BPMNScriptTask {
+                global_index,
+                id: format!("scripttask_{}", global_index.0),
+                local_index,
+                activity,
+                incoming_sequence_flows: vec![],
+                outgoing_sequence_flows: vec![],
+                incoming_message_flow: None,
+                outgoing_message_flow: None,
+                multi_instance: None,
+                standard_loop: None,
+            }),
+            TaskType::Send => BPMNElement::SendTask(BPMNSendTask {
+                global_index,
+                id: format!("sendtask_{}", global_index.0),
+                local_index,
+                activity,
+                incoming_sequence_flows: vec![],
+                outgoing_sequence_flows: vec![],
+                outgoing_message_flow: None,
+                multi_instance: None,
+                standard_loop: None,
+            }),
+            TaskType::Service => BPMNElement::ServiceTask(BPMNServiceTask {
+                global_index,
+                id: format!("servicetask_{}", global_index.0),
+                local_index,
+                activity,
+                incoming_sequence_flows: vec![],
+                outgoing_sequence_flows: vec![],
+                incoming_message_flow: None,
+                outgoing_message_flow: None,
+                multi_instance: None,
+                standard_loop: None,
+            }),
+            TaskType::Task => BPMNElement::Task(BPMNTask {
+                global_index,
+                id: format!("task_{}", global_index.0),
+                local_index,
+                activity,
+                incoming_sequence_flows: vec![],
+                outgoing_sequence_flows: vec![],
+                incoming_message_flow: None,
+                outgoing_message_flow: None,
+                multi_instance: None,
+                standard_loop: None,
+            }),
+            TaskType::User => BPMNElement::UserTask(BPMNUserTask {
+                global_index,
+                id: format!("usertask_{}", global_index.0),
+                local_index,
+                activity,
+                incoming_sequence_flows: vec![],
+                outgoing_sequence_flows: vec![],
+                incoming_message_flow: None,
+                outgoing_message_flow: None,
+                multi_instance: None,
+                standard_loop: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        BPMNCreator,
+        creator::{EndEventType, StartEventType},
+        elements::boundary_event::BoundaryEventType,
+        error::BPMNError,
+        parser::parser_state::GlobalIndex,
+    };
+
+    #[test]
+    fn bpmn_creator_unknown_index() {
+        let mut creator = BPMNCreator::new();
+        let process = creator.add_process(None);
+        let start_event = creator
+            .add_start_event(process, StartEventType::None)
+            .unwrap();
+        let end_event = creator.add_end_event(process, EndEventType::None).unwrap();
+        let unknown = GlobalIndex(1000);
+
+        //an index that does not exist is reported as an unknown id
+        assert!(matches!(
+            creator.add_sequence_flow(unknown, end_event),
+            Err(BPMNError::UnknownId { .. })
+        ));
+        assert!(matches!(
+            creator.add_sequence_flow(start_event, unknown),
+            Err(BPMNError::UnknownId { .. })
+        ));
+        assert!(matches!(
+            creator.add_boundary_event(unknown, BoundaryEventType::Error, true),
+            Err(BPMNError::UnknownId { .. })
+        ));
+        assert!(matches!(
+            creator.remove_sequence_flow(unknown),
+            Err(BPMNError::UnknownId { .. })
+        ));
+        assert!(matches!(
+            creator.incoming_sequence_flows_of_element(unknown),
+            Err(BPMNError::UnknownId { .. })
+        ));
+
+        creator.add_sequence_flow(start_event, end_event).unwrap();
+        assert!(creator.to_bpmn().is_ok());
+    }
+}
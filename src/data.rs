@@ -0,0 +1,147 @@
+use crate::parser::parser_state::GlobalIndex;
+
+/// The kinds of data elements that can be declared in a BPMN model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataElementType {
+    DataObject,
+    DataObjectReference,
+    DataStore,
+    DataStoreReference,
+    Property,
+}
+
+impl DataElementType {
+    /// Returns the type of the XML tag with the given local name, if it is a data element.
+    pub(crate) fn from_tag_name(tag_name: &[u8]) -> Option<Self> {
+        match tag_name {
+            b"dataObject" => Some(Self::DataObject),
+            b"dataObjectReference" => Some(Self::DataObjectReference),
+            b"dataStore" => Some(Self::DataStore),
+            b"dataStoreReference" => Some(Self::DataStoreReference),
+            b"property" => Some(Self::Property),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn tag_name(&self) -> &'static str {
+        match self {
+            Self::DataObject => "dataObject",
+            Self::DataObjectReference => "dataObjectReference",
+            Self::DataStore => "dataStore",
+            Self::DataStoreReference => "dataStoreReference",
+            Self::Property => "property",
+        }
+    }
+
+    /// Returns the name of the attribute with which a reference refers to its data object or data store.
+    pub(crate) fn data_ref_attribute(&self) -> Option<&'static str> {
+        match self {
+            Self::DataObjectReference => Some("dataObjectRef"),
+            Self::DataStoreReference => Some("dataStoreRef"),
+            _ => None,
+        }
+    }
+}
+
+/// A struct that represents a data element in a BPMN model: a data object, a data store, a reference to one of these, or a property of an activity.
+/// Data elements do not influence the behaviour of the model.
+#[derive(Clone, Debug)]
+pub struct BPMNDataElement {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) element_type: DataElementType,
+    pub(crate) name: Option<String>,
+
+    ///the process or sub-process in which the element is declared; the definitions for a data store, and the activity for a property
+    pub(crate) parent_global_index: GlobalIndex,
+
+    ///for a reference, the id of the data object or data store it refers to
+    pub(crate) data_ref: Option<String>,
+    pub(crate) is_collection: bool,
+}
+
+impl BPMNDataElement {
+    pub fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn element_type(&self) -> DataElementType {
+        self.element_type
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn parent_global_index(&self) -> GlobalIndex {
+        self.parent_global_index
+    }
+
+    pub fn data_ref(&self) -> Option<&str> {
+        self.data_ref.as_deref()
+    }
+
+    pub fn is_collection(&self) -> bool {
+        self.is_collection
+    }
+
+    /// Returns whether this element is the data element with the given id, or a reference to it.
+    pub fn is_or_refers_to(&self, data_id: &str) -> bool {
+        self.id == data_id || self.data_ref.as_deref() == Some(data_id)
+    }
+}
+
+/// Whether a data association reads data into an activity or writes data from it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataAssociationDirection {
+    Input,
+    Output,
+}
+
+/// A struct that represents a data input or data output association of an activity.
+#[derive(Clone, Debug)]
+pub struct BPMNDataAssociation {
+    pub(crate) id: Option<String>,
+    pub(crate) direction: DataAssociationDirection,
+    pub(crate) activity_global_index: GlobalIndex,
+    pub(crate) source_refs: Vec<String>,
+    pub(crate) target_ref: Option<String>,
+}
+
+impl BPMNDataAssociation {
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn direction(&self) -> DataAssociationDirection {
+        self.direction
+    }
+
+    pub fn activity_global_index(&self) -> GlobalIndex {
+        self.activity_global_index
+    }
+
+    pub fn source_refs(&self) -> &[String] {
+        &self.source_refs
+    }
+
+    pub fn target_ref(&self) -> Option<&str> {
+        self.target_ref.as_deref()
+    }
+
+    /// Returns the ids of the data elements outside of the activity that this association reads from or writes to.
+    pub fn data_refs(&self) -> Vec<&str> {
+        match self.direction {
+            DataAssociationDirection::Input => {
+                self.source_refs.iter().map(|id| id.as_str()).collect()
+            }
+            DataAssociationDirection::Output => {
+                self.target_ref.iter().map(|id| id.as_str()).collect()
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::BusinessProcessModelAndNotation;
+
+/// A diagram of a BPMN model, as given by the BPMN DI (diagram interchange) standard.
+/// Contains the layout of the model; not its behaviour.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BPMNDiagram {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub plane: BPMNPlane,
+}
+
+/// The plane of a diagram, which contains the shapes and edges.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BPMNPlane {
+    pub id: Option<String>,
+
+    /// The id of the process or collaboration that is depicted.
+    pub bpmn_element: Option<String>,
+    pub shapes: Vec<BPMNShape>,
+    pub edges: Vec<BPMNEdge>,
+}
+
+/// The layout of an element or pool.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BPMNShape {
+    pub id: Option<String>,
+
+    /// The id of the element that is depicted.
+    pub bpmn_element: String,
+    pub bounds: BPMNBounds,
+    pub is_expanded: Option<bool>,
+    pub is_horizontal: Option<bool>,
+    pub is_marker_visible: Option<bool>,
+    pub label: Option<BPMNLabel>,
+}
+
+/// The layout of a sequence flow or message flow.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BPMNEdge {
+    pub id: Option<String>,
+
+    /// The id of the flow that is depicted.
+    pub bpmn_element: String,
+    pub source_element: Option<String>,
+    pub target_element: Option<String>,
+    pub waypoints: Vec<BPMNPoint>,
+    pub label: Option<BPMNLabel>,
+}
+
+/// The label of a shape or edge.
+/// A label without bounds is positioned by the modeller.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BPMNLabel {
+    pub bounds: Option<BPMNBounds>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BPMNBounds {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BPMNPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl BusinessProcessModelAndNotation {
+    /// Returns the shape of the element with the given id, if the model has layout information for it.
+    pub fn shape_of(&self, element_id: &str) -> Option<&BPMNShape> {
+        self.diagrams
+            .iter()
+            .flat_map(|diagram| diagram.plane.shapes.iter())
+            .find(|shape| shape.bpmn_element == element_id)
+    }
+
+    /// Returns the edge of the flow with the given id, if the model has layout information for it.
+    pub fn edge_of(&self, flow_id: &str) -> Option<&BPMNEdge> {
+        self.diagrams
+            .iter()
+            .flat_map(|diagram| diagram.plane.edges.iter())
+            .find(|edge| edge.bpmn_element == flow_id)
+    }
+
+    /// Returns whether the model has layout (BPMN DI) information.
+    pub fn has_diagram(&self) -> bool {
+        !self.diagrams.is_empty()
+    }
+}
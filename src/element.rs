@@ -1,24 +1,38 @@
 use crate::{
     BusinessProcessModelAndNotation,
     elements::{
-        collapsed_pool::BPMNCollapsedPool, collapsed_sub_process::BPMNCollapsedSubProcess,
-        end_event::BPMNEndEvent, event_based_gateway::BPMNEventBasedGateway,
-        exclusive_gateway::BPMNExclusiveGateway, expanded_sub_process::BPMNExpandedSubProcess,
-        inclusive_gateway::BPMNInclusiveGateway,
+        boundary_event::BPMNBoundaryEvent, business_rule_task::BPMNBusinessRuleTask,
+        call_activity::BPMNCallActivity, collapsed_pool::BPMNCollapsedPool,
+        collapsed_sub_process::BPMNCollapsedSubProcess, complex_gateway::BPMNComplexGateway,
+        end_event::BPMNEndEvent, error_end_event::BPMNErrorEndEvent,
+        error_start_event::BPMNErrorStartEvent, event_based_gateway::BPMNEventBasedGateway,
+        event_sub_process::BPMNEventSubProcess, exclusive_gateway::BPMNExclusiveGateway,
+        expanded_sub_process::BPMNExpandedSubProcess, inclusive_gateway::BPMNInclusiveGateway,
         intermediate_catch_event::BPMNIntermediateCatchEvent,
-        intermediate_throw_event::BPMNIntermediateThrowEvent, manual_task::BPMNManualTask,
+        intermediate_throw_event::BPMNIntermediateThrowEvent,
+        link_intermediate_catch_event::BPMNLinkIntermediateCatchEvent,
+        link_intermediate_throw_event::BPMNLinkIntermediateThrowEvent, manual_task::BPMNManualTask,
         message_end_event::BPMNMessageEndEvent,
         message_intermediate_catch_event::BPMNMessageIntermediateCatchEvent,
         message_intermediate_throw_event::BPMNMessageIntermediateThrowEvent,
         message_start_event::BPMNMessageStartEvent, parallel_gateway::BPMNParallelGateway,
-        process::BPMNProcess, receive_task::BPMNReceiveTask, start_event::BPMNStartEvent,
-        task::BPMNTask, timer_intermediate_catch_event::BPMNTimerIntermediateCatchEvent,
+        process::BPMNProcess, receive_task::BPMNReceiveTask, script_task::BPMNScriptTask,
+        send_task::BPMNSendTask, service_task::BPMNServiceTask,
+        signal_end_event::BPMNSignalEndEvent,
+        signal_intermediate_catch_event::BPMNSignalIntermediateCatchEvent,
+        signal_intermediate_throw_event::BPMNSignalIntermediateThrowEvent,
+        signal_start_event::BPMNSignalStartEvent, start_event::BPMNStartEvent, task::BPMNTask,
+        terminate_end_event::BPMNTerminateEndEvent,
+        timer_intermediate_catch_event::BPMNTimerIntermediateCatchEvent,
         timer_start_event::BPMNTimerStartEvent, user_task::BPMNUserTask,
     },
+    error::BPMNError,
     marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    multi_instance::BPMNMultiInstance,
     parser::parser_state::GlobalIndex,
     semantics::TransitionIndex,
     sequence_flow::BPMNSequenceFlow,
+    standard_loop::BPMNStandardLoop,
     traits::{
         objectable::BPMNObject, processable::Processable, searchable::Searchable,
         transitionable::Transitionable, writable::Writable,
@@ -32,15 +46,24 @@ use strum_macros::EnumIs;
 
 #[derive(Clone, Debug, EnumIs)]
 pub enum BPMNElement {
+    BoundaryEvent(BPMNBoundaryEvent),
+    BusinessRuleTask(BPMNBusinessRuleTask),
+    CallActivity(BPMNCallActivity),
     CollapsedPool(BPMNCollapsedPool),
     CollapsedSubProcess(BPMNCollapsedSubProcess),
+    ComplexGateway(BPMNComplexGateway),
     EndEvent(BPMNEndEvent),
+    ErrorEndEvent(BPMNErrorEndEvent),
+    ErrorStartEvent(BPMNErrorStartEvent),
     EventBasedGateway(BPMNEventBasedGateway),
+    EventSubProcess(BPMNEventSubProcess),
     ExclusiveGateway(BPMNExclusiveGateway),
     ExpandedSubProcess(BPMNExpandedSubProcess),
     InclusiveGateway(BPMNInclusiveGateway),
     IntermediateCatchEvent(BPMNIntermediateCatchEvent),
     IntermediateThrowEvent(BPMNIntermediateThrowEvent),
+    LinkIntermediateCatchEvent(BPMNLinkIntermediateCatchEvent),
+    LinkIntermediateThrowEvent(BPMNLinkIntermediateThrowEvent),
     ManualTask(BPMNManualTask),
     MessageEndEvent(BPMNMessageEndEvent),
     MessageIntermediateCatchEvent(BPMNMessageIntermediateCatchEvent),
@@ -49,13 +72,145 @@ pub enum BPMNElement {
     ParallelGateway(BPMNParallelGateway),
     Process(BPMNProcess),
     ReceiveTask(BPMNReceiveTask),
+    ScriptTask(BPMNScriptTask),
+    SendTask(BPMNSendTask),
+    ServiceTask(BPMNServiceTask),
+    SignalEndEvent(BPMNSignalEndEvent),
+    SignalIntermediateCatchEvent(BPMNSignalIntermediateCatchEvent),
+    SignalIntermediateThrowEvent(BPMNSignalIntermediateThrowEvent),
+    SignalStartEvent(BPMNSignalStartEvent),
     StartEvent(BPMNStartEvent),
     Task(BPMNTask),
+    TerminateEndEvent(BPMNTerminateEndEvent),
     TimerIntermediateCatchEvent(BPMNTimerIntermediateCatchEvent),
     TimerStartEvent(BPMNTimerStartEvent),
     UserTask(BPMNUserTask),
 }
 
+impl BPMNElement {
+    /// Returns the activity of this element, if it has one.
+    pub(crate) fn activity_mut(&mut self) -> Option<&mut Activity> {
+        match self {
+            BPMNElement::BusinessRuleTask(x) => Some(&mut x.activity),
+            BPMNElement::CollapsedSubProcess(x) => Some(&mut x.activity),
+            BPMNElement::ManualTask(x) => Some(&mut x.activity),
+            BPMNElement::ReceiveTask(x) => Some(&mut x.activity),
+            BPMNElement::ScriptTask(x) => Some(&mut x.activity),
+            BPMNElement::SendTask(x) => Some(&mut x.activity),
+            BPMNElement::ServiceTask(x) => Some(&mut x.activity),
+            BPMNElement::Task(x) => Some(&mut x.activity),
+            BPMNElement::UserTask(x) => Some(&mut x.activity),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of this element, if it has one.
+    /// Tasks and collapsed sub-processes keep their name as the label of their activity instead.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            BPMNElement::BoundaryEvent(x) => x.name.as_deref(),
+            BPMNElement::CallActivity(x) => x.name.as_deref(),
+            BPMNElement::CollapsedPool(x) => x.name.as_deref(),
+            BPMNElement::ComplexGateway(x) => x.name.as_deref(),
+            BPMNElement::EndEvent(x) => x.name.as_deref(),
+            BPMNElement::ErrorEndEvent(x) => x.name.as_deref(),
+            BPMNElement::ErrorStartEvent(x) => x.name.as_deref(),
+            BPMNElement::EventBasedGateway(x) => x.name.as_deref(),
+            BPMNElement::EventSubProcess(x) => x.name.as_deref(),
+            BPMNElement::ExclusiveGateway(x) => x.name.as_deref(),
+            BPMNElement::ExpandedSubProcess(x) => x.name.as_deref(),
+            BPMNElement::InclusiveGateway(x) => x.name.as_deref(),
+            BPMNElement::IntermediateCatchEvent(x) => x.name.as_deref(),
+            BPMNElement::IntermediateThrowEvent(x) => x.name.as_deref(),
+            BPMNElement::LinkIntermediateCatchEvent(x) => x.name.as_deref(),
+            BPMNElement::LinkIntermediateThrowEvent(x) => x.name.as_deref(),
+            BPMNElement::MessageEndEvent(x) => x.name.as_deref(),
+            BPMNElement::MessageIntermediateCatchEvent(x) => x.name.as_deref(),
+            BPMNElement::MessageIntermediateThrowEvent(x) => x.name.as_deref(),
+            BPMNElement::MessageStartEvent(x) => x.name.as_deref(),
+            BPMNElement::ParallelGateway(x) => x.name.as_deref(),
+            BPMNElement::Process(x) => x.name.as_deref(),
+            BPMNElement::SignalEndEvent(x) => x.name.as_deref(),
+            BPMNElement::SignalIntermediateCatchEvent(x) => x.name.as_deref(),
+            BPMNElement::SignalIntermediateThrowEvent(x) => x.name.as_deref(),
+            BPMNElement::SignalStartEvent(x) => x.name.as_deref(),
+            BPMNElement::StartEvent(x) => x.name.as_deref(),
+            BPMNElement::TerminateEndEvent(x) => x.name.as_deref(),
+            BPMNElement::TimerIntermediateCatchEvent(x) => x.name.as_deref(),
+            BPMNElement::TimerStartEvent(x) => x.name.as_deref(),
+            _ => None,
+        }
+    }
+    /// Returns the name of this element, if it can have one that is not the label of an activity.
+    pub(crate) fn name_mut(&mut self) -> Option<&mut Option<String>> {
+        match self {
+            BPMNElement::BoundaryEvent(x) => Some(&mut x.name),
+            BPMNElement::CallActivity(x) => Some(&mut x.name),
+            BPMNElement::CollapsedPool(x) => Some(&mut x.name),
+            BPMNElement::ComplexGateway(x) => Some(&mut x.name),
+            BPMNElement::EndEvent(x) => Some(&mut x.name),
+            BPMNElement::ErrorEndEvent(x) => Some(&mut x.name),
+            BPMNElement::ErrorStartEvent(x) => Some(&mut x.name),
+            BPMNElement::EventBasedGateway(x) => Some(&mut x.name),
+            BPMNElement::EventSubProcess(x) => Some(&mut x.name),
+            BPMNElement::ExclusiveGateway(x) => Some(&mut x.name),
+            BPMNElement::ExpandedSubProcess(x) => Some(&mut x.name),
+            BPMNElement::InclusiveGateway(x) => Some(&mut x.name),
+            BPMNElement::IntermediateCatchEvent(x) => Some(&mut x.name),
+            BPMNElement::IntermediateThrowEvent(x) => Some(&mut x.name),
+            BPMNElement::LinkIntermediateCatchEvent(x) => Some(&mut x.name),
+            BPMNElement::LinkIntermediateThrowEvent(x) => Some(&mut x.name),
+            BPMNElement::MessageEndEvent(x) => Some(&mut x.name),
+            BPMNElement::MessageIntermediateCatchEvent(x) => Some(&mut x.name),
+            BPMNElement::MessageIntermediateThrowEvent(x) => Some(&mut x.name),
+            BPMNElement::MessageStartEvent(x) => Some(&mut x.name),
+            BPMNElement::ParallelGateway(x) => Some(&mut x.name),
+            BPMNElement::Process(x) => Some(&mut x.name),
+            BPMNElement::SignalEndEvent(x) => Some(&mut x.name),
+            BPMNElement::SignalIntermediateCatchEvent(x) => Some(&mut x.name),
+            BPMNElement::SignalIntermediateThrowEvent(x) => Some(&mut x.name),
+            BPMNElement::SignalStartEvent(x) => Some(&mut x.name),
+            BPMNElement::StartEvent(x) => Some(&mut x.name),
+            BPMNElement::TerminateEndEvent(x) => Some(&mut x.name),
+            BPMNElement::TimerIntermediateCatchEvent(x) => Some(&mut x.name),
+            BPMNElement::TimerStartEvent(x) => Some(&mut x.name),
+            _ => None,
+        }
+    }
+
+    /// Returns the multi-instance loop characteristics of this element, if it is a multi-instance activity.
+    pub fn multi_instance(&self) -> Option<&BPMNMultiInstance> {
+        match self {
+            BPMNElement::BusinessRuleTask(x) => x.multi_instance.as_ref(),
+            BPMNElement::ExpandedSubProcess(x) => x.multi_instance.as_ref(),
+            BPMNElement::ManualTask(x) => x.multi_instance.as_ref(),
+            BPMNElement::ReceiveTask(x) => x.multi_instance.as_ref(),
+            BPMNElement::ScriptTask(x) => x.multi_instance.as_ref(),
+            BPMNElement::SendTask(x) => x.multi_instance.as_ref(),
+            BPMNElement::ServiceTask(x) => x.multi_instance.as_ref(),
+            BPMNElement::Task(x) => x.multi_instance.as_ref(),
+            BPMNElement::UserTask(x) => x.multi_instance.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the standard loop characteristics of this element, if it is a loop activity.
+    pub fn standard_loop(&self) -> Option<&BPMNStandardLoop> {
+        match self {
+            BPMNElement::BusinessRuleTask(x) => x.standard_loop.as_ref(),
+            BPMNElement::ExpandedSubProcess(x) => x.standard_loop.as_ref(),
+            BPMNElement::ManualTask(x) => x.standard_loop.as_ref(),
+            BPMNElement::ReceiveTask(x) => x.standard_loop.as_ref(),
+            BPMNElement::ScriptTask(x) => x.standard_loop.as_ref(),
+            BPMNElement::SendTask(x) => x.standard_loop.as_ref(),
+            BPMNElement::ServiceTask(x) => x.standard_loop.as_ref(),
+            BPMNElement::Task(x) => x.standard_loop.as_ref(),
+            BPMNElement::UserTask(x) => x.standard_loop.as_ref(),
+            _ => None,
+        }
+    }
+}
+
 pub trait BPMNElementTrait {
     ///verify that structural requirements specific to this element are fulfilled
     fn verify_structural_correctness(
@@ -86,15 +241,24 @@ pub trait BPMNElementTrait {
 macro_rules! enums {
     ($self:ident, $fn:ident, $($v:ident),*) => {
         match $self {
+            BPMNElement::BoundaryEvent(x) => BPMNBoundaryEvent::$fn(x, $($v),*),
+            BPMNElement::BusinessRuleTask(x) => BPMNBusinessRuleTask::$fn(x, $($v),*),
+            BPMNElement::CallActivity(x) => BPMNCallActivity::$fn(x, $($v),*),
             BPMNElement::CollapsedPool(x) => BPMNCollapsedPool::$fn(x, $($v),*),
             BPMNElement::CollapsedSubProcess(x) => BPMNCollapsedSubProcess::$fn(x, $($v),*),
+            BPMNElement::ComplexGateway(x) => BPMNComplexGateway::$fn(x, $($v),*),
             BPMNElement::EndEvent(x) => BPMNEndEvent::$fn(x, $($v),*),
+            BPMNElement::ErrorEndEvent(x) => BPMNErrorEndEvent::$fn(x, $($v),*),
+            BPMNElement::ErrorStartEvent(x) => BPMNErrorStartEvent::$fn(x, $($v),*),
             BPMNElement::EventBasedGateway(x) => BPMNEventBasedGateway::$fn(x, $($v),*),
+            BPMNElement::EventSubProcess(x) => BPMNEventSubProcess::$fn(x, $($v),*),
             BPMNElement::ExclusiveGateway(x) => BPMNExclusiveGateway::$fn(x, $($v),*),
             BPMNElement::ExpandedSubProcess(x) => BPMNExpandedSubProcess::$fn(x, $($v),*),
             BPMNElement::InclusiveGateway(x) => BPMNInclusiveGateway::$fn(x, $($v),*),
             BPMNElement::IntermediateCatchEvent(x) => BPMNIntermediateCatchEvent::$fn(x, $($v),*),
             BPMNElement::IntermediateThrowEvent(x) => BPMNIntermediateThrowEvent::$fn(x, $($v),*),
+            BPMNElement::LinkIntermediateCatchEvent(x) => BPMNLinkIntermediateCatchEvent::$fn(x, $($v),*),
+            BPMNElement::LinkIntermediateThrowEvent(x) => BPMNLinkIntermediateThrowEvent::$fn(x, $($v),*),
             BPMNElement::ManualTask(x) => BPMNManualTask::$fn(x, $($v),*),
             BPMNElement::MessageEndEvent(x) => BPMNMessageEndEvent::$fn(x, $($v),*),
             BPMNElement::MessageIntermediateCatchEvent(x) => {
@@ -107,8 +271,16 @@ macro_rules! enums {
             BPMNElement::ParallelGateway(x) => BPMNParallelGateway::$fn(x, $($v),*),
             BPMNElement::Process(x) => BPMNProcess::$fn(x, $($v),*),
             BPMNElement::ReceiveTask(x) => BPMNReceiveTask::$fn(x, $($v),*),
+            BPMNElement::ScriptTask(x) => BPMNScriptTask::$fn(x, $($v),*),
+            BPMNElement::SendTask(x) => BPMNSendTask::$fn(x, $($v),*),
+            BPMNElement::ServiceTask(x) => BPMNServiceTask::$fn(x, $($v),*),
+            BPMNElement::SignalEndEvent(x) => BPMNSignalEndEvent::$fn(x, $($v),*),
+            BPMNElement::SignalIntermediateCatchEvent(x) => BPMNSignalIntermediateCatchEvent::$fn(x, $($v),*),
+            BPMNElement::SignalIntermediateThrowEvent(x) => BPMNSignalIntermediateThrowEvent::$fn(x, $($v),*),
+            BPMNElement::SignalStartEvent(x) => BPMNSignalStartEvent::$fn(x, $($v),*),
             BPMNElement::StartEvent(x) => BPMNStartEvent::$fn(x, $($v),*),
             BPMNElement::Task(x) => BPMNTask::$fn(x, $($v),*),
+            BPMNElement::TerminateEndEvent(x) => BPMNTerminateEndEvent::$fn(x, $($v),*),
             BPMNElement::TimerIntermediateCatchEvent(x) => BPMNTimerIntermediateCatchEvent::$fn(x, $($v),*),
             BPMNElement::TimerStartEvent(x) => BPMNTimerStartEvent::$fn(x, $($v),*),
             BPMNElement::UserTask(x) => BPMNUserTask::$fn(x, $($v),*)
@@ -146,7 +318,17 @@ impl BPMNElementTrait for BPMNElement {
         parent: &dyn Processable,
         bpmn: &BusinessProcessModelAndNotation,
     ) -> Result<()> {
-        enums!(self, verify_structural_correctness, parent, bpmn)
+        enums!(self, verify_structural_correctness, parent, bpmn).map_err(|error| {
+            //keep the most specific typed error, such as that of a child element
+            if error.downcast_ref::<BPMNError>().is_some() {
+                error
+            } else {
+                error.context(BPMNError::StructuralViolation {
+                    element_ids: vec![self.id().to_string()],
+                    message: format!("Element `{}` is not structurally correct.", self.id()),
+                })
+            }
+        })
     }
 }
 
@@ -156,7 +338,12 @@ impl Searchable for BPMNElement {
             Some((Some(self.local_index()), self.global_index()))
         } else if self.id() == search_id {
             Some((None, self.global_index()))
-        } else if let BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess {
+        } else if let BPMNElement::EventSubProcess(BPMNEventSubProcess {
+            elements,
+            sequence_flows,
+            ..
+        })
+        | BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess {
             elements,
             sequence_flows,
             ..
@@ -178,7 +365,9 @@ impl Searchable for BPMNElement {
     }
 
     fn id_2_local_index(&self, id: &str) -> Option<usize> {
-        if let BPMNElement::ExpandedSubProcess(process) = self {
+        if let BPMNElement::EventSubProcess(process) = self {
+            process.id_2_local_index(id)
+        } else if let BPMNElement::ExpandedSubProcess(process) = self {
             process.id_2_local_index(id)
         } else if let BPMNElement::Process(process) = self {
             process.id_2_local_index(id)
@@ -193,6 +382,8 @@ impl Searchable for BPMNElement {
     ) -> Option<(&BPMNSequenceFlow, Option<&dyn Processable>)> {
         if let BPMNElement::Process(process) = self {
             process.global_index_2_sequence_flow_and_parent(sequence_flow_global_index)
+        } else if let BPMNElement::EventSubProcess(process) = self {
+            process.global_index_2_sequence_flow_and_parent(sequence_flow_global_index)
         } else if let BPMNElement::ExpandedSubProcess(process) = self {
             process.global_index_2_sequence_flow_and_parent(sequence_flow_global_index)
         } else {
@@ -201,7 +392,8 @@ impl Searchable for BPMNElement {
     }
 
     fn all_elements_ref(&self) -> Vec<&BPMNElement> {
-        if let BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })
+        if let BPMNElement::EventSubProcess(BPMNEventSubProcess { elements, .. })
+        | BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })
         | BPMNElement::Process(BPMNProcess { elements, .. }) = self
         {
             let mut result = elements
@@ -219,6 +411,8 @@ impl Searchable for BPMNElement {
     fn parent_of(&self, global_index: GlobalIndex) -> (Option<&dyn Processable>, bool) {
         if let BPMNElement::Process(process) = self {
             process.parent_of(global_index)
+        } else if let BPMNElement::EventSubProcess(process) = self {
+            process.parent_of(global_index)
         } else if let BPMNElement::ExpandedSubProcess(process) = self {
             process.parent_of(global_index)
         } else if self.global_index() == global_index {
@@ -230,6 +424,7 @@ impl Searchable for BPMNElement {
 
     fn all_sequence_flows_ref(&self) -> Vec<&BPMNSequenceFlow> {
         match self {
+            BPMNElement::EventSubProcess(p) => p.all_sequence_flows_ref(),
             BPMNElement::ExpandedSubProcess(p) => p.all_sequence_flows_ref(),
             BPMNElement::Process(p) => p.all_sequence_flows_ref(),
             _ => vec![],
@@ -241,6 +436,9 @@ impl Searchable for BPMNElement {
         sequence_flow_global_index: GlobalIndex,
     ) -> Option<&mut BPMNSequenceFlow> {
         match self {
+            BPMNElement::EventSubProcess(p) => {
+                p.global_index_2_sequence_flow_mut(sequence_flow_global_index)
+            }
             BPMNElement::ExpandedSubProcess(p) => {
                 p.global_index_2_sequence_flow_mut(sequence_flow_global_index)
             }
@@ -254,7 +452,8 @@ impl Searchable for BPMNElement {
     fn global_index_2_element(&self, index: GlobalIndex) -> Option<&BPMNElement> {
         if self.global_index() == index {
             Some(self)
-        } else if let BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })
+        } else if let BPMNElement::EventSubProcess(BPMNEventSubProcess { elements, .. })
+        | BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })
         | BPMNElement::Process(BPMNProcess { elements, .. }) = self
         {
             elements.global_index_2_element(index)
@@ -266,7 +465,8 @@ impl Searchable for BPMNElement {
     fn global_index_2_element_mut(&mut self, index: GlobalIndex) -> Option<&mut BPMNElement> {
         if self.global_index() == index {
             Some(self)
-        } else if let BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })
+        } else if let BPMNElement::EventSubProcess(BPMNEventSubProcess { elements, .. })
+        | BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })
         | BPMNElement::Process(BPMNProcess { elements, .. }) = self
         {
             elements.global_index_2_element_mut(index)
@@ -276,7 +476,8 @@ impl Searchable for BPMNElement {
     }
 
     fn local_index_2_element_mut(&mut self, index: usize) -> Option<&mut BPMNElement> {
-        if let BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })
+        if let BPMNElement::EventSubProcess(BPMNEventSubProcess { elements, .. })
+        | BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })
         | BPMNElement::Process(BPMNProcess { elements, .. }) = self
         {
             elements.local_index_2_element_mut(index)
@@ -286,7 +487,8 @@ impl Searchable for BPMNElement {
     }
 
     fn local_index_2_element(&self, index: usize) -> Option<&BPMNElement> {
-        if let BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })
+        if let BPMNElement::EventSubProcess(BPMNEventSubProcess { elements, .. })
+        | BPMNElement::ExpandedSubProcess(BPMNExpandedSubProcess { elements, .. })
         | BPMNElement::Process(BPMNProcess { elements, .. }) = self
         {
             elements.local_index_2_element(index)
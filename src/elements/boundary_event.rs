@@ -0,0 +1,640 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    element::{BPMNElement, BPMNElementTrait},
+    if_not::{IfNot, IfNotDefault},
+    marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    parser::parser_state::GlobalIndex,
+    semantics::TransitionIndex,
+    timer::{BPMNTimer, BPMNTimerSpecification, BPMNTimerType},
+    traits::{
+        objectable::{BPMNObject, EMPTY_FLOWS},
+        processable::Processable,
+        transitionable::{
+            Transitionable, execute_transition_parallel_split,
+            transition_2_consumed_tokens_message, transition_2_produced_tokens_concurrent_split,
+        },
+    },
+};
+use anyhow::{Result, anyhow};
+use bitvec::{bitvec, vec::BitVec};
+use ebi_activity_key::Activity;
+use ebi_arithmetic::{Fraction, One};
+use strum_macros::EnumIs;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIs)]
+pub enum BoundaryEventType {
+    Error,
+    Message,
+    Timer,
+}
+
+/// An event that is attached to the boundary of an activity (its host).
+/// An interrupting boundary event (`cancelActivity`) withdraws the host: the token waiting for a task, or the instance of an expanded sub-process.
+/// A non-interrupting boundary event leaves the host alone and forks an extra token onto its outgoing sequence flows.
+/// It fires at most once per activation of the host, or as often as its cycle repeats for a `timeCycle` timer.
+/// Its firings are kept as element tokens of the boundary event, until the host is no longer active.
+/// The firings are counted for the host as a whole rather than per activation: if the host has several tokens or instances,
+/// the event may fire its limit times the number of activations, distributed over the activations in any way.
+/// When an activation ends, the count is lowered to what the remaining activations allow.
+#[derive(Debug, Clone)]
+pub struct BPMNBoundaryEvent {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+    pub(crate) event_type: BoundaryEventType,
+    pub(crate) marker_id: Option<String>,
+    pub(crate) error_ref: Option<String>,
+    pub(crate) message_ref: Option<String>,
+    pub(crate) timer: Option<BPMNTimer>,
+    pub(crate) cancel_activity: bool,
+    pub(crate) attached_to_id: String,
+    ///local index of the host, in the same parent as the boundary event
+    pub(crate) attached_to_local_index: usize,
+    pub(crate) outgoing_sequence_flows: Vec<usize>,
+    pub(crate) incoming_message_flow: Option<usize>,
+}
+
+impl BPMNBoundaryEvent {
+    pub fn event_type(&self) -> BoundaryEventType {
+        self.event_type
+    }
+
+    pub fn cancels_activity(&self) -> bool {
+        self.cancel_activity
+    }
+
+    pub fn attached_to_id(&self) -> &str {
+        &self.attached_to_id
+    }
+
+    pub fn attached_to_local_index(&self) -> usize {
+        self.attached_to_local_index
+    }
+
+    /// Returns the id of the error that is caught by an error boundary event, if any.
+    /// An error boundary event without one catches all errors.
+    pub fn error_ref(&self) -> Option<&str> {
+        self.error_ref.as_deref()
+    }
+
+    /// Returns the id of the message that is caught by a message boundary event, if any.
+    pub fn message_ref(&self) -> Option<&str> {
+        self.message_ref.as_deref()
+    }
+
+    /// Returns the timer of a timer boundary event, if it has one.
+    pub fn timer(&self) -> Option<&BPMNTimer> {
+        self.timer.as_ref()
+    }
+
+    /// Returns the error boundary event that is attached to the host with the given local index and that catches the error with the given `errorRef`, if there is one.
+    /// A boundary event that catches this specific error takes precedence over one that catches all errors.
+    pub(crate) fn error_boundary_event_of<'a>(
+        host_local_index: usize,
+        error_ref: Option<&str>,
+        parent: &'a dyn Processable,
+    ) -> Option<&'a BPMNBoundaryEvent> {
+        let error_boundary_events =
+            parent
+                .elements_non_recursive()
+                .iter()
+                .filter_map(move |element| match element {
+                    BPMNElement::BoundaryEvent(boundary_event)
+                        if boundary_event.event_type.is_error()
+                            && boundary_event.attached_to_local_index == host_local_index =>
+                    {
+                        Some(boundary_event)
+                    }
+                    _ => None,
+                });
+        error_boundary_events
+            .clone()
+            .find(|boundary_event| {
+                boundary_event.error_ref.is_some()
+                    && boundary_event.error_ref.as_deref() == error_ref
+            })
+            .or_else(|| {
+                error_boundary_events
+                    .clone()
+                    .find(|boundary_event| boundary_event.error_ref.is_none())
+            })
+    }
+
+    fn host<'a>(&self, parent: &'a dyn Processable) -> Result<&'a BPMNElement> {
+        parent
+            .elements_non_recursive()
+            .get(self.attached_to_local_index)
+            .and_if_not("Host of boundary event not found.")
+    }
+
+    /// Returns whether the host keeps its instances in the sub-markings of its element.
+    /// This is the case for expanded sub-processes, call activities, and multi-instance and loop activities.
+    fn host_has_instances(host: &BPMNElement) -> bool {
+        host.is_expanded_sub_process()
+            || host.is_call_activity()
+            || host.multi_instance().is_some()
+            || host.standard_loop().is_some()
+    }
+
+    /// Returns whether the trigger of the event is available.
+    fn trigger_is_present(
+        &self,
+        root_marking: &BPMNRootMarking,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<bool> {
+        match self.event_type {
+            BoundaryEventType::Timer => {
+                //timers may expire at any moment
+                Ok(true)
+            }
+            BoundaryEventType::Message => {
+                if let Some(message_flow_index) = self.incoming_message_flow {
+                    //there is a connected message flow
+                    let source = bpmn.message_flow_index_2_source(message_flow_index)?;
+                    if !source.outgoing_message_flows_always_have_tokens() {
+                        //this message must actually be there
+                        Ok(root_marking.message_flow_2_tokens[message_flow_index] > 0)
+                    } else {
+                        //if the message flow has always tokens, we do not need to check the marking
+                        Ok(true)
+                    }
+                } else {
+                    //if there is no incoming message flow, we assume there is always a message
+                    Ok(true)
+                }
+            }
+            BoundaryEventType::Error => {
+                //errors are caught by the host when an error end event within it fires, not by a transition of their own
+                Ok(false)
+            }
+        }
+    }
+
+    /// Returns how often the event may fire per activation of the host, or None if there is no limit.
+    /// An interrupting event ends the activation, and a `timeCycle` timer fires once per repetition of its cycle.
+    /// A cycle without a number of repetitions would repeat indefinitely; it fires once per activation.
+    fn firing_limit(&self) -> Option<u64> {
+        if self.cancel_activity {
+            return None;
+        }
+        match &self.timer {
+            Some(timer) if timer.timer_type == BPMNTimerType::Cycle => match &timer.specification {
+                Some(BPMNTimerSpecification::Cycle { repetitions, .. }) => {
+                    Some(repetitions.unwrap_or(1))
+                }
+                _ => Some(1),
+            },
+            _ => Some(1),
+        }
+    }
+
+    /// Returns the number of activations of the host: its instances, or the tokens it is waiting with.
+    fn host_activations(host: &BPMNElement, sub_marking: &BPMNSubMarking) -> u64 {
+        if Self::host_has_instances(host) {
+            sub_marking.element_index_2_sub_markings[host.local_index()].len() as u64
+        } else if host.incoming_sequence_flows().is_empty() {
+            sub_marking.element_index_2_tokens[host.local_index()]
+        } else {
+            host.incoming_sequence_flows()
+                .iter()
+                .map(|sequence_flow_index| sub_marking.sequence_flow_2_tokens[*sequence_flow_index])
+                .sum()
+        }
+    }
+
+    /// Returns whether the event has fired less often than the activations of its host allow.
+    fn can_fire_again(&self, host: &BPMNElement, sub_marking: &BPMNSubMarking) -> bool {
+        self.firing_limit().is_none_or(|limit| {
+            sub_marking.element_index_2_tokens[self.local_index]
+                < limit * Self::host_activations(host, sub_marking)
+        })
+    }
+
+    /// Forgets the firings of non-interrupting boundary events of activations that are no longer active.
+    /// Must be called after every transition in the given process or sub-process instance.
+    pub(crate) fn bound_firings(elements: &[BPMNElement], sub_marking: &mut BPMNSubMarking) {
+        for element in elements {
+            let BPMNElement::BoundaryEvent(boundary_event) = element else {
+                continue;
+            };
+            if let (Some(limit), Some(host)) = (
+                boundary_event.firing_limit(),
+                elements.get(boundary_event.attached_to_local_index),
+            ) {
+                let maximum = limit * Self::host_activations(host, sub_marking);
+                let firings = &mut sub_marking.element_index_2_tokens[boundary_event.local_index];
+                *firings = (*firings).min(maximum);
+            }
+        }
+    }
+
+    /// For a host that does not have instances, returns the incoming sequence flow of the host that carries a token.
+    /// Returns Some(None) if the host is enabled by an element token, and None if the host is not enabled.
+    fn host_token(host: &BPMNElement, sub_marking: &BPMNSubMarking) -> Option<Option<usize>> {
+        if host.incoming_sequence_flows().is_empty() {
+            //the host is in initiation mode 2
+            if sub_marking.element_index_2_tokens[host.local_index()] >= 1 {
+                Some(None)
+            } else {
+                None
+            }
+        } else {
+            host.incoming_sequence_flows()
+                .iter()
+                .find(|sequence_flow_index| {
+                    sub_marking.sequence_flow_2_tokens[**sequence_flow_index] >= 1
+                })
+                .map(|sequence_flow_index| Some(*sequence_flow_index))
+        }
+    }
+}
+
+impl BPMNElementTrait for BPMNBoundaryEvent {
+    fn add_incoming_sequence_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!(
+            "boundary events cannot have incoming sequence flows"
+        ))
+    }
+
+    fn add_outgoing_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
+        self.outgoing_sequence_flows.push(flow_index);
+        Ok(())
+    }
+
+    fn clear_incoming_sequence_flows(&mut self) {}
+
+    fn clear_outgoing_sequence_flows(&mut self) {
+        self.outgoing_sequence_flows.clear();
+    }
+
+    fn add_incoming_message_flow(&mut self, flow_index: usize) -> Result<()> {
+        if !self.event_type.is_message() {
+            return Err(anyhow!(
+                "only message boundary events can have incoming message flows"
+            ));
+        }
+        if self.incoming_message_flow.is_some() {
+            return Err(anyhow!("cannot add a second incoming message flow"));
+        }
+        self.incoming_message_flow = Some(flow_index);
+        Ok(())
+    }
+
+    fn add_outgoing_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!(
+            "boundary events cannot have outgoing message flows"
+        ))
+    }
+
+    fn verify_structural_correctness(
+        &self,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        let host = self.host(parent)?;
+        if host.id() != self.attached_to_id {
+            return Err(anyhow!(
+                "Boundary event `{}` should be attached to `{}`, but its host is `{}`.",
+                self.id,
+                self.attached_to_id,
+                host.id()
+            ));
+        }
+        if self.event_type.is_error() && !self.cancel_activity {
+            return Err(anyhow!(
+                "Error boundary event `{}` must be interrupting.",
+                self.id
+            ));
+        }
+        match host {
+            BPMNElement::BusinessRuleTask(_)
+            | BPMNElement::CallActivity(_)
+            | BPMNElement::CollapsedSubProcess(_)
+            | BPMNElement::ExpandedSubProcess(_)
+            | BPMNElement::ManualTask(_)
+            | BPMNElement::ReceiveTask(_)
+            | BPMNElement::ScriptTask(_)
+            | BPMNElement::SendTask(_)
+            | BPMNElement::ServiceTask(_)
+            | BPMNElement::Task(_)
+            | BPMNElement::UserTask(_) => Ok(()),
+            BPMNElement::BoundaryEvent(_)
+            | BPMNElement::CollapsedPool(_)
+            | BPMNElement::ComplexGateway(_)
+            | BPMNElement::EndEvent(_)
+            | BPMNElement::ErrorEndEvent(_)
+            | BPMNElement::ErrorStartEvent(_)
+            | BPMNElement::EventBasedGateway(_)
+            | BPMNElement::EventSubProcess(_)
+            | BPMNElement::ExclusiveGateway(_)
+            | BPMNElement::InclusiveGateway(_)
+            | BPMNElement::IntermediateCatchEvent(_)
+            | BPMNElement::IntermediateThrowEvent(_)
+            | BPMNElement::LinkIntermediateCatchEvent(_)
+            | BPMNElement::LinkIntermediateThrowEvent(_)
+            | BPMNElement::MessageEndEvent(_)
+            | BPMNElement::MessageIntermediateCatchEvent(_)
+            | BPMNElement::MessageIntermediateThrowEvent(_)
+            | BPMNElement::MessageStartEvent(_)
+            | BPMNElement::ParallelGateway(_)
+            | BPMNElement::Process(_)
+            | BPMNElement::SignalEndEvent(_)
+            | BPMNElement::SignalIntermediateCatchEvent(_)
+            | BPMNElement::SignalIntermediateThrowEvent(_)
+            | BPMNElement::SignalStartEvent(_)
+            | BPMNElement::StartEvent(_)
+            | BPMNElement::TerminateEndEvent(_)
+            | BPMNElement::TimerIntermediateCatchEvent(_)
+            | BPMNElement::TimerStartEvent(_) => Err(anyhow!(
+                "Boundary event `{}` is attached to `{}`, which is not an activity.",
+                self.id,
+                host.id()
+            )),
+        }
+    }
+}
+
+impl BPMNObject for BPMNBoundaryEvent {
+    fn local_index(&self) -> usize {
+        self.local_index
+    }
+
+    fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        None
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn is_unconstrained_start_event(
+        &self,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn is_end_event(&self) -> bool {
+        false
+    }
+
+    fn incoming_sequence_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn outgoing_sequence_flows(&self) -> &[usize] {
+        &self.outgoing_sequence_flows
+    }
+
+    fn incoming_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Err(anyhow!(
+            "boundary events cannot have incoming sequence flows"
+        ))
+    }
+
+    fn outgoing_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Ok(&mut self.outgoing_sequence_flows)
+    }
+
+    fn incoming_message_flows(&self) -> &[usize] {
+        self.incoming_message_flow.as_slice()
+    }
+
+    fn outgoing_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn can_start_process_instance(&self, _bpmn: &BusinessProcessModelAndNotation) -> Result<bool> {
+        //a boundary event can only fire while its host is active
+        Ok(false)
+    }
+
+    fn outgoing_message_flows_always_have_tokens(&self) -> bool {
+        false
+    }
+
+    fn outgoing_messages_cannot_be_removed(&self) -> bool {
+        false
+    }
+
+    fn incoming_messages_are_ignored(&self) -> bool {
+        false
+    }
+
+    fn can_have_incoming_sequence_flows(&self) -> bool {
+        false
+    }
+
+    fn can_have_outgoing_sequence_flows(&self) -> bool {
+        true
+    }
+}
+
+impl Transitionable for BPMNBoundaryEvent {
+    fn number_of_transitions(&self, marking: &BPMNSubMarking) -> usize {
+        //one transition per instance of the host; a host that is not an expanded sub-process, a call activity or a multi-instance activity has no instances
+        marking.element_index_2_sub_markings[self.attached_to_local_index]
+            .len()
+            .max(1)
+    }
+
+    fn enabled_transitions(
+        &self,
+        root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<BitVec> {
+        let mut result = bitvec![0; self.number_of_transitions(sub_marking)];
+
+        if !self.trigger_is_present(root_marking, bpmn)? {
+            return Ok(result);
+        }
+
+        let host = self.host(parent)?;
+        if !self.can_fire_again(host, sub_marking) {
+            //a non-interrupting event has fired for each activation of the host already
+            return Ok(result);
+        }
+
+        if Self::host_has_instances(host) {
+            //each running instance of the sub-process or called process, or each activation of a multi-instance activity, can be interrupted
+            let instances =
+                sub_marking.element_index_2_sub_markings[self.attached_to_local_index].len();
+            result[..instances].fill(true);
+        } else if Self::host_token(host, sub_marking).is_some() {
+            //the host is waiting to be executed
+            result.set(0, true);
+        }
+
+        Ok(result)
+    }
+
+    fn execute_transition(
+        &self,
+        transition_index: TransitionIndex,
+        root_marking: &mut BPMNRootMarking,
+        sub_marking: &mut BPMNSubMarking,
+        parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        //cancel the host
+        if self.cancel_activity {
+            let host = self.host(parent)?;
+            if Self::host_has_instances(host) {
+                //remove the instance of the sub-process or called process, or the activation of the multi-instance activity
+                sub_marking.element_index_2_sub_markings[self.attached_to_local_index]
+                    .remove(transition_index);
+            } else {
+                match Self::host_token(host, sub_marking).and_if_not("Host is not enabled.")? {
+                    Some(sequence_flow_index) => {
+                        let sequence_flow =
+                            &parent.sequence_flows_non_recursive()[sequence_flow_index];
+                        let source =
+                            &parent.elements_non_recursive()[sequence_flow.source_local_index];
+                        if source.is_event_based_gateway() {
+                            //special case: source is an event-based gateway
+
+                            //remove a token from all outgoing sequence flows of the event-based gateway
+                            for outgoing_sequence_flow in source.outgoing_sequence_flows() {
+                                sub_marking.sequence_flow_2_tokens[*outgoing_sequence_flow] -= 1;
+                            }
+                        } else {
+                            sub_marking.sequence_flow_2_tokens[sequence_flow_index] -= 1;
+                        }
+                    }
+                    None => sub_marking.element_index_2_tokens[host.local_index()] -= 1,
+                }
+            }
+        } else if self.firing_limit().is_some() {
+            //remember the firing for this activation of the host
+            sub_marking.element_index_2_tokens[self.local_index] += 1;
+        }
+
+        //consume message
+        if let Some(message_flow_index) = self.incoming_message_flow {
+            //there is a connected message flow
+            let source = bpmn.message_flow_index_2_source(message_flow_index)?;
+            if !source.outgoing_message_flows_always_have_tokens() {
+                //this message must actually be there
+                if !source.outgoing_messages_cannot_be_removed() {
+                    root_marking.message_flow_2_tokens[message_flow_index] -= 1;
+                }
+            } else {
+                //if the message flow has always tokens, we do not need to check them
+            }
+        } else {
+            //if there is no incoming message flow, we assume there is always a message
+        }
+
+        //produce
+        execute_transition_parallel_split!(self, sub_marking);
+        Ok(())
+    }
+
+    fn transition_activity(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+    ) -> Option<Activity> {
+        None
+    }
+
+    fn transition_debug(
+        &self,
+        transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Option<String> {
+        Some(format!(
+            "boundary event `{}` on `{}`; internal transition {}{}",
+            self.id,
+            self.attached_to_id,
+            transition_index,
+            bpmn.message_debug(self.message_ref())
+        ))
+    }
+
+    fn transition_probabilistic_penalty(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+    ) -> Option<Fraction> {
+        Some(Fraction::one())
+    }
+
+    fn transition_2_consumed_tokens(
+        &self,
+        transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        let mut result = vec![];
+
+        //host
+        if self.cancel_activity {
+            let host = self.host(parent)?;
+            if Self::host_has_instances(host) {
+                //the cancelled instance or activation, with everything nested in it
+                let instance = sub_marking.element_index_2_sub_markings[host.local_index()]
+                    .get(transition_index..=transition_index)
+                    .and_if_not("Instance of host not found.")?;
+                result.append(&mut BPMNSubMarking::instances_2_tokens(host, instance)?);
+            } else {
+                match Self::host_token(host, sub_marking).and_if_not("Host is not enabled.")? {
+                    Some(sequence_flow_index) => {
+                        let sequence_flow = parent
+                            .sequence_flows_non_recursive()
+                            .get(sequence_flow_index)
+                            .and_if_not_error_default()?;
+                        let source =
+                            &parent.elements_non_recursive()[sequence_flow.source_local_index];
+                        if source.is_event_based_gateway() {
+                            //special case: source is an event-based gateway
+
+                            //remove a token from all outgoing sequence flows of the event-based gateway
+                            for outgoing_sequence_flow in source.outgoing_sequence_flows() {
+                                let sequence_flow = parent
+                                    .sequence_flows_non_recursive()
+                                    .get(*outgoing_sequence_flow)
+                                    .and_if_not_error_default()?;
+                                result.push(Token::SequenceFlow(sequence_flow.global_index));
+                            }
+                        } else {
+                            result.push(Token::SequenceFlow(sequence_flow.global_index));
+                        }
+                    }
+                    None => result.push(Token::Element(host.global_index())),
+                }
+            }
+        }
+
+        //message
+        result.append(&mut transition_2_consumed_tokens_message!(self, bpmn));
+        Ok(result)
+    }
+
+    fn transition_2_produced_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        let mut result = transition_2_produced_tokens_concurrent_split!(self, parent);
+        if !self.cancel_activity && self.firing_limit().is_some() {
+            //the firing for this activation of the host
+            result.push(Token::Element(self.global_index));
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,546 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    element::BPMNElementTrait,
+    elements::{boundary_event::BPMNBoundaryEvent, process::BPMNProcess},
+    error::BPMNError,
+    marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    parser::parser_state::GlobalIndex,
+    semantics::TransitionIndex,
+    traits::{
+        objectable::{BPMNObject, EMPTY_FLOWS},
+        processable::Processable,
+        startable::Startable,
+        transitionable::{
+            Transitionable, enabledness_xor_join_only, execute_transition_parallel_split,
+            execute_transition_xor_join_consume, number_of_transitions_xor_join_only,
+        },
+    },
+};
+use anyhow::{Context, Result, anyhow};
+use bitvec::{bitvec, vec::BitVec};
+use ebi_activity_key::Activity;
+use ebi_arithmetic::{Fraction, One};
+use std::borrow::Cow;
+
+#[derive(Debug, Clone)]
+pub struct BPMNCallActivity {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+
+    /// The id of the process that is called.
+    pub(crate) called_element: String,
+    /// A copy of the called process, resolved after parsing. None if the called element is not a process of this model.
+    pub(crate) called_process: Option<Box<BPMNProcess>>,
+    /// The index of the import of which the model contains the called process; None if it is a process of this model.
+    /// The called process is executed against the imported model, as its indices refer to that model.
+    pub(crate) called_import: Option<usize>,
+
+    pub(crate) incoming_sequence_flows: Vec<usize>,
+    pub(crate) outgoing_sequence_flows: Vec<usize>,
+}
+
+impl BPMNCallActivity {
+    pub fn called_element(&self) -> &str {
+        &self.called_element
+    }
+
+    pub(crate) fn start_process_instance(
+        &self,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<BPMNSubMarking> {
+        let called_process = self.called_process()?;
+        let initiation_mode = called_process.initiation_mode(self.called_model(bpmn)?)?;
+        called_process.to_sub_marking(&initiation_mode)
+    }
+
+    /// Returns the model that contains the called process: the imported model, or otherwise the given model.
+    pub(crate) fn called_model<'a>(
+        &self,
+        bpmn: &'a BusinessProcessModelAndNotation,
+    ) -> Result<&'a BusinessProcessModelAndNotation> {
+        match self.called_import {
+            None => Ok(bpmn),
+            Some(import_index) => bpmn
+                .imports
+                .get(import_index)
+                .and_then(|import| import.definitions.as_deref())
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Call activity `{}` calls `{}` of an import that is not loaded.",
+                        self.id,
+                        self.called_element
+                    )
+                }),
+        }
+    }
+
+    /// Returns the root marking against which the called process is executed.
+    /// An imported model exchanges no messages with the importing model, and its signals are broadcast by id afterwards, thus these start empty.
+    fn called_root_marking(
+        &self,
+        root_marking: &BPMNRootMarking,
+        called_model: &BusinessProcessModelAndNotation,
+    ) -> BPMNRootMarking {
+        BPMNRootMarking {
+            root_initial_choice_token: false,
+            message_flow_2_tokens: vec![0; called_model.number_of_message_flows()],
+            signal_2_tokens: vec![0; called_model.signals.len()],
+            error_thrown: root_marking.error_thrown.clone(),
+            variables: root_marking.variables.clone(),
+        }
+    }
+
+    /// Executes a transition of an instance of the called process, against the model that contains the called process.
+    fn execute_called_transition(
+        &self,
+        transition_index: TransitionIndex,
+        root_marking: &mut BPMNRootMarking,
+        instance: &mut BPMNSubMarking,
+        parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        let called_process = self.called_process()?;
+        if self.called_import.is_none() {
+            return called_process.execute_transition(
+                transition_index,
+                root_marking,
+                instance,
+                parent,
+                bpmn,
+            );
+        }
+
+        let called_model = self.called_model(bpmn)?;
+        let mut called_root_marking = self.called_root_marking(root_marking, called_model);
+        called_process.execute_transition(
+            transition_index,
+            &mut called_root_marking,
+            instance,
+            parent,
+            called_model,
+        )?;
+
+        //carry the data, the thrown error and the thrown signals back to the importing model
+        root_marking.error_thrown = called_root_marking.error_thrown;
+        root_marking.variables = called_root_marking.variables;
+        for (signal, tokens) in called_model
+            .signals
+            .iter()
+            .zip(called_root_marking.signal_2_tokens)
+        {
+            if let Some(signal_index) = bpmn.signal_id_2_local_index(&signal.id) {
+                root_marking.signal_2_tokens[signal_index] += tokens;
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn called_process(&self) -> Result<&BPMNProcess> {
+        self.called_process.as_deref().ok_or_else(|| {
+            BPMNError::UnknownId {
+                message: format!(
+                    "Call activity `{}` calls `{}`, which is not a process in this model.",
+                    self.id, self.called_element
+                ),
+                id: self.called_element.clone(),
+            }
+            .into()
+        })
+    }
+}
+
+impl BPMNElementTrait for BPMNCallActivity {
+    fn add_incoming_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
+        self.incoming_sequence_flows.push(flow_index);
+        Ok(())
+    }
+
+    fn add_outgoing_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
+        self.outgoing_sequence_flows.push(flow_index);
+        Ok(())
+    }
+
+    fn clear_incoming_sequence_flows(&mut self) {
+        self.incoming_sequence_flows.clear();
+    }
+
+    fn clear_outgoing_sequence_flows(&mut self) {
+        self.outgoing_sequence_flows.clear();
+    }
+
+    fn add_incoming_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!(
+            "call activities cannot have incoming message flows"
+        ))
+    }
+
+    fn add_outgoing_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!(
+            "call activities cannot have outgoing message flows"
+        ))
+    }
+
+    fn verify_structural_correctness(
+        &self,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        //the called process itself is verified as an element of the model
+        self.called_process()?;
+        Ok(())
+    }
+}
+
+impl BPMNObject for BPMNCallActivity {
+    fn local_index(&self) -> usize {
+        self.local_index
+    }
+
+    fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        None
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn is_unconstrained_start_event(
+        &self,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn is_end_event(&self) -> bool {
+        false
+    }
+
+    fn incoming_sequence_flows(&self) -> &[usize] {
+        &self.incoming_sequence_flows
+    }
+
+    fn outgoing_sequence_flows(&self) -> &[usize] {
+        &self.outgoing_sequence_flows
+    }
+
+    fn incoming_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Ok(&mut self.incoming_sequence_flows)
+    }
+
+    fn outgoing_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Ok(&mut self.outgoing_sequence_flows)
+    }
+
+    fn incoming_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn outgoing_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn can_start_process_instance(&self, _bpmn: &BusinessProcessModelAndNotation) -> Result<bool> {
+        Ok(self.incoming_sequence_flows().len() == 0)
+    }
+
+    fn outgoing_message_flows_always_have_tokens(&self) -> bool {
+        false
+    }
+
+    fn outgoing_messages_cannot_be_removed(&self) -> bool {
+        false
+    }
+
+    fn incoming_messages_are_ignored(&self) -> bool {
+        false
+    }
+
+    fn can_have_incoming_sequence_flows(&self) -> bool {
+        true
+    }
+
+    fn can_have_outgoing_sequence_flows(&self) -> bool {
+        true
+    }
+}
+
+impl Transitionable for BPMNCallActivity {
+    fn number_of_transitions(&self, marking: &BPMNSubMarking) -> usize {
+        //behaves like an XOR-join to start
+        let mut result = number_of_transitions_xor_join_only!(self);
+
+        if let Some(called_process) = &self.called_process {
+            for sub_marking in &marking.element_index_2_sub_markings[self.local_index] {
+                // one transition to end the instantiation
+                result += 1;
+                // and the transitions of the called process
+                result += called_process.number_of_transitions(sub_marking);
+            }
+        }
+
+        result
+    }
+
+    fn enabled_transitions(
+        &self,
+        root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<BitVec> {
+        let called_process = match &self.called_process {
+            Some(called_process) => called_process,
+            None => return Ok(bitvec![0; self.number_of_transitions(sub_marking)]),
+        };
+
+        //start transitions: like an xor join
+        let mut result = enabledness_xor_join_only!(self, sub_marking);
+
+        //gather the transitions of the instantiations of the called process
+        let called_model = self.called_model(bpmn)?;
+        let called_root_marking = match self.called_import {
+            Some(_) => Cow::Owned(self.called_root_marking(root_marking, called_model)),
+            None => Cow::Borrowed(root_marking),
+        };
+        for sub_marking in &sub_marking.element_index_2_sub_markings[self.local_index] {
+            let sub_marking_enabled_transitions = called_process.enabled_transitions(
+                &called_root_marking,
+                sub_marking,
+                parent,
+                called_model,
+            )?;
+
+            //end transition
+            if sub_marking_enabled_transitions.not_any() {
+                result.push(true);
+            } else {
+                result.push(false);
+            }
+
+            //transitions from this instantiation
+            result.extend(sub_marking_enabled_transitions);
+        }
+
+        Ok(result)
+    }
+
+    fn execute_transition(
+        &self,
+        mut transition_index: TransitionIndex,
+        root_marking: &mut BPMNRootMarking,
+        sub_marking: &mut BPMNSubMarking,
+        parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        let called_process = self.called_process()?;
+
+        if transition_index < number_of_transitions_xor_join_only!(self) {
+            //behaves like an XOR-join to start
+
+            //consume
+            execute_transition_xor_join_consume!(self, sub_marking, transition_index);
+
+            //produce -> start a new instance of the called process
+            sub_marking.element_index_2_sub_markings[self.local_index]
+                .push(self.start_process_instance(bpmn)?);
+            return Ok(());
+        }
+        transition_index -= number_of_transitions_xor_join_only!(self);
+
+        //find the sub-marking that contains the transition index
+        let mut remove_instantiation = None;
+        for (instantiation_index, sub_sub_marking) in sub_marking.element_index_2_sub_markings
+            [self.local_index]
+            .iter_mut()
+            .enumerate()
+        {
+            // one transition to end the instantiation
+            if transition_index == 0 {
+                //end the process instance
+                remove_instantiation = Some(instantiation_index);
+                //produce tokens
+                execute_transition_parallel_split!(self, sub_marking);
+                break;
+            }
+            transition_index -= 1;
+
+            // and the transitions of the called process
+            let number_of_sub_transitions = called_process.number_of_transitions(sub_sub_marking);
+            if transition_index < number_of_sub_transitions {
+                self.execute_called_transition(
+                    transition_index,
+                    root_marking,
+                    sub_sub_marking,
+                    parent,
+                    bpmn,
+                )
+                .with_context(|| {
+                    format!(
+                        "Execute transition in process `{}`, called by `{}`.",
+                        self.called_element, self.id
+                    )
+                })?;
+
+                //an error that is not caught here ends the enclosing instance, which includes this instance
+                if let Some(error_ref) = &root_marking.error_thrown {
+                    if let Some(boundary_event) = BPMNBoundaryEvent::error_boundary_event_of(
+                        self.local_index,
+                        error_ref.as_deref(),
+                        parent,
+                    ) {
+                        //the error is caught: cancel the instance and continue along the boundary event
+                        root_marking.error_thrown = None;
+                        remove_instantiation = Some(instantiation_index);
+                        execute_transition_parallel_split!(boundary_event, sub_marking);
+                        break;
+                    }
+                }
+                return Ok(());
+            }
+            transition_index -= number_of_sub_transitions;
+        }
+
+        if let Some(remove_instantiation_index) = remove_instantiation {
+            sub_marking.element_index_2_sub_markings[self.local_index]
+                .remove(remove_instantiation_index);
+        }
+
+        Ok(())
+    }
+
+    fn transition_activity(
+        &self,
+        mut transition_index: TransitionIndex,
+        marking: &BPMNSubMarking,
+    ) -> Option<Activity> {
+        //start transition
+        if transition_index < number_of_transitions_xor_join_only!(self) {
+            return None;
+        }
+        transition_index -= number_of_transitions_xor_join_only!(self);
+
+        let called_process = self.called_process.as_ref()?;
+        for sub_marking in &marking.element_index_2_sub_markings[self.local_index] {
+            if transition_index == 0 {
+                //end transition
+                return None;
+            }
+            transition_index -= 1;
+
+            //transitions of the called process
+            let sub_number_of_transitions = called_process.number_of_transitions(&sub_marking);
+            if transition_index < sub_number_of_transitions {
+                return called_process.transition_activity(transition_index, &sub_marking);
+            }
+            transition_index -= sub_number_of_transitions;
+        }
+        None
+    }
+
+    fn transition_debug(
+        &self,
+        mut transition_index: TransitionIndex,
+        marking: &BPMNSubMarking,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Option<String> {
+        //start transition
+        if transition_index < self.incoming_sequence_flows.len().max(1) {
+            return Some(format!(
+                "call activity `{}`; start internal transition {}",
+                self.id, transition_index
+            ));
+        }
+        transition_index -= self.incoming_sequence_flows.len().max(1);
+
+        //instantiations
+        let called_process = self.called_process.as_ref()?;
+        for (i, sub_marking) in marking.element_index_2_sub_markings[self.local_index]
+            .iter()
+            .enumerate()
+        {
+            if transition_index == 0 {
+                //end transition
+                return Some(format!(
+                    "call activity `{}`; instantiation {}, end transition",
+                    self.id, i
+                ));
+            }
+            transition_index -= 1;
+
+            //transitions of the called process
+            let sub_number_of_transitions = called_process.number_of_transitions(&sub_marking);
+            if transition_index < sub_number_of_transitions {
+                return called_process.transition_debug(
+                    transition_index,
+                    &sub_marking,
+                    self.called_model(bpmn).ok()?,
+                );
+            }
+            transition_index -= sub_number_of_transitions;
+        }
+        None
+    }
+
+    fn transition_probabilistic_penalty(
+        &self,
+        mut transition_index: TransitionIndex,
+        marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+    ) -> Option<Fraction> {
+        //start transition
+        if transition_index < self.incoming_sequence_flows.len().max(1) {
+            return Some(Fraction::one());
+        }
+        transition_index -= self.incoming_sequence_flows.len().max(1);
+
+        //instantiations
+        let called_process = self.called_process.as_ref()?;
+        for sub_marking in marking.element_index_2_sub_markings[self.local_index].iter() {
+            if transition_index == 0 {
+                //end transition
+                return Some(Fraction::one());
+            }
+            transition_index -= 1;
+
+            //transitions of the called process
+            let sub_number_of_transitions = called_process.number_of_transitions(&sub_marking);
+            if transition_index < sub_number_of_transitions {
+                return called_process.transition_probabilistic_penalty(
+                    transition_index,
+                    &sub_marking,
+                    parent,
+                );
+            }
+            transition_index -= sub_number_of_transitions;
+        }
+        None
+    }
+
+    fn transition_2_consumed_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        Err(anyhow!("Call activities are not yet supported here."))
+    }
+
+    fn transition_2_produced_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        Err(anyhow!("Call activities are not yet supported here."))
+    }
+}
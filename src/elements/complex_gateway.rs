@@ -0,0 +1,508 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    element::BPMNElementTrait,
+    elements::inclusive_gateway::{
+        enabled_inclusive_splits, inclusive_split_probabilistic_penalty, tokens_may_arrive,
+        transition_index_2_sequence_flows,
+    },
+    if_not::{IfNot, IfNotDefault},
+    marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    parser::parser_state::GlobalIndex,
+    semantics::TransitionIndex,
+    traits::{
+        objectable::{BPMNObject, EMPTY_FLOWS},
+        processable::Processable,
+        transitionable::Transitionable,
+    },
+};
+use anyhow::{Result, anyhow};
+use bitvec::{bitvec, vec::BitVec};
+use ebi_activity_key::Activity;
+use ebi_arithmetic::{Fraction, One};
+use std::collections::HashSet;
+
+/// A complex gateway. Its join fires as soon as the number of incoming sequence flows that have a token reaches the activation count, and then consumes a token from each incoming sequence flow that has one.
+/// The join then waits for reset: it absorbs a token from each other incoming sequence flow, without producing any, and fires again only once these tokens have been absorbed or can no longer come.
+/// The reset is kept in the sub-markings of the gateway.
+/// Its split behaves like the split of an inclusive gateway.
+#[derive(Debug, Clone)]
+pub struct BPMNComplexGateway {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+    ///the number of incoming sequence flows that must have a token for the join to fire; if absent, one suffices
+    pub(crate) activation_count: Option<u64>,
+    pub(crate) incoming_sequence_flows: Vec<usize>,
+    pub(crate) outgoing_sequence_flows: Vec<usize>,
+}
+
+impl BPMNComplexGateway {
+    /// Returns the number of incoming sequence flows that must have a token for the join to fire.
+    pub fn activation_count(&self) -> u64 {
+        self.activation_count.unwrap_or(1)
+    }
+
+    fn number_of_split_transitions(&self) -> usize {
+        2usize.pow(self.outgoing_sequence_flows.len() as u32) - 1
+    }
+
+    /// Returns the reset that the gateway with the given local index waits for, if it does.
+    /// Its sequence flow tokens mark the incoming sequence flows of which a token is still to be absorbed.
+    fn reset(sub_marking: &BPMNSubMarking, local_index: usize) -> Option<&BPMNSubMarking> {
+        sub_marking.element_index_2_sub_markings[local_index].first()
+    }
+
+    fn new_reset(awaited: Vec<u64>) -> BPMNSubMarking {
+        BPMNSubMarking {
+            sequence_flow_2_tokens: awaited,
+            initial_choice_token: false,
+            element_index_2_tokens: vec![],
+            element_index_2_sub_markings: vec![],
+        }
+    }
+
+    /// Returns the virtual tokens of the given reset, one for each incoming sequence flow of which a token is still to be absorbed.
+    pub(crate) fn reset_2_tokens(
+        &self,
+        resets: &[BPMNSubMarking],
+        parent: &dyn Processable,
+    ) -> Result<Vec<Token>> {
+        let mut result = vec![];
+        for reset in resets {
+            for sequence_flow_index in &self.incoming_sequence_flows {
+                if reset.sequence_flow_2_tokens[*sequence_flow_index] > 0 {
+                    let sequence_flow = parent
+                        .sequence_flows_non_recursive()
+                        .get(*sequence_flow_index)
+                        .and_if_not_error_default()?;
+                    result.push(Token::Reset {
+                        of: self.global_index,
+                        awaiting: sequence_flow.global_index,
+                    });
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Adds the virtual token of an awaited incoming sequence flow to the reset of this gateway.
+    pub(crate) fn add_reset_token(
+        &self,
+        sequence_flow_index: usize,
+        sub_marking: &mut BPMNSubMarking,
+        parent: &dyn Processable,
+    ) {
+        let resets = &mut sub_marking.element_index_2_sub_markings[self.local_index];
+        if resets.is_empty() {
+            let number_of_sequence_flows = parent.sequence_flows_non_recursive().len();
+            resets.push(Self::new_reset(vec![0; number_of_sequence_flows]));
+        }
+        resets[0].sequence_flow_2_tokens[sequence_flow_index] = 1;
+    }
+}
+
+impl BPMNElementTrait for BPMNComplexGateway {
+    fn add_incoming_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
+        self.incoming_sequence_flows.push(flow_index);
+        Ok(())
+    }
+
+    fn add_outgoing_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
+        if self.outgoing_sequence_flows.len() as u32 == usize::BITS {
+            return Err(anyhow!(
+                "cannot add more than {} outgoing sequence flows to a complex gateway",
+                usize::BITS
+            ));
+        }
+        self.outgoing_sequence_flows.push(flow_index);
+        Ok(())
+    }
+
+    fn clear_incoming_sequence_flows(&mut self) {
+        self.incoming_sequence_flows.clear();
+    }
+
+    fn clear_outgoing_sequence_flows(&mut self) {
+        self.outgoing_sequence_flows.clear();
+    }
+
+    fn add_incoming_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!("gateways cannot have incoming message flows"))
+    }
+
+    fn add_outgoing_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!("gateways cannot have outgoing message flows"))
+    }
+
+    fn verify_structural_correctness(
+        &self,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        if self.activation_count() == 0 {
+            return Err(anyhow!(
+                "Complex gateway `{}` has an activation count of 0, which must be at least 1.",
+                self.id
+            ));
+        }
+        if !self.incoming_sequence_flows.is_empty()
+            && self.activation_count() > self.incoming_sequence_flows.len() as u64
+        {
+            return Err(anyhow!(
+                "Complex gateway `{}` has an activation count of {}, but only {} incoming sequence flows.",
+                self.id,
+                self.activation_count(),
+                self.incoming_sequence_flows.len()
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl BPMNObject for BPMNComplexGateway {
+    fn local_index(&self) -> usize {
+        self.local_index
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        None
+    }
+
+    fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    fn is_unconstrained_start_event(
+        &self,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn is_end_event(&self) -> bool {
+        false
+    }
+
+    fn incoming_sequence_flows(&self) -> &[usize] {
+        &self.incoming_sequence_flows
+    }
+
+    fn outgoing_sequence_flows(&self) -> &[usize] {
+        &self.outgoing_sequence_flows
+    }
+
+    fn incoming_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Ok(&mut self.incoming_sequence_flows)
+    }
+
+    fn outgoing_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Ok(&mut self.outgoing_sequence_flows)
+    }
+
+    fn incoming_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn outgoing_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn can_start_process_instance(&self, _bpmn: &BusinessProcessModelAndNotation) -> Result<bool> {
+        Ok(self.incoming_sequence_flows().len() == 0)
+    }
+
+    fn outgoing_message_flows_always_have_tokens(&self) -> bool {
+        false
+    }
+
+    fn outgoing_messages_cannot_be_removed(&self) -> bool {
+        false
+    }
+
+    fn incoming_messages_are_ignored(&self) -> bool {
+        false
+    }
+
+    fn can_have_incoming_sequence_flows(&self) -> bool {
+        true
+    }
+
+    fn can_have_outgoing_sequence_flows(&self) -> bool {
+        true
+    }
+}
+
+impl Transitionable for BPMNComplexGateway {
+    fn number_of_transitions(&self, _marking: &BPMNSubMarking) -> usize {
+        //the splits, the absorption of a token per incoming sequence flow, and the reset
+        self.number_of_split_transitions() + self.incoming_sequence_flows.len() + 1
+    }
+
+    fn enabled_transitions(
+        &self,
+        root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<BitVec> {
+        let mut result = bitvec![0; self.number_of_split_transitions()];
+        let mut resets = bitvec![0; self.incoming_sequence_flows.len() + 1];
+
+        if let Some(reset) = Self::reset(sub_marking, self.local_index) {
+            //waiting for reset: absorb the tokens of the incoming sequence flows that did not take part in the activation
+            let mut awaited_sequence_flows = HashSet::new();
+            for (i, sequence_flow_index) in self.incoming_sequence_flows.iter().enumerate() {
+                if reset.sequence_flow_2_tokens[*sequence_flow_index] > 0 {
+                    if sub_marking.sequence_flow_2_tokens[*sequence_flow_index] > 0 {
+                        resets.set(i, true);
+                    }
+                    awaited_sequence_flows.insert(*sequence_flow_index);
+                }
+            }
+
+            //reset once no token can come to the awaited incoming sequence flows anymore
+            if resets.not_any()
+                && !tokens_may_arrive(
+                    awaited_sequence_flows,
+                    self.local_index,
+                    sub_marking,
+                    parent,
+                )?
+            {
+                resets.set(self.incoming_sequence_flows.len(), true);
+            }
+        } else {
+            let enabled = if self.incoming_sequence_flows.is_empty() {
+                //if there are no sequence flows, then initiation mode 2 applies.
+                //that is, look in the extra virtual sequence flow
+                sub_marking.element_index_2_tokens[self.local_index] >= 1
+            } else {
+                //count the incoming sequence flows that have a token
+                let marked_sequence_flows = self
+                    .incoming_sequence_flows
+                    .iter()
+                    .filter(|sequence_flow_index| {
+                        sub_marking.sequence_flow_2_tokens[**sequence_flow_index] > 0
+                    })
+                    .count();
+                marked_sequence_flows as u64 >= self.activation_count()
+            };
+            if enabled {
+                result = enabled_inclusive_splits(
+                    &self.outgoing_sequence_flows,
+                    None,
+                    root_marking,
+                    parent,
+                )?;
+            }
+        }
+
+        result.extend(resets);
+        Ok(result)
+    }
+
+    fn execute_transition(
+        &self,
+        mut transition_index: TransitionIndex,
+        _root_marking: &mut BPMNRootMarking,
+        sub_marking: &mut BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        let number_of_split_transitions = self.number_of_split_transitions();
+        if transition_index >= number_of_split_transitions + self.incoming_sequence_flows.len() {
+            //reset
+            sub_marking.element_index_2_sub_markings[self.local_index].clear();
+            return Ok(());
+        } else if transition_index >= number_of_split_transitions {
+            //absorb the token of an awaited incoming sequence flow
+            let sequence_flow_index =
+                self.incoming_sequence_flows[transition_index - number_of_split_transitions];
+            sub_marking.sequence_flow_2_tokens[sequence_flow_index] -= 1;
+            let resets = &mut sub_marking.element_index_2_sub_markings[self.local_index];
+            let reset = resets
+                .first_mut()
+                .and_if_not("Complex gateway is not waiting for reset.")?;
+            reset.sequence_flow_2_tokens[sequence_flow_index] = 0;
+            if reset.is_empty() {
+                //all awaited tokens have been absorbed
+                resets.clear();
+            }
+            return Ok(());
+        }
+
+        //consume
+        if self.incoming_sequence_flows.is_empty() {
+            //if there are no sequence flows, then initiation mode 2 applies.
+            //that is, look in the extra virtual sequence flow
+            sub_marking.element_index_2_tokens[self.local_index] -= 1;
+        } else {
+            //consume a token from each incoming sequence flow that has one, and await a token from each other one
+            let mut awaited = vec![0; parent.sequence_flows_non_recursive().len()];
+            for sequence_flow_index in &self.incoming_sequence_flows {
+                if sub_marking.sequence_flow_2_tokens[*sequence_flow_index] > 0 {
+                    sub_marking.sequence_flow_2_tokens[*sequence_flow_index] -= 1;
+                } else {
+                    awaited[*sequence_flow_index] = 1;
+                }
+            }
+            if awaited.iter().any(|tokens| *tokens > 0) {
+                sub_marking.element_index_2_sub_markings[self.local_index]
+                    .push(Self::new_reset(awaited));
+            }
+        }
+
+        //produce
+        for sequence_flow_index in &self.outgoing_sequence_flows {
+            if transition_index % 2 == 0 {
+                sub_marking.sequence_flow_2_tokens[*sequence_flow_index] += 1;
+            }
+            transition_index >>= 1;
+        }
+        Ok(())
+    }
+
+    fn transition_activity(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+    ) -> Option<Activity> {
+        None
+    }
+
+    fn transition_debug(
+        &self,
+        transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Option<String> {
+        let number_of_split_transitions = self.number_of_split_transitions();
+        if transition_index >= number_of_split_transitions + self.incoming_sequence_flows.len() {
+            Some(format!("complex gateway `{}`; reset", self.id))
+        } else if transition_index >= number_of_split_transitions {
+            Some(format!(
+                "complex gateway `{}`; absorb token of incoming sequence flow {}",
+                self.id,
+                transition_index - number_of_split_transitions
+            ))
+        } else {
+            Some(format!(
+                "complex gateway `{}`; internal transition {}",
+                self.id, transition_index
+            ))
+        }
+    }
+
+    fn transition_probabilistic_penalty(
+        &self,
+        transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+    ) -> Option<Fraction> {
+        if transition_index >= self.number_of_split_transitions() {
+            //absorbing and resetting are not choices
+            return Some(Fraction::one());
+        }
+
+        //the split chooses like an inclusive split
+        inclusive_split_probabilistic_penalty(
+            &self.outgoing_sequence_flows,
+            None,
+            parent,
+            transition_index,
+        )
+    }
+
+    fn transition_2_consumed_tokens(
+        &self,
+        transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        let number_of_split_transitions = self.number_of_split_transitions();
+        if transition_index >= number_of_split_transitions + self.incoming_sequence_flows.len() {
+            //reset: the awaited incoming sequence flows
+            return self.reset_2_tokens(
+                &sub_marking.element_index_2_sub_markings[self.local_index],
+                parent,
+            );
+        } else if transition_index >= number_of_split_transitions {
+            //absorb: the token and its awaited incoming sequence flow
+            let sequence_flow = parent
+                .sequence_flows_non_recursive()
+                .get(self.incoming_sequence_flows[transition_index - number_of_split_transitions])
+                .and_if_not_error_default()?;
+            return Ok(vec![
+                Token::SequenceFlow(sequence_flow.global_index),
+                Token::Reset {
+                    of: self.global_index,
+                    awaiting: sequence_flow.global_index,
+                },
+            ]);
+        }
+
+        if self.incoming_sequence_flows.is_empty() {
+            //if there are no sequence flows, then initiation mode 2 applies.
+            //that is, look in the extra virtual sequence flow
+            Ok(vec![Token::Element(self.global_index)])
+        } else {
+            //consume a token from each incoming sequence flow that has one
+            let mut result = vec![];
+            for sequence_flow_index in &self.incoming_sequence_flows {
+                if sub_marking.sequence_flow_2_tokens[*sequence_flow_index] > 0 {
+                    let sequence_flow = parent
+                        .sequence_flows_non_recursive()
+                        .get(*sequence_flow_index)
+                        .and_if_not_error_default()?;
+                    result.push(Token::SequenceFlow(sequence_flow.global_index));
+                }
+            }
+            Ok(result)
+        }
+    }
+
+    fn transition_2_produced_tokens(
+        &self,
+        transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        if transition_index >= self.number_of_split_transitions() {
+            //absorbing and resetting produce nothing
+            return Ok(vec![]);
+        }
+
+        let mut result = transition_index_2_sequence_flows(
+            &self.outgoing_sequence_flows,
+            parent,
+            transition_index,
+        )?
+        .into_iter()
+        .map(|sequence_flow| Token::SequenceFlow(sequence_flow.global_index()))
+        .collect::<Vec<_>>();
+
+        //the incoming sequence flows without a token are awaited for the reset
+        for sequence_flow_index in &self.incoming_sequence_flows {
+            if sub_marking.sequence_flow_2_tokens[*sequence_flow_index] == 0 {
+                let sequence_flow = parent
+                    .sequence_flows_non_recursive()
+                    .get(*sequence_flow_index)
+                    .and_if_not_error_default()?;
+                result.push(Token::Reset {
+                    of: self.global_index,
+                    awaiting: sequence_flow.global_index,
+                });
+            }
+        }
+        Ok(result)
+    }
+}
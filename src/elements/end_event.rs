@@ -23,6 +23,7 @@ pub struct BPMNEndEvent {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
     pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
     pub(crate) incoming_sequence_flows: Vec<usize>,
 }
 
@@ -0,0 +1,252 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    element::BPMNElementTrait,
+    elements::event_sub_process::BPMNEventSubProcess,
+    marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    parser::parser_state::GlobalIndex,
+    semantics::TransitionIndex,
+    traits::{
+        objectable::{BPMNObject, EMPTY_FLOWS},
+        processable::Processable,
+        transitionable::{
+            Transitionable, enabledness_xor_join_only, execute_transition_xor_join_consume,
+            number_of_transitions_xor_join_only,
+        },
+    },
+};
+use anyhow::{Result, anyhow};
+use bitvec::{bitvec, vec::BitVec};
+use ebi_activity_key::Activity;
+use ebi_arithmetic::{Fraction, One};
+
+/// An end event that throws an error, which ends its instance.
+/// The error is caught by an error event sub-process of the instance, or else by an error boundary event of the nearest enclosing sub-process or call activity, which cancels that instance.
+/// A catching event without an `errorRef` catches all errors; otherwise, it only catches errors with the same `errorRef`.
+/// An error that is not caught ends all instances up to and including the process instance.
+#[derive(Debug, Clone)]
+pub struct BPMNErrorEndEvent {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+    pub(crate) error_marker_id: Option<String>,
+    pub(crate) error_ref: Option<String>,
+    pub(crate) incoming_sequence_flows: Vec<usize>,
+}
+
+impl BPMNErrorEndEvent {
+    /// Returns the id of the error that is thrown, if any.
+    pub fn error_ref(&self) -> Option<&str> {
+        self.error_ref.as_deref()
+    }
+}
+
+impl BPMNElementTrait for BPMNErrorEndEvent {
+    fn add_incoming_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
+        self.incoming_sequence_flows.push(flow_index);
+        Ok(())
+    }
+
+    fn add_outgoing_sequence_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!("end events cannot have outgoing sequence flows"))
+    }
+
+    fn clear_incoming_sequence_flows(&mut self) {
+        self.incoming_sequence_flows.clear();
+    }
+
+    fn clear_outgoing_sequence_flows(&mut self) {}
+
+    fn add_incoming_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!("error events cannot have incoming message flows"))
+    }
+
+    fn add_outgoing_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!("error events cannot have outgoing message flows"))
+    }
+
+    fn verify_structural_correctness(
+        &self,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl BPMNObject for BPMNErrorEndEvent {
+    fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    fn local_index(&self) -> usize {
+        self.local_index
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        None
+    }
+
+    fn is_unconstrained_start_event(
+        &self,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn is_end_event(&self) -> bool {
+        true
+    }
+
+    fn incoming_sequence_flows(&self) -> &[usize] {
+        &self.incoming_sequence_flows
+    }
+
+    fn outgoing_sequence_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn incoming_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Ok(&mut self.incoming_sequence_flows)
+    }
+
+    fn outgoing_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Err(anyhow!("element does not have outgoing sequence flows."))
+    }
+
+    fn incoming_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn outgoing_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn can_start_process_instance(&self, _bpmn: &BusinessProcessModelAndNotation) -> Result<bool> {
+        Ok(self.incoming_sequence_flows().len() == 0)
+    }
+
+    fn outgoing_message_flows_always_have_tokens(&self) -> bool {
+        false
+    }
+
+    fn outgoing_messages_cannot_be_removed(&self) -> bool {
+        false
+    }
+
+    fn incoming_messages_are_ignored(&self) -> bool {
+        false
+    }
+
+    fn can_have_incoming_sequence_flows(&self) -> bool {
+        true
+    }
+
+    fn can_have_outgoing_sequence_flows(&self) -> bool {
+        false
+    }
+}
+
+impl Transitionable for BPMNErrorEndEvent {
+    fn number_of_transitions(&self, _marking: &BPMNSubMarking) -> usize {
+        number_of_transitions_xor_join_only!(self)
+    }
+
+    fn enabled_transitions(
+        &self,
+        _root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<BitVec> {
+        Ok(enabledness_xor_join_only!(self, sub_marking))
+    }
+
+    fn execute_transition(
+        &self,
+        transition_index: TransitionIndex,
+        root_marking: &mut BPMNRootMarking,
+        sub_marking: &mut BPMNSubMarking,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        execute_transition_xor_join_consume!(self, sub_marking, transition_index);
+
+        //the error is caught by an enclosing instance once this transition has been executed
+        root_marking.error_thrown = Some(self.error_ref.clone());
+        Ok(())
+    }
+
+    fn transition_activity(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+    ) -> Option<Activity> {
+        None
+    }
+
+    fn transition_debug(
+        &self,
+        transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Option<String> {
+        Some(format!(
+            "error end event `{}`; internal transition {}",
+            self.id, transition_index
+        ))
+    }
+
+    fn transition_probabilistic_penalty(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+    ) -> Option<Fraction> {
+        Some(Fraction::one())
+    }
+
+    fn transition_2_consumed_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        //the error ends the instance, whether it is caught by an (always interrupting) error event sub-process or not,
+        //thus all tokens of the instance are consumed, which includes our own token and the tokens of nested instances
+        sub_marking.instance_2_tokens(parent)
+    }
+
+    fn transition_2_produced_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        //an error event sub-process of the instance starts
+        let Some(event_sub_process) = BPMNEventSubProcess::error_event_sub_process_of(
+            parent.elements_non_recursive(),
+            self.error_ref.as_deref(),
+        ) else {
+            return Ok(vec![]);
+        };
+        let Some(start_event) = event_sub_process.start_event() else {
+            return Ok(vec![]);
+        };
+        Ok(start_event
+            .outgoing_sequence_flows()
+            .iter()
+            .map(|sequence_flow| {
+                Token::SequenceFlow(event_sub_process.sequence_flows[*sequence_flow].global_index)
+            })
+            .collect())
+    }
+}
@@ -29,6 +29,7 @@ pub struct BPMNEventBasedGateway {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
     pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
     pub(crate) incoming_sequence_flows: Vec<usize>,
     pub(crate) outgoing_sequence_flows: Vec<usize>,
 }
@@ -70,21 +71,37 @@ impl BPMNElementTrait for BPMNEventBasedGateway {
                 }
 
                 match target {
-                    BPMNElement::CollapsedPool(_)
+                    BPMNElement::BoundaryEvent(_)
+                    | BPMNElement::BusinessRuleTask(_)
+                    | BPMNElement::CallActivity(_)
+                    | BPMNElement::CollapsedPool(_)
                     | BPMNElement::CollapsedSubProcess(_)
+                    | BPMNElement::ComplexGateway(_)
                     | BPMNElement::EndEvent(_)
+                    | BPMNElement::ErrorEndEvent(_)
+                    | BPMNElement::ErrorStartEvent(_)
                     | BPMNElement::EventBasedGateway(_)
+                    | BPMNElement::EventSubProcess(_)
                     | BPMNElement::ExclusiveGateway(_)
                     | BPMNElement::ExpandedSubProcess(_)
                     | BPMNElement::InclusiveGateway(_)
                     | BPMNElement::IntermediateThrowEvent(_)
+                    | BPMNElement::LinkIntermediateCatchEvent(_)
+                    | BPMNElement::LinkIntermediateThrowEvent(_)
                     | BPMNElement::ManualTask(_)
                     | BPMNElement::MessageEndEvent(_)
                     | BPMNElement::MessageIntermediateThrowEvent(_)
                     | BPMNElement::MessageStartEvent(_)
                     | BPMNElement::ParallelGateway(_)
                     | BPMNElement::Process(_)
+                    | BPMNElement::ScriptTask(_)
+                    | BPMNElement::SendTask(_)
+                    | BPMNElement::ServiceTask(_)
+                    | BPMNElement::SignalEndEvent(_)
+                    | BPMNElement::SignalIntermediateThrowEvent(_)
+                    | BPMNElement::SignalStartEvent(_)
                     | BPMNElement::StartEvent(_)
+                    | BPMNElement::TerminateEndEvent(_)
                     | BPMNElement::TimerStartEvent(_)
                     | BPMNElement::UserTask(_) => {
                         return Err(anyhow!(
@@ -93,7 +110,8 @@ impl BPMNElementTrait for BPMNEventBasedGateway {
                         ));
                     }
 
-                    BPMNElement::IntermediateCatchEvent(_) => {}
+                    BPMNElement::IntermediateCatchEvent(_)
+                    | BPMNElement::SignalIntermediateCatchEvent(_) => {}
 
                     BPMNElement::MessageIntermediateCatchEvent(_) => {
                         if configuration.is_tasks() {
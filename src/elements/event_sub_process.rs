@@ -0,0 +1,712 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    element::{BPMNElement, BPMNElementTrait},
+    elements::expanded_sub_process::to_sub_marking,
+    marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    parser::parser_state::GlobalIndex,
+    semantics::TransitionIndex,
+    sequence_flow::BPMNSequenceFlow,
+    traits::{
+        objectable::{BPMNObject, EMPTY_FLOWS},
+        processable::Processable,
+        searchable::Searchable,
+        startable::{InitiationMode, Startable},
+        transitionable::Transitionable,
+    },
+};
+use anyhow::{Context, Result, anyhow};
+use bitvec::{bitvec, vec::BitVec};
+use ebi_activity_key::Activity;
+use ebi_arithmetic::{Fraction, One};
+
+/// A sub-process that is not connected by sequence flows, but is started by its start event while the instance of its parent process or sub-process is active.
+/// The start event determines the trigger: a message, a timer, a signal or an error that is thrown in the parent instance.
+/// An interrupting event sub-process cancels the rest of the parent instance when it starts; a non-interrupting one runs alongside it.
+///
+/// The running instances of an event sub-process are kept in the sub-markings of the event sub-process, in the marking of its parent instance.
+#[derive(Debug, Clone)]
+pub struct BPMNEventSubProcess {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+    pub(crate) elements: Vec<BPMNElement>,
+    ///internal sequence flows
+    pub(crate) sequence_flows: Vec<BPMNSequenceFlow>,
+}
+
+impl BPMNEventSubProcess {
+    /// Returns the start event that triggers this event sub-process, if there is one.
+    pub fn start_event(&self) -> Option<&BPMNElement> {
+        self.elements.iter().find(|element| {
+            element.is_error_start_event()
+                || element.is_message_start_event()
+                || element.is_signal_start_event()
+                || element.is_start_event()
+                || element.is_timer_start_event()
+        })
+    }
+
+    /// Returns whether this event sub-process cancels the rest of its parent instance when it starts.
+    pub fn is_interrupting(&self) -> bool {
+        match self.start_event() {
+            Some(BPMNElement::MessageStartEvent(start_event)) => start_event.is_interrupting,
+            Some(BPMNElement::SignalStartEvent(start_event)) => start_event.is_interrupting,
+            Some(BPMNElement::TimerStartEvent(start_event)) => start_event.is_interrupting,
+            _ => true,
+        }
+    }
+
+    /// Returns whether the instance of a process or sub-process with the given marking is active.
+    /// That is, whether it has a token outside of its event sub-processes.
+    pub(crate) fn instance_is_active(parent: &dyn Processable, sub_marking: &BPMNSubMarking) -> bool {
+        sub_marking
+            .sequence_flow_2_tokens
+            .iter()
+            .any(|tokens| *tokens > 0)
+            || parent.elements_non_recursive().iter().any(|element| {
+                !element.is_event_sub_process()
+                    && (sub_marking.element_index_2_tokens[element.local_index()] > 0
+                        || !sub_marking.element_index_2_sub_markings[element.local_index()]
+                            .is_empty())
+            })
+    }
+
+    /// Returns whether the trigger of the start transition is available.
+    /// Signals and errors do not need a transition: they start the event sub-process when they are thrown.
+    fn trigger_is_present(
+        &self,
+        root_marking: &BPMNRootMarking,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<bool> {
+        match self.start_event() {
+            Some(BPMNElement::TimerStartEvent(_)) => {
+                //timers may expire at any moment
+                Ok(true)
+            }
+            Some(BPMNElement::MessageStartEvent(start_event)) => {
+                if let Some(message_flow_index) = start_event.incoming_message_flow {
+                    //there is a connected message flow
+                    let source = bpmn.message_flow_index_2_source(message_flow_index)?;
+                    if !source.outgoing_message_flows_always_have_tokens() {
+                        //this message must actually be there
+                        Ok(root_marking.message_flow_2_tokens[message_flow_index] > 0)
+                    } else {
+                        //if the message flow has always tokens, we do not need to check the marking
+                        Ok(true)
+                    }
+                } else {
+                    //if there is no incoming message flow, we assume there is always a message
+                    Ok(true)
+                }
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Starts a new instance of this event sub-process in the given parent instance, in which the start event has fired.
+    /// If the event sub-process is interrupting, the rest of the parent instance is cancelled first.
+    fn start_instance(&self, sub_marking: &mut BPMNSubMarking) -> Result<()> {
+        if self.is_interrupting() {
+            sub_marking.clear();
+        }
+
+        let mut instance = self.to_sub_marking(&InitiationMode::ParallelElements(vec![]))?;
+        if let Some(start_event) = self.start_event() {
+            for outgoing_sequence_flow in start_event.outgoing_sequence_flows() {
+                instance.sequence_flow_2_tokens[*outgoing_sequence_flow] += 1;
+            }
+        }
+        sub_marking.element_index_2_sub_markings[self.local_index].push(instance);
+        Ok(())
+    }
+
+    /// Lets an error event sub-process among the given elements catch the error that was thrown in their instance, if there is one.
+    pub(crate) fn catch_error(
+        elements: &[BPMNElement],
+        root_marking: &mut BPMNRootMarking,
+        sub_marking: &mut BPMNSubMarking,
+    ) -> Result<()> {
+        let Some(error_ref) = &root_marking.error_thrown else {
+            return Ok(());
+        };
+
+        if let Some(event_sub_process) =
+            Self::error_event_sub_process_of(elements, error_ref.as_deref())
+        {
+            //the error is caught: the instance continues in the event sub-process
+            root_marking.error_thrown = None;
+            event_sub_process.start_instance(sub_marking)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the error event sub-process among the given elements that catches the error with the given `errorRef`, if there is one.
+    /// An event sub-process that catches this specific error takes precedence over one that catches all errors.
+    pub(crate) fn error_event_sub_process_of<'a>(
+        elements: &'a [BPMNElement],
+        error_ref: Option<&str>,
+    ) -> Option<&'a BPMNEventSubProcess> {
+        let error_event_sub_processes = elements.iter().filter_map(|element| match element {
+            BPMNElement::EventSubProcess(event_sub_process) => {
+                match event_sub_process.start_event() {
+                    Some(BPMNElement::ErrorStartEvent(start_event)) => {
+                        Some((event_sub_process, start_event.error_ref()))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        });
+        error_event_sub_processes
+            .clone()
+            .find(|(_, catches)| catches.is_some() && *catches == error_ref)
+            .or_else(|| {
+                error_event_sub_processes
+                    .clone()
+                    .find(|(_, catches)| catches.is_none())
+            })
+            .map(|(event_sub_process, _)| event_sub_process)
+    }
+
+    /// Returns whether this event sub-process is started by the signal with the given id.
+    pub(crate) fn is_triggered_by_signal(&self, signal_id: &str) -> bool {
+        match self.start_event() {
+            Some(BPMNElement::SignalStartEvent(start_event)) => {
+                start_event.signal_ref() == Some(signal_id)
+            }
+            _ => false,
+        }
+    }
+
+    /// Catches a broadcast signal: a new instance of this event sub-process is started.
+    pub(crate) fn catch_signal(&self, sub_marking: &mut BPMNSubMarking) -> Result<()> {
+        self.start_instance(sub_marking)
+    }
+}
+
+impl BPMNElementTrait for BPMNEventSubProcess {
+    fn add_incoming_sequence_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!(
+            "event sub-processes cannot have incoming sequence flows"
+        ))
+    }
+
+    fn add_outgoing_sequence_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!(
+            "event sub-processes cannot have outgoing sequence flows"
+        ))
+    }
+
+    fn clear_incoming_sequence_flows(&mut self) {}
+
+    fn clear_outgoing_sequence_flows(&mut self) {}
+
+    fn add_incoming_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!(
+            "event sub-processes cannot have incoming message flows"
+        ))
+    }
+
+    fn add_outgoing_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!(
+            "event sub-processes cannot have outgoing message flows"
+        ))
+    }
+
+    fn verify_structural_correctness(
+        &self,
+        _parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        //recurse on elements
+        for element in &self.elements {
+            element.verify_structural_correctness(self, bpmn)?
+        }
+
+        //there must be a single start event, which has a trigger
+        let mut start_events = self.elements.iter().filter(|element| {
+            element.is_error_start_event()
+                || element.is_message_start_event()
+                || element.is_signal_start_event()
+                || element.is_start_event()
+                || element.is_timer_start_event()
+        });
+        match (start_events.next(), start_events.next()) {
+            (None, _) => Err(anyhow!(
+                "Event sub-process `{}` has no start event.",
+                self.id
+            )),
+            (Some(_), Some(_)) => Err(anyhow!(
+                "Event sub-process `{}` has more than one start event.",
+                self.id
+            )),
+            (Some(BPMNElement::StartEvent(start_event)), None) => Err(anyhow!(
+                "Start event `{}` of event sub-process `{}` must have a message, timer, signal or error event definition.",
+                start_event.id,
+                self.id
+            )),
+            (Some(_), None) => {
+                //there must be end events
+                if self.elements.end_events_without_recursing().is_empty() {
+                    return Err(anyhow!(
+                        "Event sub-process `{}` has a start event but no end events.",
+                        self.id
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl BPMNObject for BPMNEventSubProcess {
+    fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        None
+    }
+
+    fn local_index(&self) -> usize {
+        self.local_index
+    }
+
+    fn is_unconstrained_start_event(
+        &self,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn is_end_event(&self) -> bool {
+        false
+    }
+
+    fn incoming_sequence_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn outgoing_sequence_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn incoming_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Err(anyhow!(
+            "event sub-processes cannot have incoming sequence flows"
+        ))
+    }
+
+    fn outgoing_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Err(anyhow!(
+            "event sub-processes cannot have outgoing sequence flows"
+        ))
+    }
+
+    fn incoming_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn outgoing_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn can_start_process_instance(&self, _bpmn: &BusinessProcessModelAndNotation) -> Result<bool> {
+        //an event sub-process can only start while its parent instance is active
+        Ok(false)
+    }
+
+    fn outgoing_message_flows_always_have_tokens(&self) -> bool {
+        false
+    }
+
+    fn outgoing_messages_cannot_be_removed(&self) -> bool {
+        false
+    }
+
+    fn incoming_messages_are_ignored(&self) -> bool {
+        false
+    }
+
+    fn can_have_incoming_sequence_flows(&self) -> bool {
+        false
+    }
+
+    fn can_have_outgoing_sequence_flows(&self) -> bool {
+        false
+    }
+}
+
+impl Transitionable for BPMNEventSubProcess {
+    fn number_of_transitions(&self, marking: &BPMNSubMarking) -> usize {
+        //one transition to start
+        let mut result = 1;
+
+        for sub_marking in &marking.element_index_2_sub_markings[self.local_index] {
+            // one transition to end the instantiation
+            result += 1;
+            // and the transitions within us
+            result += self.elements.number_of_transitions(sub_marking);
+        }
+
+        result
+    }
+
+    fn enabled_transitions(
+        &self,
+        root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<BitVec> {
+        //start transition: enabled while the parent instance is active
+        let mut result = bitvec![0; 1];
+        if Self::instance_is_active(parent, sub_marking)
+            && self.trigger_is_present(root_marking, bpmn)?
+        {
+            result.set(0, true);
+        }
+
+        //gather sub-process instantations transitions
+        for sub_marking in &sub_marking.element_index_2_sub_markings[self.local_index] {
+            let sub_marking_enabled_transitions =
+                self.elements
+                    .enabled_transitions(root_marking, sub_marking, self, bpmn)?;
+
+            //end transition
+            if sub_marking_enabled_transitions.not_any() {
+                result.push(true);
+            } else {
+                result.push(false);
+            }
+
+            //transitions from this instantiation
+            result.extend(sub_marking_enabled_transitions);
+        }
+
+        Ok(result)
+    }
+
+    fn execute_transition(
+        &self,
+        mut transition_index: TransitionIndex,
+        root_marking: &mut BPMNRootMarking,
+        sub_marking: &mut BPMNSubMarking,
+        _parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        if transition_index == 0 {
+            //consume message
+            if let Some(BPMNElement::MessageStartEvent(start_event)) = self.start_event() {
+                if let Some(message_flow_index) = start_event.incoming_message_flow {
+                    let source = bpmn.message_flow_index_2_source(message_flow_index)?;
+                    if !source.outgoing_message_flows_always_have_tokens()
+                        && !source.outgoing_messages_cannot_be_removed()
+                    {
+                        root_marking.message_flow_2_tokens[message_flow_index] -= 1;
+                    }
+                }
+            }
+
+            //produce -> start a new instance
+            return self.start_instance(sub_marking);
+        }
+        transition_index -= 1;
+
+        //find the sub-marking that contains the transition index
+        let mut remove_instantiation = None;
+        for (instantiation_index, sub_sub_marking) in sub_marking.element_index_2_sub_markings
+            [self.local_index]
+            .iter_mut()
+            .enumerate()
+        {
+            // one transition to end the instantiation
+            if transition_index == 0 {
+                remove_instantiation = Some(instantiation_index);
+                break;
+            }
+            transition_index -= 1;
+
+            // and the transitions within us
+            let number_of_sub_transitions = self.elements.number_of_transitions(sub_sub_marking);
+            if transition_index < number_of_sub_transitions {
+                //an error that is not caught within the instance is passed on to the parent of the parent instance
+                return self
+                    .elements
+                    .execute_transition(transition_index, root_marking, sub_sub_marking, self, bpmn)
+                    .with_context(|| {
+                        format!("Execute transition in event sub-process `{}`.", self.id)
+                    });
+            }
+            transition_index -= number_of_sub_transitions;
+        }
+
+        match remove_instantiation {
+            Some(remove_instantiation_index) => {
+                sub_marking.element_index_2_sub_markings[self.local_index]
+                    .remove(remove_instantiation_index);
+                Ok(())
+            }
+            None => Err(anyhow!("Transition does not exist.")),
+        }
+    }
+
+    fn transition_activity(
+        &self,
+        mut transition_index: TransitionIndex,
+        marking: &BPMNSubMarking,
+    ) -> Option<Activity> {
+        //start transition
+        if transition_index == 0 {
+            return None;
+        }
+        transition_index -= 1;
+
+        for sub_marking in &marking.element_index_2_sub_markings[self.local_index] {
+            if transition_index == 0 {
+                //end transition
+                return None;
+            }
+            transition_index -= 1;
+
+            //own transitions
+            let sub_number_of_transitions = self.elements.number_of_transitions(sub_marking);
+            if transition_index < sub_number_of_transitions {
+                return self
+                    .elements
+                    .transition_activity(transition_index, sub_marking);
+            }
+            transition_index -= sub_number_of_transitions;
+        }
+        None
+    }
+
+    fn transition_debug(
+        &self,
+        mut transition_index: TransitionIndex,
+        marking: &BPMNSubMarking,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Option<String> {
+        //start transition
+        if transition_index == 0 {
+            return Some(format!(
+                "event sub-process `{}`; start transition",
+                self.id
+            ));
+        }
+        transition_index -= 1;
+
+        //instantiations
+        for (i, sub_marking) in marking.element_index_2_sub_markings[self.local_index]
+            .iter()
+            .enumerate()
+        {
+            if transition_index == 0 {
+                //end transition
+                return Some(format!(
+                    "event sub-process `{}`; instantiation {}, end transition",
+                    self.id, i
+                ));
+            }
+            transition_index -= 1;
+
+            //own transitions
+            let sub_number_of_transitions = self.elements.number_of_transitions(sub_marking);
+            if transition_index < sub_number_of_transitions {
+                return self
+                    .elements
+                    .transition_debug(transition_index, sub_marking, bpmn);
+            }
+            transition_index -= sub_number_of_transitions;
+        }
+        None
+    }
+
+    fn transition_probabilistic_penalty(
+        &self,
+        mut transition_index: TransitionIndex,
+        marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+    ) -> Option<Fraction> {
+        //start transition
+        if transition_index == 0 {
+            return Some(Fraction::one());
+        }
+        transition_index -= 1;
+
+        //instantiations
+        for sub_marking in &marking.element_index_2_sub_markings[self.local_index] {
+            if transition_index == 0 {
+                //end transition
+                return Some(Fraction::one());
+            }
+            transition_index -= 1;
+
+            //own transitions
+            let sub_number_of_transitions = self.elements.number_of_transitions(sub_marking);
+            if transition_index < sub_number_of_transitions {
+                return self.elements.transition_probabilistic_penalty(
+                    transition_index,
+                    sub_marking,
+                    self,
+                );
+            }
+            transition_index -= sub_number_of_transitions;
+        }
+        None
+    }
+
+    fn transition_2_consumed_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        Err(anyhow!("Sub-processes are not yet supported here."))
+    }
+
+    fn transition_2_produced_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        Err(anyhow!("Sub-processes are not yet supported here."))
+    }
+}
+
+impl Searchable for BPMNEventSubProcess {
+    fn id_2_pool_and_global_index(&self, id: &str) -> Option<(Option<usize>, GlobalIndex)> {
+        if self.id == id {
+            Some((Some(self.local_index), self.global_index))
+        } else {
+            if let Some((_, index)) = self.elements.id_2_pool_and_global_index(id) {
+                Some((Some(self.local_index), index))
+            } else {
+                None
+            }
+        }
+    }
+
+    fn global_index_2_sequence_flow_and_parent(
+        &self,
+        sequence_flow_global_index: GlobalIndex,
+    ) -> Option<(&BPMNSequenceFlow, Option<&dyn Processable>)> {
+        if let Some((flow, parent)) = self
+            .sequence_flows
+            .global_index_2_sequence_flow_and_parent(sequence_flow_global_index)
+        {
+            if parent.is_some() {
+                Some((flow, parent))
+            } else {
+                Some((flow, Some(self)))
+            }
+        } else {
+            None
+        }
+    }
+
+    fn id_2_local_index(&self, id: &str) -> Option<usize> {
+        if let Some(x) = self.elements.id_2_local_index(id) {
+            Some(x)
+        } else {
+            self.sequence_flows.id_2_local_index(id)
+        }
+    }
+
+    fn all_elements_ref(&self) -> Vec<&BPMNElement> {
+        self.elements.all_elements_ref()
+    }
+
+    fn parent_of(&self, global_index: GlobalIndex) -> (Option<&dyn Processable>, bool) {
+        if self.global_index == global_index {
+            (None, true)
+        } else {
+            let x = self.elements.parent_of(global_index);
+            if x.1 && x.0.is_none() {
+                (Some(self), true)
+            } else if x.1 {
+                x
+            } else {
+                (None, false)
+            }
+        }
+    }
+
+    fn all_sequence_flows_ref(&self) -> Vec<&BPMNSequenceFlow> {
+        let mut result: Vec<&BPMNSequenceFlow> = self.sequence_flows.iter().collect();
+        result.extend(self.elements.all_sequence_flows_ref());
+        result
+    }
+
+    fn global_index_2_sequence_flow_mut(
+        &mut self,
+        sequence_flow_global_index: GlobalIndex,
+    ) -> Option<&mut BPMNSequenceFlow> {
+        let x = self
+            .sequence_flows
+            .iter_mut()
+            .filter_map(|sequence_flow| {
+                if sequence_flow.global_index == sequence_flow_global_index {
+                    Some(sequence_flow)
+                } else {
+                    None
+                }
+            })
+            .next();
+        if x.is_some() {
+            return x;
+        }
+
+        //recurse
+        self.elements
+            .global_index_2_sequence_flow_mut(sequence_flow_global_index)
+    }
+
+    fn global_index_2_element(&self, index: GlobalIndex) -> Option<&BPMNElement> {
+        self.elements.global_index_2_element(index)
+    }
+
+    fn global_index_2_element_mut(&mut self, index: GlobalIndex) -> Option<&mut BPMNElement> {
+        self.elements.global_index_2_element_mut(index)
+    }
+
+    fn local_index_2_element(&self, index: usize) -> Option<&BPMNElement> {
+        self.elements.local_index_2_element(index)
+    }
+
+    fn local_index_2_element_mut(&mut self, index: usize) -> Option<&mut BPMNElement> {
+        self.elements.local_index_2_element_mut(index)
+    }
+}
+
+impl Processable for BPMNEventSubProcess {
+    fn elements_non_recursive(&self) -> &Vec<BPMNElement> {
+        &self.elements
+    }
+
+    fn sequence_flows_non_recursive(&self) -> &Vec<BPMNSequenceFlow> {
+        &self.sequence_flows
+    }
+
+    fn to_sub_marking(&self, initiation_mode: &InitiationMode) -> Result<BPMNSubMarking> {
+        to_sub_marking!(self, initiation_mode)
+    }
+
+    fn is_sub_process(&self) -> bool {
+        true
+    }
+
+    fn is_event_sub_process(&self) -> bool {
+        true
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    BusinessProcessModelAndNotation,
+    BPMNSequenceFlow, BusinessProcessModelAndNotation,
     element::BPMNElementTrait,
     if_not::{IfNot, IfNotDefault},
     marking::{BPMNRootMarking, BPMNSubMarking, Token},
@@ -21,10 +21,77 @@ pub struct BPMNExclusiveGateway {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
     pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+    ///the id of the outgoing sequence flow that is taken when no other one is
+    pub(crate) default_sequence_flow: Option<String>,
     pub(crate) incoming_sequence_flows: Vec<usize>,
     pub(crate) outgoing_sequence_flows: Vec<usize>,
 }
 
+impl BPMNExclusiveGateway {
+    /// Returns the id of the default sequence flow of this gateway, if it has one.
+    pub fn default_sequence_flow_id(&self) -> Option<&str> {
+        self.default_sequence_flow.as_deref()
+    }
+
+    /// Returns the default sequence flow of this gateway, if it has one.
+    pub fn default_sequence_flow<'a>(
+        &self,
+        parent: &'a dyn Processable,
+    ) -> Option<&'a BPMNSequenceFlow> {
+        default_sequence_flow(
+            &self.default_sequence_flow,
+            &self.outgoing_sequence_flows,
+            parent,
+        )
+    }
+
+    /// Returns, for each outgoing sequence flow, whether the split can take it.
+    /// In a data-aware marking, the split can only take outgoing sequence flows of which the condition holds, and the default sequence flow only if there are none.
+    fn takeable_outgoing_sequence_flows(
+        &self,
+        root_marking: &BPMNRootMarking,
+        parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<bool>> {
+        let default_sequence_flow = self
+            .default_sequence_flow(parent)
+            .map(|sequence_flow| sequence_flow.local_index);
+
+        let mut result = Vec::with_capacity(self.outgoing_sequence_flows.len());
+        for outgoing_sequence_flow_local_index in &self.outgoing_sequence_flows {
+            let outgoing_sequence_flow = parent
+                .sequence_flows_non_recursive()
+                .get(*outgoing_sequence_flow_local_index)
+                .ok_or_else(|| anyhow!("sequence flow not found"))?;
+            result.push(
+                outgoing_sequence_flow.has_fireable_weight(bpmn)
+                    && (Some(*outgoing_sequence_flow_local_index) == default_sequence_flow
+                        || outgoing_sequence_flow.condition_holds(root_marking)),
+            );
+        }
+
+        if let Some(default_sequence_flow) = default_sequence_flow {
+            if root_marking.variables.is_some()
+                && self.outgoing_sequence_flows.iter().zip(result.iter()).any(
+                    |(sequence_flow_index, takeable)| {
+                        *sequence_flow_index != default_sequence_flow && *takeable
+                    },
+                )
+            {
+                for (sequence_flow_index, takeable) in
+                    self.outgoing_sequence_flows.iter().zip(result.iter_mut())
+                {
+                    if *sequence_flow_index == default_sequence_flow {
+                        *takeable = false;
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
 impl BPMNElementTrait for BPMNExclusiveGateway {
     fn add_incoming_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
         self.incoming_sequence_flows.push(flow_index);
@@ -54,10 +121,15 @@ impl BPMNElementTrait for BPMNExclusiveGateway {
 
     fn verify_structural_correctness(
         &self,
-        _parent: &dyn Processable,
+        parent: &dyn Processable,
         _bpmn: &BusinessProcessModelAndNotation,
     ) -> Result<()> {
-        Ok(())
+        verify_default_sequence_flow(
+            &self.id,
+            &self.default_sequence_flow,
+            &self.outgoing_sequence_flows,
+            parent,
+        )
     }
 }
 
@@ -145,7 +217,7 @@ impl Transitionable for BPMNExclusiveGateway {
 
     fn enabled_transitions(
         &self,
-        _root_marking: &BPMNRootMarking,
+        root_marking: &BPMNRootMarking,
         sub_marking: &BPMNSubMarking,
         parent: &dyn Processable,
         bpmn: &BusinessProcessModelAndNotation,
@@ -153,6 +225,7 @@ impl Transitionable for BPMNExclusiveGateway {
         let mut result = bitvec![0;self.number_of_transitions(sub_marking)];
 
         let outgoing = self.outgoing_sequence_flows.len().max(1);
+        let takeable = self.takeable_outgoing_sequence_flows(root_marking, parent, bpmn)?;
 
         match (
             self.incoming_sequence_flows.len() > 0,
@@ -164,14 +237,8 @@ impl Transitionable for BPMNExclusiveGateway {
                     self.incoming_sequence_flows.iter().enumerate()
                 {
                     if sub_marking.sequence_flow_2_tokens[*incoming_sequence_flow_index] >= 1 {
-                        for (outgoing_index, outgoing_sequence_flow_local_index) in
-                            self.outgoing_sequence_flows.iter().enumerate()
-                        {
-                            let outgoing_sequence_flow = parent
-                                .sequence_flows_non_recursive()
-                                .get(*outgoing_sequence_flow_local_index)
-                                .ok_or_else(|| anyhow!("sequence flow not found"))?;
-                            if outgoing_sequence_flow.has_fireable_weight(bpmn) {
+                        for (outgoing_index, takeable) in takeable.iter().enumerate() {
+                            if *takeable {
                                 let transition = incoming_index * outgoing + outgoing_index;
                                 result.set(transition, true);
                             }
@@ -192,14 +259,8 @@ impl Transitionable for BPMNExclusiveGateway {
             (false, true) => {
                 //split only; we are in initiation mode 2.
                 if sub_marking.element_index_2_tokens[self.local_index] >= 1 {
-                    for (outgoing_index, outgoing_sequence_flow_local_index) in
-                        self.outgoing_sequence_flows.iter().enumerate()
-                    {
-                        let outgoing_sequence_flow = parent
-                            .sequence_flows_non_recursive()
-                            .get(*outgoing_sequence_flow_local_index)
-                            .ok_or_else(|| anyhow!("sequence flow not found"))?;
-                        if outgoing_sequence_flow.has_fireable_weight(bpmn) {
+                    for (outgoing_index, takeable) in takeable.iter().enumerate() {
+                        if *takeable {
                             result.set(outgoing_index, true);
                         }
                     }
@@ -448,3 +509,41 @@ impl Transitionable for BPMNExclusiveGateway {
         }
     }
 }
+
+/// Returns the default sequence flow of a gateway, if it has one and it is one of the gateway's outgoing sequence flows.
+pub(crate) fn default_sequence_flow<'a>(
+    default_sequence_flow: &Option<String>,
+    outgoing_sequence_flows: &[usize],
+    parent: &'a dyn Processable,
+) -> Option<&'a BPMNSequenceFlow> {
+    let default_sequence_flow = default_sequence_flow.as_ref()?;
+    outgoing_sequence_flows
+        .iter()
+        .filter_map(|sequence_flow_index| {
+            parent
+                .sequence_flows_non_recursive()
+                .get(*sequence_flow_index)
+        })
+        .find(|sequence_flow| &sequence_flow.id == default_sequence_flow)
+}
+
+/// Checks that the default sequence flow of a gateway, if any, is one of its outgoing sequence flows.
+pub(crate) fn verify_default_sequence_flow(
+    gateway_id: &str,
+    default_sequence_flow_id: &Option<String>,
+    outgoing_sequence_flows: &[usize],
+    parent: &dyn Processable,
+) -> Result<()> {
+    if let Some(id) = default_sequence_flow_id {
+        if default_sequence_flow(default_sequence_flow_id, outgoing_sequence_flows, parent)
+            .is_none()
+        {
+            return Err(anyhow!(
+                "The default sequence flow `{}` of gateway `{}` is not one of its outgoing sequence flows.",
+                id,
+                gateway_id
+            ));
+        }
+    }
+    Ok(())
+}
@@ -1,10 +1,14 @@
 use crate::{
     BusinessProcessModelAndNotation,
     element::{BPMNElement, BPMNElementTrait},
+    elements::boundary_event::BPMNBoundaryEvent,
+    error::BPMNError,
     marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    multi_instance::BPMNMultiInstance,
     parser::parser_state::GlobalIndex,
     semantics::TransitionIndex,
     sequence_flow::BPMNSequenceFlow,
+    standard_loop::BPMNStandardLoop,
     structure_checker::verify_structural_correctness_initiation_mode,
     traits::{
         objectable::{BPMNObject, EMPTY_FLOWS},
@@ -35,6 +39,9 @@ pub struct BPMNExpandedSubProcess {
     //external sequence flows
     pub(crate) incoming_sequence_flows: Vec<usize>,
     pub(crate) outgoing_sequence_flows: Vec<usize>,
+
+    pub(crate) multi_instance: Option<BPMNMultiInstance>,
+    pub(crate) standard_loop: Option<BPMNStandardLoop>,
 }
 
 impl BPMNExpandedSubProcess {
@@ -45,6 +52,122 @@ impl BPMNExpandedSubProcess {
         let initiation_mode = self.initiation_mode(bpmn)?;
         self.to_sub_marking(&initiation_mode)
     }
+
+    /// Returns whether this sub-process keeps activations, each with their own instances, rather than instances directly.
+    /// This is the case for multi-instance and loop sub-processes.
+    fn has_activations(&self) -> bool {
+        self.multi_instance.is_some() || self.standard_loop.is_some()
+    }
+
+    /// Returns the number of transitions that choose to repeat or continue a loop sub-process.
+    fn number_of_choice_transitions(&self, sub_markings: &[BPMNSubMarking]) -> usize {
+        match &self.standard_loop {
+            Some(standard_loop) => standard_loop.number_of_choice_transitions(sub_markings),
+            None => 0,
+        }
+    }
+
+    /// Returns the running process instances of this sub-process, given its sub-markings.
+    /// For a multi-instance or loop sub-process, these are the running instances of all its activations.
+    pub(crate) fn instances<'a>(
+        &self,
+        sub_markings: &'a [BPMNSubMarking],
+    ) -> Vec<&'a BPMNSubMarking> {
+        if self.has_activations() {
+            sub_markings
+                .iter()
+                .flat_map(|activation| activation.element_index_2_sub_markings[0].iter())
+                .collect()
+        } else {
+            sub_markings.iter().collect()
+        }
+    }
+
+    /// Returns the running process instances of this sub-process, given its sub-markings.
+    /// For a multi-instance or loop sub-process, these are the running instances of all its activations.
+    pub(crate) fn instances_mut<'a>(
+        &self,
+        sub_markings: &'a mut [BPMNSubMarking],
+    ) -> Vec<&'a mut BPMNSubMarking> {
+        if self.has_activations() {
+            sub_markings
+                .iter_mut()
+                .flat_map(|activation| activation.element_index_2_sub_markings[0].iter_mut())
+                .collect()
+        } else {
+            sub_markings.iter_mut().collect()
+        }
+    }
+
+    /// Executes a transition of a running instance of a multi-instance or loop sub-process.
+    /// The transition index excludes the start and choice transitions.
+    fn execute_activation_transition(
+        &self,
+        mut transition_index: TransitionIndex,
+        root_marking: &mut BPMNRootMarking,
+        sub_marking: &mut BPMNSubMarking,
+        parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        for activation_index in 0..sub_marking.element_index_2_sub_markings[self.local_index].len()
+        {
+            let activation =
+                &mut sub_marking.element_index_2_sub_markings[self.local_index][activation_index];
+            for instance_index in 0..activation.element_index_2_sub_markings[0].len() {
+                // one transition to end the instance
+                if transition_index == 0 {
+                    activation.element_index_2_sub_markings[0].remove(instance_index);
+
+                    //a loop activation now waits for the choice to repeat or continue
+                    if self.multi_instance.is_some() {
+                        if activation.element_index_2_tokens[0] > 0 {
+                            //sequential: start the next instance
+                            activation.element_index_2_tokens[0] -= 1;
+                            activation.element_index_2_sub_markings[0]
+                                .push(self.start_process_instance(bpmn)?);
+                        } else if activation.element_index_2_sub_markings[0].is_empty() {
+                            //all instances have ended: the activity completes
+                            sub_marking.element_index_2_sub_markings[self.local_index]
+                                .remove(activation_index);
+                            execute_transition_parallel_split!(self, sub_marking);
+                        }
+                    }
+                    return Ok(());
+                }
+                transition_index -= 1;
+
+                // and the transitions within the instance
+                let instance = &mut activation.element_index_2_sub_markings[0][instance_index];
+                let number_of_sub_transitions = self.elements.number_of_transitions(instance);
+                if transition_index < number_of_sub_transitions {
+                    self.elements
+                        .execute_transition(transition_index, root_marking, instance, self, bpmn)
+                        .with_context(|| {
+                            format!("Execute transition in sub-process `{}`.", self.id)
+                        })?;
+
+                    //an error that is not caught here ends the enclosing instance, which includes this activation
+                    if let Some(error_ref) = &root_marking.error_thrown {
+                        if let Some(boundary_event) = BPMNBoundaryEvent::error_boundary_event_of(
+                            self.local_index,
+                            error_ref.as_deref(),
+                            parent,
+                        ) {
+                            //the error is caught: cancel all instances and continue along the boundary event
+                            root_marking.error_thrown = None;
+                            sub_marking.element_index_2_sub_markings[self.local_index]
+                                .remove(activation_index);
+                            execute_transition_parallel_split!(boundary_event, sub_marking);
+                        }
+                    }
+                    return Ok(());
+                }
+                transition_index -= number_of_sub_transitions;
+            }
+        }
+
+        Err(anyhow!("Transition does not exist."))
+    }
 }
 
 impl BPMNElementTrait for BPMNExpandedSubProcess {
@@ -177,7 +300,11 @@ impl Transitionable for BPMNExpandedSubProcess {
         //behaves like an XOR-join to start
         let mut result = number_of_transitions_xor_join_only!(self);
 
-        for sub_marking in &marking.element_index_2_sub_markings[self.local_index] {
+        //choices of a loop
+        result += self
+            .number_of_choice_transitions(&marking.element_index_2_sub_markings[self.local_index]);
+
+        for sub_marking in self.instances(&marking.element_index_2_sub_markings[self.local_index]) {
             // one transition to end the instantiation
             result += 1;
             // and the transitions within us
@@ -197,8 +324,19 @@ impl Transitionable for BPMNExpandedSubProcess {
         //start transitions: like an xor join
         let mut result = enabledness_xor_join_only!(self, sub_marking);
 
+        //choices of a loop
+        if let Some(standard_loop) = &self.standard_loop {
+            result.extend(
+                standard_loop.choice_enabledness(
+                    &sub_marking.element_index_2_sub_markings[self.local_index],
+                ),
+            );
+        }
+
         //gather sub-process instantations transitions
-        for sub_marking in &sub_marking.element_index_2_sub_markings[self.local_index] {
+        for sub_marking in
+            self.instances(&sub_marking.element_index_2_sub_markings[self.local_index])
+        {
             let sub_marking_enabled_transitions =
                 self.elements
                     .enabled_transitions(root_marking, sub_marking, self, bpmn)?;
@@ -222,7 +360,7 @@ impl Transitionable for BPMNExpandedSubProcess {
         mut transition_index: TransitionIndex,
         root_marking: &mut BPMNRootMarking,
         sub_marking: &mut BPMNSubMarking,
-        _parent: &dyn Processable,
+        parent: &dyn Processable,
         bpmn: &BusinessProcessModelAndNotation,
     ) -> Result<()> {
         if transition_index < number_of_transitions_xor_join_only!(self) {
@@ -231,13 +369,60 @@ impl Transitionable for BPMNExpandedSubProcess {
             //consume
             execute_transition_xor_join_consume!(self, sub_marking, transition_index);
 
-            //produce -> start a new sub-process instance
-            sub_marking.element_index_2_sub_markings[self.local_index]
-                .push(self.start_process_instance(bpmn)?);
+            match (&self.multi_instance, &self.standard_loop) {
+                (Some(multi_instance), _) if multi_instance.loop_cardinality == 0 => {
+                    //produce -> no instances to execute
+                    execute_transition_parallel_split!(self, sub_marking);
+                }
+                (Some(multi_instance), _) => {
+                    //produce -> start a new activation with its first instances
+                    let activation = multi_instance
+                        .new_sub_process_activation(|| self.start_process_instance(bpmn))?;
+                    sub_marking.element_index_2_sub_markings[self.local_index].push(activation);
+                }
+                (None, Some(standard_loop)) => {
+                    //produce -> start a new activation, with its first instance unless the loop chooses first
+                    let activation = standard_loop
+                        .new_sub_process_activation(|| self.start_process_instance(bpmn))?;
+                    sub_marking.element_index_2_sub_markings[self.local_index].push(activation);
+                }
+                (None, None) => {
+                    //produce -> start a new sub-process instance
+                    sub_marking.element_index_2_sub_markings[self.local_index]
+                        .push(self.start_process_instance(bpmn)?);
+                }
+            }
             return Ok(());
         }
         transition_index -= number_of_transitions_xor_join_only!(self);
 
+        //choices of a loop
+        if let Some(standard_loop) = &self.standard_loop {
+            let activations = &mut sub_marking.element_index_2_sub_markings[self.local_index];
+            if transition_index < standard_loop.number_of_choice_transitions(activations) {
+                if standard_loop.execute_choice_transition(activations, transition_index)? {
+                    //continue
+                    execute_transition_parallel_split!(self, sub_marking);
+                } else {
+                    //repeat -> start a new instance
+                    activations[transition_index / 2].element_index_2_sub_markings[0]
+                        .push(self.start_process_instance(bpmn)?);
+                }
+                return Ok(());
+            }
+            transition_index -= standard_loop.number_of_choice_transitions(activations);
+        }
+
+        if self.has_activations() {
+            return self.execute_activation_transition(
+                transition_index,
+                root_marking,
+                sub_marking,
+                parent,
+                bpmn,
+            );
+        }
+
         //find the sub-marking that contains the transition index
         let mut remove_instantiation = None;
         for (instantiation_index, sub_sub_marking) in sub_marking.element_index_2_sub_markings
@@ -261,6 +446,21 @@ impl Transitionable for BPMNExpandedSubProcess {
                 self.elements
                     .execute_transition(transition_index, root_marking, sub_sub_marking, self, bpmn)
                     .with_context(|| format!("Execute transition in sub-process `{}`.", self.id))?;
+
+                //an error that is not caught here ends the enclosing instance, which includes this instance
+                if let Some(error_ref) = &root_marking.error_thrown {
+                    if let Some(boundary_event) = BPMNBoundaryEvent::error_boundary_event_of(
+                        self.local_index,
+                        error_ref.as_deref(),
+                        parent,
+                    ) {
+                        //the error is caught: cancel the instance and continue along the boundary event
+                        root_marking.error_thrown = None;
+                        remove_instantiation = Some(instantiation_index);
+                        execute_transition_parallel_split!(boundary_event, sub_marking);
+                        break;
+                    }
+                }
                 return Ok(());
             }
             transition_index -= number_of_sub_transitions;
@@ -285,7 +485,15 @@ impl Transitionable for BPMNExpandedSubProcess {
         }
         transition_index -= number_of_transitions_xor_join_only!(self);
 
-        for sub_marking in &marking.element_index_2_sub_markings[self.local_index] {
+        //choices of a loop are silent
+        let number_of_choice_transitions = self
+            .number_of_choice_transitions(&marking.element_index_2_sub_markings[self.local_index]);
+        if transition_index < number_of_choice_transitions {
+            return None;
+        }
+        transition_index -= number_of_choice_transitions;
+
+        for sub_marking in self.instances(&marking.element_index_2_sub_markings[self.local_index]) {
             if transition_index == 0 {
                 //end transition
                 return None;
@@ -319,9 +527,27 @@ impl Transitionable for BPMNExpandedSubProcess {
         }
         transition_index -= self.incoming_sequence_flows.len().max(1);
 
+        //choices of a loop
+        let number_of_choice_transitions = self
+            .number_of_choice_transitions(&marking.element_index_2_sub_markings[self.local_index]);
+        if transition_index < number_of_choice_transitions {
+            return Some(format!(
+                "expanded sub-process `{}`; activation {}, {} transition",
+                self.id,
+                transition_index / 2,
+                if transition_index % 2 == 0 {
+                    "repeat"
+                } else {
+                    "continue"
+                }
+            ));
+        }
+        transition_index -= number_of_choice_transitions;
+
         //instantiations
-        for (i, sub_marking) in marking.element_index_2_sub_markings[self.local_index]
-            .iter()
+        for (i, sub_marking) in self
+            .instances(&marking.element_index_2_sub_markings[self.local_index])
+            .into_iter()
             .enumerate()
         {
             if transition_index == 0 {
@@ -357,8 +583,17 @@ impl Transitionable for BPMNExpandedSubProcess {
         }
         transition_index -= self.incoming_sequence_flows.len().max(1);
 
+        //choices of a loop
+        if let Some(standard_loop) = &self.standard_loop {
+            let activations = &marking.element_index_2_sub_markings[self.local_index];
+            if transition_index < standard_loop.number_of_choice_transitions(activations) {
+                return standard_loop.choice_probabilistic_penalty(activations, transition_index);
+            }
+            transition_index -= standard_loop.number_of_choice_transitions(activations);
+        }
+
         //instantiations
-        for sub_marking in marking.element_index_2_sub_markings[self.local_index].iter() {
+        for sub_marking in self.instances(&marking.element_index_2_sub_markings[self.local_index]) {
             if transition_index == 0 {
                 //end transition
                 return Some(Fraction::one());
@@ -1,6 +1,7 @@
 use crate::{
     BPMNSequenceFlow, BusinessProcessModelAndNotation,
     element::BPMNElementTrait,
+    elements::exclusive_gateway::{default_sequence_flow, verify_default_sequence_flow},
     if_not::{IfNot, IfNotDefault},
     marking::{BPMNRootMarking, BPMNSubMarking, Token},
     parser::parser_state::GlobalIndex,
@@ -22,9 +23,32 @@ pub struct BPMNInclusiveGateway {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
     pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+    ///the id of the outgoing sequence flow that is taken when no other one is
+    pub(crate) default_sequence_flow: Option<String>,
     pub(crate) incoming_sequence_flows: Vec<usize>,
     pub(crate) outgoing_sequence_flows: Vec<usize>,
 }
+
+impl BPMNInclusiveGateway {
+    /// Returns the id of the default sequence flow of this gateway, if it has one.
+    pub fn default_sequence_flow_id(&self) -> Option<&str> {
+        self.default_sequence_flow.as_deref()
+    }
+
+    /// Returns the default sequence flow of this gateway, if it has one.
+    pub fn default_sequence_flow<'a>(
+        &self,
+        parent: &'a dyn Processable,
+    ) -> Option<&'a BPMNSequenceFlow> {
+        default_sequence_flow(
+            &self.default_sequence_flow,
+            &self.outgoing_sequence_flows,
+            parent,
+        )
+    }
+}
+
 impl BPMNElementTrait for BPMNInclusiveGateway {
     fn add_incoming_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
         self.incoming_sequence_flows.push(flow_index);
@@ -60,10 +84,15 @@ impl BPMNElementTrait for BPMNInclusiveGateway {
 
     fn verify_structural_correctness(
         &self,
-        _parent: &dyn Processable,
+        parent: &dyn Processable,
         _bpmn: &BusinessProcessModelAndNotation,
     ) -> Result<()> {
-        Ok(())
+        verify_default_sequence_flow(
+            &self.id,
+            &self.default_sequence_flow,
+            &self.outgoing_sequence_flows,
+            parent,
+        )
     }
 }
 
@@ -151,7 +180,7 @@ impl Transitionable for BPMNInclusiveGateway {
 
     fn enabled_transitions(
         &self,
-        _root_marking: &BPMNRootMarking,
+        root_marking: &BPMNRootMarking,
         sub_marking: &BPMNSubMarking,
         parent: &dyn Processable,
         _bpmn: &BusinessProcessModelAndNotation,
@@ -161,7 +190,13 @@ impl Transitionable for BPMNInclusiveGateway {
             //that is, look in the extra virtual sequence flow
             if sub_marking.element_index_2_tokens[self.local_index] >= 1 {
                 //enabled
-                return Ok(bitvec![1;self.number_of_transitions(sub_marking)]);
+                return enabled_inclusive_splits(
+                    &self.outgoing_sequence_flows,
+                    self.default_sequence_flow(parent)
+                        .map(|sequence_flow| sequence_flow.local_index),
+                    root_marking,
+                    parent,
+                );
             } else {
                 //not enabled
                 return Ok(bitvec![0;self.number_of_transitions(sub_marking)]);
@@ -183,61 +218,19 @@ impl Transitionable for BPMNInclusiveGateway {
             }
 
             //perform a backwards search to find tokens that may still come to the gateway
-            let mut queue = VecDeque::new();
-            queue.extend(empty_sequence_flows.clone());
-            let mut seen_sequence_flows = empty_sequence_flows;
-            while let Some(sequence_flow_index) = queue.pop_front() {
-                let sequence_flow = &parent.sequence_flows_non_recursive()[sequence_flow_index];
-
-                //check whether this sequence flow has a token
-                if *sub_marking
-                    .sequence_flow_2_tokens
-                    .get(sequence_flow.local_index)
-                    .ok_or_else(|| anyhow!("sequence flow not found"))?
-                    >= 1
-                {
-                    // we encountered a token on our search
-                    // OR join is not enabled, as that token may end up at the OR join
-                    return Ok(bitvec![0;self.number_of_transitions(sub_marking)]);
-                }
-
-                //check whether this sequece flow comes from a sub-process that has instantiations
-                if !sub_marking.element_index_2_sub_markings[sequence_flow.source_local_index]
-                    .is_empty()
-                {
-                    // OR join is not enabled: the instantiation may finish and that token may end up at the OR join
-                    return Ok(bitvec![0;self.number_of_transitions(sub_marking)]);
-                }
-
-                //check whether the source of this sequence flow is enabled by the initiation mode
-                if sub_marking.element_index_2_tokens[sequence_flow.source_local_index] >= 1 {
-                    // OR join is not enabled: this virtual token may end up at the OR join
-                    return Ok(bitvec![0;self.number_of_transitions(sub_marking)]);
-                }
-
-                //get the source
-                let source = parent
-                    .elements_non_recursive()
-                    .get(sequence_flow.source_local_index)
-                    .and_if_not("source not found")?;
-                for next_sequence_flow_index in source.incoming_sequence_flows() {
-                    let next_sequence_flow = parent
-                        .sequence_flows_non_recursive()
-                        .get(*next_sequence_flow_index)
-                        .and_if_not("next sequence flow not found")?;
-                    if seen_sequence_flows.insert(next_sequence_flow.local_index) {
-                        // Check whether this source is not an OR gateway itself that is "lower" than our OR join.
-                        // If it is, then we do not count a token that may come from it.
-                        if !source.is_inclusive_gateway() || source.local_index() < self.local_index
-                        {
-                            queue.push_back(*&next_sequence_flow.local_index);
-                        }
-                    }
-                }
+            if tokens_may_arrive(empty_sequence_flows, self.local_index, sub_marking, parent)? {
+                // OR join is not enabled, as a token may end up at the OR join
+                return Ok(bitvec![0;self.number_of_transitions(sub_marking)]);
             }
 
             //enabled
-            return Ok(bitvec![1;self.number_of_transitions(sub_marking)]);
+            return enabled_inclusive_splits(
+                &self.outgoing_sequence_flows,
+                self.default_sequence_flow(parent)
+                    .map(|sequence_flow| sequence_flow.local_index),
+                root_marking,
+                parent,
+            );
         }
     }
 
@@ -299,48 +292,13 @@ impl Transitionable for BPMNInclusiveGateway {
         _marking: &BPMNSubMarking,
         parent: &dyn Processable,
     ) -> Option<Fraction> {
-        if self.outgoing_sequence_flows.len() <= 1 {
-            return Some(Fraction::one());
-        }
-
-        let sum_weights = self
-            .outgoing_sequence_flows
-            .iter()
-            .filter_map(|sequence_flow_index| {
-                parent.sequence_flows_non_recursive()[*sequence_flow_index]
-                    .weight
-                    .as_ref()
-            })
-            .sum::<Fraction>();
-        let number_of_outgoing_sequence_flows = self.outgoing_sequence_flows.len();
-
-        let s_1 = (2_usize.pow(number_of_outgoing_sequence_flows.try_into().unwrap()) - 2)
-            * (number_of_outgoing_sequence_flows - 1);
-        let s_2 = Fraction::one()
-            + (1..number_of_outgoing_sequence_flows)
-                .map(|z| {
-                    Fraction::binomial_coefficient(number_of_outgoing_sequence_flows - 1, z - 1)
-                        * Fraction::from(z).recip()
-                })
-                .sum::<Fraction>();
-        let s = Fraction::from(s_1) + s_2 * Fraction::from(sum_weights.clone());
-
-        let selected_sequence_flows =
-            transition_index_2_sequence_flows(self, parent, transition_index).ok()?;
-        if selected_sequence_flows.len() == self.outgoing_sequence_flows.len() {
-            //full set chosen
-            Some(sum_weights / s)
-        } else {
-            //sub-set chosen
-            let mut sum_chosen: Fraction = selected_sequence_flows
-                .into_iter()
-                .filter_map(|sequence_flow| sequence_flow.weight.as_ref())
-                .sum();
-            sum_chosen *= transition_index.count_ones();
-            let sum_chosen = sum_chosen.recip();
-
-            Some((Fraction::from(number_of_outgoing_sequence_flows - 1) + sum_chosen) / s)
-        }
+        inclusive_split_probabilistic_penalty(
+            &self.outgoing_sequence_flows,
+            self.default_sequence_flow(parent)
+                .map(|sequence_flow| sequence_flow.local_index),
+            parent,
+            transition_index,
+        )
     }
 
     fn transition_2_consumed_tokens(
@@ -379,31 +337,240 @@ impl Transitionable for BPMNInclusiveGateway {
         parent: &dyn Processable,
         _bpmn: &BusinessProcessModelAndNotation,
     ) -> Result<Vec<Token>> {
-        Ok(
-            transition_index_2_sequence_flows(self, parent, transition_index)?
-                .into_iter()
-                .map(|sequence_flow| Token::SequenceFlow(sequence_flow.global_index()))
-                .collect::<Vec<_>>(),
-        )
+        Ok(transition_index_2_sequence_flows(
+            &self.outgoing_sequence_flows,
+            parent,
+            transition_index,
+        )?
+        .into_iter()
+        .map(|sequence_flow| Token::SequenceFlow(sequence_flow.global_index()))
+        .collect::<Vec<_>>())
     }
 }
 
-fn transition_index_2_sequence_flows<'a>(
-    gateway: &'a BPMNInclusiveGateway,
-    parent: &'a dyn Processable,
+/// Performs a backwards search from the given empty incoming sequence flows of a join, and returns whether a token may still come to them.
+/// The search does not pass through the join itself, nor through inclusive gateways that are "lower" than the join.
+pub(crate) fn tokens_may_arrive(
+    empty_sequence_flows: HashSet<usize>,
+    join_local_index: usize,
+    sub_marking: &BPMNSubMarking,
+    parent: &dyn Processable,
+) -> Result<bool> {
+    let mut queue = VecDeque::new();
+    queue.extend(empty_sequence_flows.clone());
+    let mut seen_sequence_flows = empty_sequence_flows;
+    while let Some(sequence_flow_index) = queue.pop_front() {
+        let sequence_flow = &parent.sequence_flows_non_recursive()[sequence_flow_index];
+
+        //check whether this sequence flow has a token
+        if *sub_marking
+            .sequence_flow_2_tokens
+            .get(sequence_flow.local_index)
+            .ok_or_else(|| anyhow!("sequence flow not found"))?
+            >= 1
+        {
+            // we encountered a token on our search, which may end up at the join
+            return Ok(true);
+        }
+
+        //get the source
+        let source = parent
+            .elements_non_recursive()
+            .get(sequence_flow.source_local_index)
+            .and_if_not("source not found")?;
+
+        //check whether this sequece flow comes from a sub-process that has instantiations
+        //(a complex gateway that waits for reset keeps its reset there, but produces no tokens)
+        if !source.is_complex_gateway()
+            && !sub_marking.element_index_2_sub_markings[sequence_flow.source_local_index]
+                .is_empty()
+        {
+            // the instantiation may finish and that token may end up at the join
+            return Ok(true);
+        }
+
+        //check whether the source of this sequence flow is enabled by the initiation mode
+        if sub_marking.element_index_2_tokens[sequence_flow.source_local_index] >= 1 {
+            // this virtual token may end up at the join
+            return Ok(true);
+        }
+
+        for next_sequence_flow_index in source.incoming_sequence_flows() {
+            let next_sequence_flow = parent
+                .sequence_flows_non_recursive()
+                .get(*next_sequence_flow_index)
+                .and_if_not("next sequence flow not found")?;
+            if seen_sequence_flows.insert(next_sequence_flow.local_index) {
+                // Check whether this source is not the join itself, or an OR gateway that is "lower" than the join.
+                // If it is, then we do not count a token that may come from it.
+                if source.local_index() != join_local_index
+                    && (!source.is_inclusive_gateway() || source.local_index() < join_local_index)
+                {
+                    queue.push_back(next_sequence_flow.local_index);
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Returns the local indices of the outgoing sequence flows that receive a token in the given transition of an inclusive split.
+fn transition_index_2_sequence_flow_indices(
+    outgoing_sequence_flows: &[usize],
     mut transition_index: usize,
-) -> Result<Vec<&'a BPMNSequenceFlow>> {
+) -> Vec<usize> {
     let mut result = vec![];
-    for sequence_flow_index in &gateway.outgoing_sequence_flows {
+    for sequence_flow_index in outgoing_sequence_flows {
         if transition_index % 2 == 0 {
-            result.push(
-                parent
-                    .sequence_flows_non_recursive()
-                    .get(*sequence_flow_index)
-                    .and_if_not_error_default()?,
-            );
+            result.push(*sequence_flow_index);
         }
         transition_index >>= 1;
     }
-    Ok(result)
+    result
+}
+
+/// Returns the transitions of an inclusive split that are enabled once its join is.
+///
+/// If the marking is not data-aware, these are all non-empty subsets of the outgoing sequence flows, except those that take the default sequence flow together with another one.
+/// If the marking is data-aware, this is only the set of outgoing sequence flows of which the condition holds, or the default sequence flow if there are none.
+pub(crate) fn enabled_inclusive_splits(
+    outgoing_sequence_flows: &[usize],
+    default_sequence_flow: Option<usize>,
+    root_marking: &BPMNRootMarking,
+    parent: &dyn Processable,
+) -> Result<BitVec> {
+    let number_of_transitions = 2usize.pow(outgoing_sequence_flows.len() as u32) - 1;
+
+    if root_marking.variables.is_some() {
+        //select the sequence flows of which the condition holds
+        let mut selected_sequence_flows = vec![];
+        for sequence_flow_index in outgoing_sequence_flows {
+            let sequence_flow = parent
+                .sequence_flows_non_recursive()
+                .get(*sequence_flow_index)
+                .and_if_not_error_default()?;
+            if Some(*sequence_flow_index) != default_sequence_flow
+                && sequence_flow.condition_holds(root_marking)
+            {
+                selected_sequence_flows.push(*sequence_flow_index);
+            }
+        }
+        if selected_sequence_flows.is_empty() {
+            selected_sequence_flows.extend(default_sequence_flow);
+        }
+
+        Ok((0..number_of_transitions)
+            .map(|transition_index| {
+                !selected_sequence_flows.is_empty()
+                    && transition_index_2_sequence_flow_indices(
+                        outgoing_sequence_flows,
+                        transition_index,
+                    ) == selected_sequence_flows
+            })
+            .collect())
+    } else {
+        Ok((0..number_of_transitions)
+            .map(|transition_index| {
+                inclusive_split_may_choose(
+                    outgoing_sequence_flows,
+                    default_sequence_flow,
+                    transition_index,
+                )
+            })
+            .collect())
+    }
+}
+
+/// Returns the outgoing sequence flows that receive a token in the given transition of an inclusive split.
+pub(crate) fn transition_index_2_sequence_flows<'a>(
+    outgoing_sequence_flows: &[usize],
+    parent: &'a dyn Processable,
+    transition_index: usize,
+) -> Result<Vec<&'a BPMNSequenceFlow>> {
+    transition_index_2_sequence_flow_indices(outgoing_sequence_flows, transition_index)
+        .into_iter()
+        .map(|sequence_flow_index| {
+            parent
+                .sequence_flows_non_recursive()
+                .get(sequence_flow_index)
+                .and_if_not_error_default()
+        })
+        .collect()
+}
+
+/// Returns the probabilistic penalty of the given transition of an inclusive split, which chooses a non-empty subset of its outgoing sequence flows.
+/// The penalties are normalised over the subsets that the split may choose, which excludes those that take the default sequence flow together with another one.
+pub(crate) fn inclusive_split_probabilistic_penalty(
+    outgoing_sequence_flows: &[usize],
+    default_sequence_flow: Option<usize>,
+    parent: &dyn Processable,
+    transition_index: TransitionIndex,
+) -> Option<Fraction> {
+    if outgoing_sequence_flows.len() <= 1 {
+        return Some(Fraction::one());
+    }
+
+    let number_of_transitions = 2usize.pow(outgoing_sequence_flows.len() as u32) - 1;
+    let s = (0..number_of_transitions)
+        .filter(|transition_index| {
+            inclusive_split_may_choose(
+                outgoing_sequence_flows,
+                default_sequence_flow,
+                *transition_index,
+            )
+        })
+        .map(|transition_index| {
+            inclusive_split_weight(outgoing_sequence_flows, parent, transition_index)
+        })
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .sum::<Fraction>();
+
+    Some(inclusive_split_weight(outgoing_sequence_flows, parent, transition_index)? / s)
+}
+
+/// Returns whether an inclusive split may choose the given transition, regardless of conditions.
+/// That is, whether the transition takes the default sequence flow, if there is one, only on its own.
+fn inclusive_split_may_choose(
+    outgoing_sequence_flows: &[usize],
+    default_sequence_flow: Option<usize>,
+    transition_index: TransitionIndex,
+) -> bool {
+    let sequence_flows =
+        transition_index_2_sequence_flow_indices(outgoing_sequence_flows, transition_index);
+    match default_sequence_flow {
+        Some(default_sequence_flow) => {
+            sequence_flows.len() == 1 || !sequence_flows.contains(&default_sequence_flow)
+        }
+        None => true,
+    }
+}
+
+/// Returns the weight of the given transition of an inclusive split, before normalisation.
+/// The full set of outgoing sequence flows weighs the sum of their weights.
+/// Any other subset weighs the number of outgoing sequence flows minus one, plus the average weight of the sequence flows in the subset.
+fn inclusive_split_weight(
+    outgoing_sequence_flows: &[usize],
+    parent: &dyn Processable,
+    transition_index: TransitionIndex,
+) -> Option<Fraction> {
+    let selected_sequence_flows =
+        transition_index_2_sequence_flows(outgoing_sequence_flows, parent, transition_index)
+            .ok()?;
+    let number_of_selected_sequence_flows = selected_sequence_flows.len();
+    let sum_selected: Fraction = selected_sequence_flows
+        .into_iter()
+        .filter_map(|sequence_flow| sequence_flow.weight.as_ref())
+        .sum();
+
+    if number_of_selected_sequence_flows == outgoing_sequence_flows.len() {
+        //full set chosen
+        Some(sum_selected)
+    } else {
+        //sub-set chosen
+        Some(
+            Fraction::from(outgoing_sequence_flows.len() - 1)
+                + sum_selected / Fraction::from(number_of_selected_sequence_flows),
+        )
+    }
 }
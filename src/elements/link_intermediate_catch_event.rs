@@ -0,0 +1,238 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    element::{BPMNElement, BPMNElementTrait},
+    marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    parser::parser_state::GlobalIndex,
+    semantics::TransitionIndex,
+    traits::{
+        objectable::{BPMNObject, EMPTY_FLOWS},
+        processable::Processable,
+        transitionable::Transitionable,
+    },
+};
+use anyhow::{Result, anyhow};
+use bitvec::{bitvec, vec::BitVec};
+use ebi_activity_key::Activity;
+use ebi_arithmetic::Fraction;
+
+/// An intermediate catch event that is the target of the link throw events with the same link name in the same process.
+/// It has no transitions of its own: the link throw events produce tokens on its outgoing sequence flows directly.
+#[derive(Debug, Clone)]
+pub struct BPMNLinkIntermediateCatchEvent {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+    pub(crate) link_marker_id: Option<String>,
+    pub(crate) link_name: String,
+    pub(crate) outgoing_sequence_flows: Vec<usize>,
+}
+
+impl BPMNLinkIntermediateCatchEvent {
+    pub fn link_name(&self) -> &str {
+        &self.link_name
+    }
+}
+
+impl BPMNElementTrait for BPMNLinkIntermediateCatchEvent {
+    fn add_incoming_sequence_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!(
+            "link catch events cannot have incoming sequence flows"
+        ))
+    }
+
+    fn add_outgoing_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
+        self.outgoing_sequence_flows.push(flow_index);
+        Ok(())
+    }
+
+    fn clear_incoming_sequence_flows(&mut self) {}
+
+    fn clear_outgoing_sequence_flows(&mut self) {
+        self.outgoing_sequence_flows.clear();
+    }
+
+    fn add_incoming_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!("link events cannot have incoming message flows"))
+    }
+
+    fn add_outgoing_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!("link events cannot have outgoing message flows"))
+    }
+
+    fn verify_structural_correctness(
+        &self,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        //the link name must identify this catch event within its process
+        let number_of_catch_events = parent
+            .elements_non_recursive()
+            .iter()
+            .filter(|element| match element {
+                BPMNElement::LinkIntermediateCatchEvent(event) => event.link_name == self.link_name,
+                _ => false,
+            })
+            .count();
+        if number_of_catch_events > 1 {
+            return Err(anyhow!(
+                "There are multiple link catch events named `{}` in the same process.",
+                self.link_name
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl BPMNObject for BPMNLinkIntermediateCatchEvent {
+    fn local_index(&self) -> usize {
+        self.local_index
+    }
+
+    fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        None
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn is_unconstrained_start_event(
+        &self,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn is_end_event(&self) -> bool {
+        false
+    }
+
+    fn incoming_sequence_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn outgoing_sequence_flows(&self) -> &[usize] {
+        &self.outgoing_sequence_flows
+    }
+
+    fn incoming_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Err(anyhow!("element does not have incoming sequence flows."))
+    }
+
+    fn outgoing_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Ok(&mut self.outgoing_sequence_flows)
+    }
+
+    fn incoming_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn outgoing_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn can_start_process_instance(&self, _bpmn: &BusinessProcessModelAndNotation) -> Result<bool> {
+        //a link catch event is only reached through its link throw events
+        Ok(false)
+    }
+
+    fn outgoing_message_flows_always_have_tokens(&self) -> bool {
+        false
+    }
+
+    fn outgoing_messages_cannot_be_removed(&self) -> bool {
+        false
+    }
+
+    fn incoming_messages_are_ignored(&self) -> bool {
+        false
+    }
+
+    fn can_have_incoming_sequence_flows(&self) -> bool {
+        false
+    }
+
+    fn can_have_outgoing_sequence_flows(&self) -> bool {
+        true
+    }
+}
+
+impl Transitionable for BPMNLinkIntermediateCatchEvent {
+    fn number_of_transitions(&self, _marking: &BPMNSubMarking) -> usize {
+        0
+    }
+
+    fn enabled_transitions(
+        &self,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<BitVec> {
+        Ok(bitvec![0; 0])
+    }
+
+    fn execute_transition(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &mut BPMNRootMarking,
+        _sub_marking: &mut BPMNSubMarking,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        Err(anyhow!("link catch events do not have transitions"))
+    }
+
+    fn transition_activity(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+    ) -> Option<Activity> {
+        None
+    }
+
+    fn transition_debug(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Option<String> {
+        None
+    }
+
+    fn transition_probabilistic_penalty(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+    ) -> Option<Fraction> {
+        None
+    }
+
+    fn transition_2_consumed_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        Err(anyhow!("link catch events do not have transitions"))
+    }
+
+    fn transition_2_produced_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        Err(anyhow!("link catch events do not have transitions"))
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    element::{BPMNElement, BPMNElementTrait},
+    elements::link_intermediate_catch_event::BPMNLinkIntermediateCatchEvent,
+    marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    parser::parser_state::GlobalIndex,
+    semantics::TransitionIndex,
+    traits::{
+        objectable::{BPMNObject, EMPTY_FLOWS},
+        processable::Processable,
+        transitionable::{
+            Transitionable, enabledness_xor_join_only, execute_transition_parallel_split,
+            execute_transition_xor_join_consume, number_of_transitions_xor_join_only,
+            transition_2_consumed_tokens_xor_join, transition_2_produced_tokens_concurrent_split,
+        },
+    },
+};
+use anyhow::{Result, anyhow};
+use bitvec::{bitvec, vec::BitVec};
+use ebi_activity_key::Activity;
+use ebi_arithmetic::{Fraction, One};
+
+/// An intermediate throw event that jumps to the link catch event with the same link name in the same process.
+/// Together, the pair behaves like an invisible sequence flow: the throw event directly produces tokens on the outgoing sequence flows of the catch event.
+#[derive(Debug, Clone)]
+pub struct BPMNLinkIntermediateThrowEvent {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+    pub(crate) link_marker_id: Option<String>,
+    pub(crate) link_name: String,
+    pub(crate) incoming_sequence_flows: Vec<usize>,
+}
+
+impl BPMNLinkIntermediateThrowEvent {
+    pub fn link_name(&self) -> &str {
+        &self.link_name
+    }
+
+    /// Returns the link catch event to which this event jumps.
+    pub fn target<'a>(
+        &self,
+        parent: &'a dyn Processable,
+    ) -> Result<&'a BPMNLinkIntermediateCatchEvent> {
+        parent
+            .elements_non_recursive()
+            .iter()
+            .find_map(|element| match element {
+                BPMNElement::LinkIntermediateCatchEvent(event)
+                    if event.link_name == self.link_name =>
+                {
+                    Some(event)
+                }
+                _ => None,
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "Link throw event `{}` has no link catch event named `{}` in the same process.",
+                    self.id,
+                    self.link_name
+                )
+            })
+    }
+}
+
+impl BPMNElementTrait for BPMNLinkIntermediateThrowEvent {
+    fn add_incoming_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
+        self.incoming_sequence_flows.push(flow_index);
+        Ok(())
+    }
+
+    fn add_outgoing_sequence_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!(
+            "link throw events cannot have outgoing sequence flows"
+        ))
+    }
+
+    fn clear_incoming_sequence_flows(&mut self) {
+        self.incoming_sequence_flows.clear();
+    }
+
+    fn clear_outgoing_sequence_flows(&mut self) {}
+
+    fn add_incoming_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!("link events cannot have incoming message flows"))
+    }
+
+    fn add_outgoing_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!("link events cannot have outgoing message flows"))
+    }
+
+    fn verify_structural_correctness(
+        &self,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        self.target(parent)?;
+        Ok(())
+    }
+}
+
+impl BPMNObject for BPMNLinkIntermediateThrowEvent {
+    fn local_index(&self) -> usize {
+        self.local_index
+    }
+
+    fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        None
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn is_unconstrained_start_event(
+        &self,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn is_end_event(&self) -> bool {
+        false
+    }
+
+    fn incoming_sequence_flows(&self) -> &[usize] {
+        &self.incoming_sequence_flows
+    }
+
+    fn outgoing_sequence_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn incoming_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Ok(&mut self.incoming_sequence_flows)
+    }
+
+    fn outgoing_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Err(anyhow!("element does not have outgoing sequence flows."))
+    }
+
+    fn incoming_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn outgoing_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn can_start_process_instance(&self, _bpmn: &BusinessProcessModelAndNotation) -> Result<bool> {
+        Ok(self.incoming_sequence_flows().len() == 0)
+    }
+
+    fn outgoing_message_flows_always_have_tokens(&self) -> bool {
+        false
+    }
+
+    fn outgoing_messages_cannot_be_removed(&self) -> bool {
+        false
+    }
+
+    fn incoming_messages_are_ignored(&self) -> bool {
+        false
+    }
+
+    fn can_have_incoming_sequence_flows(&self) -> bool {
+        true
+    }
+
+    fn can_have_outgoing_sequence_flows(&self) -> bool {
+        false
+    }
+}
+
+impl Transitionable for BPMNLinkIntermediateThrowEvent {
+    fn number_of_transitions(&self, _marking: &BPMNSubMarking) -> usize {
+        number_of_transitions_xor_join_only!(self)
+    }
+
+    fn enabled_transitions(
+        &self,
+        _root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<BitVec> {
+        Ok(enabledness_xor_join_only!(self, sub_marking))
+    }
+
+    fn execute_transition(
+        &self,
+        transition_index: TransitionIndex,
+        _root_marking: &mut BPMNRootMarking,
+        sub_marking: &mut BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        //consume
+        execute_transition_xor_join_consume!(self, sub_marking, transition_index);
+
+        //produce on the outgoing sequence flows of the catch event
+        let target = self.target(parent)?;
+        execute_transition_parallel_split!(target, sub_marking);
+        Ok(())
+    }
+
+    fn transition_activity(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+    ) -> Option<Activity> {
+        None
+    }
+
+    fn transition_debug(
+        &self,
+        transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Option<String> {
+        Some(format!(
+            "link throw event `{}` to link `{}`; internal transition {}",
+            self.id, self.link_name, transition_index
+        ))
+    }
+
+    fn transition_probabilistic_penalty(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+    ) -> Option<Fraction> {
+        Some(Fraction::one())
+    }
+
+    fn transition_2_consumed_tokens(
+        &self,
+        transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        Ok(transition_2_consumed_tokens_xor_join!(
+            self,
+            transition_index,
+            parent
+        ))
+    }
+
+    fn transition_2_produced_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        let target = self.target(parent)?;
+        Ok(transition_2_produced_tokens_concurrent_split!(
+            target, parent
+        ))
+    }
+}
@@ -25,11 +25,20 @@ pub struct BPMNMessageEndEvent {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
     pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
     pub(crate) message_marker_id: Option<String>,
+    pub(crate) message_ref: Option<String>,
     pub(crate) incoming_sequence_flows: Vec<usize>,
     pub(crate) outgoing_message_flow: Option<usize>,
 }
 
+impl BPMNMessageEndEvent {
+    /// Returns the id of the message that is thrown, if any.
+    pub fn message_ref(&self) -> Option<&str> {
+        self.message_ref.as_deref()
+    }
+}
+
 impl BPMNElementTrait for BPMNMessageEndEvent {
     fn add_incoming_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
         self.incoming_sequence_flows.push(flow_index);
@@ -192,11 +201,13 @@ impl Transitionable for BPMNMessageEndEvent {
         &self,
         transition_index: TransitionIndex,
         _marking: &BPMNSubMarking,
-        _bpmn: &BusinessProcessModelAndNotation,
+        bpmn: &BusinessProcessModelAndNotation,
     ) -> Option<String> {
         Some(format!(
-            "message end event `{}`; internal transition {}",
-            self.id, transition_index
+            "message end event `{}`; internal transition {}{}",
+            self.id,
+            transition_index,
+            bpmn.message_debug(self.message_ref())
         ))
     }
 
@@ -25,12 +25,21 @@ pub struct BPMNMessageIntermediateCatchEvent {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
     pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
     pub(crate) message_marker_id: Option<String>,
+    pub(crate) message_ref: Option<String>,
     pub(crate) incoming_sequence_flows: Vec<usize>,
     pub(crate) outgoing_sequence_flows: Vec<usize>,
     pub(crate) incoming_message_flow: Option<usize>,
 }
 
+impl BPMNMessageIntermediateCatchEvent {
+    /// Returns the id of the message that is caught, if any.
+    pub fn message_ref(&self) -> Option<&str> {
+        self.message_ref.as_deref()
+    }
+}
+
 impl BPMNElementTrait for BPMNMessageIntermediateCatchEvent {
     fn add_incoming_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
         self.incoming_sequence_flows.push(flow_index);
@@ -260,11 +269,13 @@ impl Transitionable for BPMNMessageIntermediateCatchEvent {
         &self,
         transition_index: TransitionIndex,
         _marking: &BPMNSubMarking,
-        _bpmn: &BusinessProcessModelAndNotation,
+        bpmn: &BusinessProcessModelAndNotation,
     ) -> Option<String> {
         Some(format!(
-            "message intermediate catch event `{}`; internal transition {}",
-            self.id, transition_index
+            "message intermediate catch event `{}`; internal transition {}{}",
+            self.id,
+            transition_index,
+            bpmn.message_debug(self.message_ref())
         ))
     }
 
@@ -28,11 +28,22 @@ pub struct BPMNMessageStartEvent {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
     pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
     pub(crate) message_marker_id: Option<String>,
+    pub(crate) message_ref: Option<String>,
     pub(crate) outgoing_sequence_flows: Vec<usize>,
+    ///whether the event cancels the rest of the parent instance when it starts an event sub-process
+    pub(crate) is_interrupting: bool,
     pub(crate) incoming_message_flow: Option<usize>,
 }
 
+impl BPMNMessageStartEvent {
+    /// Returns the id of the message that is caught, if any.
+    pub fn message_ref(&self) -> Option<&str> {
+        self.message_ref.as_deref()
+    }
+}
+
 impl BPMNElementTrait for BPMNMessageStartEvent {
     fn add_incoming_sequence_flow(&mut self, _flow_index: usize) -> Result<()> {
         Err(anyhow!(
@@ -172,7 +183,10 @@ impl Transitionable for BPMNMessageStartEvent {
         parent: &dyn Processable,
         bpmn: &BusinessProcessModelAndNotation,
     ) -> Result<BitVec> {
-        if let Some(message_flow_index) = self.incoming_message_flow {
+        if let Some(message_flow_index) = self
+            .incoming_message_flow
+            .filter(|_| !parent.is_event_sub_process())
+        {
             //event has a message attached
 
             //Two cases apply:
@@ -199,7 +213,7 @@ impl Transitionable for BPMNMessageStartEvent {
                 }
             }
         } else {
-            //model does not have an incoming message flow; treat as a regular start event
+            //model does not have an incoming message flow, or the message is caught by the enclosing event sub-process; treat as a regular start event
             Ok(enabled_transitions_start_event!(
                 self,
                 root_marking,
@@ -218,7 +232,10 @@ impl Transitionable for BPMNMessageStartEvent {
         bpmn: &BusinessProcessModelAndNotation,
     ) -> Result<()> {
         //consume
-        if let Some(message_flow_index) = self.incoming_message_flow {
+        if let Some(message_flow_index) = self
+            .incoming_message_flow
+            .filter(|_| !parent.is_event_sub_process())
+        {
             //event has a message attached
 
             //Two cases apply:
@@ -235,7 +252,7 @@ impl Transitionable for BPMNMessageStartEvent {
                 }
             }
         } else {
-            //model does not have an incoming message flow; treat as a regular start event
+            //model does not have an incoming message flow, or the message is caught by the enclosing event sub-process; treat as a regular start event
             execute_transition_start_event!(self, root_marking, sub_marking, parent);
         }
 
@@ -256,11 +273,13 @@ impl Transitionable for BPMNMessageStartEvent {
         &self,
         transition_index: TransitionIndex,
         _marking: &BPMNSubMarking,
-        _bpmn: &BusinessProcessModelAndNotation,
+        bpmn: &BusinessProcessModelAndNotation,
     ) -> Option<String> {
         Some(format!(
-            "message start event `{}`; internal transition {}",
-            self.id, transition_index
+            "message start event `{}`; internal transition {}{}",
+            self.id,
+            transition_index,
+            bpmn.message_debug(self.message_ref())
         ))
     }
 
@@ -281,7 +300,10 @@ impl Transitionable for BPMNMessageStartEvent {
         parent: &dyn Processable,
         bpmn: &BusinessProcessModelAndNotation,
     ) -> Result<Vec<Token>> {
-        if let Some(message_flow_index) = self.incoming_message_flow {
+        if let Some(message_flow_index) = self
+            .incoming_message_flow
+            .filter(|_| !parent.is_event_sub_process())
+        {
             //event has a message attached
 
             //Two cases apply:
@@ -304,7 +326,7 @@ impl Transitionable for BPMNMessageStartEvent {
                 }
             }
         } else {
-            //model does not have an incoming message flow; treat as a regular start event
+            //model does not have an incoming message flow, or the message is caught by the enclosing event sub-process; treat as a regular start event
             transition_2_consumed_tokens_start_event!(self, root_marking, sub_marking, parent)
         }
     }
@@ -2,6 +2,8 @@ use crate::{
     BusinessProcessModelAndNotation,
     element::{BPMNElement, BPMNElementTrait},
     elements::expanded_sub_process::to_sub_marking,
+    error::BPMNError,
+    lane::BPMNLaneSet,
     marking::{BPMNRootMarking, BPMNSubMarking, Token},
     parser::parser_state::GlobalIndex,
     semantics::TransitionIndex,
@@ -31,6 +33,13 @@ pub struct BPMNProcess {
     pub participant_id: Option<String>,
     pub elements: Vec<BPMNElement>,
     pub sequence_flows: Vec<BPMNSequenceFlow>,
+
+    /// The lane sets that assign the elements of this process to lanes.
+    pub lane_sets: Vec<BPMNLaneSet>,
+
+    /// Whether this process is called by a call activity.
+    /// A called process does not start by itself, but behaves as a sub-process of each call activity that calls it.
+    pub is_called: bool,
 }
 
 impl Searchable for BPMNProcess {
@@ -278,26 +287,14 @@ impl Transitionable for BPMNProcess {
 
     fn execute_transition(
         &self,
-        mut transition_index: TransitionIndex,
+        transition_index: TransitionIndex,
         root_marking: &mut BPMNRootMarking,
         sub_marking: &mut BPMNSubMarking,
         _parent: &dyn Processable,
         bpmn: &BusinessProcessModelAndNotation,
     ) -> Result<()> {
-        for element in &self.elements {
-            let number_of_transitions = element.number_of_transitions(sub_marking);
-            if transition_index < number_of_transitions {
-                return element.execute_transition(
-                    transition_index,
-                    root_marking,
-                    sub_marking,
-                    self,
-                    bpmn,
-                );
-            }
-            transition_index -= number_of_transitions;
-        }
-        Ok(())
+        self.elements
+            .execute_transition(transition_index, root_marking, sub_marking, self, bpmn)
     }
 
     fn transition_activity(
@@ -410,11 +407,13 @@ impl Processable for BPMNProcess {
     fn to_sub_marking(&self, initiation_mode: &InitiationMode) -> Result<BPMNSubMarking> {
         let result: Result<BPMNSubMarking> = to_sub_marking!(self, initiation_mode);
         let mut result = result?;
-        result.initial_choice_token = false; //handled by root; should not be accessed; change to false for clarity
+        if !self.is_called {
+            result.initial_choice_token = false; //handled by root; should not be accessed; change to false for clarity
+        }
         Ok(result)
     }
 
     fn is_sub_process(&self) -> bool {
-        false
+        self.is_called
     }
 }
@@ -3,16 +3,23 @@ use crate::{
     element::BPMNElementTrait,
     elements::task::task_consumed_tokens,
     marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    multi_instance::BPMNMultiInstance,
     parser::parser_state::GlobalIndex,
     semantics::TransitionIndex,
+    standard_loop::BPMNStandardLoop,
     traits::{
         objectable::{BPMNObject, EMPTY_FLOWS},
         processable::Processable,
         transitionable::{
-            Transitionable, enabledness_xor_join_only, execute_transition_parallel_split,
-            execute_transition_xor_join_consume, number_of_transitions_xor_join_only,
-            transition_2_consumed_tokens_message, transition_2_consumed_tokens_xor_join,
-            transition_2_produced_tokens_concurrent_split,
+            Transitionable, enabledness_task_activations, enabledness_xor_join_only,
+            execute_transition_parallel_split, execute_transition_task_activate,
+            execute_transition_task_activation, execute_transition_xor_join_consume,
+            number_of_transitions_task_activations, number_of_transitions_xor_join_only,
+            task_activation_counts, task_activation_initial_count,
+            transition_2_consumed_tokens_message, transition_2_consumed_tokens_task,
+            transition_2_consumed_tokens_xor_join, transition_2_produced_tokens_concurrent_split,
+            transition_2_produced_tokens_task, transition_activity_task,
+            transition_probabilistic_penalty_task,
         },
     },
 };
@@ -30,6 +37,8 @@ pub struct BPMNReceiveTask {
     pub(crate) incoming_sequence_flows: Vec<usize>,
     pub(crate) outgoing_sequence_flows: Vec<usize>,
     pub(crate) incoming_message_flow: Option<usize>,
+    pub(crate) multi_instance: Option<BPMNMultiInstance>,
+    pub(crate) standard_loop: Option<BPMNStandardLoop>,
 }
 
 impl BPMNElementTrait for BPMNReceiveTask {
@@ -119,7 +128,7 @@ impl BPMNObject for BPMNReceiveTask {
     fn outgoing_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
         Ok(&mut self.outgoing_sequence_flows)
     }
-    
+
     fn incoming_message_flows(&self) -> &[usize] {
         &self.incoming_message_flow.as_slice()
     }
@@ -166,8 +175,9 @@ impl BPMNObject for BPMNReceiveTask {
 }
 
 impl Transitionable for BPMNReceiveTask {
-    fn number_of_transitions(&self, _marking: &BPMNSubMarking) -> usize {
+    fn number_of_transitions(&self, marking: &BPMNSubMarking) -> usize {
         number_of_transitions_xor_join_only!(self)
+            + number_of_transitions_task_activations!(self, marking)
     }
 
     fn enabled_transitions(
@@ -185,8 +195,10 @@ impl Transitionable for BPMNReceiveTask {
                 //this message must actually be there
 
                 if root_marking.message_flow_2_tokens[message_flow_index] == 0 {
-                    //message is not present; all transitions are not enabled
-                    return Ok(bitvec![0;self.number_of_transitions(sub_marking)]);
+                    //message is not present; the start transitions are not enabled
+                    let mut result = bitvec![0; number_of_transitions_xor_join_only!(self)];
+                    result.extend(enabledness_task_activations!(self, sub_marking));
+                    return Ok(result);
                 }
             } else {
                 //if the message flow has always tokens, we do not need to check the marking
@@ -195,7 +207,9 @@ impl Transitionable for BPMNReceiveTask {
             //if there is no incoming message flow, there is no restriction
         }
 
-        Ok(enabledness_xor_join_only!(self, sub_marking))
+        let mut result = enabledness_xor_join_only!(self, sub_marking);
+        result.extend(enabledness_task_activations!(self, sub_marking));
+        Ok(result)
     }
 
     fn execute_transition(
@@ -206,6 +220,18 @@ impl Transitionable for BPMNReceiveTask {
         parent: &dyn Processable,
         bpmn: &BusinessProcessModelAndNotation,
     ) -> Result<()> {
+        if transition_index >= number_of_transitions_xor_join_only!(self) {
+            //execute a transition of an activation; the last instance of a multi-instance task or the continuation of a loop task completes the task
+            if execute_transition_task_activation!(
+                self,
+                sub_marking,
+                transition_index - number_of_transitions_xor_join_only!(self)
+            ) {
+                execute_transition_parallel_split!(self, sub_marking);
+            }
+            return Ok(());
+        }
+
         //consume token
         if let Some(sequence_flow_index) = self.incoming_sequence_flows.iter().next() {
             let sequence_flow = &parent.sequence_flows_non_recursive()[*sequence_flow_index];
@@ -245,6 +271,11 @@ impl Transitionable for BPMNReceiveTask {
             }
         }
 
+        //a multi-instance or loop task is activated rather than completed
+        if execute_transition_task_activate!(self, sub_marking) {
+            return Ok(());
+        }
+
         //produce
         execute_transition_parallel_split!(self, sub_marking);
 
@@ -253,10 +284,10 @@ impl Transitionable for BPMNReceiveTask {
 
     fn transition_activity(
         &self,
-        _transition_index: TransitionIndex,
+        transition_index: TransitionIndex,
         _marking: &BPMNSubMarking,
     ) -> Option<Activity> {
-        Some(self.activity)
+        transition_activity_task!(self, transition_index)
     }
 
     fn transition_debug(
@@ -275,36 +306,43 @@ impl Transitionable for BPMNReceiveTask {
 
     fn transition_probabilistic_penalty(
         &self,
-        _transition_index: TransitionIndex,
-        _marking: &BPMNSubMarking,
+        transition_index: TransitionIndex,
+        marking: &BPMNSubMarking,
         _parent: &dyn Processable,
     ) -> Option<Fraction> {
-        Some(Fraction::one())
+        transition_probabilistic_penalty_task!(self, transition_index, marking)
     }
 
     fn transition_2_consumed_tokens<'a>(
         &'a self,
         transition_index: TransitionIndex,
         _marking: &BPMNRootMarking,
-        _sub_marking: &BPMNSubMarking,
+        sub_marking: &BPMNSubMarking,
         parent: &'a dyn Processable,
         bpmn: &BusinessProcessModelAndNotation,
     ) -> Result<Vec<Token>> {
-        let mut result = task_consumed_tokens!(self, transition_index, parent);
-
-        //messages
-        result.append(&mut transition_2_consumed_tokens_message!(self, bpmn));
-        Ok(result)
+        Ok(transition_2_consumed_tokens_task!(
+            self,
+            transition_index,
+            sub_marking,
+            parent,
+            bpmn
+        ))
     }
 
     fn transition_2_produced_tokens(
         &self,
-        _transition_index: TransitionIndex,
+        transition_index: TransitionIndex,
         _marking: &BPMNRootMarking,
-        _sub_marking: &BPMNSubMarking,
+        sub_marking: &BPMNSubMarking,
         parent: &dyn Processable,
         _bpmn: &BusinessProcessModelAndNotation,
     ) -> Result<Vec<Token>> {
-        Ok(transition_2_produced_tokens_concurrent_split!(self, parent))
+        Ok(transition_2_produced_tokens_task!(
+            self,
+            transition_index,
+            sub_marking,
+            parent
+        ))
     }
 }
@@ -0,0 +1,46 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    element::BPMNElementTrait,
+    elements::task::{impl_task, task_consumed_tokens},
+    marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    multi_instance::BPMNMultiInstance,
+    parser::parser_state::GlobalIndex,
+    semantics::TransitionIndex,
+    standard_loop::BPMNStandardLoop,
+    traits::{
+        objectable::BPMNObject,
+        processable::Processable,
+        transitionable::{
+            Transitionable, enabledness_task_activations, enabledness_xor_join_only,
+            execute_transition_message_produce, execute_transition_parallel_split,
+            execute_transition_task_activate, execute_transition_task_activation,
+            execute_transition_xor_join_consume, number_of_transitions_task_activations,
+            number_of_transitions_xor_join_only, task_activation_counts,
+            task_activation_initial_count, transition_2_consumed_tokens_message,
+            transition_2_consumed_tokens_task, transition_2_consumed_tokens_xor_join,
+            transition_2_produced_tokens_concurrent_split, transition_2_produced_tokens_message,
+            transition_2_produced_tokens_task, transition_activity_task,
+            transition_probabilistic_penalty_task,
+        },
+    },
+};
+use anyhow::{Result, anyhow};
+use bitvec::{bitvec, vec::BitVec};
+use ebi_activity_key::Activity;
+use ebi_arithmetic::{Fraction, One};
+
+#[derive(Debug, Clone)]
+pub struct BPMNScriptTask {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) local_index: usize,
+    pub activity: Activity,
+    pub(crate) incoming_sequence_flows: Vec<usize>,
+    pub(crate) outgoing_sequence_flows: Vec<usize>,
+    pub(crate) incoming_message_flow: Option<usize>,
+    pub(crate) outgoing_message_flow: Option<usize>,
+    pub(crate) multi_instance: Option<BPMNMultiInstance>,
+    pub(crate) standard_loop: Option<BPMNStandardLoop>,
+}
+
+impl_task!(BPMNScriptTask);
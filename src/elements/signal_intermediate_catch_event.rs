@@ -0,0 +1,317 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    element::BPMNElementTrait,
+    if_not::IfNotDefault,
+    marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    parser::parser_state::GlobalIndex,
+    semantics::TransitionIndex,
+    traits::{
+        objectable::{BPMNObject, EMPTY_FLOWS},
+        processable::Processable,
+        transitionable::{
+            Transitionable, enabledness_xor_join_only, execute_transition_parallel_split,
+            execute_transition_xor_join_consume, number_of_transitions_xor_join_only,
+            transition_2_consumed_tokens_xor_join, transition_2_produced_tokens_concurrent_split,
+        },
+    },
+};
+use anyhow::{Result, anyhow};
+use bitvec::{bitvec, vec::BitVec};
+use ebi_activity_key::Activity;
+use ebi_arithmetic::{Fraction, One};
+
+#[derive(Debug, Clone)]
+pub struct BPMNSignalIntermediateCatchEvent {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+    pub(crate) signal_marker_id: Option<String>,
+    pub(crate) signal_ref: Option<String>,
+    pub(crate) incoming_sequence_flows: Vec<usize>,
+    pub(crate) outgoing_sequence_flows: Vec<usize>,
+}
+
+impl BPMNSignalIntermediateCatchEvent {
+    /// Returns the id of the signal that is caught, if any.
+    pub fn signal_ref(&self) -> Option<&str> {
+        self.signal_ref.as_deref()
+    }
+
+    /// Returns the number of tokens that are waiting for the signal.
+    pub(crate) fn number_of_waiting_tokens(&self, sub_marking: &BPMNSubMarking) -> u64 {
+        if self.incoming_sequence_flows.is_empty() {
+            sub_marking.element_index_2_tokens[self.local_index]
+        } else {
+            self.incoming_sequence_flows
+                .iter()
+                .map(|sequence_flow_index| sub_marking.sequence_flow_2_tokens[*sequence_flow_index])
+                .sum()
+        }
+    }
+
+    /// Catches a broadcast signal for one of the waiting tokens.
+    pub(crate) fn catch_signal(
+        &self,
+        sub_marking: &mut BPMNSubMarking,
+        parent: &dyn Processable,
+    ) -> Result<()> {
+        let transition_index = enabledness_xor_join_only!(self, sub_marking)
+            .first_one()
+            .ok_or_else(|| anyhow!("Signal catch event `{}` is not waiting.", self.id))?;
+
+        self.consume(transition_index, sub_marking, parent);
+        execute_transition_parallel_split!(self, sub_marking);
+        Ok(())
+    }
+
+    fn consume(
+        &self,
+        transition_index: TransitionIndex,
+        sub_marking: &mut BPMNSubMarking,
+        parent: &dyn Processable,
+    ) {
+        if let Some(sequence_flow_index) = self.incoming_sequence_flows.iter().next() {
+            let sequence_flow = &parent.sequence_flows_non_recursive()[*sequence_flow_index];
+            let source = &parent.elements_non_recursive()[sequence_flow.source_local_index];
+            if source.is_event_based_gateway() {
+                //special case: source is an event-based gateway
+
+                //remove a token from all outgoing sequence flows of the event-based gateway
+                for outgoing_sequence_flow in source.outgoing_sequence_flows() {
+                    sub_marking.sequence_flow_2_tokens[*outgoing_sequence_flow] -= 1;
+                }
+                return;
+            }
+        }
+
+        //not a special case
+        execute_transition_xor_join_consume!(self, sub_marking, transition_index);
+    }
+}
+
+impl BPMNElementTrait for BPMNSignalIntermediateCatchEvent {
+    fn add_incoming_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
+        self.incoming_sequence_flows.push(flow_index);
+        Ok(())
+    }
+
+    fn add_outgoing_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
+        self.outgoing_sequence_flows.push(flow_index);
+        Ok(())
+    }
+
+    fn clear_incoming_sequence_flows(&mut self) {
+        self.incoming_sequence_flows.clear();
+    }
+
+    fn clear_outgoing_sequence_flows(&mut self) {
+        self.outgoing_sequence_flows.clear();
+    }
+
+    fn add_incoming_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!("signal events cannot have incoming message flows"))
+    }
+
+    fn add_outgoing_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!("signal events cannot have outgoing message flows"))
+    }
+
+    fn verify_structural_correctness(
+        &self,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl BPMNObject for BPMNSignalIntermediateCatchEvent {
+    fn local_index(&self) -> usize {
+        self.local_index
+    }
+
+    fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        None
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn is_unconstrained_start_event(
+        &self,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn is_end_event(&self) -> bool {
+        false
+    }
+
+    fn incoming_sequence_flows(&self) -> &[usize] {
+        &self.incoming_sequence_flows
+    }
+
+    fn outgoing_sequence_flows(&self) -> &[usize] {
+        &self.outgoing_sequence_flows
+    }
+
+    fn incoming_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Ok(&mut self.incoming_sequence_flows)
+    }
+
+    fn outgoing_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Ok(&mut self.outgoing_sequence_flows)
+    }
+
+    fn incoming_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn outgoing_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn can_start_process_instance(&self, _bpmn: &BusinessProcessModelAndNotation) -> Result<bool> {
+        Ok(self.incoming_sequence_flows().len() == 0)
+    }
+
+    fn outgoing_message_flows_always_have_tokens(&self) -> bool {
+        false
+    }
+
+    fn outgoing_messages_cannot_be_removed(&self) -> bool {
+        false
+    }
+
+    fn incoming_messages_are_ignored(&self) -> bool {
+        false
+    }
+
+    fn can_have_incoming_sequence_flows(&self) -> bool {
+        true
+    }
+
+    fn can_have_outgoing_sequence_flows(&self) -> bool {
+        true
+    }
+}
+
+impl Transitionable for BPMNSignalIntermediateCatchEvent {
+    fn number_of_transitions(&self, _marking: &BPMNSubMarking) -> usize {
+        number_of_transitions_xor_join_only!(self)
+    }
+
+    fn enabled_transitions(
+        &self,
+        _root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<BitVec> {
+        if bpmn.signal_is_thrown(&self.signal_ref) {
+            //this event only fires when the signal is broadcast
+            Ok(bitvec![0; self.incoming_sequence_flows.len().max(1)])
+        } else {
+            //the signal comes from the environment, which may send it at any time
+            Ok(enabledness_xor_join_only!(self, sub_marking))
+        }
+    }
+
+    fn execute_transition(
+        &self,
+        transition_index: TransitionIndex,
+        _root_marking: &mut BPMNRootMarking,
+        sub_marking: &mut BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        //consume
+        self.consume(transition_index, sub_marking, parent);
+
+        //produce
+        execute_transition_parallel_split!(self, sub_marking);
+        Ok(())
+    }
+
+    fn transition_activity(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+    ) -> Option<Activity> {
+        None
+    }
+
+    fn transition_debug(
+        &self,
+        transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Option<String> {
+        Some(format!(
+            "signal intermediate catch event `{}`; internal transition {}",
+            self.id, transition_index
+        ))
+    }
+
+    fn transition_probabilistic_penalty(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+    ) -> Option<Fraction> {
+        Some(Fraction::one())
+    }
+
+    fn transition_2_consumed_tokens(
+        &self,
+        transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        if let Some(sequence_flow_index) = self.incoming_sequence_flows.iter().next() {
+            let sequence_flow = &parent.sequence_flows_non_recursive()[*sequence_flow_index];
+            let source = &parent.elements_non_recursive()[sequence_flow.source_local_index];
+            if source.is_event_based_gateway() {
+                //special case: source is an event-based gateway
+
+                //remove a token from all outgoing sequence flows of the event-based gateway
+                let mut result = Vec::with_capacity(source.outgoing_sequence_flows().len());
+                for sequence_flow_local_index in source.outgoing_sequence_flows() {
+                    let sequence_flow = parent
+                        .sequence_flows_non_recursive()
+                        .get(*sequence_flow_local_index)
+                        .and_if_not_error_default()?;
+                    result.push(Token::SequenceFlow(sequence_flow.global_index));
+                }
+                return Ok(result);
+            }
+        }
+
+        //not a special case
+        Ok(transition_2_consumed_tokens_xor_join!(
+            self,
+            transition_index,
+            parent
+        ))
+    }
+
+    fn transition_2_produced_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        Ok(transition_2_produced_tokens_concurrent_split!(self, parent))
+    }
+}
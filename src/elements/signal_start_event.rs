@@ -0,0 +1,258 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    element::BPMNElementTrait,
+    elements::start_event::{
+        enabled_transitions_start_event, execute_transition_start_event,
+        transition_2_consumed_tokens_start_event,
+    },
+    marking::{BPMNRootMarking, BPMNSubMarking, Token},
+    parser::parser_state::GlobalIndex,
+    semantics::TransitionIndex,
+    traits::{
+        objectable::{BPMNObject, EMPTY_FLOWS},
+        processable::Processable,
+        transitionable::{
+            Transitionable, execute_transition_parallel_split,
+            transition_2_produced_tokens_concurrent_split,
+        },
+    },
+};
+use anyhow::{Result, anyhow};
+use bitvec::{bitvec, vec::BitVec};
+use ebi_activity_key::Activity;
+use ebi_arithmetic::{Fraction, One};
+
+#[derive(Debug, Clone)]
+pub struct BPMNSignalStartEvent {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+    pub(crate) signal_marker_id: Option<String>,
+    pub(crate) signal_ref: Option<String>,
+    pub(crate) outgoing_sequence_flows: Vec<usize>,
+    ///whether the event cancels the rest of the parent instance when it starts an event sub-process
+    pub(crate) is_interrupting: bool,
+}
+
+impl BPMNSignalStartEvent {
+    /// Returns the id of the signal that is caught, if any.
+    pub fn signal_ref(&self) -> Option<&str> {
+        self.signal_ref.as_deref()
+    }
+
+    /// Catches a broadcast signal: a new process instance is started.
+    pub(crate) fn catch_signal(&self, sub_marking: &mut BPMNSubMarking) {
+        execute_transition_parallel_split!(self, sub_marking);
+    }
+}
+
+impl BPMNElementTrait for BPMNSignalStartEvent {
+    fn add_incoming_sequence_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!("start events cannot have incoming sequence flows"))
+    }
+
+    fn add_outgoing_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
+        self.outgoing_sequence_flows.push(flow_index);
+        Ok(())
+    }
+
+    fn clear_incoming_sequence_flows(&mut self) {}
+
+    fn clear_outgoing_sequence_flows(&mut self) {
+        self.outgoing_sequence_flows.clear();
+    }
+
+    fn add_incoming_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!(
+            "signal start events cannot have incoming message flows"
+        ))
+    }
+
+    fn add_outgoing_message_flow(&mut self, _flow_index: usize) -> Result<()> {
+        Err(anyhow!(
+            "signal start events cannot have outgoing message flows"
+        ))
+    }
+
+    fn verify_structural_correctness(
+        &self,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        if parent.is_sub_process() && !parent.is_event_sub_process() {
+            return Err(anyhow!(
+                "Signal start event `{}` is in a sub-process, which is not supported.",
+                self.id
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl BPMNObject for BPMNSignalStartEvent {
+    fn local_index(&self) -> usize {
+        self.local_index
+    }
+
+    fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    fn activity(&self) -> Option<Activity> {
+        None
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn is_unconstrained_start_event(&self, bpmn: &BusinessProcessModelAndNotation) -> Result<bool> {
+        //if the signal is thrown in the model, then this event has to wait for it; otherwise, the signal comes from the environment
+        Ok(!bpmn.signal_is_thrown(&self.signal_ref))
+    }
+
+    fn is_end_event(&self) -> bool {
+        false
+    }
+
+    fn incoming_sequence_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn outgoing_sequence_flows(&self) -> &[usize] {
+        &self.outgoing_sequence_flows
+    }
+
+    fn incoming_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Err(anyhow!("element does not have incoming sequence flows."))
+    }
+
+    fn outgoing_sequence_flows_mut(&mut self) -> Result<&mut Vec<usize>> {
+        Ok(&mut self.outgoing_sequence_flows)
+    }
+
+    fn incoming_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn outgoing_message_flows(&self) -> &[usize] {
+        &EMPTY_FLOWS
+    }
+
+    fn can_start_process_instance(&self, bpmn: &BusinessProcessModelAndNotation) -> Result<bool> {
+        self.is_unconstrained_start_event(bpmn)
+    }
+
+    fn outgoing_message_flows_always_have_tokens(&self) -> bool {
+        false
+    }
+
+    fn outgoing_messages_cannot_be_removed(&self) -> bool {
+        false
+    }
+
+    fn incoming_messages_are_ignored(&self) -> bool {
+        false
+    }
+
+    fn can_have_incoming_sequence_flows(&self) -> bool {
+        false
+    }
+
+    fn can_have_outgoing_sequence_flows(&self) -> bool {
+        true
+    }
+}
+
+impl Transitionable for BPMNSignalStartEvent {
+    fn number_of_transitions(&self, _marking: &BPMNSubMarking) -> usize {
+        1
+    }
+
+    fn enabled_transitions(
+        &self,
+        root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<BitVec> {
+        if bpmn.signal_is_thrown(&self.signal_ref) {
+            //this event only fires when the signal is broadcast
+            Ok(bitvec![0;1])
+        } else {
+            //the signal comes from the environment; treat as a regular start event
+            Ok(enabled_transitions_start_event!(
+                self,
+                root_marking,
+                sub_marking,
+                parent
+            ))
+        }
+    }
+
+    fn execute_transition(
+        &self,
+        _transition_index: TransitionIndex,
+        root_marking: &mut BPMNRootMarking,
+        sub_marking: &mut BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        execute_transition_start_event!(self, root_marking, sub_marking, parent);
+        Ok(())
+    }
+
+    fn transition_activity(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+    ) -> Option<Activity> {
+        None
+    }
+
+    fn transition_debug(
+        &self,
+        transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Option<String> {
+        Some(format!(
+            "signal start event `{}`; internal transition {}",
+            self.id, transition_index
+        ))
+    }
+
+    fn transition_probabilistic_penalty(
+        &self,
+        _transition_index: TransitionIndex,
+        _marking: &BPMNSubMarking,
+        _parent: &dyn Processable,
+    ) -> Option<Fraction> {
+        Some(Fraction::one())
+    }
+
+    fn transition_2_consumed_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        root_marking: &BPMNRootMarking,
+        sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        let result =
+            transition_2_consumed_tokens_start_event!(self, root_marking, sub_marking, parent)?;
+        Ok(result)
+    }
+
+    fn transition_2_produced_tokens(
+        &self,
+        _transition_index: TransitionIndex,
+        _root_marking: &BPMNRootMarking,
+        _sub_marking: &BPMNSubMarking,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<Vec<Token>> {
+        Ok(transition_2_produced_tokens_concurrent_split!(self, parent))
+    }
+}
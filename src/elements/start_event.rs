@@ -23,6 +23,7 @@ pub struct BPMNStartEvent {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
     pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
     pub(crate) outgoing_sequence_flows: Vec<usize>,
 }
 
@@ -5,6 +5,7 @@ use crate::{
     marking::{BPMNRootMarking, BPMNSubMarking, Token},
     parser::parser_state::GlobalIndex,
     semantics::TransitionIndex,
+    timer::BPMNTimer,
     traits::{
         objectable::{BPMNObject, EMPTY_FLOWS},
         processable::Processable,
@@ -25,11 +26,20 @@ pub struct BPMNTimerIntermediateCatchEvent {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
     pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
     pub(crate) timer_marker_id: Option<String>,
+    pub(crate) timer: Option<BPMNTimer>,
     pub(crate) incoming_sequence_flows: Vec<usize>,
     pub(crate) outgoing_sequence_flows: Vec<usize>,
 }
 
+impl BPMNTimerIntermediateCatchEvent {
+    /// Returns the timer of the event, if it has one.
+    pub fn timer(&self) -> Option<&BPMNTimer> {
+        self.timer.as_ref()
+    }
+}
+
 impl BPMNElementTrait for BPMNTimerIntermediateCatchEvent {
     fn add_incoming_sequence_flow(&mut self, flow_index: usize) -> Result<()> {
         self.incoming_sequence_flows.push(flow_index);
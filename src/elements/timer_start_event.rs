@@ -8,6 +8,7 @@ use crate::{
     marking::{BPMNRootMarking, BPMNSubMarking, Token},
     parser::parser_state::GlobalIndex,
     semantics::TransitionIndex,
+    timer::BPMNTimer,
     traits::{
         objectable::{BPMNObject, EMPTY_FLOWS},
         processable::Processable,
@@ -27,8 +28,19 @@ pub struct BPMNTimerStartEvent {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
     pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
     pub(crate) timer_marker_id: Option<String>,
+    pub(crate) timer: Option<BPMNTimer>,
     pub(crate) outgoing_sequence_flows: Vec<usize>,
+    ///whether the event cancels the rest of the parent instance when it starts an event sub-process
+    pub(crate) is_interrupting: bool,
+}
+
+impl BPMNTimerStartEvent {
+    /// Returns the timer of the event, if it has one.
+    pub fn timer(&self) -> Option<&BPMNTimer> {
+        self.timer.as_ref()
+    }
 }
 
 impl BPMNElementTrait for BPMNTimerStartEvent {
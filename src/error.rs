@@ -0,0 +1,94 @@
+use std::fmt::Display;
+
+/// An error of importing, checking, executing or creating a BPMN model.
+///
+/// Internally, errors are built up with [anyhow], and are converted to a [BPMNError] at the public methods of this crate.
+/// The variant is that of the outermost typed error, and the message contains the full chain of contexts.
+/// Errors without a typed cause become [BPMNError::Other].
+#[derive(Debug)]
+pub enum BPMNError {
+    /// The input is not well-formed XML, or ended unexpectedly.
+    Xml(String),
+
+    /// A reference to an id that does not exist in the model, such as the source of a sequence flow.
+    UnknownId {
+        id: String,
+        message: String,
+    },
+
+    /// An id that is used by multiple elements.
+    DuplicateId {
+        id: String,
+        message: String,
+    },
+
+    /// The model is not structurally correct, for instance because a gateway has no outgoing sequence flows.
+    StructuralViolation {
+        element_ids: Vec<String>,
+        message: String,
+    },
+
+    /// A construct that this crate does not support, such as recursive calls of processes.
+    Unsupported(String),
+
+    /// A method was used in a way that does not fit the model, such as executing a transition that does not exist.
+    SemanticMisuse(String),
+
+    Other(anyhow::Error),
+}
+
+impl BPMNError {
+    /// Returns the same kind of error with a different message, or None for [BPMNError::Other].
+    fn with_message(&self, message: String) -> Option<Self> {
+        match self {
+            BPMNError::Xml(_) => Some(BPMNError::Xml(message)),
+            BPMNError::UnknownId { id, .. } => Some(BPMNError::UnknownId {
+                id: id.clone(),
+                message,
+            }),
+            BPMNError::DuplicateId { id, .. } => Some(BPMNError::DuplicateId {
+                id: id.clone(),
+                message,
+            }),
+            BPMNError::StructuralViolation { element_ids, .. } => {
+                Some(BPMNError::StructuralViolation {
+                    element_ids: element_ids.clone(),
+                    message,
+                })
+            }
+            BPMNError::Unsupported(_) => Some(BPMNError::Unsupported(message)),
+            BPMNError::SemanticMisuse(_) => Some(BPMNError::SemanticMisuse(message)),
+            BPMNError::Other(_) => None,
+        }
+    }
+}
+
+impl Display for BPMNError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BPMNError::Xml(message)
+            | BPMNError::UnknownId { message, .. }
+            | BPMNError::DuplicateId { message, .. }
+            | BPMNError::StructuralViolation { message, .. }
+            | BPMNError::Unsupported(message)
+            | BPMNError::SemanticMisuse(message) => write!(f, "{}", message),
+            BPMNError::Other(error) => write!(f, "{:#}", error),
+        }
+    }
+}
+
+impl std::error::Error for BPMNError {}
+
+impl From<anyhow::Error> for BPMNError {
+    fn from(error: anyhow::Error) -> Self {
+        let typed = error
+            .downcast_ref::<BPMNError>()
+            .or_else(|| {
+                error
+                    .chain()
+                    .find_map(|cause| cause.downcast_ref::<BPMNError>())
+            })
+            .and_then(|typed| typed.with_message(format!("{:#}", error)));
+        typed.unwrap_or_else(|| BPMNError::Other(error))
+    }
+}
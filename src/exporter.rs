@@ -4,6 +4,7 @@ use crate::{
     elements::collapsed_pool::BPMNCollapsedPool,
     stochastic_business_process_model_and_notation::StochasticBusinessProcessModelAndNotation,
     traits::{objectable::BPMNObject, writable::Writable},
+    writer::{data::write_data_elements, unrecognised_xml::write_unrecognised_xml},
 };
 use anyhow::Result;
 use quick_xml::{
@@ -14,7 +15,18 @@ use std::io::Write;
 
 impl BusinessProcessModelAndNotation {
     /// Exports the model to a writer.
+    /// XML of the imported file that is not interpreted, such as documentation and extension elements, is written again.
     pub fn export_to_writer(&self, f: &mut dyn Write) -> Result<()> {
+        if self.unrecognised_xml.is_empty() && self.unrecognised_document_xml.is_empty() {
+            self.export_recognised_to_writer(f)
+        } else {
+            let mut buffer = vec![];
+            self.export_recognised_to_writer(&mut buffer)?;
+            write_unrecognised_xml(&buffer, f, self)
+        }
+    }
+
+    fn export_recognised_to_writer(&self, f: &mut dyn Write) -> Result<()> {
         let mut x = Writer::new_with_indent(f, b'\t', 1);
 
         //XML declaration
@@ -30,8 +42,17 @@ impl BusinessProcessModelAndNotation {
             bytes_start = bytes_start
                 .with_attributes([(("xmlns:sbpmn", "https://www.ebitools.org/sbpmn/20260305"))]);
         }
+        bytes_start = bytes_start.with_attributes([
+            ("xmlns:bpmndi", "http://www.omg.org/spec/BPMN/20100524/DI"),
+            ("xmlns:dc", "http://www.omg.org/spec/DD/20100524/DC"),
+            ("xmlns:di", "http://www.omg.org/spec/DD/20100524/DI"),
+            ("xmlns:xsi", "http://www.w3.org/2001/XMLSchema-instance"),
+        ]);
         x.write_event(Event::Start(bytes_start))?;
 
+        //imports
+        self.imports.write(&mut x, self, self)?;
+
         //collaboration
         if let Some(collaboration_id) = &self.collaboration_id {
             x.write_event(Event::Start(
@@ -74,8 +95,22 @@ impl BusinessProcessModelAndNotation {
             x.write_event(Event::End(BytesEnd::new("collaboration")))?;
         }
 
+        //messages and signals
+        self.messages.write(&mut x, self, self)?;
+        self.signals.write(&mut x, self, self)?;
+
+        //data stores
+        write_data_elements(&mut x, self.definitions_index, self)?;
+
         self.elements.write(&mut x, self, self)?;
 
+        //layout; if the model has none, compute one such that the diagram can be displayed
+        if self.has_diagram() {
+            self.diagrams.write(&mut x, self, self)?;
+        } else {
+            self.compute_layout()?.write(&mut x, self, self)?;
+        }
+
         x.write_event(Event::End(BytesEnd::new("definitions")))?;
 
         Ok(())
@@ -92,9 +127,15 @@ impl StochasticBusinessProcessModelAndNotation {
 mod tests {
     use crate::{
         BusinessProcessModelAndNotation,
+        diagram::BPMNLabel,
+        element::BPMNElement,
         stochastic_business_process_model_and_notation::StochasticBusinessProcessModelAndNotation,
+        traits::objectable::BPMNObject, unrecognised_xml::BPMNXmlNode,
+    };
+    use std::{
+        fs::{self},
+        path::Path,
     };
-    use std::fs::{self};
 
     #[test]
     fn bpmn_export_import() {
@@ -139,4 +180,592 @@ mod tests {
 
         println!("{}", String::from_utf8_lossy(&f));
     }
+
+    #[test]
+    fn bpmn_export_import_diagram() {
+        let fin = fs::read_to_string("testfiles/model.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let shape = bpmn.shape_of("EndEvent_1shnzze").unwrap();
+        assert_eq!(shape.bounds.x, 859.0);
+        assert_eq!(shape.bounds.width, 36.0);
+        assert_eq!(shape.label.unwrap().bounds.unwrap().y, 199.0);
+        assert_eq!(bpmn.edge_of("SequenceFlow_1kuprd5").unwrap().waypoints.len(), 4);
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        assert_eq!(bpmn.diagrams, bpmn2.diagrams);
+    }
+
+    #[test]
+    fn bpmn_export_import_empty_label() {
+        let fin = fs::read_to_string("testfiles/eventbasedgateway.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let shape = bpmn.shape_of("Activity_1dquq8r").unwrap();
+        assert_eq!(shape.label, Some(BPMNLabel { bounds: None }));
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("<bpmndi:BPMNLabel/>"));
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        assert_eq!(bpmn.diagrams, bpmn2.diagrams);
+    }
+
+    #[test]
+    fn bpmn_export_computed_layout() {
+        let fin = fs::read_to_string("testfiles/flower.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+        assert!(!bpmn.has_diagram());
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        for element in bpmn2.elements() {
+            if !element.is_process() {
+                assert!(bpmn2.shape_of(element.id()).is_some());
+            }
+        }
+        for sequence_flow in bpmn2.sequence_flows() {
+            assert!(bpmn2.edge_of(&sequence_flow.id).unwrap().waypoints.len() >= 2);
+        }
+        assert!(
+            bpmn2.shape_of("startevent_2").unwrap().bounds.x
+                < bpmn2.shape_of("exclusivegateway_4").unwrap().bounds.x
+        );
+    }
+
+    #[test]
+    fn bpmn_export_computed_layout_lanes() {
+        let fin = fs::read_to_string("testfiles/lanes-nested.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+        assert!(!bpmn.has_diagram());
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        for lane in bpmn2.lanes() {
+            assert!(bpmn2.shape_of(lane.id()).is_some());
+        }
+        let contains = |outer: &str, inner: &str| {
+            let outer = bpmn2.shape_of(outer).unwrap().bounds;
+            let inner = bpmn2.shape_of(inner).unwrap().bounds;
+            outer.x <= inner.x
+                && outer.y <= inner.y
+                && inner.x + inner.width <= outer.x + outer.width
+                && inner.y + inner.height <= outer.y + outer.height
+        };
+        assert!(contains("Lane_Sales", "Lane_Desk"));
+        assert!(contains("Lane_Sales", "Lane_Office"));
+        assert!(contains("Lane_Desk", "StartEvent_1"));
+        assert!(contains("Lane_Desk", "Task_1"));
+        assert!(contains("Lane_Office", "Task_2"));
+        assert!(contains("Lane_Warehouse", "Task_3"));
+        assert!(contains("Lane_Warehouse", "EndEvent_1"));
+        assert!(!contains("Lane_Sales", "Task_3"));
+    }
+
+    #[test]
+    fn bpmn_export_import_signals() {
+        let fin = fs::read_to_string("testfiles/signal-event.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        assert_eq!(bpmn2.signals.len(), 2);
+        assert_eq!(bpmn2.signals[0].name(), Some("go"));
+        assert_eq!(bpmn2.number_of_elements(), bpmn.number_of_elements());
+    }
+
+    #[test]
+    fn bpmn_export_import_messages() {
+        let fin = fs::read_to_string("testfiles/messages.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        assert_eq!(bpmn2.messages.len(), 2);
+        assert_eq!(bpmn2.message("message_1").unwrap().name(), Some("order"));
+        assert_eq!(bpmn2.message_flows[0].message_ref(), Some("message_1"));
+        assert_eq!(fout.matches("messageRef=\"message_1\"").count(), 3);
+        assert_eq!(fout.matches("messageRef=\"message_2\"").count(), 1);
+    }
+
+    #[test]
+    fn bpmn_export_import_timers() {
+        let fin = fs::read_to_string("testfiles/timers.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        let _bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        for expected in [
+            "<timeCycle xsi:type=\"tFormalExpression\">R3/2026-01-01T09:00:00Z/P1D</timeCycle>",
+            "<timeDuration xsi:type=\"tFormalExpression\">PT1H30M</timeDuration>",
+            "<timeDate xsi:type=\"tFormalExpression\">2026-12-31T23:59:30.5+01:00</timeDate>",
+            "<timeDuration xsi:type=\"tFormalExpression\">${reminderDelay}</timeDuration>",
+        ] {
+            assert!(fout.contains(expected), "{}", expected);
+        }
+    }
+
+    #[test]
+    fn bpmn_export_import_links() {
+        let fin = fs::read_to_string("testfiles/link-event.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("<linkEventDefinition id=\"linkmarker_1\" name=\"continue\"/>"));
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let marking = bpmn2.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn2.number_of_transitions(&marking), 5);
+    }
+
+    #[test]
+    fn bpmn_export_import_call_activities() {
+        let fin = fs::read_to_string("testfiles/call-activity.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("calledElement=\"process_2\""));
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let marking = bpmn2.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn2.number_of_transitions(&marking), 7);
+        assert_eq!(bpmn2.get_enabled_transitions(&marking).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn bpmn_export_import_multi_instance() {
+        let fin = fs::read_to_string("testfiles/multi-instance.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("isSequential=\"true\""));
+        assert!(fout.contains("<loopCardinality xsi:type=\"tFormalExpression\">3</loopCardinality>"));
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn2.get_initial_marking().unwrap().unwrap();
+        bpmn2.execute_transition(&mut marking, 0).unwrap();
+        bpmn2.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(bpmn2.get_enabled_transitions(&marking).unwrap(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn bpmn_export_import_foreign_repeat_probability() {
+        let fin = fs::read_to_string("testfiles/standard-loop.sbpmn")
+            .unwrap()
+            .replace(
+                "xmlns:sbpmn=\"https://www.ebitools.org/sbpmn/20260305\"",
+                "xmlns:sbpmn=\"https://www.ebitools.org/sbpmn/20260305\" xmlns:other=\"https://example.org/other\"",
+            )
+            .replace(
+                "sbpmn:repeatProbability=\"1/4\"",
+                "other:repeatProbability=\"1/4\"",
+            );
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        //an attribute of another namespace is not interpreted, but kept
+        let task_a = bpmn
+            .elements()
+            .into_iter()
+            .find(|element| element.id() == "task_a")
+            .unwrap();
+        assert!(task_a.standard_loop().unwrap().repeat_probability.is_none());
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("other:repeatProbability=\"1/4\""));
+        assert!(fout.contains("sbpmn:repeatProbability=\"1/2\""));
+    }
+
+    #[test]
+    fn sbpmn_export_import_standard_loop() {
+        let fin = fs::read_to_string("testfiles/standard-loop.sbpmn").unwrap();
+        let sbpmn = fin
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        let mut f = vec![];
+        sbpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("loopMaximum=\"2\""));
+        assert!(fout.contains("testBefore=\"true\""));
+        assert!(fout.contains("sbpmn:repeatProbability="));
+        let sbpmn2 = fout
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        let mut marking = sbpmn2.get_initial_marking().unwrap().unwrap();
+        sbpmn2.execute_transition(&mut marking, 0).unwrap();
+        sbpmn2.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(sbpmn2.get_enabled_transitions(&marking).unwrap(), vec![2, 3]);
+    }
+
+    #[test]
+    fn bpmn_import_standard_loop_without_weights() {
+        let fin = fs::read_to_string("testfiles/standard-loop.sbpmn").unwrap();
+        let mut reader = std::io::Cursor::new(fin);
+        assert!(BusinessProcessModelAndNotation::import_from_reader(&mut reader, true).is_err());
+    }
+
+    #[test]
+    fn sbpmn_export_import_task_types_with_loops() {
+        let fin = fs::read_to_string("testfiles/task-types-loops.sbpmn").unwrap();
+        let sbpmn = fin
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        let mut f = vec![];
+        sbpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert_eq!(fout.matches("<multiInstanceLoopCharacteristics").count(), 4);
+        assert_eq!(fout.matches("<standardLoopCharacteristics").count(), 4);
+        let sbpmn2 = fout
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        let marking = sbpmn2.get_initial_marking().unwrap().unwrap();
+        assert_eq!(sbpmn2.get_enabled_transitions(&marking).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn bpmn_export_import_task_types() {
+        let fin = fs::read_to_string("testfiles/task-types.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("<sendTask id=\"sendtask_1\""));
+        assert!(fout.contains("<serviceTask id=\"servicetask_1\""));
+        assert!(fout.contains("<scriptTask id=\"scripttask_1\""));
+        assert!(fout.contains("<businessRuleTask id=\"businessruletask_1\""));
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn2.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn2.number_of_transitions(&marking), 8);
+        bpmn2.execute_transition(&mut marking, 0).unwrap();
+        bpmn2.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(bpmn2.get_enabled_transitions(&marking).unwrap(), vec![2, 4]);
+    }
+
+    #[test]
+    fn bpmn_export_import_event_sub_process() {
+        let fin = fs::read_to_string("testfiles/event-sub-process.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("<subProcess id=\"subprocess_1\" triggeredByEvent=\"true\">"));
+        assert!(fout.contains("<startEvent id=\"startevent_2\" isInterrupting=\"false\">"));
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn2.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn2.number_of_transitions(&marking), 5);
+        bpmn2.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(bpmn2.get_enabled_transitions(&marking).unwrap(), vec![1, 3]);
+    }
+
+    #[test]
+    fn sbpmn_export_import_complex_gateway() {
+        let fin = fs::read_to_string("testfiles/complex-gateway.sbpmn").unwrap();
+        let sbpmn = fin
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        let mut f = vec![];
+        sbpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("<complexGateway id=\"gateway_2\" sbpmn:activationCount=\"2\">"));
+        let sbpmn2 = fout
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        let mut marking = sbpmn2.get_initial_marking().unwrap().unwrap();
+        for transition in [0, 1, 2] {
+            sbpmn2.execute_transition(&mut marking, transition).unwrap();
+        }
+        assert_eq!(
+            sbpmn2.get_enabled_transitions(&marking).unwrap(),
+            vec![3, 4]
+        );
+    }
+
+    #[test]
+    fn sbpmn_export_import_complex_gateway_foreign_activation_count() {
+        let fin = fs::read_to_string("testfiles/complex-gateway.sbpmn")
+            .unwrap()
+            .replace(
+                "xmlns:sbpmn=\"https://www.ebitools.org/sbpmn/20260305\"",
+                "xmlns:sbpmn=\"https://www.ebitools.org/sbpmn/20260305\" xmlns:other=\"https://example.org/other\"",
+            )
+            .replace(
+                "sbpmn:activationCount=\"2\"",
+                "other:activationCount=\"2\"",
+            );
+        let sbpmn = fin
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        //an attribute of another namespace is not interpreted, but kept
+        let gateway = sbpmn
+            .elements()
+            .into_iter()
+            .find(|element| element.id() == "gateway_2")
+            .unwrap();
+        let BPMNElement::ComplexGateway(gateway) = gateway else {
+            panic!("expected a complex gateway");
+        };
+        assert_eq!(gateway.activation_count(), 1);
+
+        let mut f = vec![];
+        sbpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("other:activationCount=\"2\""));
+        assert!(!fout.contains("sbpmn:activationCount"));
+    }
+
+    #[test]
+    fn bpmn_export_import_default_sequence_flow() {
+        let fin = fs::read_to_string("testfiles/default-flow.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("<inclusiveGateway id=\"gateway_1\" default=\"sequenceflow_4\">"));
+        assert!(fout.contains("<exclusiveGateway id=\"gateway_3\" default=\"sequenceflow_10\">"));
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn2.get_initial_marking().unwrap().unwrap();
+        bpmn2.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(
+            bpmn2.get_enabled_transitions(&marking).unwrap(),
+            vec![4, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn bpmn_export_import_condition_expressions() {
+        let fin = fs::read_to_string("testfiles/conditions.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains(
+            "<conditionExpression xsi:type=\"tFormalExpression\">amount &gt; 1000</conditionExpression>"
+        ));
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn2.get_initial_marking().unwrap().unwrap();
+        marking.set_variable("amount", 100);
+        marking.set_variable("risk", "low");
+        bpmn2.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(bpmn2.get_enabled_transitions(&marking).unwrap(), vec![2]);
+    }
+
+    #[test]
+    fn bpmn_export_import_data_objects() {
+        let fin = fs::read_to_string("testfiles/data-objects.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("<dataStore id=\"DataStore_1\" name=\"customers\"/>"));
+        assert!(fout.contains("<targetRef>Property_1</targetRef>"));
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        assert_eq!(bpmn2.data_elements.len(), bpmn.data_elements.len());
+        assert_eq!(bpmn2.data_associations.len(), bpmn.data_associations.len());
+        for (data_element, data_element2) in bpmn.data_elements.iter().zip(&bpmn2.data_elements) {
+            assert_eq!(data_element.id(), data_element2.id());
+            assert_eq!(data_element.data_ref(), data_element2.data_ref());
+        }
+        assert_eq!(bpmn2.activities_writing("DataStore_1").len(), 1);
+    }
+
+    #[test]
+    fn bpmn_export_import_nested_lanes() {
+        let fin = fs::read_to_string("testfiles/lanes-nested.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("<childLaneSet id=\"LaneSet_2\">"));
+        assert!(fout.contains("<flowNodeRef>Task_3</flowNodeRef>"));
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let lanes = bpmn.lanes();
+        let lanes2 = bpmn2.lanes();
+        assert_eq!(lanes.len(), lanes2.len());
+        for (lane, lane2) in lanes.into_iter().zip(lanes2) {
+            assert_eq!(lane.id(), lane2.id());
+            assert_eq!(lane.name(), lane2.name());
+            assert_eq!(
+                bpmn.elements_in_lane(lane.global_index()).len(),
+                bpmn2.elements_in_lane(lane2.global_index()).len()
+            );
+        }
+    }
+
+    #[test]
+    fn sbpmn_export_import_names() {
+        let fin = fs::read_to_string("testfiles/model.sbpmn").unwrap();
+        let sbpmn = fin
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        let mut f = vec![];
+        sbpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f);
+        assert!(fout.contains("<startEvent id=\"StartEvent_1\" name=\"claim received\">"));
+        assert!(fout.contains("name=\"20%\""));
+        let sbpmn2 = fout
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        let flow_names = |sbpmn: &StochasticBusinessProcessModelAndNotation| {
+            sbpmn
+                .bpmn
+                .sequence_flows()
+                .into_iter()
+                .filter_map(|sequence_flow| sequence_flow.name().map(|name| name.to_string()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(flow_names(&sbpmn2), vec!["20%", "80%", "90%", "10%"]);
+        assert_eq!(flow_names(&sbpmn2), flow_names(&sbpmn));
+
+        let element_names = |sbpmn: &StochasticBusinessProcessModelAndNotation| {
+            sbpmn
+                .bpmn
+                .elements()
+                .into_iter()
+                .filter_map(|element| element.name().map(|name| name.to_string()))
+                .collect::<Vec<_>>()
+        };
+        assert!(element_names(&sbpmn2).contains(&"claim handled".to_string()));
+        assert_eq!(element_names(&sbpmn2), element_names(&sbpmn));
+    }
+
+    #[test]
+    fn bpmn_export_import_unrecognised() {
+        let fin = fs::read_to_string("testfiles/unrecognised.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let documentation = bpmn.unrecognised_xml_of("Task_1").unwrap().children();
+        assert_eq!(
+            documentation[0],
+            &BPMNXmlNode::Element {
+                name: "documentation".to_string(),
+                attributes: vec![],
+                children: vec![BPMNXmlNode::Text(
+                    "Check whether the claim is covered by the policy & whether it is complete."
+                        .to_string()
+                )]
+            }
+        );
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f).to_string();
+        assert!(fout.contains("xmlns:camunda=\"http://camunda.org/schema/1.0/bpmn\""));
+        assert!(fout.contains(
+            "<process id=\"Process_1\" name=\"claims\" isExecutable=\"true\" camunda:historyTimeToLive=\"180\">"
+        ));
+        assert!(fout.contains("<documentation>Handles the claims of customers.</documentation>"));
+        assert!(fout.contains("policy &amp; whether"));
+        assert!(fout.contains("<camunda:property name=\"priority\" value=\"high\"/>"));
+        assert!(fout.contains("<![CDATA[if (a < b) { check(); }]]>"));
+        assert!(fout.contains("<!-- the claim is closed either way -->"));
+        assert!(fout.contains("<timeDuration xsi:type=\"tFormalExpression\">PT1H</timeDuration>"));
+        assert!(fout.contains("<text>Claims above 1000 euro need a second opinion.</text>"));
+        assert!(fout.contains("<categoryValue id=\"CategoryValue_1\" value=\"intake\"/>"));
+        assert!(fout.contains("bioc:stroke=\"#831311\""));
+        assert!(!fout.contains("Camunda Modeler"));
+
+        //attributes that are interpreted on other tags, tags without an id and comments outside the definitions
+        assert!(fout.contains("name=\"claims model\""));
+        assert!(fout.contains("<bpmndi:BPMNLabel labelStyle=\"LabelStyle_1\">"));
+        assert!(fout.contains(
+            "?>\n<!-- modelled in a tool that adds its own extensions -->\n<definitions id=\"Definitions_1\""
+        ));
+
+        //a second round trip does not change the model
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+        let mut f2 = vec![];
+        bpmn2.export_to_writer(&mut f2).unwrap();
+        assert_eq!(String::from_utf8_lossy(&f2), fout);
+    }
+
+    #[test]
+    fn bpmn_export_import_unrecognised_without_id() {
+        let fin = fs::read_to_string("testfiles/ignored-elements.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        //the loop condition is kept with the task, as its loop characteristics have no id
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f).to_string();
+        let loop_start = fout.find("<standardLoopCharacteristics").unwrap();
+        let loop_end = fout.find("</standardLoopCharacteristics>").unwrap();
+        assert!(
+            fout[loop_start..loop_end]
+                .contains("<loopCondition xsi:type=\"tFormalExpression\">unsolved</loopCondition>")
+        );
+
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+        let mut f2 = vec![];
+        bpmn2.export_to_writer(&mut f2).unwrap();
+        assert_eq!(String::from_utf8_lossy(&f2), fout);
+    }
+
+    #[test]
+    fn bpmn_export_import_imports() {
+        let bpmn = BusinessProcessModelAndNotation::import_from_path(
+            Path::new("testfiles/import-main.bpmn"),
+            true,
+        )
+        .unwrap();
+
+        let mut f = vec![];
+        bpmn.export_to_writer(&mut f).unwrap();
+        let fout = String::from_utf8_lossy(&f).to_string();
+        assert!(fout.contains("<import namespace=\"http://example.org/shared\" location=\"import-shared.bpmn\" importType=\"http://www.omg.org/spec/BPMN/20100524/MODEL\"/>"));
+        assert!(fout.contains("xmlns:shared=\"http://example.org/shared\""));
+        assert!(fout.contains("calledElement=\"shared:process_shared\""));
+
+        let bpmn2 = fout.parse::<BusinessProcessModelAndNotation>().unwrap();
+        assert_eq!(bpmn2.imports.len(), 1);
+        assert_eq!(bpmn2.imports[0].location(), "import-shared.bpmn");
+    }
 }
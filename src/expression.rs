@@ -0,0 +1,565 @@
+use anyhow::{Result, anyhow};
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+/// A value of a variable, or the result of evaluating an expression.
+///
+/// Two numbers are equal if they have the same bit pattern, such that values can be part of a marking.
+#[derive(Debug, Clone)]
+pub enum BPMNValue {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+}
+
+impl PartialEq for BPMNValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (BPMNValue::Null, BPMNValue::Null) => true,
+            (BPMNValue::Boolean(a), BPMNValue::Boolean(b)) => a == b,
+            (BPMNValue::Number(a), BPMNValue::Number(b)) => a.to_bits() == b.to_bits(),
+            (BPMNValue::String(a), BPMNValue::String(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for BPMNValue {}
+
+impl Hash for BPMNValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            BPMNValue::Null => {}
+            BPMNValue::Boolean(value) => value.hash(state),
+            BPMNValue::Number(value) => value.to_bits().hash(state),
+            BPMNValue::String(value) => value.hash(state),
+        }
+    }
+}
+
+impl Display for BPMNValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BPMNValue::Null => write!(f, "null"),
+            BPMNValue::Boolean(value) => write!(f, "{}", value),
+            BPMNValue::Number(value) => write!(f, "{}", value),
+            BPMNValue::String(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+impl From<bool> for BPMNValue {
+    fn from(value: bool) -> Self {
+        BPMNValue::Boolean(value)
+    }
+}
+
+impl From<f64> for BPMNValue {
+    fn from(value: f64) -> Self {
+        BPMNValue::Number(value)
+    }
+}
+
+impl From<i32> for BPMNValue {
+    fn from(value: i32) -> Self {
+        BPMNValue::Number(value as f64)
+    }
+}
+
+impl From<i64> for BPMNValue {
+    fn from(value: i64) -> Self {
+        BPMNValue::Number(value as f64)
+    }
+}
+
+impl From<&str> for BPMNValue {
+    fn from(value: &str) -> Self {
+        BPMNValue::String(value.to_string())
+    }
+}
+
+impl From<String> for BPMNValue {
+    fn from(value: String) -> Self {
+        BPMNValue::String(value)
+    }
+}
+
+/// The condition expression of a sequence flow.
+///
+/// Conditions are evaluated as a subset of FEEL: number, string and boolean literals, `null`, variables,
+/// arithmetic (`+`, `-`, `*`, `/`), comparisons (`=`, `!=`, `<`, `<=`, `>`, `>=`), `and`, `or`, `not(...)` and parentheses.
+/// As in FEEL, a variable that is not set is `null`, and an operation on values of the wrong types results in `null`.
+/// A condition holds if it evaluates to `true`.
+#[derive(Debug, Clone)]
+pub struct BPMNConditionExpression {
+    pub(crate) text: String,
+    pub(crate) language: Option<String>,
+
+    ///the parsed expression; absent if the text is not in the supported subset of FEEL
+    pub(crate) expression: Option<Expression>,
+}
+
+impl BPMNConditionExpression {
+    /// Creates a condition expression. A text that is not in the supported subset of FEEL, or that is in another language, is kept, but cannot be evaluated.
+    pub fn new(text: String, language: Option<String>) -> Self {
+        let expression = if language.as_deref().is_none_or(is_feel) {
+            text.parse().ok()
+        } else {
+            None
+        };
+        Self {
+            text,
+            language,
+            expression,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    /// Returns whether the condition is in the supported subset of FEEL, such that it can be evaluated.
+    pub fn is_supported(&self) -> bool {
+        self.expression.is_some()
+    }
+
+    /// Evaluates the condition against the given variables.
+    /// Returns an error if the condition is not in the supported subset of FEEL, or is in another language.
+    pub fn evaluate(&self, variables: &BTreeMap<String, BPMNValue>) -> Result<BPMNValue> {
+        match &self.expression {
+            Some(expression) => Ok(expression.evaluate(variables)),
+            None if !self.language.as_deref().is_none_or(is_feel) => Err(anyhow!(
+                "conditions in language `{}` are not supported",
+                self.language.as_deref().unwrap_or_default()
+            )),
+            None => Err(self
+                .text
+                .parse::<Expression>()
+                .err()
+                .unwrap_or_else(|| anyhow!("condition could not be parsed"))),
+        }
+    }
+
+    /// Returns whether the condition evaluates to `true` for the given variables.
+    pub fn holds(&self, variables: &BTreeMap<String, BPMNValue>) -> Result<bool> {
+        Ok(self.evaluate(variables)? == BPMNValue::Boolean(true))
+    }
+}
+
+/// Returns whether the `language` of a condition expression is FEEL, such as `https://www.omg.org/spec/DMN/20191111/FEEL/`.
+fn is_feel(language: &str) -> bool {
+    language.to_ascii_uppercase().contains("FEEL")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Operator {
+    Or,
+    And,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Expression {
+    Literal(BPMNValue),
+    Variable(String),
+    Not(Box<Expression>),
+    Negate(Box<Expression>),
+    Binary(Operator, Box<Expression>, Box<Expression>),
+}
+
+impl Expression {
+    pub(crate) fn evaluate(&self, variables: &BTreeMap<String, BPMNValue>) -> BPMNValue {
+        match self {
+            Expression::Literal(value) => value.clone(),
+            Expression::Variable(name) => variables.get(name).cloned().unwrap_or(BPMNValue::Null),
+            Expression::Not(expression) => match expression.evaluate(variables) {
+                BPMNValue::Boolean(value) => BPMNValue::Boolean(!value),
+                _ => BPMNValue::Null,
+            },
+            Expression::Negate(expression) => match expression.evaluate(variables) {
+                BPMNValue::Number(value) => BPMNValue::Number(-value),
+                _ => BPMNValue::Null,
+            },
+            Expression::Binary(operator, left, right) => {
+                operator.apply(left.evaluate(variables), right.evaluate(variables))
+            }
+        }
+    }
+}
+
+impl Operator {
+    fn apply(&self, left: BPMNValue, right: BPMNValue) -> BPMNValue {
+        use BPMNValue::{Boolean, Null, Number, String};
+        match (self, left, right) {
+            //three-valued logic
+            (Operator::And, Boolean(false), _) | (Operator::And, _, Boolean(false)) => {
+                Boolean(false)
+            }
+            (Operator::And, Boolean(true), Boolean(true)) => Boolean(true),
+            (Operator::Or, Boolean(true), _) | (Operator::Or, _, Boolean(true)) => Boolean(true),
+            (Operator::Or, Boolean(false), Boolean(false)) => Boolean(false),
+
+            //equality
+            (Operator::Equal, left, right) => equals(&left, &right),
+            (Operator::NotEqual, left, right) => match equals(&left, &right) {
+                Boolean(value) => Boolean(!value),
+                _ => Null,
+            },
+
+            //ordering
+            (Operator::Less, Number(a), Number(b)) => Boolean(a < b),
+            (Operator::LessOrEqual, Number(a), Number(b)) => Boolean(a <= b),
+            (Operator::Greater, Number(a), Number(b)) => Boolean(a > b),
+            (Operator::GreaterOrEqual, Number(a), Number(b)) => Boolean(a >= b),
+            (Operator::Less, String(a), String(b)) => Boolean(a < b),
+            (Operator::LessOrEqual, String(a), String(b)) => Boolean(a <= b),
+            (Operator::Greater, String(a), String(b)) => Boolean(a > b),
+            (Operator::GreaterOrEqual, String(a), String(b)) => Boolean(a >= b),
+
+            //arithmetic
+            (Operator::Add, Number(a), Number(b)) => Number(a + b),
+            (Operator::Add, String(a), String(b)) => String(a + &b),
+            (Operator::Subtract, Number(a), Number(b)) => Number(a - b),
+            (Operator::Multiply, Number(a), Number(b)) => Number(a * b),
+            (Operator::Divide, Number(a), Number(b)) if b != 0.0 => Number(a / b),
+
+            _ => Null,
+        }
+    }
+}
+
+fn equals(left: &BPMNValue, right: &BPMNValue) -> BPMNValue {
+    match (left, right) {
+        (BPMNValue::Null, BPMNValue::Null) => BPMNValue::Boolean(true),
+        (BPMNValue::Null, _) | (_, BPMNValue::Null) => BPMNValue::Boolean(false),
+        (BPMNValue::Boolean(a), BPMNValue::Boolean(b)) => BPMNValue::Boolean(a == b),
+        (BPMNValue::Number(a), BPMNValue::Number(b)) => BPMNValue::Boolean(a == b),
+        (BPMNValue::String(a), BPMNValue::String(b)) => BPMNValue::Boolean(a == b),
+        _ => BPMNValue::Null,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ExpressionToken {
+    Number(f64),
+    String(String),
+    Name(String),
+    Symbol(&'static str),
+}
+
+const SYMBOLS: [&str; 12] = [
+    "!=", "<=", ">=", "=", "<", ">", "+", "-", "*", "/", "(", ")",
+];
+
+fn tokenise(text: &str) -> Result<Vec<ExpressionToken>> {
+    let mut result = vec![];
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut end = start + c.len_utf8();
+            while let Some((index, next)) = chars.peek() {
+                if next.is_ascii_digit() || *next == '.' {
+                    end = index + next.len_utf8();
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            let number = text[start..end]
+                .parse::<f64>()
+                .map_err(|_| anyhow!("`{}` is not a number", &text[start..end]))?;
+            result.push(ExpressionToken::Number(number));
+        } else if c == '"' {
+            let mut string = String::new();
+            loop {
+                match chars.next() {
+                    Some((_, '"')) => break,
+                    Some((_, '\\')) => match chars.next() {
+                        Some((_, 'n')) => string.push('\n'),
+                        Some((_, 't')) => string.push('\t'),
+                        Some((_, escaped)) => string.push(escaped),
+                        None => return Err(anyhow!("unterminated string")),
+                    },
+                    Some((_, next)) => string.push(next),
+                    None => return Err(anyhow!("unterminated string")),
+                }
+            }
+            result.push(ExpressionToken::String(string));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut end = start + c.len_utf8();
+            while let Some((index, next)) = chars.peek() {
+                if next.is_alphanumeric() || *next == '_' {
+                    end = index + next.len_utf8();
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            result.push(ExpressionToken::Name(text[start..end].to_string()));
+        } else if let Some(symbol) = SYMBOLS
+            .iter()
+            .find(|symbol| text[start..].starts_with(**symbol))
+        {
+            for _ in 1..symbol.len() {
+                chars.next();
+            }
+            result.push(ExpressionToken::Symbol(*symbol));
+        } else {
+            return Err(anyhow!("unexpected character `{}`", c));
+        }
+    }
+    Ok(result)
+}
+
+/// A recursive-descent parser over the tokens of an expression, from the lowest to the highest precedence.
+struct ExpressionParser {
+    tokens: Vec<ExpressionToken>,
+    position: usize,
+}
+
+impl ExpressionParser {
+    fn peek(&self) -> Option<&ExpressionToken> {
+        self.tokens.get(self.position)
+    }
+
+    fn next_token(&mut self) -> Option<ExpressionToken> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn next_is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(ExpressionToken::Name(name)) if name == keyword)
+    }
+
+    fn next_is_symbol(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Some(ExpressionToken::Symbol(s)) if *s == symbol)
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<()> {
+        if self.next_is_symbol(symbol) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(anyhow!("expected `{}`", symbol))
+        }
+    }
+
+    fn disjunction(&mut self) -> Result<Expression> {
+        let mut result = self.conjunction()?;
+        while self.next_is_keyword("or") {
+            self.position += 1;
+            let right = self.conjunction()?;
+            result = Expression::Binary(Operator::Or, Box::new(result), Box::new(right));
+        }
+        Ok(result)
+    }
+
+    fn conjunction(&mut self) -> Result<Expression> {
+        let mut result = self.comparison()?;
+        while self.next_is_keyword("and") {
+            self.position += 1;
+            let right = self.comparison()?;
+            result = Expression::Binary(Operator::And, Box::new(result), Box::new(right));
+        }
+        Ok(result)
+    }
+
+    fn comparison(&mut self) -> Result<Expression> {
+        let left = self.sum()?;
+        let operator = match self.peek() {
+            Some(ExpressionToken::Symbol("=")) => Operator::Equal,
+            Some(ExpressionToken::Symbol("!=")) => Operator::NotEqual,
+            Some(ExpressionToken::Symbol("<")) => Operator::Less,
+            Some(ExpressionToken::Symbol("<=")) => Operator::LessOrEqual,
+            Some(ExpressionToken::Symbol(">")) => Operator::Greater,
+            Some(ExpressionToken::Symbol(">=")) => Operator::GreaterOrEqual,
+            _ => return Ok(left),
+        };
+        self.position += 1;
+        let right = self.sum()?;
+        Ok(Expression::Binary(
+            operator,
+            Box::new(left),
+            Box::new(right),
+        ))
+    }
+
+    fn sum(&mut self) -> Result<Expression> {
+        let mut result = self.product()?;
+        loop {
+            let operator = match self.peek() {
+                Some(ExpressionToken::Symbol("+")) => Operator::Add,
+                Some(ExpressionToken::Symbol("-")) => Operator::Subtract,
+                _ => return Ok(result),
+            };
+            self.position += 1;
+            let right = self.product()?;
+            result = Expression::Binary(operator, Box::new(result), Box::new(right));
+        }
+    }
+
+    fn product(&mut self) -> Result<Expression> {
+        let mut result = self.unary()?;
+        loop {
+            let operator = match self.peek() {
+                Some(ExpressionToken::Symbol("*")) => Operator::Multiply,
+                Some(ExpressionToken::Symbol("/")) => Operator::Divide,
+                _ => return Ok(result),
+            };
+            self.position += 1;
+            let right = self.unary()?;
+            result = Expression::Binary(operator, Box::new(result), Box::new(right));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expression> {
+        if self.next_is_symbol("-") {
+            self.position += 1;
+            Ok(Expression::Negate(Box::new(self.unary()?)))
+        } else {
+            self.primary()
+        }
+    }
+
+    fn primary(&mut self) -> Result<Expression> {
+        match self.next_token() {
+            Some(ExpressionToken::Number(value)) => {
+                Ok(Expression::Literal(BPMNValue::Number(value)))
+            }
+            Some(ExpressionToken::String(value)) => {
+                Ok(Expression::Literal(BPMNValue::String(value)))
+            }
+            Some(ExpressionToken::Symbol("(")) => {
+                let result = self.disjunction()?;
+                self.expect_symbol(")")?;
+                Ok(result)
+            }
+            Some(ExpressionToken::Name(name)) => match name.as_str() {
+                "true" => Ok(Expression::Literal(BPMNValue::Boolean(true))),
+                "false" => Ok(Expression::Literal(BPMNValue::Boolean(false))),
+                "null" => Ok(Expression::Literal(BPMNValue::Null)),
+                "not" => {
+                    self.expect_symbol("(")?;
+                    let result = self.disjunction()?;
+                    self.expect_symbol(")")?;
+                    Ok(Expression::Not(Box::new(result)))
+                }
+                "and" | "or" => Err(anyhow!("unexpected `{}`", name)),
+                _ => Ok(Expression::Variable(name)),
+            },
+            Some(ExpressionToken::Symbol(symbol)) => Err(anyhow!("unexpected `{}`", symbol)),
+            None => Err(anyhow!("unexpected end of expression")),
+        }
+    }
+}
+
+impl FromStr for Expression {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut parser = ExpressionParser {
+            tokens: tokenise(s)?,
+            position: 0,
+        };
+        let result = parser.disjunction()?;
+        if let Some(token) = parser.peek() {
+            return Err(anyhow!(
+                "unexpected {:?} after the end of expression `{}`",
+                token,
+                s
+            ));
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expression::{BPMNConditionExpression, BPMNValue};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn expression_evaluate() {
+        let mut variables = BTreeMap::new();
+        variables.insert("amount".to_string(), BPMNValue::from(1500));
+        variables.insert("risk".to_string(), BPMNValue::from("low"));
+
+        let condition =
+            BPMNConditionExpression::new("amount > 1000 and risk = \"low\"".to_string(), None);
+        assert!(condition.holds(&variables).unwrap());
+
+        let condition = BPMNConditionExpression::new(
+            "not(amount - 500 <= 1000) or risk != \"low\"".to_string(),
+            None,
+        );
+        assert!(condition.holds(&variables).unwrap());
+
+        let condition = BPMNConditionExpression::new("(amount + 1) / 2".to_string(), None);
+        assert_eq!(
+            condition.evaluate(&variables).unwrap(),
+            BPMNValue::Number(750.5)
+        );
+    }
+
+    #[test]
+    fn expression_null() {
+        let variables = BTreeMap::new();
+
+        //an unset variable is null, and comparing null to a number is neither true nor false
+        let condition = BPMNConditionExpression::new("amount > 1000".to_string(), None);
+        assert_eq!(condition.evaluate(&variables).unwrap(), BPMNValue::Null);
+        assert!(!condition.holds(&variables).unwrap());
+
+        let condition = BPMNConditionExpression::new("amount = null".to_string(), None);
+        assert!(condition.holds(&variables).unwrap());
+
+        let condition = BPMNConditionExpression::new("false and amount > 1000".to_string(), None);
+        assert_eq!(
+            condition.evaluate(&variables).unwrap(),
+            BPMNValue::Boolean(false)
+        );
+    }
+
+    #[test]
+    fn expression_unsupported() {
+        let condition = BPMNConditionExpression::new("${amount > 1000}".to_string(), None);
+        assert_eq!(condition.text(), "${amount > 1000}");
+        assert!(!condition.is_supported());
+        assert!(condition.evaluate(&BTreeMap::new()).is_err());
+
+        //a condition in another language is not evaluated, even if it looks like FEEL
+        let condition = BPMNConditionExpression::new(
+            "amount > 1000".to_string(),
+            Some("http://www.w3.org/1999/XPath".to_string()),
+        );
+        assert!(!condition.is_supported());
+
+        let condition = BPMNConditionExpression::new(
+            "amount > 1000".to_string(),
+            Some("https://www.omg.org/spec/DMN/20191111/FEEL/".to_string()),
+        );
+        assert!(condition.is_supported());
+    }
+}
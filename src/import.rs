@@ -0,0 +1,69 @@
+use crate::BusinessProcessModelAndNotation;
+use anyhow::{Context, Result, anyhow};
+use std::io::BufRead;
+
+/// The import type of BPMN files; other import types, such as XML schemas, are kept but not loaded.
+pub(crate) const IMPORT_TYPE_BPMN: &str = "http://www.omg.org/spec/BPMN/20100524/MODEL";
+
+/// An `import` of a BPMN model: a reference to another file, of which the elements are referred to with the namespace of the import.
+/// Call activities can call the processes of imported BPMN files, such as `calledElement="shared:Process_1"` where `xmlns:shared` is the namespace of the import.
+#[derive(Clone, Debug)]
+pub struct BPMNImport {
+    pub(crate) namespace: String,
+    pub(crate) location: String,
+    pub(crate) import_type: String,
+
+    ///the imported model; absent if the model was imported without a resolver, or if the import is not a BPMN file
+    pub(crate) definitions: Option<Box<BusinessProcessModelAndNotation>>,
+}
+
+impl BPMNImport {
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    pub fn import_type(&self) -> &str {
+        &self.import_type
+    }
+
+    pub fn definitions(&self) -> Option<&BusinessProcessModelAndNotation> {
+        self.definitions.as_deref()
+    }
+
+    /// Loads the imported model if it is a BPMN file. `loading` contains the locations of the files that are being imported, to detect cycles.
+    pub(crate) fn load(
+        &mut self,
+        resolver: &mut dyn FnMut(&str) -> Result<Box<dyn BufRead>>,
+        disallow_sequence_flow_weights: bool,
+        loading: &mut Vec<String>,
+    ) -> Result<()> {
+        if self.import_type != IMPORT_TYPE_BPMN {
+            return Ok(());
+        }
+        if loading.contains(&self.location) {
+            return Err(anyhow!(
+                "File `{}` imports itself, possibly through other files.",
+                self.location
+            ));
+        }
+
+        let mut reader = resolver(&self.location)
+            .with_context(|| anyhow!("Resolving import `{}`.", self.location))?;
+        loading.push(self.location.clone());
+        let (definitions, _) = BusinessProcessModelAndNotation::import_from_reader_resolving(
+            &mut reader,
+            disallow_sequence_flow_weights,
+            Some(resolver),
+            loading,
+        )
+        .with_context(|| anyhow!("Importing `{}`.", self.location))?;
+        loading.pop();
+
+        self.definitions = Some(Box::new(definitions));
+        Ok(())
+    }
+}
@@ -1,41 +1,170 @@
 use crate::{
     BusinessProcessModelAndNotation,
+    error::BPMNError,
     parser::{
-        parser::{can_eof, close_tag, empty_tag, is_in_namespace, open_tag},
+        parser::{
+            NameSpace, can_eof, cdata, close_tag, comment, empty_tag, general_reference,
+            is_in_namespace, open_tag, text,
+        },
         parser_state::ParserState,
     },
+    warning::{BPMNImportWarning, BPMNImportWarningReason},
 };
-use anyhow::{Context, Error, Result};
+use anyhow::{Context, Result, anyhow};
+use itertools::Itertools;
 use quick_xml::{
     NsReader,
     events::{BytesStart, Event},
 };
-use std::{io::BufRead, str::FromStr};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::{Component, Path, PathBuf},
+    str::FromStr,
+};
 
 impl BusinessProcessModelAndNotation {
     /// Attempts to import a BPMN model. If `disallow_sequence_flow_weights` is set to true, parsing will fail if any sequence flow has a weight.
     /// Will only succeed if the model is structurally correct.
+    ///
+    /// Imported files are not loaded; to call processes of other files, use [Self::import_from_reader_with_resolver] or [Self::import_from_path].
     pub fn import_from_reader(
         reader: &mut dyn BufRead,
         disallow_sequence_flow_weights: bool,
-    ) -> Result<Self>
+    ) -> Result<Self, BPMNError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::import_from_reader_resolving(
+            reader,
+            disallow_sequence_flow_weights,
+            None,
+            &mut vec![],
+        )?
+        .0)
+    }
+
+    /// Attempts to import a BPMN model, like [Self::import_from_reader], and reports the tags that were ignored.
+    /// Tags of other namespaces than those of BPMN, such as extensions of modelling tools, are not reported.
+    pub fn import_from_reader_with_warnings(
+        reader: &mut dyn BufRead,
+        disallow_sequence_flow_weights: bool,
+    ) -> Result<(Self, Vec<BPMNImportWarning>), BPMNError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::import_from_reader_resolving(
+            reader,
+            disallow_sequence_flow_weights,
+            None,
+            &mut vec![],
+        )?)
+    }
+
+    /// Attempts to import a BPMN model, like [Self::import_from_reader], but fails if a flow node was ignored,
+    /// for instance because it is not supported by this crate, or if a condition expression cannot be evaluated.
+    pub fn import_from_reader_strict(
+        reader: &mut dyn BufRead,
+        disallow_sequence_flow_weights: bool,
+    ) -> Result<Self, BPMNError>
+    where
+        Self: Sized,
+    {
+        let (bpmn, warnings) =
+            Self::import_from_reader_with_warnings(reader, disallow_sequence_flow_weights)?;
+        let ignored_behaviour = warnings
+            .iter()
+            .filter(|warning| {
+                warning.reason == BPMNImportWarningReason::IgnoredFlowNode
+                    || warning.reason == BPMNImportWarningReason::UnsupportedCondition
+            })
+            .collect::<Vec<_>>();
+        if ignored_behaviour.is_empty() {
+            Ok(bpmn)
+        } else {
+            Err(BPMNError::Unsupported(format!(
+                "{}\nThe model has flow nodes or conditions that are ignored, which is not allowed in this import mode.",
+                ignored_behaviour.iter().join("\n")
+            )))
+        }
+    }
+
+    /// Attempts to import a BPMN model, of which the imported BPMN files are loaded with the given resolver.
+    /// The resolver receives the `location` of an `import` and opens it; files that are imported by imported files are resolved in the same way.
+    /// Call activities can then call the processes of imported files.
+    pub fn import_from_reader_with_resolver(
+        reader: &mut dyn BufRead,
+        disallow_sequence_flow_weights: bool,
+        resolver: &mut dyn FnMut(&str) -> Result<Box<dyn BufRead>>,
+    ) -> Result<Self, BPMNError>
     where
         Self: Sized,
     {
+        Ok(Self::import_from_reader_resolving(
+            reader,
+            disallow_sequence_flow_weights,
+            Some(resolver),
+            &mut vec![],
+        )?
+        .0)
+    }
+
+    /// Attempts to import the BPMN model in the given file.
+    /// Imported BPMN files are loaded from the directory of the file; imports of other locations, such as URLs, are not supported.
+    pub fn import_from_path(
+        path: &Path,
+        disallow_sequence_flow_weights: bool,
+    ) -> Result<Self, BPMNError>
+    where
+        Self: Sized,
+    {
+        let directory = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        let file = File::open(path).with_context(|| anyhow!("Opening `{}`.", path.display()))?;
+        let mut reader = BufReader::new(file);
+
+        //the file itself is being loaded, such that it cannot import itself
+        let mut loading = path
+            .file_name()
+            .map(|name| vec![name.to_string_lossy().to_string()])
+            .unwrap_or_default();
+        Ok(Self::import_from_reader_resolving(
+            &mut reader,
+            disallow_sequence_flow_weights,
+            Some(&mut |location| {
+                let file = File::open(local_import_path(&directory, location)?)
+                    .with_context(|| anyhow!("Opening `{}`.", location))?;
+                Ok(Box::new(BufReader::new(file)))
+            }),
+            &mut loading,
+        )?
+        .0)
+    }
+
+    /// `loading` contains the locations of the files that are being imported, to detect cycles.
+    /// Returns the model with the tags that were ignored.
+    pub(crate) fn import_from_reader_resolving(
+        reader: &mut dyn BufRead,
+        disallow_sequence_flow_weights: bool,
+        resolver: Option<&mut dyn FnMut(&str) -> Result<Box<dyn BufRead>>>,
+        loading: &mut Vec<String>,
+    ) -> Result<(Self, Vec<BPMNImportWarning>)> {
         let mut xml_reader = NsReader::from_reader(reader);
-        xml_reader.config_mut().trim_text(true);
+        //whitespace is kept for unrecognised text; recognised text is trimmed when its tag closes
+        xml_reader.config_mut().trim_text(false);
 
         let mut buf = vec![];
         let mut state = ParserState::new();
         loop {
             buf.clear();
+            state.position = xml_reader.buffer_position();
             let (namespace, xml_event) = xml_reader
                 .read_resolved_event_into(&mut buf)
-                .with_context(|| "Cannot read XML event.")?;
+                .with_context(|| BPMNError::Xml("Cannot read XML event.".to_string()))?;
             let in_namespace = is_in_namespace(namespace);
             match (in_namespace, xml_event) {
                 //start tag
-                (Some(n), Event::Start(e)) => {
+                (n, Event::Start(e)) => {
+                    state.sbpmn_attributes = sbpmn_attributes(&xml_reader, &e);
                     open_tag(&mut state, &e, n).with_context(|| {
                         format!(
                             "Tag `{}` at position {}.",
@@ -46,7 +175,7 @@ impl BusinessProcessModelAndNotation {
                 }
 
                 //end of tag
-                (Some(n), Event::End(e)) => close_tag(&mut state, &e, n).with_context(|| {
+                (n, Event::End(e)) => close_tag(&mut state, &e, n).with_context(|| {
                     format!(
                         "Tag `{}` at position {}.",
                         String::from_utf8_lossy(e.local_name().as_ref()),
@@ -55,18 +184,43 @@ impl BusinessProcessModelAndNotation {
                 })?,
 
                 //empty tag
-                (Some(n), Event::Empty(e)) => empty_tag(&mut state, &e, n).with_context(|| {
-                    format!(
-                        "Tag `{}` at position {}.",
-                        String::from_utf8_lossy(e.local_name().as_ref()),
-                        xml_reader.buffer_position()
-                    )
+                (n, Event::Empty(e)) => {
+                    state.sbpmn_attributes = sbpmn_attributes(&xml_reader, &e);
+                    empty_tag(&mut state, &e, n).with_context(|| {
+                        format!(
+                            "Tag `{}` at position {}.",
+                            String::from_utf8_lossy(e.local_name().as_ref()),
+                            xml_reader.buffer_position()
+                        )
+                    })?
+                }
+
+                //text
+                (_, Event::Text(e)) => text(&mut state, &e).with_context(|| {
+                    format!("Text at position {}.", xml_reader.buffer_position())
+                })?,
+                (_, Event::CData(e)) => cdata(&mut state, &e).with_context(|| {
+                    format!("CDATA at position {}.", xml_reader.buffer_position())
+                })?,
+                (_, Event::GeneralRef(e)) => {
+                    general_reference(&mut state, &e).with_context(|| {
+                        format!("Reference at position {}.", xml_reader.buffer_position())
+                    })?
+                }
+
+                (_, Event::Comment(e)) => comment(&mut state, &e).with_context(|| {
+                    format!("Comment at position {}.", xml_reader.buffer_position())
                 })?,
 
                 //end of file: check whether we can finish
                 (_, Event::Eof) => {
-                    can_eof(&state).with_context(|| "Unexpected end of file.")?;
-                    return Ok(state.to_model(disallow_sequence_flow_weights)?);
+                    can_eof(&state)
+                        .with_context(|| BPMNError::Xml("Unexpected end of file.".to_string()))?;
+                    let warnings = std::mem::take(&mut state.warnings);
+                    return Ok((
+                        state.to_model(disallow_sequence_flow_weights, resolver, loading)?,
+                        warnings,
+                    ));
                 }
 
                 _ => (),
@@ -76,7 +230,7 @@ impl BusinessProcessModelAndNotation {
 }
 
 impl FromStr for BusinessProcessModelAndNotation {
-    type Err = Error;
+    type Err = BPMNError;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         let mut reader = std::io::Cursor::new(s);
@@ -84,6 +238,23 @@ impl FromStr for BusinessProcessModelAndNotation {
     }
 }
 
+/// Returns the path of an imported file, which must be in the given directory or one of its subdirectories.
+fn local_import_path(directory: &Path, location: &str) -> Result<PathBuf> {
+    let relative = Path::new(location);
+    if location.contains("://")
+        || relative
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(BPMNError::Unsupported(format!(
+            "Import `{}` is not a file in the local directory.",
+            location
+        ))
+        .into());
+    }
+    Ok(directory.join(relative))
+}
+
 pub(crate) fn parse_attribute(e: &BytesStart, attribute_name: &str) -> Option<String> {
     if let Ok(Some(attribute)) = e.try_get_attribute(attribute_name) {
         Some(
@@ -98,14 +269,61 @@ pub(crate) fn parse_attribute(e: &BytesStart, attribute_name: &str) -> Option<St
     }
 }
 
+/// Returns the qualified names of the attributes of the tag that are in the SBPMN namespace, whatever their prefix.
+fn sbpmn_attributes<R>(xml_reader: &NsReader<R>, e: &BytesStart) -> Vec<Vec<u8>> {
+    e.attributes()
+        .flatten()
+        .filter(|attribute| {
+            attribute.key.prefix().is_some()
+                && matches!(
+                    is_in_namespace(xml_reader.resolve_attribute(attribute.key).0),
+                    Some(NameSpace::SBPMN)
+                )
+        })
+        .map(|attribute| attribute.key.as_ref().to_vec())
+        .collect()
+}
+
+/// Returns the value of an attribute of the SBPMN namespace, such as `sbpmn:repeatProbability`, by its local name.
+/// Attributes with the same local name in other namespaces are not considered.
+pub(crate) fn parse_sbpmn_attribute(
+    e: &BytesStart,
+    state: &ParserState,
+    local_name: &str,
+) -> Option<String> {
+    let attribute = e.attributes().flatten().find(|attribute| {
+        attribute.key.local_name().as_ref() == local_name.as_bytes()
+            && state
+                .sbpmn_attributes
+                .iter()
+                .any(|name| name == attribute.key.as_ref())
+    })?;
+    Some(
+        attribute
+            .decoded_and_normalized_value(quick_xml::XmlVersion::Implicit1_0, e.decoder())
+            .ok()?
+            .as_ref()
+            .to_owned(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        BusinessProcessModelAndNotation, semantics::tests::debug_transitions,
+        BusinessProcessModelAndNotation,
+        element::BPMNElement,
+        error::BPMNError,
+        semantics::tests::debug_transitions,
         stochastic_business_process_model_and_notation::StochasticBusinessProcessModelAndNotation,
-        traits::processable::Processable,
+        timer::{BPMNDateTime, BPMNDuration, BPMNTimer, BPMNTimerSpecification, BPMNTimerType},
+        traits::{objectable::BPMNObject, processable::Processable},
+        warning::BPMNImportWarningReason,
+    };
+    use std::{
+        fs::{self},
+        io::Cursor,
+        path::Path,
     };
-    use std::fs::{self};
 
     #[test]
     fn bpmn_import() {
@@ -156,6 +374,7 @@ mod tests {
 
         assert_eq!(bpmn.elements.len(), 2);
         assert_eq!(bpmn.message_flows.len(), 1);
+        assert_eq!(bpmn.lanes().len(), 2);
     }
 
     #[test]
@@ -279,4 +498,471 @@ mod tests {
                 .unwrap();
         let _bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
     }
+
+    #[test]
+    fn bpmn_data_objects_import() {
+        let fin = fs::read_to_string("testfiles/data-objects.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        assert_eq!(bpmn.data_elements.len(), 6);
+        assert_eq!(bpmn.data_associations.len(), 3);
+        assert!(bpmn.data_element("DataObject_1").unwrap().is_collection());
+
+        let ids = |elements: Vec<&BPMNElement>| {
+            elements
+                .into_iter()
+                .map(|element| element.id().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(bpmn.activities_reading("DataObject_1")), vec!["Task_1"]);
+        assert_eq!(ids(bpmn.activities_writing("DataObject_1")), vec!["Task_1"]);
+        assert_eq!(ids(bpmn.activities_reading("DataStore_1")), vec!["Task_1"]);
+        assert_eq!(ids(bpmn.activities_writing("DataStore_1")), vec!["Task_2"]);
+        assert!(bpmn.activities_writing("DataObjectReference_1").is_empty());
+
+        let task_1 = bpmn
+            .elements()
+            .into_iter()
+            .find(|element| element.id() == "Task_1")
+            .unwrap();
+        let read = bpmn
+            .data_read_by(task_1.global_index())
+            .into_iter()
+            .map(|data_element| data_element.id())
+            .collect::<Vec<_>>();
+        assert_eq!(read, vec!["DataObjectReference_1", "DataStoreReference_1"]);
+    }
+
+    #[test]
+    fn bpmn_nested_lanes_import() {
+        let fin = fs::read_to_string("testfiles/lanes-nested.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let lanes = bpmn
+            .lanes()
+            .into_iter()
+            .map(|lane| lane.id())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lanes,
+            vec!["Lane_Sales", "Lane_Desk", "Lane_Office", "Lane_Warehouse"]
+        );
+
+        let element = |id: &str| {
+            bpmn.elements()
+                .into_iter()
+                .find(|element| element.id() == id)
+                .unwrap()
+                .global_index()
+        };
+        let lane_of = |id: &str| bpmn.lane_of(element(id)).unwrap().name().unwrap();
+        assert_eq!(lane_of("StartEvent_1"), "sales");
+        assert_eq!(lane_of("Task_1"), "front desk");
+        assert_eq!(lane_of("Task_2"), "back office");
+        assert_eq!(lane_of("EndEvent_1"), "warehouse");
+
+        let sales = bpmn.lanes()[0].global_index();
+        let in_sales = bpmn
+            .elements_in_lane(sales)
+            .into_iter()
+            .map(|element| element.id())
+            .collect::<Vec<_>>();
+        assert_eq!(in_sales, vec!["StartEvent_1", "Task_1", "Task_2"]);
+    }
+
+    #[test]
+    fn bpmn_messages_import() {
+        let fin = fs::read_to_string("testfiles/messages.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        assert_eq!(bpmn.messages.len(), 2);
+        assert_eq!(bpmn.message("message_1").unwrap().name(), Some("order"));
+        assert_eq!(bpmn.message("message_2").unwrap().name(), None);
+        assert_eq!(bpmn.message_flows[0].message_ref(), Some("message_1"));
+
+        let catch_event = bpmn
+            .elements()
+            .into_iter()
+            .find_map(|element| match element {
+                BPMNElement::MessageIntermediateCatchEvent(event) => Some(event),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(catch_event.message_ref(), Some("message_1"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn bpmn_message_ref_invalid() {
+        let fin = fs::read_to_string("testfiles/messages.bpmn")
+            .unwrap()
+            .replace("messageRef=\"message_2\"", "messageRef=\"message_3\"");
+        fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+    }
+
+    #[test]
+    fn bpmn_timers_import() {
+        let fin = fs::read_to_string("testfiles/timers.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let timer = |id: &str| {
+            match bpmn
+                .elements()
+                .into_iter()
+                .find(|element| element.id() == id)
+                .unwrap()
+            {
+                BPMNElement::TimerStartEvent(event) => event.timer(),
+                BPMNElement::TimerIntermediateCatchEvent(event) => event.timer(),
+                BPMNElement::BoundaryEvent(event) => event.timer(),
+                _ => None,
+            }
+            .unwrap()
+        };
+
+        let cycle = timer("startevent_1");
+        assert_eq!(cycle.timer_type(), BPMNTimerType::Cycle);
+        assert_eq!(cycle.text(), "R3/2026-01-01T09:00:00Z/P1D");
+        assert_eq!(
+            cycle.specification(),
+            Some(&BPMNTimerSpecification::Cycle {
+                repetitions: Some(3),
+                start: Some(BPMNDateTime {
+                    year: 2026,
+                    month: 1,
+                    day: 1,
+                    hour: 9,
+                    offset_minutes: Some(0),
+                    ..Default::default()
+                }),
+                interval: BPMNDuration {
+                    days: 1,
+                    ..Default::default()
+                },
+                end: None,
+            })
+        );
+
+        let duration = timer("intermediatecatchevent_1");
+        assert_eq!(duration.timer_type(), BPMNTimerType::Duration);
+        assert_eq!(
+            duration.specification(),
+            Some(&BPMNTimerSpecification::Duration(BPMNDuration {
+                hours: 1,
+                minutes: 30,
+                ..Default::default()
+            }))
+        );
+
+        let date = timer("boundaryevent_1");
+        assert_eq!(date.timer_type(), BPMNTimerType::Date);
+        assert_eq!(
+            date.specification(),
+            Some(&BPMNTimerSpecification::Date(BPMNDateTime {
+                year: 2026,
+                month: 12,
+                day: 31,
+                hour: 23,
+                minute: 59,
+                second: 30.5,
+                offset_minutes: Some(60),
+            }))
+        );
+
+        //an expression of an engine is kept, but not parsed
+        let expression = timer("boundaryevent_2");
+        assert_eq!(expression.text(), "${reminderDelay}");
+        assert_eq!(expression.specification(), None);
+    }
+
+    #[test]
+    fn iso_8601_timers() {
+        let cycle = BPMNTimer::new(BPMNTimerType::Cycle, "R/PT10M".to_string());
+        assert_eq!(
+            cycle.specification(),
+            Some(&BPMNTimerSpecification::Cycle {
+                repetitions: None,
+                start: None,
+                interval: BPMNDuration {
+                    minutes: 10,
+                    ..Default::default()
+                },
+                end: None,
+            })
+        );
+
+        let duration = "P1Y2M3W4DT5H6M7.5S".parse::<BPMNDuration>().unwrap();
+        assert_eq!(duration.weeks, 3);
+        assert_eq!(duration.seconds, 7.5);
+        assert_eq!(duration.to_string(), "P1Y2M3W4DT5H6M7.5S");
+
+        assert!("P".parse::<BPMNDuration>().is_err());
+        assert!("PT".parse::<BPMNDuration>().is_err());
+        assert!("P1.5D".parse::<BPMNDuration>().is_err());
+        assert!("2026-13-01".parse::<BPMNDateTime>().is_err());
+        assert!("2026-03-01T09:00+1é1".parse::<BPMNDateTime>().is_err());
+        assert!("2026-03-01T09:00:-1Z".parse::<BPMNDateTime>().is_err());
+        assert!("2026-03-01T09:00:NaN".parse::<BPMNDateTime>().is_err());
+        assert_eq!(
+            "2026-03-01T09:00:07,5+01:30"
+                .parse::<BPMNDateTime>()
+                .unwrap()
+                .second,
+            7.5
+        );
+        assert!(
+            BPMNTimer::new(BPMNTimerType::Cycle, "0 0 9 * * ?".to_string())
+                .specification()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn bpmn_imports() {
+        let bpmn = BusinessProcessModelAndNotation::import_from_path(
+            Path::new("testfiles/import-main.bpmn"),
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(bpmn.imports.len(), 1);
+        assert_eq!(bpmn.imports[0].namespace(), "http://example.org/shared");
+        assert_eq!(bpmn.imports[0].location(), "import-shared.bpmn");
+        assert!(bpmn.imports[0].definitions().is_some());
+
+        //the imported process is called, although its ids collide with the ids of the importing file
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        debug_transitions(&bpmn, &marking);
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3]);
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![4]);
+        assert_eq!(
+            bpmn.activity_key
+                .deprocess_activity(&bpmn.get_transition_activity(4, &marking).unwrap()),
+            "b"
+        );
+    }
+
+    #[test]
+    fn bpmn_imports_collaboration() {
+        let bpmn = BusinessProcessModelAndNotation::import_from_path(
+            Path::new("testfiles/import-collaboration-main.bpmn"),
+            true,
+        )
+        .unwrap();
+
+        //the message flow of the imported process refers to the imported model, of which the customer pool always sends messages
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![4]);
+        assert_eq!(
+            bpmn.activity_key
+                .deprocess_activity(&bpmn.get_transition_activity(4, &marking).unwrap()),
+            "b"
+        );
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![5]);
+    }
+
+    #[test]
+    fn bpmn_imports_without_resolver() {
+        let fin = fs::read_to_string("testfiles/import-main.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        assert_eq!(bpmn.imports.len(), 1);
+        assert!(bpmn.imports[0].definitions().is_none());
+
+        //the call activity cannot start
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        assert!(bpmn.execute_transition(&mut marking, 1).is_err());
+    }
+
+    #[test]
+    fn bpmn_imports_cycle() {
+        let fin = fs::read_to_string("testfiles/import-main.bpmn").unwrap();
+        let result = BusinessProcessModelAndNotation::import_from_reader_with_resolver(
+            &mut fin.as_bytes(),
+            true,
+            &mut |_| Ok(Box::new(Cursor::new(fin.clone().into_bytes()))),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn bpmn_imports_self() {
+        //the cycle is found before the file is imported a second time
+        let result = BusinessProcessModelAndNotation::import_from_path(
+            Path::new("testfiles/import-self.bpmn"),
+            true,
+        );
+        match result {
+            Err(BPMNError::Other(error)) => {
+                let message = format!("{:#}", error);
+                assert!(message.contains("imports itself"));
+                assert!(!message.contains("Importing `import-self.bpmn`"));
+            }
+            _ => panic!("expected a cycle"),
+        }
+    }
+
+    #[test]
+    fn bpmn_imports_outside_directory() {
+        let result = BusinessProcessModelAndNotation::import_from_path(
+            Path::new("testfiles/import-outside.bpmn"),
+            true,
+        );
+        assert!(matches!(result, Err(BPMNError::Unsupported(_))));
+
+        assert!(local_import_path(Path::new("testfiles"), "/etc/hosts").is_err());
+        assert!(local_import_path(Path::new("testfiles"), "sub/../../x.bpmn").is_err());
+        assert!(local_import_path(Path::new("testfiles"), "http://example.org/x.bpmn").is_err());
+        assert!(local_import_path(Path::new("testfiles"), "./sub/x.bpmn").is_ok());
+    }
+
+    #[test]
+    fn bpmn_import_warnings() {
+        let fin = fs::read_to_string("testfiles/ignored-elements.bpmn").unwrap();
+        let (bpmn, warnings) = BusinessProcessModelAndNotation::import_from_reader_with_warnings(
+            &mut fin.as_bytes(),
+            true,
+        )
+        .unwrap();
+        assert_eq!(bpmn.number_of_elements(), 5);
+
+        let reasons = warnings
+            .iter()
+            .map(|warning| (warning.tag(), warning.reason()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reasons,
+            vec![
+                ("documentation", BPMNImportWarningReason::NotInterpreted),
+                ("loopCondition", BPMNImportWarningReason::NotInterpreted),
+                (
+                    "escalationEventDefinition",
+                    BPMNImportWarningReason::IgnoredEventDefinition
+                ),
+                ("transaction", BPMNImportWarningReason::IgnoredFlowNode),
+            ]
+        );
+
+        let transaction = &warnings[3];
+        assert_eq!(transaction.id(), Some("transaction_1"));
+        assert!(
+            fin[transaction.position() as usize..].starts_with("<transaction id=\"transaction_1\"")
+        );
+    }
+
+    #[test]
+    fn bpmn_import_strict() {
+        let fin = fs::read_to_string("testfiles/ignored-elements.bpmn").unwrap();
+        assert!(
+            BusinessProcessModelAndNotation::import_from_reader_strict(&mut fin.as_bytes(), true)
+                .is_err()
+        );
+
+        //ignored tags that are not flow nodes are allowed
+        let fin = fin.replace("<transaction id=\"transaction_1\" name=\"refund\"/>", "");
+        BusinessProcessModelAndNotation::import_from_reader_strict(&mut fin.as_bytes(), true)
+            .unwrap();
+    }
+
+    #[test]
+    fn bpmn_import_unsupported_condition_expression() {
+        let fin = fs::read_to_string("testfiles/conditions.bpmn")
+            .unwrap()
+            .replace(
+                "<conditionExpression xsi:type=\"tFormalExpression\">notify = true",
+                "<conditionExpression xsi:type=\"tFormalExpression\" language=\"http://www.w3.org/1999/XPath\">notify = true",
+            );
+        let (_, warnings) = BusinessProcessModelAndNotation::import_from_reader_with_warnings(
+            &mut fin.as_bytes(),
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|warning| (warning.tag(), warning.reason()))
+                .collect::<Vec<_>>(),
+            vec![(
+                "conditionExpression",
+                BPMNImportWarningReason::UnsupportedCondition
+            )]
+        );
+
+        assert!(matches!(
+            BusinessProcessModelAndNotation::import_from_reader_strict(&mut fin.as_bytes(), true),
+            Err(BPMNError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn bpmn_import_errors() {
+        let fin = fs::read_to_string("testfiles/model.bpmn").unwrap();
+
+        let duplicate = fin.replace("id=\"Task_19ath6m\"", "id=\"Task_0t8avf0\"");
+        match duplicate.parse::<BusinessProcessModelAndNotation>() {
+            Err(BPMNError::DuplicateId { id, .. }) => assert_eq!(id, "Task_0t8avf0"),
+            result => panic!("expected a duplicate id, got {:?}", result.err()),
+        }
+
+        let unknown = fin.replace(
+            "targetRef=\"EndEvent_1shnzze\"",
+            "targetRef=\"EndEvent_unknown\"",
+        );
+        match unknown.parse::<BusinessProcessModelAndNotation>() {
+            Err(BPMNError::UnknownId { id, .. }) => assert_eq!(id, "EndEvent_unknown"),
+            result => panic!("expected an unknown id, got {:?}", result.err()),
+        }
+
+        let truncated = &fin[..fin.len() / 2];
+        assert!(matches!(
+            truncated.parse::<BusinessProcessModelAndNotation>(),
+            Err(BPMNError::Xml(_))
+        ));
+
+        //a structural violation names the element that violates the structure
+        let fin = fs::read_to_string("testfiles/terminate-end-event.bpmn").unwrap();
+        let dead_end = fin
+            .replace("<outgoing>sequenceflow_5</outgoing>", "")
+            .replace("<incoming>sequenceflow_5</incoming>", "")
+            .replace(
+                "<sequenceFlow id=\"sequenceflow_5\" sourceRef=\"task_b\" targetRef=\"endevent_2\"/>",
+                "",
+            );
+        match dead_end.parse::<BusinessProcessModelAndNotation>() {
+            Err(BPMNError::StructuralViolation { element_ids, .. }) => {
+                assert_eq!(element_ids, vec!["task_b".to_string()])
+            }
+            result => panic!("expected a structural violation, got {:?}", result.err()),
+        }
+
+        //errors keep their kind through anyhow
+        let error = anyhow::Error::from(
+            duplicate
+                .parse::<BusinessProcessModelAndNotation>()
+                .unwrap_err(),
+        );
+        assert!(matches!(
+            BPMNError::from(error),
+            BPMNError::DuplicateId { .. }
+        ));
+    }
+
+    #[test]
+    fn bpmn_import_link_without_name() {
+        //links are paired by name, so a link without a name is rejected rather than paired with other unnamed links
+        let fin = fs::read_to_string("testfiles/link-event.bpmn").unwrap();
+        let unnamed = fin.replace(" name=\"continue\"", "");
+        assert!(unnamed.parse::<BusinessProcessModelAndNotation>().is_err());
+
+        let empty = fin.replace(" name=\"continue\"", " name=\"\"");
+        assert!(empty.parse::<BusinessProcessModelAndNotation>().is_err());
+    }
 }
@@ -0,0 +1,137 @@
+use crate::parser::parser_state::GlobalIndex;
+
+/// A struct that represents a lane set of a process: a partition of the elements of the process into lanes.
+/// Lanes typically represent the roles or organisational units that perform the activities, and do not influence the behaviour of the model.
+#[derive(Clone, Debug)]
+pub struct BPMNLaneSet {
+    pub(crate) id: Option<String>,
+    pub(crate) name: Option<String>,
+    pub(crate) lanes: Vec<BPMNLane>,
+}
+
+impl BPMNLaneSet {
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the lanes of this lane set (does not recurse).
+    pub fn lanes(&self) -> &[BPMNLane] {
+        &self.lanes
+    }
+
+    /// Returns the lanes of this lane set and of their child lane sets (recurses).
+    pub fn all_lanes(&self) -> Vec<&BPMNLane> {
+        let mut result = vec![];
+        for lane in &self.lanes {
+            result.push(lane);
+            if let Some(child_lane_set) = &lane.child_lane_set {
+                result.extend(child_lane_set.all_lanes());
+            }
+        }
+        result
+    }
+
+    /// Returns the lane with the given index, if it is in this lane set (recurses).
+    pub fn lane(&self, lane_global_index: GlobalIndex) -> Option<&BPMNLane> {
+        self.all_lanes()
+            .into_iter()
+            .find(|lane| lane.global_index == lane_global_index)
+    }
+
+    /// Returns the lane with the given index, if it is in this lane set (recurses).
+    pub(crate) fn lane_mut(&mut self, lane_global_index: GlobalIndex) -> Option<&mut BPMNLane> {
+        for lane in &mut self.lanes {
+            if lane.global_index == lane_global_index {
+                return Some(lane);
+            }
+            if let Some(child_lane_set) = &mut lane.child_lane_set {
+                if let Some(lane) = child_lane_set.lane_mut(lane_global_index) {
+                    return Some(lane);
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the most specific lane that contains the given element, if any (recurses).
+    pub fn lane_of(&self, element_global_index: GlobalIndex) -> Option<&BPMNLane> {
+        for lane in &self.lanes {
+            if let Some(child_lane) = lane
+                .child_lane_set
+                .as_ref()
+                .and_then(|child_lane_set| child_lane_set.lane_of(element_global_index))
+            {
+                return Some(child_lane);
+            }
+            if lane.flow_node_refs.contains(&element_global_index) {
+                return Some(lane);
+            }
+        }
+        None
+    }
+
+    /// Removes the given element from all lanes (recurses).
+    pub(crate) fn remove_element(&mut self, element_global_index: GlobalIndex) {
+        for lane in &mut self.lanes {
+            lane.flow_node_refs
+                .retain(|flow_node_ref| *flow_node_ref != element_global_index);
+            if let Some(child_lane_set) = &mut lane.child_lane_set {
+                child_lane_set.remove_element(element_global_index);
+            }
+        }
+    }
+}
+
+/// A struct that represents a lane, which contains elements of its process, and may be subdivided further by a child lane set.
+#[derive(Clone, Debug)]
+pub struct BPMNLane {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) name: Option<String>,
+
+    ///the elements that are in this lane, in the order of the model
+    pub(crate) flow_node_refs: Vec<GlobalIndex>,
+    pub(crate) child_lane_set: Option<BPMNLaneSet>,
+}
+
+impl BPMNLane {
+    pub fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the elements in this lane, not including the elements that are only in its child lanes.
+    pub fn flow_node_refs(&self) -> &[GlobalIndex] {
+        &self.flow_node_refs
+    }
+
+    pub fn child_lane_set(&self) -> Option<&BPMNLaneSet> {
+        self.child_lane_set.as_ref()
+    }
+
+    /// Returns the elements in this lane, including the elements in its child lanes (recurses).
+    pub fn all_flow_node_refs(&self) -> Vec<GlobalIndex> {
+        let mut result = self.flow_node_refs.clone();
+        if let Some(child_lane_set) = &self.child_lane_set {
+            for lane in child_lane_set.all_lanes() {
+                for flow_node_ref in &lane.flow_node_refs {
+                    if !result.contains(flow_node_ref) {
+                        result.push(*flow_node_ref);
+                    }
+                }
+            }
+        }
+        result
+    }
+}
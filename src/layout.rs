@@ -0,0 +1,865 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    diagram::{BPMNBounds, BPMNDiagram, BPMNEdge, BPMNPlane, BPMNPoint, BPMNShape},
+    element::BPMNElement,
+    elements::process::BPMNProcess,
+    if_not::IfNot,
+    lane::{BPMNLane, BPMNLaneSet},
+    sequence_flow::BPMNSequenceFlow,
+    traits::{objectable::BPMNObject, processable::Processable},
+};
+use anyhow::Result;
+use std::collections::{HashMap, VecDeque};
+
+const TASK_WIDTH: f64 = 100.0;
+const TASK_HEIGHT: f64 = 80.0;
+const EVENT_SIZE: f64 = 36.0;
+const GATEWAY_SIZE: f64 = 50.0;
+const HORIZONTAL_GAP: f64 = 50.0;
+const VERTICAL_GAP: f64 = 30.0;
+const CONTAINER_PADDING: f64 = 20.0;
+const SUB_PROCESS_HEADER: f64 = 20.0;
+const POOL_HEADER: f64 = 30.0;
+const LANE_HEADER: f64 = 30.0;
+const POOL_MINIMUM_WIDTH: f64 = 600.0;
+const POOL_GAP: f64 = 30.0;
+const COLLAPSED_POOL_HEIGHT: f64 = 60.0;
+const BOUNDARY_EVENT_GAP: f64 = 10.0;
+
+impl BusinessProcessModelAndNotation {
+    /// Computes a left-to-right layered layout of the model.
+    /// Pools are stacked vertically, and expanded sub-processes are laid out as nested containers.
+    /// The lanes of the first lane set of a pool are laid out as horizontal bands around their elements.
+    pub fn compute_layout(&self) -> Result<BPMNDiagram> {
+        //lay out the contents of the pools
+        let mut pools = vec![];
+        for element in &self.elements {
+            match element {
+                BPMNElement::Process(process) => {
+                    let lane_bands = process.lane_sets.first().and_then(|lane_set| {
+                        compute_lane_bands(process.elements_non_recursive(), lane_set)
+                    });
+                    pools.push((
+                        element,
+                        Some(layout_container(process, lane_bands.as_ref())?),
+                    ))
+                }
+                BPMNElement::CollapsedPool(_) => pools.push((element, None)),
+                _ => {}
+            }
+        }
+        let pool_width = pools
+            .iter()
+            .filter_map(|(element, layout)| match (element, layout) {
+                (BPMNElement::Process(process), Some(layout)) => {
+                    Some(layout.width + pool_header(process) + 2.0 * CONTAINER_PADDING)
+                }
+                _ => None,
+            })
+            .fold(POOL_MINIMUM_WIDTH, f64::max);
+
+        //stack the pools
+        let mut shapes = vec![];
+        let mut edges = vec![];
+        let mut id_2_bounds = HashMap::new();
+        let mut y = 0.0;
+        for (element, layout) in pools {
+            match (element, layout) {
+                (BPMNElement::Process(process), Some(mut layout)) => {
+                    let bounds = BPMNBounds {
+                        x: 0.0,
+                        y,
+                        width: pool_width,
+                        height: layout.height + 2.0 * CONTAINER_PADDING,
+                    };
+                    if let Some(participant_id) = &process.participant_id {
+                        shapes.push(BPMNShape {
+                            id: Some(format!("{}_di", participant_id)),
+                            bpmn_element: participant_id.clone(),
+                            bounds,
+                            is_expanded: None,
+                            is_horizontal: Some(true),
+                            is_marker_visible: None,
+                            label: None,
+                        });
+                        id_2_bounds.insert(participant_id.clone(), bounds);
+                    }
+                    id_2_bounds.insert(process.id.clone(), bounds);
+
+                    layout.translate(
+                        pool_header(process) + CONTAINER_PADDING,
+                        y + CONTAINER_PADDING,
+                    );
+                    if let Some(lane_set) = process.lane_sets.first() {
+                        if !layout.band_2_extent.is_empty() {
+                            layout_lanes(
+                                lane_set,
+                                0,
+                                &mut 0,
+                                &layout.band_2_extent,
+                                &bounds,
+                                &mut shapes,
+                            );
+                        }
+                    }
+                    for shape in &layout.shapes {
+                        id_2_bounds.insert(shape.bpmn_element.clone(), shape.bounds);
+                    }
+                    shapes.extend(layout.shapes);
+                    edges.extend(layout.edges);
+                    y += bounds.height + POOL_GAP;
+                }
+                (element, _) => {
+                    let bounds = BPMNBounds {
+                        x: 0.0,
+                        y,
+                        width: pool_width,
+                        height: COLLAPSED_POOL_HEIGHT,
+                    };
+                    shapes.push(BPMNShape {
+                        id: Some(format!("{}_di", element.id())),
+                        bpmn_element: element.id().to_string(),
+                        bounds,
+                        is_expanded: None,
+                        is_horizontal: Some(true),
+                        is_marker_visible: None,
+                        label: None,
+                    });
+                    id_2_bounds.insert(element.id().to_string(), bounds);
+                    y += bounds.height + POOL_GAP;
+                }
+            }
+        }
+
+        //message flows
+        for message_flow in &self.message_flows {
+            let source_id = self
+                .global_index_2_element(message_flow.source_global_index)
+                .and_if_not("Source of message flow not found.")?
+                .id();
+            let target_id = self
+                .global_index_2_element(message_flow.target_global_index)
+                .and_if_not("Target of message flow not found.")?
+                .id();
+            if let (Some(source), Some(target)) =
+                (id_2_bounds.get(source_id), id_2_bounds.get(target_id))
+            {
+                edges.push(BPMNEdge {
+                    id: Some(format!("{}_di", message_flow.id)),
+                    bpmn_element: message_flow.id.clone(),
+                    source_element: None,
+                    target_element: None,
+                    waypoints: route_message_flow(source, target),
+                    label: None,
+                });
+            }
+        }
+
+        let plane_element = match &self.collaboration_id {
+            Some(collaboration_id) => Some(collaboration_id.clone()),
+            None => self
+                .elements
+                .iter()
+                .find(|element| element.is_process())
+                .map(|element| element.id().to_string()),
+        };
+
+        Ok(BPMNDiagram {
+            id: Some("diagram_1".to_string()),
+            name: None,
+            plane: BPMNPlane {
+                id: Some("plane_1".to_string()),
+                bpmn_element: plane_element,
+                shapes,
+                edges,
+            },
+        })
+    }
+
+    /// Replaces the layout information of the model by a computed layout.
+    pub fn layout(&mut self) -> Result<()> {
+        self.diagrams = vec![self.compute_layout()?];
+        Ok(())
+    }
+}
+
+/// The layout of the contents of a container, relative to the top-left corner of its contents.
+struct ContainerLayout {
+    width: f64,
+    height: f64,
+    shapes: Vec<BPMNShape>,
+    edges: Vec<BPMNEdge>,
+
+    ///the (top, bottom) of each lane band; empty if the container is not laid out in lanes
+    band_2_extent: Vec<(f64, f64)>,
+}
+
+impl ContainerLayout {
+    fn translate(&mut self, dx: f64, dy: f64) {
+        for shape in self.shapes.iter_mut() {
+            shape.bounds.x += dx;
+            shape.bounds.y += dy;
+        }
+        for edge in self.edges.iter_mut() {
+            for waypoint in edge.waypoints.iter_mut() {
+                waypoint.x += dx;
+                waypoint.y += dy;
+            }
+        }
+        for (top, bottom) in self.band_2_extent.iter_mut() {
+            *top += dy;
+            *bottom += dy;
+        }
+    }
+}
+
+/// The assignment of the elements of a container to horizontal bands: one for each lane without child lanes, in order.
+/// Elements that are not in any lane are placed in an extra band below the lanes.
+struct LaneBands {
+    element_2_band: Vec<usize>,
+    number_of_bands: usize,
+}
+
+fn layout_container(
+    container: &dyn Processable,
+    lane_bands: Option<&LaneBands>,
+) -> Result<ContainerLayout> {
+    let elements = container.elements_non_recursive();
+    let sequence_flows = container.sequence_flows_non_recursive();
+    let element_2_band =
+        |element: usize| lane_bands.map_or(0, |lane_bands| lane_bands.element_2_band[element]);
+
+    //sizes, laying out nested containers first
+    let mut element_2_size = Vec::with_capacity(elements.len());
+    let mut element_2_nested = Vec::with_capacity(elements.len());
+    for element in elements {
+        let nested_container: Option<&dyn Processable> = match element {
+            BPMNElement::EventSubProcess(sub_process) => Some(sub_process),
+            BPMNElement::ExpandedSubProcess(sub_process) => Some(sub_process),
+            _ => None,
+        };
+        if let Some(sub_process) = nested_container {
+            let nested = layout_container(sub_process, None)?;
+            element_2_size.push((
+                (nested.width + 2.0 * CONTAINER_PADDING).max(TASK_WIDTH),
+                (nested.height + 2.0 * CONTAINER_PADDING + SUB_PROCESS_HEADER).max(TASK_HEIGHT),
+            ));
+            element_2_nested.push(Some(nested));
+        } else {
+            element_2_size.push(element_size(element));
+            element_2_nested.push(None);
+        }
+    }
+
+    //boundary events are not laid out by themselves, but on the border of their host
+    let attachments = elements
+        .iter()
+        .filter_map(|element| {
+            if let BPMNElement::BoundaryEvent(boundary_event) = element {
+                Some((
+                    boundary_event.attached_to_local_index,
+                    boundary_event.local_index,
+                ))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    //layers
+    let (element_2_layer, sequence_flow_2_backward) =
+        compute_layers(elements.len(), sequence_flows, &attachments);
+    let number_of_layers = element_2_layer
+        .iter()
+        .map(|layer| layer + 1)
+        .max()
+        .unwrap_or(0);
+    let mut layer_2_elements = vec![vec![]; number_of_layers];
+    for (element, layer) in element_2_layer.iter().enumerate() {
+        if !elements[element].is_boundary_event() {
+            layer_2_elements[*layer].push(element);
+        }
+    }
+
+    //order the elements of each layer by the average position of their predecessors
+    let mut element_2_position = vec![0.0; elements.len()];
+    for layer in 0..number_of_layers {
+        if layer > 0 {
+            let barycenters = layer_2_elements[layer]
+                .iter()
+                .map(|element| {
+                    let positions = sequence_flows
+                        .iter()
+                        .zip(sequence_flow_2_backward.iter())
+                        .filter(|(sequence_flow, backward)| {
+                            !**backward && sequence_flow.target_local_index == *element
+                        })
+                        .map(|(sequence_flow, _)| {
+                            element_2_position[sequence_flow.source_local_index]
+                        })
+                        .collect::<Vec<_>>();
+                    if positions.is_empty() {
+                        (*element, f64::MAX)
+                    } else {
+                        (
+                            *element,
+                            positions.iter().sum::<f64>() / positions.len() as f64,
+                        )
+                    }
+                })
+                .collect::<HashMap<_, _>>();
+            layer_2_elements[layer].sort_by(|a, b| barycenters[a].total_cmp(&barycenters[b]));
+        }
+        layer_2_elements[layer].sort_by_key(|element| element_2_band(*element));
+        for (position, element) in layer_2_elements[layer].iter().enumerate() {
+            element_2_position[*element] = position as f64;
+        }
+        for (host, boundary_event) in &attachments {
+            if element_2_layer[*host] == layer {
+                element_2_position[*boundary_event] = element_2_position[*host];
+            }
+        }
+    }
+
+    //positions
+    let number_of_bands = lane_bands.map_or(1, |lane_bands| lane_bands.number_of_bands);
+    let layer_2_width = layer_2_elements
+        .iter()
+        .map(|layer| {
+            layer
+                .iter()
+                .map(|element| element_2_size[*element].0)
+                .fold(0.0, f64::max)
+        })
+        .collect::<Vec<_>>();
+    let layer_2_band_2_height = layer_2_elements
+        .iter()
+        .map(|layer| {
+            (0..number_of_bands)
+                .map(|band| {
+                    let band_elements = layer
+                        .iter()
+                        .filter(|element| element_2_band(**element) == band)
+                        .collect::<Vec<_>>();
+                    band_elements
+                        .iter()
+                        .map(|element| element_2_size[**element].1)
+                        .sum::<f64>()
+                        + VERTICAL_GAP * (band_elements.len().max(1) - 1) as f64
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    //bands are stacked vertically, separated by padding on both sides of the lane borders; an empty lane is as high as a task
+    let minimum_band_height = if lane_bands.is_some() {
+        TASK_HEIGHT
+    } else {
+        0.0
+    };
+    let band_2_height = (0..number_of_bands)
+        .map(|band| {
+            layer_2_band_2_height
+                .iter()
+                .map(|band_2_height| band_2_height[band])
+                .fold(minimum_band_height, f64::max)
+        })
+        .collect::<Vec<_>>();
+    let mut band_2_top = Vec::with_capacity(number_of_bands);
+    let mut content_height = 0.0;
+    for (band, height) in band_2_height.iter().enumerate() {
+        if band > 0 {
+            content_height += 2.0 * CONTAINER_PADDING;
+        }
+        band_2_top.push(content_height);
+        content_height += height;
+    }
+
+    let mut element_2_bounds = vec![
+        BPMNBounds {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0
+        };
+        elements.len()
+    ];
+    let mut x = 0.0;
+    for (layer, layer_elements) in layer_2_elements.iter().enumerate() {
+        let mut band_2_y = (0..number_of_bands)
+            .map(|band| {
+                band_2_top[band] + (band_2_height[band] - layer_2_band_2_height[layer][band]) / 2.0
+            })
+            .collect::<Vec<_>>();
+        for element in layer_elements {
+            let band = element_2_band(*element);
+            let (width, height) = element_2_size[*element];
+            element_2_bounds[*element] = BPMNBounds {
+                x: x + (layer_2_width[layer] - width) / 2.0,
+                y: band_2_y[band],
+                width,
+                height,
+            };
+            band_2_y[band] += height + VERTICAL_GAP;
+        }
+        x += layer_2_width[layer] + HORIZONTAL_GAP;
+    }
+    let width = (x - HORIZONTAL_GAP).max(0.0);
+
+    //place the boundary events on the lower border of their host, from right to left
+    let mut host_2_boundary_events = HashMap::new();
+    for (host, boundary_event) in &attachments {
+        let host_bounds = element_2_bounds[*host];
+        let index = host_2_boundary_events.entry(*host).or_insert(0);
+        *index += 1;
+        element_2_bounds[*boundary_event] = BPMNBounds {
+            x: host_bounds.x + host_bounds.width
+                - *index as f64 * (EVENT_SIZE + BOUNDARY_EVENT_GAP),
+            y: host_bounds.y + host_bounds.height - EVENT_SIZE / 2.0,
+            width: EVENT_SIZE,
+            height: EVENT_SIZE,
+        };
+    }
+
+    //shapes
+    let mut shapes = vec![];
+    let mut edges = vec![];
+    for ((element, bounds), nested) in elements
+        .iter()
+        .zip(element_2_bounds.iter())
+        .zip(element_2_nested.into_iter())
+    {
+        shapes.push(BPMNShape {
+            id: Some(format!("{}_di", element.id())),
+            bpmn_element: element.id().to_string(),
+            bounds: *bounds,
+            is_expanded: if element.is_expanded_sub_process() || element.is_event_sub_process() {
+                Some(true)
+            } else {
+                None
+            },
+            is_horizontal: None,
+            is_marker_visible: if element.is_exclusive_gateway() {
+                Some(true)
+            } else {
+                None
+            },
+            label: None,
+        });
+
+        if let Some(mut nested) = nested {
+            nested.translate(
+                bounds.x + (bounds.width - nested.width) / 2.0,
+                bounds.y + SUB_PROCESS_HEADER + CONTAINER_PADDING,
+            );
+            shapes.extend(nested.shapes);
+            edges.extend(nested.edges);
+        }
+    }
+
+    //edges; backward flows are routed below the contents
+    let below = content_height + VERTICAL_GAP / 2.0;
+    for (sequence_flow, backward) in sequence_flows.iter().zip(sequence_flow_2_backward.iter()) {
+        let source = &element_2_bounds[sequence_flow.source_local_index];
+        let target = &element_2_bounds[sequence_flow.target_local_index];
+        let waypoints = if *backward {
+            vec![
+                BPMNPoint {
+                    x: source.x + source.width / 2.0,
+                    y: source.y + source.height,
+                },
+                BPMNPoint {
+                    x: source.x + source.width / 2.0,
+                    y: below,
+                },
+                BPMNPoint {
+                    x: target.x + target.width / 2.0,
+                    y: below,
+                },
+                BPMNPoint {
+                    x: target.x + target.width / 2.0,
+                    y: target.y + target.height,
+                },
+            ]
+        } else {
+            route_forward(source, target)
+        };
+        edges.push(BPMNEdge {
+            id: Some(format!("{}_di", sequence_flow.id)),
+            bpmn_element: sequence_flow.id.clone(),
+            source_element: None,
+            target_element: None,
+            waypoints,
+            label: None,
+        });
+    }
+
+    let height = if sequence_flow_2_backward.iter().any(|backward| *backward) {
+        content_height + VERTICAL_GAP
+    } else {
+        content_height
+    };
+
+    let band_2_extent = if lane_bands.is_some() {
+        band_2_top
+            .iter()
+            .zip(band_2_height.iter())
+            .map(|(top, height)| (*top, top + height))
+            .collect()
+    } else {
+        vec![]
+    };
+
+    Ok(ContainerLayout {
+        width,
+        height,
+        shapes,
+        edges,
+        band_2_extent,
+    })
+}
+
+/// Returns the width of the header of a pool, which includes the headers of its (nested) lanes.
+fn pool_header(process: &BPMNProcess) -> f64 {
+    POOL_HEADER
+        + LANE_HEADER
+            * process
+                .lane_sets
+                .first()
+                .map_or(0, lane_depth) as f64
+}
+
+fn lane_depth(lane_set: &BPMNLaneSet) -> usize {
+    lane_set
+        .lanes
+        .iter()
+        .map(|lane| 1 + lane.child_lane_set.as_ref().map_or(0, lane_depth))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the lanes of the lane set that have no child lanes, from top to bottom (recurses).
+fn leaf_lanes(lane_set: &BPMNLaneSet) -> Vec<&BPMNLane> {
+    let mut result = vec![];
+    for lane in &lane_set.lanes {
+        match &lane.child_lane_set {
+            Some(child_lane_set) if !child_lane_set.lanes.is_empty() => {
+                result.extend(leaf_lanes(child_lane_set))
+            }
+            _ => result.push(lane),
+        }
+    }
+    result
+}
+
+/// Assigns each element to the band of its lane.
+/// An element of a lane with child lanes that is not in any of these child lanes is placed in the topmost of them.
+fn compute_lane_bands(elements: &[BPMNElement], lane_set: &BPMNLaneSet) -> Option<LaneBands> {
+    let leaves = leaf_lanes(lane_set);
+    if leaves.is_empty() {
+        return None;
+    }
+
+    let element_2_band = elements
+        .iter()
+        .map(|element| {
+            let mut lane = match lane_set.lane_of(element.global_index()) {
+                Some(lane) => lane,
+                None => return leaves.len(),
+            };
+            while let Some(child_lane) = lane
+                .child_lane_set
+                .as_ref()
+                .and_then(|child_lane_set| child_lane_set.lanes.first())
+            {
+                lane = child_lane;
+            }
+            leaves
+                .iter()
+                .position(|leaf| leaf.global_index == lane.global_index)
+                .unwrap_or(leaves.len())
+        })
+        .collect::<Vec<_>>();
+
+    let number_of_bands = if element_2_band.contains(&leaves.len()) {
+        leaves.len() + 1
+    } else {
+        leaves.len()
+    };
+    Some(LaneBands {
+        element_2_band,
+        number_of_bands,
+    })
+}
+
+/// Adds the shapes of the lanes of the lane set, given the extents of the bands of the leaf lanes (recurses).
+/// Each lane extends up to the lane borders, which lie halfway between the bands; the bottommost band extends to the bottom of the pool.
+/// Returns the top and bottom of the lane set.
+fn layout_lanes(
+    lane_set: &BPMNLaneSet,
+    depth: usize,
+    next_band: &mut usize,
+    band_2_extent: &[(f64, f64)],
+    pool: &BPMNBounds,
+    shapes: &mut Vec<BPMNShape>,
+) -> (f64, f64) {
+    let mut lane_set_top = f64::MAX;
+    let mut lane_set_bottom = f64::MIN;
+    for lane in &lane_set.lanes {
+        let index = shapes.len();
+        let (top, bottom) = match &lane.child_lane_set {
+            Some(child_lane_set) if !child_lane_set.lanes.is_empty() => layout_lanes(
+                child_lane_set,
+                depth + 1,
+                next_band,
+                band_2_extent,
+                pool,
+                shapes,
+            ),
+            _ => {
+                let band = *next_band;
+                *next_band += 1;
+                let (top, bottom) = band_2_extent[band];
+                if band + 1 == band_2_extent.len() {
+                    (top - CONTAINER_PADDING, pool.y + pool.height)
+                } else {
+                    (top - CONTAINER_PADDING, bottom + CONTAINER_PADDING)
+                }
+            }
+        };
+
+        //a lane is drawn before its child lanes
+        let x = pool.x + POOL_HEADER + LANE_HEADER * depth as f64;
+        shapes.insert(
+            index,
+            BPMNShape {
+                id: Some(format!("{}_di", lane.id)),
+                bpmn_element: lane.id.clone(),
+                bounds: BPMNBounds {
+                    x,
+                    y: top,
+                    width: pool.x + pool.width - x,
+                    height: bottom - top,
+                },
+                is_expanded: None,
+                is_horizontal: Some(true),
+                is_marker_visible: None,
+                label: None,
+            },
+        );
+
+        lane_set_top = lane_set_top.min(top);
+        lane_set_bottom = lane_set_bottom.max(bottom);
+    }
+    (lane_set_top, lane_set_bottom)
+}
+
+fn element_size(element: &BPMNElement) -> (f64, f64) {
+    match element {
+        BPMNElement::BoundaryEvent(_)
+        | BPMNElement::EndEvent(_)
+        | BPMNElement::ErrorEndEvent(_)
+        | BPMNElement::ErrorStartEvent(_)
+        | BPMNElement::IntermediateCatchEvent(_)
+        | BPMNElement::IntermediateThrowEvent(_)
+        | BPMNElement::LinkIntermediateCatchEvent(_)
+        | BPMNElement::LinkIntermediateThrowEvent(_)
+        | BPMNElement::MessageEndEvent(_)
+        | BPMNElement::MessageIntermediateCatchEvent(_)
+        | BPMNElement::MessageIntermediateThrowEvent(_)
+        | BPMNElement::MessageStartEvent(_)
+        | BPMNElement::SignalEndEvent(_)
+        | BPMNElement::SignalIntermediateCatchEvent(_)
+        | BPMNElement::SignalIntermediateThrowEvent(_)
+        | BPMNElement::SignalStartEvent(_)
+        | BPMNElement::StartEvent(_)
+        | BPMNElement::TerminateEndEvent(_)
+        | BPMNElement::TimerIntermediateCatchEvent(_)
+        | BPMNElement::TimerStartEvent(_) => (EVENT_SIZE, EVENT_SIZE),
+        BPMNElement::ComplexGateway(_)
+        | BPMNElement::EventBasedGateway(_)
+        | BPMNElement::ExclusiveGateway(_)
+        | BPMNElement::InclusiveGateway(_)
+        | BPMNElement::ParallelGateway(_) => (GATEWAY_SIZE, GATEWAY_SIZE),
+        BPMNElement::BusinessRuleTask(_)
+        | BPMNElement::CallActivity(_)
+        | BPMNElement::CollapsedPool(_)
+        | BPMNElement::CollapsedSubProcess(_)
+        | BPMNElement::EventSubProcess(_)
+        | BPMNElement::ExpandedSubProcess(_)
+        | BPMNElement::ManualTask(_)
+        | BPMNElement::Process(_)
+        | BPMNElement::ReceiveTask(_)
+        | BPMNElement::ScriptTask(_)
+        | BPMNElement::SendTask(_)
+        | BPMNElement::ServiceTask(_)
+        | BPMNElement::Task(_)
+        | BPMNElement::UserTask(_) => (TASK_WIDTH, TASK_HEIGHT),
+    }
+}
+
+/// Assigns a layer to each element, such that sequence flows point to higher layers.
+/// Sequence flows that close a cycle are marked as backward and are not considered.
+/// Boundary events are attached to their host (given as (host, boundary event)) and end up in the same layer.
+fn compute_layers(
+    number_of_elements: usize,
+    sequence_flows: &[BPMNSequenceFlow],
+    attachments: &[(usize, usize)],
+) -> (Vec<usize>, Vec<bool>) {
+    //arcs: (source, target, layer increment); sequence flows first, then attachments
+    let arcs = sequence_flows
+        .iter()
+        .map(|sequence_flow| {
+            (
+                sequence_flow.source_local_index,
+                sequence_flow.target_local_index,
+                1,
+            )
+        })
+        .chain(
+            attachments
+                .iter()
+                .map(|(host, boundary_event)| (*host, *boundary_event, 0)),
+        )
+        .collect::<Vec<_>>();
+
+    let mut element_2_outgoing = vec![vec![]; number_of_elements];
+    let mut element_2_has_incoming = vec![false; number_of_elements];
+    for (arc_index, (source, target, _)) in arcs.iter().enumerate() {
+        element_2_outgoing[*source].push((arc_index, *target));
+        element_2_has_incoming[*target] = true;
+    }
+
+    //find backward arcs with a depth-first search, starting from the elements without incoming arcs
+    let mut arc_2_backward = vec![false; arcs.len()];
+    let mut element_2_visited = vec![false; number_of_elements];
+    let mut element_2_on_stack = vec![false; number_of_elements];
+    let roots = (0..number_of_elements)
+        .filter(|element| !element_2_has_incoming[*element])
+        .chain(0..number_of_elements)
+        .collect::<Vec<_>>();
+    for root in roots {
+        if element_2_visited[root] {
+            continue;
+        }
+        element_2_visited[root] = true;
+        element_2_on_stack[root] = true;
+        let mut stack = vec![(root, 0)];
+        while let Some((element, next_child)) = stack.last_mut() {
+            let element = *element;
+            if let Some((arc_index, target)) = element_2_outgoing[element].get(*next_child) {
+                *next_child += 1;
+                if element_2_on_stack[*target] {
+                    arc_2_backward[*arc_index] = true;
+                } else if !element_2_visited[*target] {
+                    element_2_visited[*target] = true;
+                    element_2_on_stack[*target] = true;
+                    stack.push((*target, 0));
+                }
+            } else {
+                element_2_on_stack[element] = false;
+                stack.pop();
+            }
+        }
+    }
+
+    //longest path layering on the forward arcs
+    let mut element_2_indegree = vec![0; number_of_elements];
+    for ((_, target, _), backward) in arcs.iter().zip(arc_2_backward.iter()) {
+        if !backward {
+            element_2_indegree[*target] += 1;
+        }
+    }
+    let mut element_2_layer = vec![0; number_of_elements];
+    let mut queue = (0..number_of_elements)
+        .filter(|element| element_2_indegree[*element] == 0)
+        .collect::<VecDeque<_>>();
+    while let Some(element) = queue.pop_front() {
+        for (arc_index, target) in &element_2_outgoing[element] {
+            if !arc_2_backward[*arc_index] {
+                let increment = arcs[*arc_index].2;
+                element_2_layer[*target] =
+                    element_2_layer[*target].max(element_2_layer[element] + increment);
+                element_2_indegree[*target] -= 1;
+                if element_2_indegree[*target] == 0 {
+                    queue.push_back(*target);
+                }
+            }
+        }
+    }
+
+    //only the sequence flows are of interest to the caller
+    arc_2_backward.truncate(sequence_flows.len());
+    (element_2_layer, arc_2_backward)
+}
+
+fn route_forward(source: &BPMNBounds, target: &BPMNBounds) -> Vec<BPMNPoint> {
+    let start = BPMNPoint {
+        x: source.x + source.width,
+        y: source.y + source.height / 2.0,
+    };
+    let end = BPMNPoint {
+        x: target.x,
+        y: target.y + target.height / 2.0,
+    };
+    if start.y == end.y {
+        vec![start, end]
+    } else {
+        let middle = (start.x + end.x) / 2.0;
+        vec![
+            start,
+            BPMNPoint {
+                x: middle,
+                y: start.y,
+            },
+            BPMNPoint {
+                x: middle,
+                y: end.y,
+            },
+            end,
+        ]
+    }
+}
+
+fn route_message_flow(source: &BPMNBounds, target: &BPMNBounds) -> Vec<BPMNPoint> {
+    let (start, end) = if source.y < target.y {
+        (
+            BPMNPoint {
+                x: source.x + source.width / 2.0,
+                y: source.y + source.height,
+            },
+            BPMNPoint {
+                x: target.x + target.width / 2.0,
+                y: target.y,
+            },
+        )
+    } else {
+        (
+            BPMNPoint {
+                x: source.x + source.width / 2.0,
+                y: source.y,
+            },
+            BPMNPoint {
+                x: target.x + target.width / 2.0,
+                y: target.y + target.height,
+            },
+        )
+    };
+    if start.x == end.x {
+        vec![start, end]
+    } else {
+        let middle = (start.y + end.y) / 2.0;
+        vec![
+            start,
+            BPMNPoint {
+                x: start.x,
+                y: middle,
+            },
+            BPMNPoint {
+                x: end.x,
+                y: middle,
+            },
+            end,
+        ]
+    }
+}
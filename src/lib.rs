@@ -40,17 +40,28 @@
 pub(crate) mod business_process_model_and_notation;
 pub(crate) mod conversion;
 pub(crate) mod creator;
+pub(crate) mod data;
+pub mod diagram;
 pub mod element;
 pub mod elements {
+    pub mod boundary_event;
+    pub mod business_rule_task;
+    pub mod call_activity;
     pub mod collapsed_pool;
     pub mod collapsed_sub_process;
+    pub mod complex_gateway;
     pub mod end_event;
+    pub mod error_end_event;
+    pub mod error_start_event;
     pub mod event_based_gateway;
+    pub mod event_sub_process;
     pub mod exclusive_gateway;
     pub mod expanded_sub_process;
     pub mod inclusive_gateway;
     pub mod intermediate_catch_event;
     pub mod intermediate_throw_event;
+    pub mod link_intermediate_catch_event;
+    pub mod link_intermediate_throw_event;
     pub mod manual_task;
     pub mod message_end_event;
     pub mod message_intermediate_catch_event;
@@ -59,45 +70,94 @@ pub mod elements {
     pub mod parallel_gateway;
     pub mod process;
     pub mod receive_task;
+    pub mod script_task;
+    pub mod send_task;
+    pub mod service_task;
+    pub mod signal_end_event;
+    pub mod signal_intermediate_catch_event;
+    pub mod signal_intermediate_throw_event;
+    pub mod signal_start_event;
     pub mod start_event;
     pub mod task;
+    pub mod terminate_end_event;
     pub mod timer_intermediate_catch_event;
     pub mod timer_start_event;
     pub mod user_task;
 }
+pub(crate) mod error;
 pub(crate) mod exporter;
+pub(crate) mod expression;
 pub mod if_not;
+pub(crate) mod import;
 pub(crate) mod importer;
+pub(crate) mod lane;
+pub(crate) mod layout;
 pub(crate) mod marking;
+pub(crate) mod message;
 pub(crate) mod message_flow;
+pub(crate) mod multi_instance;
 pub(crate) mod semantics;
 pub(crate) mod sequence_flow;
+pub(crate) mod signal;
+pub(crate) mod standard_loop;
 pub(crate) mod structure_checker;
 pub(crate) mod parser {
     pub mod parser;
     pub mod parser_state;
     pub mod parser_traits;
+    pub mod tag_boundary_event;
+    pub mod tag_bounds;
+    pub mod tag_business_rule_task;
+    pub mod tag_call_activity;
     pub mod tag_collaboration;
+    pub mod tag_complex_gateway;
+    pub mod tag_condition_expression;
+    pub mod tag_data_association;
+    pub mod tag_data_association_reference;
+    pub mod tag_data_element;
     pub mod tag_definitions;
+    pub mod tag_diagram;
+    pub mod tag_edge;
     pub mod tag_end_event;
+    pub mod tag_error_event_definition;
     pub mod tag_event_based_gateway;
     pub mod tag_exclusive_gateway;
+    pub mod tag_flow_node_ref;
+    pub mod tag_import;
     pub mod tag_inclusive_gateway;
     pub mod tag_intermediate_catch_event;
     pub mod tag_intermediate_throw_event;
+    pub mod tag_label;
+    pub mod tag_lane;
+    pub mod tag_lane_set;
+    pub mod tag_link_event_definition;
+    pub mod tag_loop_cardinality;
     pub mod tag_manual_task;
+    pub mod tag_message;
     pub mod tag_message_event_definition;
     pub mod tag_message_flow;
+    pub mod tag_multi_instance_loop_characteristics;
     pub mod tag_parallel_gateway;
     pub mod tag_participant;
+    pub mod tag_plane;
     pub mod tag_process;
     pub mod tag_receive_task;
+    pub mod tag_script_task;
+    pub mod tag_send_task;
     pub mod tag_sequence_flow;
+    pub mod tag_service_task;
+    pub mod tag_shape;
+    pub mod tag_signal;
+    pub mod tag_signal_event_definition;
+    pub mod tag_standard_loop_characteristics;
     pub mod tag_start_event;
     pub mod tag_subprocess;
     pub mod tag_task;
+    pub mod tag_terminate_event_definition;
     pub mod tag_timer_event_definition;
+    pub mod tag_timer_expression;
     pub mod tag_user_task;
+    pub mod tag_waypoint;
     pub mod tag_weight;
     pub mod tags;
 }
@@ -111,30 +171,60 @@ pub mod traits {
     pub mod transitionable;
     pub mod writable;
 }
+pub(crate) mod timer;
+pub(crate) mod unrecognised_xml;
+pub(crate) mod warning;
 pub(crate) mod writer {
+    pub mod boundary_event;
+    pub mod business_rule_task;
+    pub mod call_activity;
     pub mod collapsed_pool;
     pub mod collapsed_sub_process;
+    pub mod complex_gateway;
+    pub mod data;
+    pub mod diagram;
     pub mod end_event;
+    pub mod error_end_event;
+    pub mod error_start_event;
     pub mod event_based_gateway;
+    pub mod event_sub_process;
     pub mod exclusive_gateway;
     pub mod expanded_sub_process;
+    pub mod import;
     pub mod inclusive_gateway;
     pub mod intermediate_catch_event;
     pub mod intermediate_throw_event;
+    pub mod lane;
+    pub mod link_intermediate_catch_event;
+    pub mod link_intermediate_throw_event;
     pub mod manual_task;
+    pub mod message;
     pub mod message_end_event;
     pub mod message_flow;
     pub mod message_intermediate_catch_event;
     pub mod message_intermediate_throw_event;
     pub mod message_start_event;
+    pub mod multi_instance;
     pub mod parallel_gateway;
     pub mod process;
     pub mod receive_task;
+    pub mod script_task;
+    pub mod send_task;
     pub mod sequence_flow;
+    pub mod service_task;
+    pub mod signal;
+    pub mod signal_end_event;
+    pub mod signal_intermediate_catch_event;
+    pub mod signal_intermediate_throw_event;
+    pub mod signal_start_event;
+    pub mod standard_loop;
     pub mod start_event;
     pub mod task;
+    pub mod terminate_end_event;
+    pub mod timer;
     pub mod timer_intermediate_catch_event;
     pub mod timer_start_event;
+    pub mod unrecognised_xml;
     pub mod user_task;
 }
 
@@ -145,10 +235,35 @@ pub use creator::EndEventType;
 pub use creator::GatewayType;
 pub use creator::IntermediateEventType;
 pub use creator::StartEventType;
+pub use creator::TaskType;
+pub use data::BPMNDataAssociation;
+pub use data::BPMNDataElement;
+pub use data::DataAssociationDirection;
+pub use data::DataElementType;
+pub use elements::boundary_event::BoundaryEventType;
+pub use error::BPMNError;
+pub use expression::BPMNConditionExpression;
+pub use expression::BPMNValue;
+pub use import::BPMNImport;
+pub use lane::BPMNLane;
+pub use lane::BPMNLaneSet;
 pub use marking::BPMNMarking;
 pub use marking::Token;
+pub use message::BPMNMessage;
 pub use message_flow::BPMNMessageFlow;
+pub use multi_instance::BPMNMultiInstance;
 pub use parser::parser_state::GlobalIndex;
 pub use sequence_flow::BPMNSequenceFlow;
+pub use signal::BPMNSignal;
+pub use standard_loop::BPMNStandardLoop;
 pub use stochastic_business_process_model_and_notation::StochasticBusinessProcessModelAndNotation;
+pub use timer::BPMNDateTime;
+pub use timer::BPMNDuration;
+pub use timer::BPMNTimer;
+pub use timer::BPMNTimerSpecification;
+pub use timer::BPMNTimerType;
+pub use unrecognised_xml::BPMNUnrecognisedXml;
+pub use unrecognised_xml::BPMNXmlNode;
+pub use warning::BPMNImportWarning;
+pub use warning::BPMNImportWarningReason;
 pub use ebi_arithmetic;
\ No newline at end of file
@@ -1,12 +1,14 @@
 use crate::{
     BusinessProcessModelAndNotation, GlobalIndex,
     element::BPMNElement,
+    error::BPMNError,
+    expression::BPMNValue,
     if_not::IfNot,
     traits::{objectable::BPMNObject, processable::Processable, searchable::Searchable},
 };
 use anyhow::{Result, anyhow};
 use itertools::Itertools;
-use std::fmt::Display;
+use std::{collections::BTreeMap, fmt::Display};
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct BPMNMarking {
@@ -22,6 +24,9 @@ impl BPMNMarking {
             root_marking: BPMNRootMarking {
                 root_initial_choice_token: false,
                 message_flow_2_tokens: vec![0; bpmn.number_of_message_flows()],
+                signal_2_tokens: vec![0; bpmn.signals.len()],
+                error_thrown: None,
+                variables: None,
             },
         };
         for element in bpmn.elements_non_recursive() {
@@ -146,9 +151,84 @@ impl BPMNMarking {
                     .get_mut(parent.local_index())
                     .and_if_not("Element not found.")? += 1;
             }
+            Token::Activation { of, count } => {
+                let element = bpmn
+                    .global_index_2_element(*of)
+                    .and_if_not("Element not found.")?;
+                let parent = bpmn
+                    .parent_of(element.global_index())
+                    .and_if_not("Parent not found.")?;
+
+                if parent.is_sub_process() || BPMNSubMarking::instance_process(element).is_ok() {
+                    return Err(anyhow!("Sub-processes are not supported for now."));
+                }
+
+                let sub_marking = self
+                    .element_index_2_sub_markings
+                    .get_mut(parent.local_index())
+                    .and_if_not("Sub-marking not found.")?;
+
+                sub_marking
+                    .element_index_2_sub_markings
+                    .get_mut(element.local_index())
+                    .and_if_not("Element not found.")?
+                    .push(BPMNSubMarking {
+                        sequence_flow_2_tokens: vec![],
+                        initial_choice_token: false,
+                        element_index_2_tokens: vec![*count],
+                        element_index_2_sub_markings: vec![],
+                    });
+            }
+            Token::Reset { of, awaiting } => {
+                let Some(BPMNElement::ComplexGateway(gateway)) = bpmn.global_index_2_element(*of)
+                else {
+                    return Err(anyhow!("Complex gateway not found."));
+                };
+                let (sequence_flow, parent) = bpmn
+                    .global_index_2_sequence_flow_and_parent(*awaiting)
+                    .and_if_not("Sequence flow not found.")?;
+
+                if parent.is_sub_process() {
+                    return Err(anyhow!("Sub-processes are not supported for now."));
+                }
+
+                let sub_marking = self
+                    .element_index_2_sub_markings
+                    .get_mut(parent.local_index())
+                    .and_if_not("Sub-marking not found.")?;
+                gateway.add_reset_token(sequence_flow.local_index, sub_marking, parent);
+            }
         }
         Ok(())
     }
+
+    /// Returns whether the marking is data-aware.
+    /// In a data-aware marking, gateways only take the outgoing sequence flows of which the condition holds for the variables of the marking.
+    pub fn is_data_aware(&self) -> bool {
+        self.root_marking.variables.is_some()
+    }
+
+    /// Makes the marking data-aware, or not. Making a marking not data-aware removes its variables.
+    pub fn set_data_aware(&mut self, data_aware: bool) {
+        if !data_aware {
+            self.root_marking.variables = None;
+        } else if self.root_marking.variables.is_none() {
+            self.root_marking.variables = Some(BTreeMap::new());
+        }
+    }
+
+    /// Sets the value of a variable, and makes the marking data-aware.
+    pub fn set_variable(&mut self, name: impl Into<String>, value: impl Into<BPMNValue>) {
+        self.root_marking
+            .variables
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), value.into());
+    }
+
+    /// Returns the value of a variable, if it is set.
+    pub fn variable(&self, name: &str) -> Option<&BPMNValue> {
+        self.root_marking.variables.as_ref()?.get(name)
+    }
 }
 
 impl Display for BPMNMarking {
@@ -176,11 +256,25 @@ impl Display for BPMNMarking {
 pub struct BPMNRootMarking {
     pub(crate) root_initial_choice_token: bool,
     pub(crate) message_flow_2_tokens: Vec<u64>,
+
+    /// Signals that have been thrown but not yet broadcast to the catch events that wait for them.
+    /// Signals are broadcast directly after the transition that threw them, so outside of a transition, this is always empty.
+    pub(crate) signal_2_tokens: Vec<u64>,
+
+    /// The error that has been thrown but has not been caught yet, with its `errorRef` if it has one.
+    /// Errors are caught, or end all instances up to the process instance, within the transition that threw them, so outside of a transition, this is always None.
+    pub(crate) error_thrown: Option<Option<String>>,
+
+    /// The values of the variables if the marking is data-aware, that is, if gateways evaluate the conditions of their outgoing sequence flows.
+    pub(crate) variables: Option<BTreeMap<String, BPMNValue>>,
 }
 
 impl BPMNRootMarking {
     pub fn is_empty(&self) -> bool {
-        !self.root_initial_choice_token && self.message_flow_2_tokens.iter().all(|x| x == &0)
+        !self.root_initial_choice_token
+            && self.message_flow_2_tokens.iter().all(|x| x == &0)
+            && self.signal_2_tokens.iter().all(|x| x == &0)
+            && self.error_thrown.is_none()
     }
 }
 
@@ -199,6 +293,16 @@ impl Display for BPMNRootMarking {
                 }
                 write!(f, "]")?;
             }
+            if self.signal_2_tokens.iter().any(|x| x > &0) {
+                write!(f, "signals: [")?;
+                for signal in &self.signal_2_tokens {
+                    write!(f, "{}", signal)?;
+                }
+                write!(f, "]")?;
+            }
+            if self.error_thrown.is_some() {
+                write!(f, "error thrown")?;
+            }
             write!(f, "")
         }
     }
@@ -222,6 +326,101 @@ impl BPMNSubMarking {
         }
     }
 
+    /// Removes all tokens, including the instances of nested sub-processes.
+    pub(crate) fn clear(&mut self) {
+        self.sequence_flow_2_tokens.fill(0);
+        self.initial_choice_token = false;
+        self.element_index_2_tokens.fill(0);
+        self.element_index_2_sub_markings
+            .iter_mut()
+            .for_each(|instances| instances.clear());
+    }
+
+    /// Returns the tokens of this instance of the given process or sub-process, including the tokens of the instances and activations nested in it.
+    pub(crate) fn instance_2_tokens(&self, process: &dyn Processable) -> Result<Vec<Token>> {
+        let mut result = vec![];
+        if self.initial_choice_token {
+            result.push(Token::SubProcessStart {
+                in_process: process.global_index(),
+            });
+        }
+        for (sequence_flow, tokens) in process
+            .sequence_flows_non_recursive()
+            .iter()
+            .zip(self.sequence_flow_2_tokens.iter())
+        {
+            for _ in 0..*tokens {
+                result.push(Token::SequenceFlow(sequence_flow.global_index));
+            }
+        }
+        for element in process.elements_non_recursive() {
+            for _ in 0..self.element_index_2_tokens[element.local_index()] {
+                result.push(Token::Element(element.global_index()));
+            }
+            if let BPMNElement::ComplexGateway(gateway) = element {
+                //a complex gateway keeps its reset, rather than instances
+                result.append(&mut gateway.reset_2_tokens(
+                    &self.element_index_2_sub_markings[element.local_index()],
+                    process,
+                )?);
+            } else {
+                result.append(&mut Self::instances_2_tokens(
+                    element,
+                    &self.element_index_2_sub_markings[element.local_index()],
+                )?);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns the tokens of the given instances or activations of an element, including the tokens nested in them.
+    pub(crate) fn instances_2_tokens(
+        element: &BPMNElement,
+        sub_markings: &[BPMNSubMarking],
+    ) -> Result<Vec<Token>> {
+        let mut result = vec![];
+        if element.multi_instance().is_some() || element.standard_loop().is_some() {
+            for activation in sub_markings {
+                result.push(Token::Activation {
+                    of: element.global_index(),
+                    count: activation.element_index_2_tokens[0],
+                });
+                if let Some(instances) = activation.element_index_2_sub_markings.first() {
+                    for instance in instances {
+                        result.append(
+                            &mut instance.instance_2_tokens(Self::instance_process(element)?)?,
+                        );
+                    }
+                }
+            }
+        } else {
+            for instance in sub_markings {
+                result.append(&mut instance.instance_2_tokens(Self::instance_process(element)?)?);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns the process or sub-process of which the given element keeps instances.
+    fn instance_process(element: &BPMNElement) -> Result<&dyn Processable> {
+        match element {
+            BPMNElement::ExpandedSubProcess(sub_process) => Ok(sub_process),
+            BPMNElement::EventSubProcess(sub_process) => Ok(sub_process),
+            BPMNElement::CallActivity(call_activity) => {
+                if call_activity.called_import.is_some() {
+                    //the global indices of an imported process refer to the imported model
+                    return Err(BPMNError::Unsupported(format!(
+                        "The tokens of `{}`, which calls a process of an imported model, are not supported.",
+                        call_activity.id
+                    ))
+                    .into());
+                }
+                Ok(call_activity.called_process()?)
+            }
+            _ => Err(anyhow!("Element `{}` has no instances.", element.id())),
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         self.sequence_flow_2_tokens.iter().all(|x| *x == 0)
             && !self.initial_choice_token
@@ -327,4 +526,14 @@ pub enum Token {
 
     /// A token in front of an element on a virtual sequence flow; used if there are no start events to start the process with.
     Element(GlobalIndex),
+
+    /// A virtual token for an activation of a multi-instance or loop activity.
+    /// For a multi-instance activity, the count is the number of instances that have not started yet; for a loop activity, the number of executions so far.
+    Activation { of: GlobalIndex, count: u64 },
+
+    /// A virtual token of a complex gateway that waits for reset, for an incoming sequence flow of which it is still to absorb a token.
+    Reset {
+        of: GlobalIndex,
+        awaiting: GlobalIndex,
+    },
 }
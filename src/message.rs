@@ -0,0 +1,39 @@
+use crate::{BusinessProcessModelAndNotation, parser::parser_state::GlobalIndex};
+
+/// A struct that represents a message definition in a BPMN model.
+/// Message events and message flows may refer to a message by its id, which tells which message is sent or awaited.
+#[derive(Clone, Debug)]
+pub struct BPMNMessage {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+}
+
+impl BPMNMessage {
+    pub fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+impl BusinessProcessModelAndNotation {
+    /// Returns a suffix for transition_debug that mentions the referred message by name, or by id if it has no name.
+    pub(crate) fn message_debug(&self, message_ref: Option<&str>) -> String {
+        match message_ref {
+            Some(message_ref) => match self.message(message_ref).and_then(|message| message.name())
+            {
+                Some(name) => format!("; message `{}`", name),
+                None => format!("; message `{}`", message_ref),
+            },
+            None => String::new(),
+        }
+    }
+}
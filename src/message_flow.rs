@@ -6,10 +6,12 @@ pub struct BPMNMessageFlow {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
     pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
     pub(crate) source_pool_index: usize,
     pub(crate) source_global_index: GlobalIndex,
     pub(crate) target_pool_index: usize,
     pub(crate) target_global_index: GlobalIndex,
+    pub(crate) message_ref: Option<String>,
 }
 
 impl BPMNMessageFlow {
@@ -17,6 +19,15 @@ impl BPMNMessageFlow {
         self.global_index
     }
 
+    /// Returns the id of the message that is sent over this flow, if any.
+    pub fn message_ref(&self) -> Option<&str> {
+        self.message_ref.as_deref()
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     pub fn source_global_index(&self) -> GlobalIndex {
         self.source_global_index
     }
@@ -0,0 +1,106 @@
+use crate::marking::BPMNSubMarking;
+use anyhow::{Result, anyhow};
+
+/// The multi-instance loop characteristics of an activity: the activity is executed a fixed number of times, either concurrently or one after another.
+///
+/// While a multi-instance activity is running, its activations are kept in the sub-markings of the activity.
+/// An activation of a task keeps the number of instances that still have to be executed as its only element token.
+/// An activation of a sub-process keeps the number of instances that still have to be started as its only element token, and the running instances as its only list of sub-markings.
+#[derive(Debug, Clone)]
+pub struct BPMNMultiInstance {
+    pub(crate) id: Option<String>,
+    pub is_sequential: bool,
+    pub loop_cardinality: u64,
+}
+
+impl BPMNMultiInstance {
+    /// Returns a new activation of a task, in which no instance has been executed yet.
+    pub(crate) fn new_task_activation(&self) -> BPMNSubMarking {
+        BPMNSubMarking {
+            sequence_flow_2_tokens: vec![],
+            initial_choice_token: false,
+            element_index_2_tokens: vec![self.loop_cardinality],
+            element_index_2_sub_markings: vec![],
+        }
+    }
+
+    /// Returns the number of transitions of the activations of a task: one for each instance that can be executed.
+    pub(crate) fn number_of_task_transitions(&self, activations: &[BPMNSubMarking]) -> usize {
+        activations
+            .iter()
+            .map(|activation| self.number_of_task_activation_transitions(activation))
+            .sum()
+    }
+
+    fn number_of_task_activation_transitions(&self, activation: &BPMNSubMarking) -> usize {
+        let remaining = activation.element_index_2_tokens[0] as usize;
+        if self.is_sequential {
+            //only the next instance can be executed
+            remaining.min(1)
+        } else {
+            //all remaining instances can be executed concurrently
+            remaining
+        }
+    }
+
+    /// Executes an instance of a task. Returns whether this was the last instance of its activation, that is, whether the task has completed.
+    pub(crate) fn execute_task_transition(
+        &self,
+        activations: &mut Vec<BPMNSubMarking>,
+        mut transition_index: usize,
+    ) -> Result<bool> {
+        for activation_index in 0..activations.len() {
+            let number_of_transitions =
+                self.number_of_task_activation_transitions(&activations[activation_index]);
+            if transition_index < number_of_transitions {
+                activations[activation_index].element_index_2_tokens[0] -= 1;
+                if activations[activation_index].element_index_2_tokens[0] == 0 {
+                    activations.remove(activation_index);
+                    return Ok(true);
+                }
+                return Ok(false);
+            }
+            transition_index -= number_of_transitions;
+        }
+        Err(anyhow!("Transition does not exist."))
+    }
+
+    /// Returns the number of instances that still have to be executed in the activation to which the given transition of a task belongs, before and after the transition.
+    pub(crate) fn task_transition_2_remaining(
+        &self,
+        activations: &[BPMNSubMarking],
+        mut transition_index: usize,
+    ) -> Result<(u64, u64)> {
+        for activation in activations {
+            let number_of_transitions = self.number_of_task_activation_transitions(activation);
+            if transition_index < number_of_transitions {
+                let remaining = activation.element_index_2_tokens[0];
+                return Ok((remaining, remaining - 1));
+            }
+            transition_index -= number_of_transitions;
+        }
+        Err(anyhow!("Transition does not exist."))
+    }
+
+    /// Returns a new activation of a sub-process, with its first instances started.
+    pub(crate) fn new_sub_process_activation(
+        &self,
+        mut start_instance: impl FnMut() -> Result<BPMNSubMarking>,
+    ) -> Result<BPMNSubMarking> {
+        let started = if self.is_sequential {
+            self.loop_cardinality.min(1)
+        } else {
+            self.loop_cardinality
+        };
+        let mut instances = vec![];
+        for _ in 0..started {
+            instances.push(start_instance()?);
+        }
+        Ok(BPMNSubMarking {
+            sequence_flow_2_tokens: vec![],
+            initial_choice_token: false,
+            element_index_2_tokens: vec![self.loop_cardinality - started],
+            element_index_2_sub_markings: vec![instances],
+        })
+    }
+}
@@ -1,37 +1,178 @@
 use crate::{
     importer::parse_attribute,
     parser::{
-        parser_state::ParserState,
+        parser_state::{DraftUnrecognisedXml, ParserState},
         parser_traits::{Closeable, Openable, Recognisable},
         tags::{OpenedTag, Tag},
     },
+    unrecognised_xml::BPMNXmlNode,
+    warning::{BPMNImportWarning, BPMNImportWarningReason},
 };
 use anyhow::{Context, Result, anyhow};
 use quick_xml::{
-    events::{BytesEnd, BytesStart},
-    name::{Namespace, ResolveResult},
+    escape::resolve_predefined_entity,
+    events::{BytesCData, BytesEnd, BytesRef, BytesStart, BytesText},
+    name::{Namespace, QName, ResolveResult},
 };
 use strum_macros::EnumIs;
 
-pub(crate) fn empty_tag(state: &mut ParserState, e: &BytesStart, n: NameSpace) -> Result<()> {
+/// Returns the attributes that are interpreted by this crate for the given tag, and are thus not kept as unrecognised XML.
+/// These are written by the exporter itself, which also writes the id of exactly the tags for which `id` is listed.
+fn interpreted_attributes(tag: Tag) -> &'static [&'static [u8]] {
+    match tag {
+        Tag::BoundaryEvent => &[b"id", b"name", b"attachedToRef", b"cancelActivity"],
+        Tag::Bounds => &[b"x", b"y", b"width", b"height"],
+        Tag::BusinessRuleTask
+        | Tag::EndEvent
+        | Tag::EventBasedGateway
+        | Tag::IntermediateCatchEvent
+        | Tag::IntermediateThrowEvent
+        | Tag::Lane
+        | Tag::LaneSet
+        | Tag::LinkEventDefinition
+        | Tag::ManualTask
+        | Tag::Message
+        | Tag::ParallelGateway
+        | Tag::Process
+        | Tag::ReceiveTask
+        | Tag::ScriptTask
+        | Tag::SendTask
+        | Tag::ServiceTask
+        | Tag::Signal
+        | Tag::Task
+        | Tag::UserTask => &[b"id", b"name"],
+        Tag::CallActivity => &[b"id", b"name", b"calledElement"],
+        Tag::Collaboration
+        | Tag::DataAssociation
+        | Tag::TerminateEventDefinition
+        | Tag::TimerEventDefinition => &[b"id"],
+        Tag::ComplexGateway => &[b"id", b"name"],
+        Tag::ConditionExpression => &[b"language"],
+        Tag::DataAssociationReference
+        | Tag::FlowNodeRef
+        | Tag::Label
+        | Tag::LoopCardinality
+        | Tag::TimerExpression => &[],
+        Tag::DataElement => &[
+            b"id",
+            b"name",
+            b"isCollection",
+            b"dataObjectRef",
+            b"dataStoreRef",
+        ],
+        Tag::Definitions => &[b"id", b"exporter", b"exporterVersion"],
+        Tag::Diagram => &[b"id", b"name"],
+        Tag::Edge => &[b"id", b"bpmnElement", b"sourceElement", b"targetElement"],
+        Tag::ErrorEventDefinition => &[b"id", b"errorRef"],
+        Tag::ExclusiveGateway | Tag::InclusiveGateway => &[b"id", b"name", b"default"],
+        Tag::Import => &[b"importType", b"location", b"namespace"],
+        Tag::MessageEventDefinition => &[b"id", b"messageRef"],
+        Tag::MessageFlow => &[b"id", b"name", b"sourceRef", b"targetRef", b"messageRef"],
+        Tag::MultiInstanceLoopCharacteristics => &[b"id", b"isSequential"],
+        Tag::Participant => &[b"id", b"name", b"processRef"],
+        Tag::Plane => &[b"id", b"bpmnElement"],
+        Tag::SequenceFlow => &[b"id", b"name", b"sourceRef", b"targetRef"],
+        Tag::Shape => &[
+            b"id",
+            b"bpmnElement",
+            b"isExpanded",
+            b"isHorizontal",
+            b"isMarkerVisible",
+        ],
+        Tag::SignalEventDefinition => &[b"id", b"signalRef"],
+        Tag::StandardLoopCharacteristics => &[b"id", b"loopMaximum", b"testBefore"],
+        Tag::StartEvent => &[b"id", b"name", b"isInterrupting"],
+        Tag::SubProcess => &[b"id", b"name", b"triggeredByEvent"],
+        Tag::Waypoint => &[b"x", b"y"],
+        Tag::Weight => &[b"constant"],
+    }
+}
+
+/// Attributes of the SBPMN namespace that are interpreted by this crate, by their local name.
+const INTERPRETED_EXTENSION_ATTRIBUTES: &[&[u8]] = &[b"activationCount", b"repeatProbability"];
+
+pub(crate) fn empty_tag(
+    state: &mut ParserState,
+    e: &BytesStart,
+    n: Option<NameSpace>,
+) -> Result<()> {
     open_tag(state, e, n)?;
     close_tag(state, &e.to_end(), n)
 }
 
-pub(crate) fn open_tag(state: &mut ParserState, e: &BytesStart, n: NameSpace) -> Result<()> {
-    if let Some(tag) = Tag::recognise_tag(e, state, n) {
+pub(crate) fn open_tag(
+    state: &mut ParserState,
+    e: &BytesStart,
+    n: Option<NameSpace>,
+) -> Result<()> {
+    if let Some(tag) = n.and_then(|n| Tag::recognise_tag(e, state, n)) {
         let opened_tag =
             Tag::open_tag(tag, e, state).with_context(|| anyhow!("parsing tag `{}`", tag))?;
         state.open_tags.push(opened_tag);
+
+        //keep the attributes that we do not interpret
+        //the unrecognised XML of a tag is kept by its id if the exporter writes it, and otherwise by its path from its parent
+        let interpreted = interpreted_attributes(tag);
+        let id = if interpreted.contains(&b"id".as_slice()) {
+            parse_attribute(e, "id")
+        } else {
+            None
+        };
+        let mut step = None;
+        if let Some(parent) = state.draft_unrecognised_xmls.last_mut() {
+            if id.is_some() {
+                parent.anchor = id.clone();
+            } else {
+                let local_name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                let occurrence = parent
+                    .id_less_children
+                    .iter()
+                    .filter(|name| **name == local_name)
+                    .count();
+                parent.id_less_children.push(local_name.clone());
+                step = Some((local_name, occurrence));
+            }
+        }
+        state
+            .draft_unrecognised_xmls
+            .push(DraftUnrecognisedXml::new(
+                id,
+                step,
+                read_attributes(e, Some(interpreted), &state.sbpmn_attributes),
+            ));
     } else {
+        //report the outermost ignored tags, except references to sequence flows, which are interpreted through the sequence flows themselves
+        let local_name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+        if n.is_some()
+            && !is_in_unrecognised_tag(state)
+            && local_name != "incoming"
+            && local_name != "outgoing"
+        {
+            state.warnings.push(BPMNImportWarning {
+                tag: String::from_utf8_lossy(e.name().as_ref()).to_string(),
+                id: parse_attribute(e, "id"),
+                position: state.position,
+                reason: BPMNImportWarningReason::of(&local_name),
+            });
+        }
+
         state.open_tags.push(OpenedTag::Unknown);
 
         //save id'ed tags for more helpful error messages
-        if let Some(id) = parse_attribute(e, "id") {
-            state
-                .not_recognised_id_2_tag
-                .insert(id, String::from_utf8_lossy(e.name().as_ref()).to_string());
+        if n.is_some() {
+            if let Some(id) = parse_attribute(e, "id") {
+                state
+                    .not_recognised_id_2_tag
+                    .insert(id, String::from_utf8_lossy(e.name().as_ref()).to_string());
+            }
         }
+
+        //keep the tag, such that it can be exported again
+        state.unrecognised_xml_nodes.push(BPMNXmlNode::Element {
+            name: qualified_name(e, n),
+            attributes: read_attributes(e, None, &state.sbpmn_attributes),
+            children: vec![],
+        });
     }
 
     state
@@ -41,12 +182,17 @@ pub(crate) fn open_tag(state: &mut ParserState, e: &BytesStart, n: NameSpace) ->
     Ok(())
 }
 
-pub(crate) fn close_tag(state: &mut ParserState, e: &BytesEnd, _n: NameSpace) -> Result<()> {
+pub(crate) fn close_tag(
+    state: &mut ParserState,
+    e: &BytesEnd,
+    _n: Option<NameSpace>,
+) -> Result<()> {
     if let (Some(most_recent_open_tag_name), Some(most_recent_open_tag)) =
         (state.open_tag_names.pop(), state.open_tags.pop())
     {
         if most_recent_open_tag_name == e.local_name().as_ref() {
             //closing tag matches last remaining opening tag
+            let is_unknown = most_recent_open_tag.is_unknown();
 
             OpenedTag::close_tag(most_recent_open_tag, e, state).with_context(|| {
                 anyhow!(
@@ -55,6 +201,28 @@ pub(crate) fn close_tag(state: &mut ParserState, e: &BytesEnd, _n: NameSpace) ->
                 )
             })?;
 
+            if is_unknown {
+                close_unrecognised_node(state);
+            } else if let Some(draft) = state.draft_unrecognised_xmls.pop() {
+                if let Some(id) = draft.id {
+                    if !draft.xml.is_empty() {
+                        state.unrecognised_xml.insert(id, draft.xml);
+                    }
+                } else if let (Some(step), Some(parent)) =
+                    (draft.step, state.draft_unrecognised_xmls.last_mut())
+                {
+                    //a tag without an id is found on export by its path from the nearest ancestor with an id
+                    let mut xml = draft.xml;
+                    for (mut path, descendant) in std::mem::take(&mut xml.descendants) {
+                        path.insert(0, step.clone());
+                        parent.xml.descendants.push((path, descendant));
+                    }
+                    if !xml.is_empty() {
+                        parent.xml.descendants.push((vec![step], xml));
+                    }
+                }
+            }
+
             Ok(())
         } else {
             Err(anyhow!(
@@ -71,6 +239,163 @@ pub(crate) fn close_tag(state: &mut ParserState, e: &BytesEnd, _n: NameSpace) ->
     }
 }
 
+pub(crate) fn text(state: &mut ParserState, e: &BytesText) -> Result<()> {
+    if is_in_unrecognised_tag(state) {
+        append_unrecognised_text(state, &String::from_utf8_lossy(e));
+    } else {
+        append_text(state, &String::from_utf8_lossy(e));
+    }
+    Ok(())
+}
+
+pub(crate) fn cdata(state: &mut ParserState, e: &BytesCData) -> Result<()> {
+    if is_in_unrecognised_tag(state) {
+        add_unrecognised_node(
+            state,
+            BPMNXmlNode::CData(String::from_utf8_lossy(e).to_string()),
+        );
+    } else {
+        append_text(state, &String::from_utf8_lossy(e));
+    }
+    Ok(())
+}
+
+/// Handles a reference to a character or to a predefined entity, such as `&lt;`, which is part of the text around it.
+pub(crate) fn general_reference(state: &mut ParserState, e: &BytesRef) -> Result<()> {
+    let t = if let Some(c) = e.resolve_char_ref()? {
+        c.to_string()
+    } else if let Some(entity) = resolve_predefined_entity(&String::from_utf8_lossy(e)) {
+        entity.to_string()
+    } else {
+        return Ok(());
+    };
+    if is_in_unrecognised_tag(state) {
+        append_unrecognised_text(state, &t);
+    } else {
+        append_text(state, &t);
+    }
+    Ok(())
+}
+
+pub(crate) fn comment(state: &mut ParserState, e: &BytesText) -> Result<()> {
+    add_unrecognised_node(
+        state,
+        BPMNXmlNode::Comment(String::from_utf8_lossy(e).to_string()),
+    );
+    Ok(())
+}
+
+fn append_text(state: &mut ParserState, t: &str) {
+    //text is only meaningful inside a few tags; elsewhere it is ignored
+    match state.open_tags.iter_mut().last() {
+        Some(OpenedTag::LoopCardinality { text })
+        | Some(OpenedTag::ConditionExpression { text, .. })
+        | Some(OpenedTag::DataAssociationReference { text, .. })
+        | Some(OpenedTag::FlowNodeRef { text })
+        | Some(OpenedTag::TimerExpression { text, .. }) => {
+            text.push_str(t);
+        }
+        _ => {}
+    }
+}
+
+fn is_in_unrecognised_tag(state: &ParserState) -> bool {
+    state.open_tags.last().is_some_and(|tag| tag.is_unknown())
+}
+
+fn append_unrecognised_text(state: &mut ParserState, t: &str) {
+    if let Some(BPMNXmlNode::Element { children, .. }) = state.unrecognised_xml_nodes.last_mut() {
+        if let Some(BPMNXmlNode::Text(text)) = children.last_mut() {
+            text.push_str(t);
+        } else {
+            children.push(BPMNXmlNode::Text(t.to_string()));
+        }
+    }
+}
+
+/// Adds a node to the unrecognised tag that is open, or otherwise to the unrecognised XML of the recognised tag that is open.
+fn add_unrecognised_node(state: &mut ParserState, node: BPMNXmlNode) {
+    if is_in_unrecognised_tag(state) {
+        if let Some(BPMNXmlNode::Element { children, .. }) = state.unrecognised_xml_nodes.last_mut()
+        {
+            children.push(node);
+        }
+    } else if let Some(draft) = state.draft_unrecognised_xmls.last_mut() {
+        draft.xml.children.push((draft.anchor.clone(), node));
+    }
+}
+
+fn close_unrecognised_node(state: &mut ParserState) {
+    if let Some(mut node) = state.unrecognised_xml_nodes.pop() {
+        if let BPMNXmlNode::Element { name, children, .. } = &mut node {
+            //incoming and outgoing references are written by the exporter itself
+            if !is_in_unrecognised_tag(state) && (name == "incoming" || name == "outgoing") {
+                return;
+            }
+
+            //whitespace between child tags is layout, not content
+            if children
+                .iter()
+                .any(|child| !matches!(child, BPMNXmlNode::Text(_)))
+            {
+                children.retain(|child| match child {
+                    BPMNXmlNode::Text(text) => !text.trim().is_empty(),
+                    _ => true,
+                });
+            }
+        }
+        add_unrecognised_node(state, node);
+    }
+}
+
+/// Reads the attributes of a tag, except those that are interpreted for the tag, if it is recognised.
+fn read_attributes(
+    e: &BytesStart,
+    interpreted: Option<&[&[u8]]>,
+    sbpmn_attributes: &[Vec<u8>],
+) -> Vec<(String, String)> {
+    let mut result = vec![];
+    for attribute in e.attributes().flatten() {
+        if interpreted
+            .is_some_and(|interpreted| is_interpreted(attribute.key, interpreted, sbpmn_attributes))
+        {
+            continue;
+        }
+        if let Ok(value) =
+            attribute.decoded_and_normalized_value(quick_xml::XmlVersion::Implicit1_0, e.decoder())
+        {
+            result.push((
+                String::from_utf8_lossy(attribute.key.as_ref()).to_string(),
+                value.to_string(),
+            ));
+        }
+    }
+    result
+}
+
+/// Prefixed attributes are only interpreted if they are in the SBPMN namespace; others are kept, whatever their local name.
+fn is_interpreted(key: QName, interpreted: &[&[u8]], sbpmn_attributes: &[Vec<u8>]) -> bool {
+    if key.prefix().is_some() {
+        sbpmn_attributes.iter().any(|name| name == key.as_ref())
+            && INTERPRETED_EXTENSION_ATTRIBUTES.contains(&key.local_name().as_ref())
+    } else {
+        interpreted.contains(&key.local_name().as_ref())
+    }
+}
+
+/// Returns the name of the tag, with the prefixes that the exporter declares for the namespaces it knows.
+fn qualified_name(e: &BytesStart, n: Option<NameSpace>) -> String {
+    let local_name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+    match n {
+        Some(NameSpace::BPMN) => local_name,
+        Some(NameSpace::SBPMN) => format!("sbpmn:{}", local_name),
+        Some(NameSpace::BPMNDI) => format!("bpmndi:{}", local_name),
+        Some(NameSpace::DC) => format!("dc:{}", local_name),
+        Some(NameSpace::DI) => format!("di:{}", local_name),
+        None => String::from_utf8_lossy(e.name().as_ref()).to_string(),
+    }
+}
+
 pub(crate) fn can_eof(state: &ParserState) -> Result<()> {
     if let Some(tag) = state.open_tag_names.iter().next() {
         Err(anyhow!(
@@ -86,16 +411,25 @@ pub(crate) fn can_eof(state: &ParserState) -> Result<()> {
 pub enum NameSpace {
     BPMN,
     SBPMN,
+    BPMNDI,
+    DC,
+    DI,
 }
 
 pub const NAMESPACE_SBPMN: &[u8; 39] = b"https://www.ebitools.org/sbpmn/20260305";
 pub const NAMESPACE_BPMN: &[u8; 43] = b"http://www.omg.org/spec/BPMN/20100524/MODEL";
+pub const NAMESPACE_BPMNDI: &[u8; 40] = b"http://www.omg.org/spec/BPMN/20100524/DI";
+pub const NAMESPACE_DC: &[u8; 38] = b"http://www.omg.org/spec/DD/20100524/DC";
+pub const NAMESPACE_DI: &[u8; 38] = b"http://www.omg.org/spec/DD/20100524/DI";
 
 pub(crate) fn is_in_namespace(result: ResolveResult) -> Option<NameSpace> {
     match result {
         ResolveResult::Unbound => Some(NameSpace::BPMN),
         ResolveResult::Bound(Namespace(n)) if n == NAMESPACE_BPMN => Some(NameSpace::BPMN),
         ResolveResult::Bound(Namespace(n)) if n == NAMESPACE_SBPMN => Some(NameSpace::SBPMN),
+        ResolveResult::Bound(Namespace(n)) if n == NAMESPACE_BPMNDI => Some(NameSpace::BPMNDI),
+        ResolveResult::Bound(Namespace(n)) if n == NAMESPACE_DC => Some(NameSpace::DC),
+        ResolveResult::Bound(Namespace(n)) if n == NAMESPACE_DI => Some(NameSpace::DI),
         _ => None,
     }
 }
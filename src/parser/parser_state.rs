@@ -1,16 +1,25 @@
 use crate::{
     BusinessProcessModelAndNotation,
+    data::{BPMNDataAssociation, BPMNDataElement, DataElementType},
+    error::BPMNError,
     importer::parse_attribute,
-    parser::{tag_definitions::DraftDefinitions, tags::OpenedTag},
+    parser::{
+        tag_call_activity::resolve_call_activities, tag_definitions::DraftDefinitions,
+        tags::OpenedTag,
+    },
+    traits::objectable::BPMNObject,
+    unrecognised_xml::{BPMNUnrecognisedXml, BPMNXmlNode},
+    warning::BPMNImportWarning,
 };
 use anyhow::{Context, Result, anyhow};
-use ebi_activity_key::ActivityKey;
+use ebi_activity_key::{ActivityKey, TranslateActivityKey};
 use intmap::IntKey;
 use quick_xml::events::BytesStart;
 use std::{
     collections::{HashMap, hash_map::Entry},
     fmt::Display,
     hash::Hash,
+    io::BufRead,
 };
 
 pub(crate) struct ParserState {
@@ -21,7 +30,56 @@ pub(crate) struct ParserState {
 
     pub(crate) draft_definitionss: Vec<DraftDefinitions>,
 
+    pub(crate) data_elements: Vec<BPMNDataElement>,
+    pub(crate) data_associations: Vec<BPMNDataAssociation>,
+
     pub(crate) not_recognised_id_2_tag: HashMap<String, String>,
+
+    /// The unrecognised XML of the recognised tags that are open, on top of that of the document itself.
+    pub(crate) draft_unrecognised_xmls: Vec<DraftUnrecognisedXml>,
+    pub(crate) unrecognised_xml_nodes: Vec<BPMNXmlNode>,
+    pub(crate) unrecognised_xml: HashMap<String, BPMNUnrecognisedXml>,
+
+    /// The byte position of the start of the current tag.
+    pub(crate) position: u64,
+
+    /// The qualified names of the attributes of the current tag that are in the SBPMN namespace.
+    pub(crate) sbpmn_attributes: Vec<Vec<u8>>,
+    pub(crate) warnings: Vec<BPMNImportWarning>,
+}
+
+/// The unrecognised XML of a recognised tag that is still open.
+pub(crate) struct DraftUnrecognisedXml {
+    pub(crate) id: Option<String>,
+
+    /// For a tag without an id, its local name and its occurrence among the siblings of the same name that have no id.
+    pub(crate) step: Option<(String, usize)>,
+
+    /// The local names of the recognised children without an id.
+    pub(crate) id_less_children: Vec<String>,
+
+    /// The id of the most recent recognised child, after which unrecognised children are placed.
+    pub(crate) anchor: Option<String>,
+    pub(crate) xml: BPMNUnrecognisedXml,
+}
+
+impl DraftUnrecognisedXml {
+    pub(crate) fn new(
+        id: Option<String>,
+        step: Option<(String, usize)>,
+        attributes: Vec<(String, String)>,
+    ) -> Self {
+        Self {
+            id,
+            step,
+            id_less_children: vec![],
+            anchor: None,
+            xml: BPMNUnrecognisedXml {
+                attributes,
+                ..Default::default()
+            },
+        }
+    }
 }
 
 impl ParserState {
@@ -32,17 +90,32 @@ impl ParserState {
             ids: HashMap::new(),
             open_tags: vec![],
             draft_definitionss: vec![],
+            data_elements: vec![],
+            data_associations: vec![],
             not_recognised_id_2_tag: HashMap::new(),
+            draft_unrecognised_xmls: vec![DraftUnrecognisedXml::new(None, None, vec![])],
+            unrecognised_xml_nodes: vec![],
+            unrecognised_xml: HashMap::new(),
+            position: 0,
+            sbpmn_attributes: vec![],
+            warnings: vec![],
         }
     }
 
+    /// Finalises the model. If a resolver is given, the imported BPMN files are loaded, such that call activities can call their processes.
     pub(crate) fn to_model(
         self,
         disallow_sequence_flow_weights: bool,
+        mut resolver: Option<&mut dyn FnMut(&str) -> Result<Box<dyn BufRead>>>,
+        loading: &mut Vec<String>,
     ) -> Result<BusinessProcessModelAndNotation> {
         let ParserState {
-            activity_key,
+            mut activity_key,
             mut draft_definitionss,
+            data_elements,
+            data_associations,
+            mut draft_unrecognised_xmls,
+            unrecognised_xml,
             ..
         } = self;
         if draft_definitionss.len() == 1 {
@@ -53,9 +126,28 @@ impl ParserState {
                 collaboration_index,
                 collaboration_id,
                 stochastic_namespace,
-                elements,
+                mut elements,
+                mut imports,
                 message_flows,
+                messages,
+                namespaces,
+                signals,
+                diagrams,
             } = draft_definition;
+
+            //load the imported models, with the activities of this model
+            if let Some(resolver) = resolver.as_deref_mut() {
+                for import in imports.iter_mut() {
+                    import.load(resolver, disallow_sequence_flow_weights, loading)?;
+                    if let Some(definitions) = &mut import.definitions {
+                        definitions.translate_using_activity_key(&mut activity_key);
+                    }
+                }
+            }
+
+            //link call activities to the processes they call
+            resolve_call_activities(&mut elements, &imports, &namespaces);
+
             //construct result
             let result = BusinessProcessModelAndNotation {
                 stochastic_namespace,
@@ -65,17 +157,69 @@ impl ParserState {
                 definitions_index,
                 definitions_id,
                 elements,
+                imports,
                 message_flows,
+                messages,
+                signals,
+                data_elements,
+                data_associations,
+                diagrams,
+                unrecognised_xml,
+                unrecognised_document_xml: draft_unrecognised_xmls
+                    .pop()
+                    .map(|draft| draft.xml)
+                    .unwrap_or_default(),
             };
 
+            //verify that references refer to existing data objects and data stores
+            for data_element in &result.data_elements {
+                let expected_type = match data_element.element_type {
+                    DataElementType::DataObjectReference => DataElementType::DataObject,
+                    DataElementType::DataStoreReference => DataElementType::DataStore,
+                    _ => continue,
+                };
+                if let Some(data_ref) = &data_element.data_ref {
+                    if !result
+                        .data_element(data_ref)
+                        .is_some_and(|referenced| referenced.element_type == expected_type)
+                    {
+                        return Err(BPMNError::UnknownId {
+                            message: format!(
+                                "Could not find {} `{}` of `{}`.",
+                                expected_type.tag_name(),
+                                data_ref,
+                                data_element.id
+                            ),
+                            id: data_ref.clone(),
+                        }
+                        .into());
+                    }
+                }
+            }
+
             if disallow_sequence_flow_weights {
                 //verify that the model has no sequence flows with weights
                 for sequence_flow in result.sequence_flows() {
                     if sequence_flow.weight.is_some() {
-                        return Err(anyhow!(
+                        return Err(BPMNError::Unsupported(format!(
                             "Sequence flow `{}` has a weight, which is not allowed in this import mode.",
                             sequence_flow.id
-                        ));
+                        ))
+                        .into());
+                    }
+                }
+
+                //verify that the model has no loops with repeat probabilities
+                for element in result.elements() {
+                    if element
+                        .standard_loop()
+                        .is_some_and(|standard_loop| standard_loop.repeat_probability.is_some())
+                    {
+                        return Err(BPMNError::Unsupported(format!(
+                            "Loop activity `{}` has a repeat probability, which is not allowed in this import mode.",
+                            element.id()
+                        ))
+                        .into());
                     }
                 }
             }
@@ -89,7 +233,7 @@ impl ParserState {
             if draft_definitionss.len() == 0 {
                 Err(anyhow!("no process found"))
             } else {
-                Err(anyhow!("multiple processes found"))
+                Err(BPMNError::Unsupported("multiple processes found".to_string()).into())
             }
         }
     }
@@ -98,7 +242,11 @@ impl ParserState {
         let new_index = self.ids.len();
         if let Some(id) = parse_attribute(e, "id") {
             match self.ids.entry(id.clone()) {
-                Entry::Occupied(_) => Err(anyhow!("two elements have the id `{}`", id)),
+                Entry::Occupied(_) => Err(BPMNError::DuplicateId {
+                    message: format!("two elements have the id `{}`", id),
+                    id,
+                }
+                .into()),
                 Entry::Vacant(vacant_entry) => {
                     vacant_entry.insert(new_index);
                     Ok((GlobalIndex(new_index), id))
@@ -0,0 +1,173 @@
+use crate::{
+    element::BPMNElement,
+    elements::boundary_event::{BPMNBoundaryEvent, BoundaryEventType},
+    error::BPMNError,
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tag_shape::parse_attribute_bool,
+        tags::{OpenedTag, Tag},
+    },
+    traits::objectable::BPMNObject,
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagBoundaryEvent {}
+
+impl Recognisable for TagBoundaryEvent {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Process { .. }) | Some(OpenedTag::SubProcess { .. }) => {
+                    if e.local_name().as_ref() == b"boundaryEvent" {
+                        return Some(Tag::BoundaryEvent);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagBoundaryEvent {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let (index, id) = state.read_and_add_id(e)?;
+
+        let attached_to_id = parse_attribute(e, "attachedToRef").ok_or_else(|| {
+            anyhow!(
+                "Boundary event `{}` must have an `attachedToRef` attribute.",
+                id
+            )
+        })?;
+
+        //by default, boundary events are interrupting
+        let cancel_activity = parse_attribute_bool(e, "cancelActivity").unwrap_or(true);
+
+        Ok(OpenedTag::BoundaryEvent {
+            global_index: index,
+            id,
+            name: parse_attribute(e, "name"),
+            attached_to_id,
+            cancel_activity,
+            error_marker_id: None,
+            error_ref: None,
+            message_marker_id: None,
+            message_ref: None,
+            timer_marker_id: None,
+            timer: None,
+        })
+    }
+}
+
+impl Closeable for TagBoundaryEvent {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Process { elements, .. })
+            | Some(OpenedTag::SubProcess { elements, .. }) => {
+                if let OpenedTag::BoundaryEvent {
+                    global_index,
+                    id,
+                    name,
+                    attached_to_id,
+                    cancel_activity,
+                    error_marker_id,
+                    error_ref,
+                    message_marker_id,
+                    message_ref,
+                    timer_marker_id,
+                    timer,
+                } = opened_tag
+                {
+                    let (event_type, marker_id) = match (
+                        error_marker_id,
+                        message_marker_id,
+                        timer_marker_id,
+                    ) {
+                        (Some(marker_id), None, None) => (BoundaryEventType::Error, marker_id),
+                        (None, Some(marker_id), None) => (BoundaryEventType::Message, marker_id),
+                        (None, None, Some(marker_id)) => (BoundaryEventType::Timer, marker_id),
+                        (None, None, None) => {
+                            return Err(anyhow!(
+                                "Boundary event `{}` must have an event definition.",
+                                id
+                            ));
+                        }
+                        _ => {
+                            return Err(anyhow!(
+                                "Boundary event `{}` has multiple event definitions, which is not supported.",
+                                id
+                            ));
+                        }
+                    };
+
+                    let local_index = elements.len();
+                    elements.push(BPMNElement::BoundaryEvent(BPMNBoundaryEvent {
+                        global_index,
+                        id,
+                        local_index,
+                        name,
+                        event_type,
+                        marker_id,
+                        error_ref,
+                        message_ref,
+                        timer,
+                        cancel_activity,
+                        attached_to_id,
+                        //the host may be declared after the boundary event; it is resolved when the container closes
+                        attached_to_local_index: 0,
+                        outgoing_sequence_flows: vec![],
+                        incoming_message_flow: None,
+                    }));
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a boundary event."));
+                }
+            }
+            _ => return Err(anyhow!("Expected a process or a subprocess.")),
+        }
+    }
+}
+
+/// Links the boundary events of a container to their hosts, which must be in the same container.
+pub(crate) fn resolve_boundary_events(elements: &mut [BPMNElement]) -> Result<()> {
+    let mut hosts = Vec::with_capacity(elements.len());
+    for element in elements.iter() {
+        if let BPMNElement::BoundaryEvent(boundary_event) = element {
+            let host = elements
+                .iter()
+                .find(|host| host.id() == boundary_event.attached_to_id)
+                .ok_or_else(|| {
+                    BPMNError::UnknownId {
+                        message: format!(
+                            "Could not find activity `{}` to which boundary event `{}` is attached. It must be in the same process or sub-process.",
+                            boundary_event.attached_to_id,
+                            boundary_event.id
+                        ),
+                        id: boundary_event.attached_to_id.clone(),
+                    }
+                })?;
+            hosts.push(Some(host.local_index()));
+        } else {
+            hosts.push(None);
+        }
+    }
+
+    for (element, host) in elements.iter_mut().zip(hosts) {
+        if let (BPMNElement::BoundaryEvent(boundary_event), Some(host)) = (element, host) {
+            boundary_event.attached_to_local_index = host;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::{
+    diagram::BPMNBounds,
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Context, Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagBounds {}
+
+impl Recognisable for TagBounds {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_dc() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Shape { .. }) | Some(OpenedTag::Label { .. }) => {
+                    if e.local_name().as_ref() == b"Bounds" {
+                        return Some(Tag::Bounds);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+pub(crate) fn parse_coordinate(e: &BytesStart, attribute_name: &str) -> Result<f64> {
+    parse_attribute(e, attribute_name)
+        .ok_or_else(|| anyhow!("Attribute `{}` is mandatory.", attribute_name))?
+        .parse::<f64>()
+        .with_context(|| anyhow!("Parsing attribute `{}` as a number.", attribute_name))
+}
+
+impl Openable for TagBounds {
+    fn open_tag(_tag: Tag, e: &BytesStart, _state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        Ok(OpenedTag::Bounds {
+            bounds: BPMNBounds {
+                x: parse_coordinate(e, "x")?,
+                y: parse_coordinate(e, "y")?,
+                width: parse_coordinate(e, "width")?,
+                height: parse_coordinate(e, "height")?,
+            },
+        })
+    }
+}
+
+impl Closeable for TagBounds {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        if let OpenedTag::Bounds { bounds: new_bounds } = opened_tag {
+            match state.open_tags.iter_mut().last() {
+                Some(OpenedTag::Shape { bounds, .. }) | Some(OpenedTag::Label { bounds }) => {
+                    if bounds.is_some() {
+                        return Err(anyhow!("Cannot assign two bounds to a shape or label."));
+                    }
+                    *bounds = Some(new_bounds);
+                    Ok(())
+                }
+                _ => Err(anyhow!("Expected a shape or a label.")),
+            }
+        } else {
+            Err(anyhow!("Expected bounds."))
+        }
+    }
+}
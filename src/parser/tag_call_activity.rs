@@ -0,0 +1,253 @@
+use crate::{
+    element::BPMNElement,
+    elements::{call_activity::BPMNCallActivity, process::BPMNProcess},
+    import::BPMNImport,
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+    traits::searchable::Searchable,
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+use std::collections::HashSet;
+
+pub(crate) struct TagCallActivity {}
+
+impl Recognisable for TagCallActivity {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Process { .. }) | Some(OpenedTag::SubProcess { .. }) => {
+                    if e.local_name().as_ref() == b"callActivity" {
+                        return Some(Tag::CallActivity);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagCallActivity {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let (index, id) = state.read_and_add_id(e)?;
+
+        let name = parse_attribute(e, "name");
+        let called_element = parse_attribute(e, "calledElement")
+            .ok_or_else(|| anyhow!("Call activity `{}` has no calledElement.", id))?;
+        Ok(OpenedTag::CallActivity {
+            global_index: index,
+            id,
+            name,
+            called_element,
+        })
+    }
+}
+
+impl Closeable for TagCallActivity {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Process { elements, .. })
+            | Some(OpenedTag::SubProcess { elements, .. }) => {
+                if let OpenedTag::CallActivity {
+                    global_index,
+                    id,
+                    name,
+                    called_element,
+                } = opened_tag
+                {
+                    let local_index = elements.len();
+                    elements.push(BPMNElement::CallActivity(BPMNCallActivity {
+                        global_index,
+                        id,
+                        local_index,
+                        name,
+                        called_element,
+                        called_process: None,
+                        called_import: None,
+                        incoming_sequence_flows: vec![],
+                        outgoing_sequence_flows: vec![],
+                    }));
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a call activity."));
+                }
+            }
+            _ => return Err(anyhow!("Expected a process or a subprocess.")),
+        }
+    }
+}
+
+/// Marks the processes that are called by call activities, and gives each call activity a copy of the process it calls.
+/// A process is copied only once all of its own call activities have been resolved, thus call activities in a cycle remain unresolved.
+/// A process of an imported model is executed against that model, thus the call activity records the import it is in.
+///
+/// A called element `prefix:id` of which the prefix is declared with the namespace of an import refers to a process of that imported model.
+/// A called element without prefix that is not a process of this model is looked up in all imported models.
+pub(crate) fn resolve_call_activities(
+    elements: &mut Vec<BPMNElement>,
+    imports: &[BPMNImport],
+    namespaces: &[(String, String)],
+) {
+    //mark the called processes
+    let called_elements = elements
+        .all_elements_ref()
+        .into_iter()
+        .filter_map(|element| match element {
+            BPMNElement::CallActivity(call_activity) => {
+                local_called_element(&call_activity.called_element, imports, namespaces)
+                    .map(|id| id.to_string())
+            }
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+    for element in elements.iter_mut() {
+        if let BPMNElement::Process(process) = element {
+            process.is_called = called_elements.contains(&process.id);
+        }
+    }
+
+    //resolve one call activity at a time
+    loop {
+        let mut resolution = None;
+        for element in elements.all_elements_ref() {
+            if let BPMNElement::CallActivity(call_activity) = element {
+                if call_activity.called_process.is_some() {
+                    continue;
+                }
+
+                let called_process = find_called_process(
+                    &call_activity.called_element,
+                    elements,
+                    imports,
+                    namespaces,
+                );
+                if let Some((called_import, called_process)) = called_process {
+                    let is_resolved =
+                        called_process
+                            .all_elements_ref()
+                            .iter()
+                            .all(|element| match element {
+                                BPMNElement::CallActivity(call_activity) => {
+                                    call_activity.called_process.is_some()
+                                }
+                                _ => true,
+                            });
+                    if is_resolved {
+                        resolution = Some((
+                            call_activity.global_index,
+                            called_import,
+                            called_process.clone(),
+                        ));
+                        break;
+                    }
+                }
+            }
+        }
+
+        match resolution {
+            Some((global_index, called_import, called_process)) => {
+                if let Some(BPMNElement::CallActivity(call_activity)) =
+                    elements.global_index_2_element_mut(global_index)
+                {
+                    call_activity.called_process = Some(Box::new(called_process));
+                    call_activity.called_import = called_import;
+                }
+            }
+            None => break,
+        }
+    }
+}
+
+/// Returns the indices of the imports of which the namespace is declared with the prefix of the called element, if the called element has such a prefix.
+fn imports_of_prefix<'a>(
+    called_element: &'a str,
+    imports: &[BPMNImport],
+    namespaces: &[(String, String)],
+) -> Option<(&'a str, Vec<usize>)> {
+    let (prefix, id) = called_element.split_once(':')?;
+    let (_, namespace) = namespaces
+        .iter()
+        .find(|(declared_prefix, _)| declared_prefix == prefix)?;
+    let imports = imports
+        .iter()
+        .enumerate()
+        .filter(|(_, import)| &import.namespace == namespace)
+        .map(|(import_index, _)| import_index)
+        .collect::<Vec<_>>();
+    if imports.is_empty() {
+        None
+    } else {
+        Some((id, imports))
+    }
+}
+
+/// Returns the id of the process of this model that the called element refers to, if it does not refer to an imported model.
+fn local_called_element<'a>(
+    called_element: &'a str,
+    imports: &[BPMNImport],
+    namespaces: &[(String, String)],
+) -> Option<&'a str> {
+    if imports_of_prefix(called_element, imports, namespaces).is_some() {
+        None
+    } else if let Some((_, id)) = called_element.split_once(':') {
+        Some(id)
+    } else {
+        Some(called_element)
+    }
+}
+
+/// Returns the process that the called element refers to, with the index of the import it is in if it is not a process of this model.
+fn find_called_process<'a>(
+    called_element: &str,
+    elements: &'a [BPMNElement],
+    imports: &'a [BPMNImport],
+    namespaces: &[(String, String)],
+) -> Option<(Option<usize>, &'a BPMNProcess)> {
+    let process_in = |elements: &'a [BPMNElement], id: &str| {
+        elements.iter().find_map(|element| match element {
+            BPMNElement::Process(process) if process.id == id => Some(process),
+            _ => None,
+        })
+    };
+    let imported_process = |import_index: usize, id: &str| {
+        let definitions = imports[import_index].definitions.as_deref()?;
+        process_in(definitions.elements.as_slice(), id).map(|process| (Some(import_index), process))
+    };
+
+    if let Some((id, import_indices)) = imports_of_prefix(called_element, imports, namespaces) {
+        //an imported process
+        import_indices
+            .into_iter()
+            .find_map(|import_index| imported_process(import_index, id))
+    } else {
+        //a local process, or otherwise a process of any import
+        let id = called_element
+            .split_once(':')
+            .map(|(_, id)| id)
+            .unwrap_or(called_element);
+        match process_in(elements, id) {
+            Some(process) => Some((None, process)),
+            None => {
+                if called_element.contains(':') {
+                    None
+                } else {
+                    (0..imports.len()).find_map(|import_index| imported_process(import_index, id))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    element::BPMNElement,
+    elements::complex_gateway::BPMNComplexGateway,
+    importer::parse_attribute,
+    importer::parse_sbpmn_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Context, Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub struct TagComplexGateway {}
+
+impl Recognisable for TagComplexGateway {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Process { .. }) | Some(OpenedTag::SubProcess { .. }) => {
+                    if e.local_name().as_ref() == b"complexGateway" {
+                        return Some(Tag::ComplexGateway);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagComplexGateway {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let (index, id) = state.read_and_add_id(e)?;
+
+        let activation_count = match parse_sbpmn_attribute(e, state, "activationCount") {
+            Some(value) => Some(
+                value
+                    .trim()
+                    .parse::<u64>()
+                    .with_context(|| anyhow!("Parsing activation count `{}`.", value))?,
+            ),
+            None => None,
+        };
+
+        Ok(OpenedTag::ComplexGateway {
+            global_index: index,
+            id,
+            name: parse_attribute(e, "name"),
+            activation_count,
+        })
+    }
+}
+
+impl Closeable for TagComplexGateway {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Process { elements, .. })
+            | Some(OpenedTag::SubProcess { elements, .. }) => {
+                if let OpenedTag::ComplexGateway {
+                    global_index,
+                    id,
+                    name,
+                    activation_count,
+                } = opened_tag
+                {
+                    let local_index = elements.len();
+                    elements.push(BPMNElement::ComplexGateway(BPMNComplexGateway {
+                        global_index,
+                        id,
+                        local_index,
+                        name,
+                        activation_count,
+                        incoming_sequence_flows: vec![],
+                        outgoing_sequence_flows: vec![],
+                    }));
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a complex gateway."));
+                }
+            }
+            _ => return Err(anyhow!("Expected a process or a subprocess.")),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    expression::BPMNConditionExpression,
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+    warning::{BPMNImportWarning, BPMNImportWarningReason},
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagConditionExpression {}
+
+impl Recognisable for TagConditionExpression {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::SequenceFlow { .. }) => {
+                    if e.local_name().as_ref() == b"conditionExpression" {
+                        return Some(Tag::ConditionExpression);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagConditionExpression {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let language = parse_attribute(e, "language");
+        Ok(OpenedTag::ConditionExpression {
+            id: parse_attribute(e, "id"),
+            tag: String::from_utf8_lossy(e.name().as_ref()).to_string(),
+            position: state.position,
+            language,
+            text: String::new(),
+        })
+    }
+}
+
+impl Closeable for TagConditionExpression {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::SequenceFlow {
+                condition_expression,
+                ..
+            }) => {
+                if let OpenedTag::ConditionExpression {
+                    id,
+                    tag,
+                    position,
+                    language,
+                    text,
+                } = opened_tag
+                {
+                    if condition_expression.is_some() {
+                        return Err(anyhow!(
+                            "Cannot assign two condition expressions to a sequence flow."
+                        ));
+                    }
+                    let expression =
+                        BPMNConditionExpression::new(text.trim().to_string(), language);
+
+                    //a condition that cannot be evaluated is kept, but always holds
+                    if !expression.is_supported() {
+                        state.warnings.push(BPMNImportWarning {
+                            tag,
+                            id,
+                            position,
+                            reason: BPMNImportWarningReason::UnsupportedCondition,
+                        });
+                    }
+
+                    *condition_expression = Some(expression);
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a condition expression."));
+                }
+            }
+            _ => return Err(anyhow!("Expected a sequence flow.")),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    data::{BPMNDataAssociation, DataAssociationDirection},
+    parser::{
+        parser::NameSpace,
+        parser_state::{GlobalIndex, ParserState},
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagDataAssociation {}
+
+/// Returns the global index of the activity of the given tag, if it is an activity.
+pub(crate) fn opened_activity_global_index(opened_tag: &OpenedTag) -> Option<GlobalIndex> {
+    match opened_tag {
+        OpenedTag::Task { global_index, .. }
+        | OpenedTag::UserTask { global_index, .. }
+        | OpenedTag::ManualTask { global_index, .. }
+        | OpenedTag::ReceiveTask { global_index, .. }
+        | OpenedTag::BusinessRuleTask { global_index, .. }
+        | OpenedTag::ScriptTask { global_index, .. }
+        | OpenedTag::SendTask { global_index, .. }
+        | OpenedTag::ServiceTask { global_index, .. }
+        | OpenedTag::CallActivity { global_index, .. }
+        | OpenedTag::SubProcess { global_index, .. } => Some(*global_index),
+        _ => None,
+    }
+}
+
+impl Recognisable for TagDataAssociation {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            if state
+                .open_tags
+                .iter()
+                .last()
+                .and_then(opened_activity_global_index)
+                .is_some()
+            {
+                match e.local_name().as_ref() {
+                    b"dataInputAssociation" | b"dataOutputAssociation" => {
+                        return Some(Tag::DataAssociation);
+                    }
+                    _ => {}
+                }
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagDataAssociation {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let id = state.read_and_add_id_optional(e);
+        let direction = if e.local_name().as_ref() == b"dataInputAssociation" {
+            DataAssociationDirection::Input
+        } else {
+            DataAssociationDirection::Output
+        };
+
+        Ok(OpenedTag::DataAssociation {
+            id,
+            direction,
+            source_refs: vec![],
+            target_ref: None,
+        })
+    }
+}
+
+impl Closeable for TagDataAssociation {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        let activity_global_index = state
+            .open_tags
+            .iter()
+            .last()
+            .and_then(opened_activity_global_index)
+            .ok_or_else(|| anyhow!("Expected an activity."))?;
+        if let OpenedTag::DataAssociation {
+            id,
+            direction,
+            source_refs,
+            target_ref,
+        } = opened_tag
+        {
+            state.data_associations.push(BPMNDataAssociation {
+                id,
+                direction,
+                activity_global_index,
+                source_refs,
+                target_ref,
+            });
+            Ok(())
+        } else {
+            return Err(anyhow!("Expected a data association."));
+        }
+    }
+}
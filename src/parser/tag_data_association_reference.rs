@@ -0,0 +1,72 @@
+use crate::parser::{
+    parser::NameSpace,
+    parser_state::ParserState,
+    parser_traits::{Closeable, Openable, Recognisable},
+    tags::{OpenedTag, Tag},
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+/// The `sourceRef` and `targetRef` tags of a data association.
+pub(crate) struct TagDataAssociationReference {}
+
+impl Recognisable for TagDataAssociationReference {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::DataAssociation { .. }) => match e.local_name().as_ref() {
+                    b"sourceRef" | b"targetRef" => {
+                        return Some(Tag::DataAssociationReference);
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagDataAssociationReference {
+    fn open_tag(_tag: Tag, e: &BytesStart, _state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        Ok(OpenedTag::DataAssociationReference {
+            is_source: e.local_name().as_ref() == b"sourceRef",
+            text: String::new(),
+        })
+    }
+}
+
+impl Closeable for TagDataAssociationReference {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::DataAssociation {
+                source_refs,
+                target_ref,
+                ..
+            }) => {
+                if let OpenedTag::DataAssociationReference { is_source, text } = opened_tag {
+                    let reference = text.trim().to_string();
+                    if is_source {
+                        source_refs.push(reference);
+                    } else if target_ref.is_some() {
+                        return Err(anyhow!("A data association can have only one target."));
+                    } else {
+                        *target_ref = Some(reference);
+                    }
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a source or target reference."));
+                }
+            }
+            _ => return Err(anyhow!("Expected a data association.")),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    data::{BPMNDataElement, DataElementType},
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::{GlobalIndex, ParserState},
+        parser_traits::{Closeable, Openable, Recognisable},
+        tag_data_association::opened_activity_global_index,
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+/// The tags of data objects, data stores, references to them, and properties of activities.
+pub(crate) struct TagDataElement {}
+
+/// Returns the global index of the given tag if it can contain a data element of the given type.
+fn data_element_parent(
+    opened_tag: &OpenedTag,
+    element_type: DataElementType,
+) -> Option<GlobalIndex> {
+    match (opened_tag, element_type) {
+        (OpenedTag::Definitions { global_index, .. }, DataElementType::DataStore) => {
+            Some(*global_index)
+        }
+        (
+            OpenedTag::Process { global_index, .. } | OpenedTag::SubProcess { global_index, .. },
+            DataElementType::DataObject
+            | DataElementType::DataObjectReference
+            | DataElementType::DataStoreReference,
+        ) => Some(*global_index),
+        (_, DataElementType::Property) => opened_activity_global_index(opened_tag),
+        _ => None,
+    }
+}
+
+impl Recognisable for TagDataElement {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            if let (Some(opened_tag), Some(element_type)) = (
+                state.open_tags.iter().last(),
+                DataElementType::from_tag_name(e.local_name().as_ref()),
+            ) {
+                if data_element_parent(opened_tag, element_type).is_some() {
+                    return Some(Tag::DataElement);
+                }
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagDataElement {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let element_type = DataElementType::from_tag_name(e.local_name().as_ref())
+            .ok_or_else(|| anyhow!("Expected a data element."))?;
+        let parent_global_index = state
+            .open_tags
+            .iter()
+            .last()
+            .and_then(|opened_tag| data_element_parent(opened_tag, element_type))
+            .ok_or_else(|| anyhow!("Data element in an unexpected location."))?;
+
+        let (global_index, id) = state.read_and_add_id(e)?;
+        let name = parse_attribute(e, "name");
+        let data_ref = element_type
+            .data_ref_attribute()
+            .and_then(|attribute| parse_attribute(e, attribute));
+        if element_type == DataElementType::DataObjectReference && data_ref.is_none() {
+            return Err(anyhow!(
+                "Data object reference `{}` must have a dataObjectRef.",
+                id
+            ));
+        }
+        let is_collection = match parse_attribute(e, "isCollection").as_deref() {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(value) => {
+                return Err(anyhow!(
+                    "Attribute isCollection should be `true` or `false`, but found `{}`.",
+                    value
+                ));
+            }
+        };
+
+        Ok(OpenedTag::DataElement {
+            data_element: BPMNDataElement {
+                global_index,
+                id,
+                element_type,
+                name,
+                parent_global_index,
+                data_ref,
+                is_collection,
+            },
+        })
+    }
+}
+
+impl Closeable for TagDataElement {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        if let OpenedTag::DataElement { data_element } = opened_tag {
+            state.data_elements.push(data_element);
+            Ok(())
+        } else {
+            return Err(anyhow!("Expected a data element."));
+        }
+    }
+}
@@ -1,6 +1,10 @@
 use crate::{
+    diagram::BPMNDiagram,
     element::{BPMNElement, BPMNElementTrait},
     elements::collapsed_pool::BPMNCollapsedPool,
+    error::BPMNError,
+    import::BPMNImport,
+    message::BPMNMessage,
     message_flow::BPMNMessageFlow,
     parser::{
         parser::{NAMESPACE_SBPMN, NameSpace},
@@ -9,11 +13,15 @@ use crate::{
         tag_message_flow::DraftMessageFlow,
         tags::{OpenedTag, Tag},
     },
-    traits::searchable::Searchable,
+    signal::BPMNSignal,
+    traits::{objectable::BPMNObject, searchable::Searchable},
 };
 use anyhow::{Context, Result, anyhow};
 use itertools::Itertools;
-use quick_xml::events::{BytesEnd, BytesStart};
+use quick_xml::{
+    events::{BytesEnd, BytesStart},
+    name::PrefixDeclaration,
+};
 
 pub struct Definitions {}
 
@@ -48,6 +56,22 @@ fn find_stochastic_namespace_declaration(e: &BytesStart) -> bool {
     false
 }
 
+/// Returns the declared namespace prefixes, such as `("shared", "http://example.org/shared")` for `xmlns:shared`.
+fn find_namespace_declarations(e: &BytesStart) -> Vec<(String, String)> {
+    let mut result = vec![];
+    for attribute in e.attributes() {
+        if let Ok(attribute) = attribute {
+            if let Some(PrefixDeclaration::Named(prefix)) = attribute.key.as_namespace_binding() {
+                result.push((
+                    String::from_utf8_lossy(prefix).to_string(),
+                    String::from_utf8_lossy(&attribute.value).to_string(),
+                ));
+            }
+        }
+    }
+    result
+}
+
 impl Openable for Definitions {
     fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
     where
@@ -66,6 +90,11 @@ impl Openable for Definitions {
             draft_message_flows: vec![],
             draft_participants: vec![],
             elements: vec![],
+            imports: vec![],
+            messages: vec![],
+            namespaces: find_namespace_declarations(e),
+            signals: vec![],
+            diagrams: vec![],
         })
     }
 }
@@ -83,6 +112,11 @@ impl Closeable for Definitions {
             draft_message_flows,
             mut draft_participants,
             mut elements,
+            imports,
+            messages,
+            namespaces,
+            signals,
+            diagrams,
         } = opened_tag
         {
             //match participants and processes
@@ -152,8 +186,10 @@ impl Closeable for Definitions {
                 let DraftMessageFlow {
                     global_index,
                     id,
+                    name,
                     source_id,
                     target_id,
+                    message_ref,
                 } = draft_message_flows;
 
                 //obtain source
@@ -161,20 +197,29 @@ impl Closeable for Definitions {
                     elements.id_2_pool_and_global_index(&source_id).ok_or_else(|| {
                         //source not found; try whether there's an unrecognised tag with that id
                         if let Some(tag) = state.not_recognised_id_2_tag.get(&source_id) {
-                            anyhow!(
-                                "Could not find source `{}` of message flow `{}`.\nHowever, a tag with name `{}` was found with this id. That tag is perhaps not supported or is not in an expected location.",
-                                source_id,
-                                id,
-                                tag
-                            )
+                            BPMNError::UnknownId {
+                                message: format!(
+                                    "Could not find source `{}` of message flow `{}`.\nHowever, a tag with name `{}` was found with this id. That tag is perhaps not supported or is not in an expected location.",
+                                    source_id,
+                                    id,
+                                    tag
+                                ),
+                                id: source_id.clone(),
+                            }
                         } else if let Some(_) = state.ids.get(&source_id) {
-                            anyhow!("Could not find source `{}` of message flow `{}`. However, a tag with this id was recognised elsewhere in the model. Perhaps this tag cannot have outgoing messages flows.", source_id, id)
+                            BPMNError::UnknownId {
+                                message: format!("Could not find source `{}` of message flow `{}`. However, a tag with this id was recognised elsewhere in the model. Perhaps this tag cannot have outgoing messages flows.", source_id, id),
+                                id: source_id.clone(),
+                            }
                         } else {
-                            anyhow!(
-                                "Could not find source `{}` of message flow `{}`.",
-                                source_id,
-                                id
-                            )
+                            BPMNError::UnknownId {
+                                message: format!(
+                                    "Could not find source `{}` of message flow `{}`.",
+                                    source_id,
+                                    id
+                                ),
+                                id: source_id.clone(),
+                            }
                         }
                     })?;
 
@@ -183,33 +228,48 @@ impl Closeable for Definitions {
                     //element
                     source
                         .add_outgoing_message_flow(new_message_flow_index)
-                        .with_context(|| anyhow!("message flow `{}`", id))?;
+                        .with_context(|| BPMNError::StructuralViolation {
+                            message: format!("message flow `{}`", id),
+                            element_ids: vec![id.clone(), source_id.clone()],
+                        })?;
                 } else {
-                    return Err(anyhow!(
-                        "Could not find source `{}` of message flow `{}`.",
-                        source_id,
-                        id
-                    ));
+                    return Err(BPMNError::UnknownId {
+                        message: format!(
+                            "Could not find source `{}` of message flow `{}`.",
+                            source_id, id
+                        ),
+                        id: source_id.clone(),
+                    }
+                    .into());
                 }
 
                 //obtain target
                 let (target_pool_index, target_element_index) =
                     elements.id_2_pool_and_global_index(&target_id).ok_or_else(|| {
                         if let Some(tag) = state.not_recognised_id_2_tag.get(&target_id) {
-                            anyhow!(
-                                "Could not find target `{}` of message flow `{}`.\nHowever, a tag with name `{}` was found with this id. That tag is perhaps not supported or is not in an expected location.",
-                                source_id,
-                                id,
-                                tag
-                            )
+                            BPMNError::UnknownId {
+                                message: format!(
+                                    "Could not find target `{}` of message flow `{}`.\nHowever, a tag with name `{}` was found with this id. That tag is perhaps not supported or is not in an expected location.",
+                                    source_id,
+                                    id,
+                                    tag
+                                ),
+                                id: target_id.clone(),
+                            }
                         } else if let Some(_) = state.ids.get(&target_id) {
-                            anyhow!("Could not find target `{}` of message flow `{}`. However, a tag with this id was recognised elsewhere in the model. Perhaps this tag cannot have incoming messages flows.", target_id, id)
+                            BPMNError::UnknownId {
+                                message: format!("Could not find target `{}` of message flow `{}`. However, a tag with this id was recognised elsewhere in the model. Perhaps this tag cannot have incoming messages flows.", target_id, id),
+                                id: target_id.clone(),
+                            }
                         } else {
-                            anyhow!(
-                                "Could not find target `{}` of message flow `{}`.",
-                                target_id,
-                                id
-                            )
+                            BPMNError::UnknownId {
+                                message: format!(
+                                    "Could not find target `{}` of message flow `{}`.",
+                                    target_id,
+                                    id
+                                ),
+                                id: target_id.clone(),
+                            }
                         }
                     })?;
 
@@ -218,13 +278,19 @@ impl Closeable for Definitions {
                     //element
                     target
                         .add_incoming_message_flow(new_message_flow_index)
-                        .with_context(|| anyhow!("message flow `{}`", id))?;
+                        .with_context(|| BPMNError::StructuralViolation {
+                            message: format!("message flow `{}`", id),
+                            element_ids: vec![id.clone(), target_id.clone()],
+                        })?;
                 } else {
-                    return Err(anyhow!(
-                        "could not find target `{}` of message flow `{}`",
-                        target_id,
-                        id
-                    ));
+                    return Err(BPMNError::UnknownId {
+                        message: format!(
+                            "could not find target `{}` of message flow `{}`",
+                            target_id, id
+                        ),
+                        id: target_id.clone(),
+                    }
+                    .into());
                 }
 
                 let local_index = message_flows.len();
@@ -232,15 +298,80 @@ impl Closeable for Definitions {
                     global_index,
                     id,
                     local_index,
+                    name,
                     source_global_index: source_element_index,
                     source_pool_index: source_pool_index
                         .ok_or_else(|| anyhow!("pool not found"))?,
                     target_global_index: target_element_index,
                     target_pool_index: target_pool_index
                         .ok_or_else(|| anyhow!("pool not found {}", target_id))?,
+                    message_ref,
                 });
             }
 
+            //verify that signal events refer to existing signals
+            for element in elements.all_elements_ref() {
+                let signal_ref = match element {
+                    BPMNElement::SignalEndEvent(event) => event.signal_ref(),
+                    BPMNElement::SignalIntermediateCatchEvent(event) => event.signal_ref(),
+                    BPMNElement::SignalIntermediateThrowEvent(event) => event.signal_ref(),
+                    BPMNElement::SignalStartEvent(event) => event.signal_ref(),
+                    _ => None,
+                };
+                if let Some(signal_ref) = signal_ref {
+                    if !signals.iter().any(|signal| signal.id == signal_ref) {
+                        return Err(BPMNError::UnknownId {
+                            message: format!(
+                                "Could not find signal `{}` of event `{}`.",
+                                signal_ref,
+                                element.id()
+                            ),
+                            id: signal_ref.to_string(),
+                        }
+                        .into());
+                    }
+                }
+            }
+
+            //verify that message events and message flows refer to existing messages
+            for element in elements.all_elements_ref() {
+                let message_ref = match element {
+                    BPMNElement::BoundaryEvent(event) => event.message_ref(),
+                    BPMNElement::MessageEndEvent(event) => event.message_ref(),
+                    BPMNElement::MessageIntermediateCatchEvent(event) => event.message_ref(),
+                    BPMNElement::MessageIntermediateThrowEvent(event) => event.message_ref(),
+                    BPMNElement::MessageStartEvent(event) => event.message_ref(),
+                    _ => None,
+                };
+                if let Some(message_ref) = message_ref {
+                    if !messages.iter().any(|message| message.id == message_ref) {
+                        return Err(BPMNError::UnknownId {
+                            message: format!(
+                                "Could not find message `{}` of event `{}`.",
+                                message_ref,
+                                element.id()
+                            ),
+                            id: message_ref.to_string(),
+                        }
+                        .into());
+                    }
+                }
+            }
+            for message_flow in &message_flows {
+                if let Some(message_ref) = message_flow.message_ref() {
+                    if !messages.iter().any(|message| message.id == message_ref) {
+                        return Err(BPMNError::UnknownId {
+                            message: format!(
+                                "Could not find message `{}` of message flow `{}`.",
+                                message_ref, message_flow.id
+                            ),
+                            id: message_ref.to_string(),
+                        }
+                        .into());
+                    }
+                }
+            }
+
             state.draft_definitionss.push(DraftDefinitions {
                 global_index,
                 id,
@@ -248,7 +379,12 @@ impl Closeable for Definitions {
                 collaboration_id,
                 stochastic_namespace,
                 elements,
+                imports,
                 message_flows,
+                messages,
+                namespaces,
+                signals,
+                diagrams,
             });
 
             Ok(())
@@ -265,5 +401,10 @@ pub(crate) struct DraftDefinitions {
     pub(crate) collaboration_id: Option<String>,
     pub(crate) stochastic_namespace: bool,
     pub(crate) elements: Vec<BPMNElement>,
+    pub(crate) imports: Vec<BPMNImport>,
     pub(crate) message_flows: Vec<BPMNMessageFlow>,
+    pub(crate) messages: Vec<BPMNMessage>,
+    pub(crate) namespaces: Vec<(String, String)>,
+    pub(crate) signals: Vec<BPMNSignal>,
+    pub(crate) diagrams: Vec<BPMNDiagram>,
 }
@@ -0,0 +1,65 @@
+use crate::{
+    diagram::BPMNDiagram,
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagDiagram {}
+
+impl Recognisable for TagDiagram {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmndi() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Definitions { .. }) => {
+                    if e.local_name().as_ref() == b"BPMNDiagram" {
+                        return Some(Tag::Diagram);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagDiagram {
+    fn open_tag(_tag: Tag, e: &BytesStart, _state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        Ok(OpenedTag::Diagram {
+            id: parse_attribute(e, "id"),
+            name: parse_attribute(e, "name"),
+            plane: None,
+        })
+    }
+}
+
+impl Closeable for TagDiagram {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Definitions { diagrams, .. }) => {
+                if let OpenedTag::Diagram { id, name, plane } = opened_tag {
+                    let plane = plane.ok_or_else(|| anyhow!("A diagram must have a plane."))?;
+                    diagrams.push(BPMNDiagram { id, name, plane });
+                    Ok(())
+                } else {
+                    Err(anyhow!("Expected a diagram."))
+                }
+            }
+            _ => Err(anyhow!("Expected definitions.")),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    diagram::BPMNEdge,
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagEdge {}
+
+impl Recognisable for TagEdge {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmndi() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Plane { .. }) => {
+                    if e.local_name().as_ref() == b"BPMNEdge" {
+                        return Some(Tag::Edge);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagEdge {
+    fn open_tag(_tag: Tag, e: &BytesStart, _state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let bpmn_element = parse_attribute(e, "bpmnElement")
+            .ok_or_else(|| anyhow!("An edge must have a `bpmnElement` attribute."))?;
+
+        Ok(OpenedTag::Edge {
+            id: parse_attribute(e, "id"),
+            bpmn_element,
+            source_element: parse_attribute(e, "sourceElement"),
+            target_element: parse_attribute(e, "targetElement"),
+            waypoints: vec![],
+            label: None,
+        })
+    }
+}
+
+impl Closeable for TagEdge {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Plane { edges, .. }) => {
+                if let OpenedTag::Edge {
+                    id,
+                    bpmn_element,
+                    source_element,
+                    target_element,
+                    waypoints,
+                    label,
+                } = opened_tag
+                {
+                    edges.push(BPMNEdge {
+                        id,
+                        bpmn_element,
+                        source_element,
+                        target_element,
+                        waypoints,
+                        label,
+                    });
+                    Ok(())
+                } else {
+                    Err(anyhow!("Expected an edge."))
+                }
+            }
+            _ => Err(anyhow!("Expected a plane.")),
+        }
+    }
+}
@@ -1,6 +1,11 @@
 use crate::{
     element::BPMNElement,
-    elements::{end_event::BPMNEndEvent, message_end_event::BPMNMessageEndEvent},
+    elements::{
+        end_event::BPMNEndEvent, error_end_event::BPMNErrorEndEvent,
+        message_end_event::BPMNMessageEndEvent, signal_end_event::BPMNSignalEndEvent,
+        terminate_end_event::BPMNTerminateEndEvent,
+    },
+    importer::parse_attribute,
     parser::{
         parser::NameSpace,
         parser_state::ParserState,
@@ -44,7 +49,14 @@ impl Openable for TagEndEvent {
         Ok(OpenedTag::EndEvent {
             global_index,
             id,
+            name: parse_attribute(e, "name"),
+            error_marker_id: None,
+            error_ref: None,
             message_marker_id: None,
+            message_ref: None,
+            signal_marker_id: None,
+            signal_ref: None,
+            terminate_marker_id: None,
         })
     }
 }
@@ -57,24 +69,79 @@ impl Closeable for TagEndEvent {
                 if let OpenedTag::EndEvent {
                     global_index,
                     id,
+                    name,
+                    error_marker_id,
+                    error_ref,
                     message_marker_id,
+                    message_ref,
+                    signal_marker_id,
+                    signal_ref,
+                    terminate_marker_id,
                 } = opened_tag
                 {
                     let local_index = elements.len();
-                    if let Some(message_marker_id) = message_marker_id {
+                    if [
+                        error_marker_id.is_some(),
+                        message_marker_id.is_some(),
+                        signal_marker_id.is_some(),
+                        terminate_marker_id.is_some(),
+                    ]
+                    .iter()
+                    .filter(|x| **x)
+                    .count()
+                        > 1
+                    {
+                        return Err(anyhow!(
+                            "End event `{}` has multiple event definitions, which is not supported.",
+                            id
+                        ));
+                    }
+                    if let Some(error_marker_id) = error_marker_id {
+                        elements.push(BPMNElement::ErrorEndEvent(BPMNErrorEndEvent {
+                            global_index,
+                            id,
+                            local_index,
+                            name,
+                            error_marker_id,
+                            error_ref,
+                            incoming_sequence_flows: vec![],
+                        }));
+                    } else if let Some(message_marker_id) = message_marker_id {
                         elements.push(BPMNElement::MessageEndEvent(BPMNMessageEndEvent {
                             global_index,
                             id,
                             local_index,
+                            name,
                             message_marker_id,
+                            message_ref,
                             incoming_sequence_flows: vec![],
                             outgoing_message_flow: None,
                         }));
+                    } else if let Some(signal_marker_id) = signal_marker_id {
+                        elements.push(BPMNElement::SignalEndEvent(BPMNSignalEndEvent {
+                            global_index,
+                            id,
+                            local_index,
+                            name,
+                            signal_marker_id,
+                            signal_ref,
+                            incoming_sequence_flows: vec![],
+                        }));
+                    } else if let Some(terminate_marker_id) = terminate_marker_id {
+                        elements.push(BPMNElement::TerminateEndEvent(BPMNTerminateEndEvent {
+                            global_index,
+                            id,
+                            local_index,
+                            name,
+                            terminate_marker_id,
+                            incoming_sequence_flows: vec![],
+                        }));
                     } else {
                         elements.push(BPMNElement::EndEvent(BPMNEndEvent {
                             global_index,
                             id,
                             local_index,
+                            name,
                             incoming_sequence_flows: vec![],
                         }));
                     }
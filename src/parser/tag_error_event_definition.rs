@@ -0,0 +1,80 @@
+use crate::{
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagErrorEventDefinition {}
+
+impl Recognisable for TagErrorEventDefinition {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::BoundaryEvent { .. })
+                | Some(OpenedTag::EndEvent { .. })
+                | Some(OpenedTag::StartEvent { .. }) => {
+                    if e.local_name().as_ref() == b"errorEventDefinition" {
+                        return Some(Tag::ErrorEventDefinition);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagErrorEventDefinition {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let id = state.read_and_add_id_optional(e);
+        let error_ref = parse_attribute(e, "errorRef");
+
+        Ok(OpenedTag::ErrorEventDefinition { id, error_ref })
+    }
+}
+
+impl Closeable for TagErrorEventDefinition {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        let index = state.open_tags.len() - 1;
+        match state.open_tags.get_mut(index) {
+            Some(OpenedTag::BoundaryEvent {
+                error_marker_id: error_id,
+                error_ref: event_error_ref,
+                ..
+            })
+            | Some(OpenedTag::EndEvent {
+                error_marker_id: error_id,
+                error_ref: event_error_ref,
+                ..
+            })
+            | Some(OpenedTag::StartEvent {
+                error_marker_id: error_id,
+                error_ref: event_error_ref,
+                ..
+            }) => {
+                if let OpenedTag::ErrorEventDefinition { id, error_ref } = opened_tag {
+                    *error_id = Some(id);
+                    *event_error_ref = error_ref;
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected an error event definition."));
+                }
+            }
+            _ => return Err(anyhow!("Expected an event.")),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::parser::{
+    parser::NameSpace,
+    parser_state::ParserState,
+    parser_traits::{Closeable, Openable, Recognisable},
+    tags::{OpenedTag, Tag},
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagFlowNodeRef {}
+
+impl Recognisable for TagFlowNodeRef {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Lane { .. }) => {
+                    if e.local_name().as_ref() == b"flowNodeRef" {
+                        return Some(Tag::FlowNodeRef);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagFlowNodeRef {
+    fn open_tag(_tag: Tag, _e: &BytesStart, _state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        Ok(OpenedTag::FlowNodeRef {
+            text: String::new(),
+        })
+    }
+}
+
+impl Closeable for TagFlowNodeRef {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Lane { lane }) => {
+                if let OpenedTag::FlowNodeRef { text } = opened_tag {
+                    lane.flow_node_refs.push(text.trim().to_string());
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a flow node reference."));
+                }
+            }
+            _ => return Err(anyhow!("Expected a lane.")),
+        }
+    }
+}
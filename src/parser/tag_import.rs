@@ -0,0 +1,83 @@
+use crate::{
+    import::BPMNImport,
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagImport {}
+
+impl Recognisable for TagImport {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Definitions { .. }) => {
+                    if e.local_name().as_ref() == b"import" {
+                        return Some(Tag::Import);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagImport {
+    fn open_tag(_tag: Tag, e: &BytesStart, _state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        match (
+            parse_attribute(e, "namespace"),
+            parse_attribute(e, "location"),
+            parse_attribute(e, "importType"),
+        ) {
+            (Some(namespace), Some(location), Some(import_type)) => Ok(OpenedTag::Import {
+                namespace,
+                location,
+                import_type,
+            }),
+            _ => Err(anyhow!(
+                "An import must have a namespace, a location and an import type."
+            )),
+        }
+    }
+}
+
+impl Closeable for TagImport {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Definitions { imports, .. }) => {
+                if let OpenedTag::Import {
+                    namespace,
+                    location,
+                    import_type,
+                } = opened_tag
+                {
+                    imports.push(BPMNImport {
+                        namespace,
+                        location,
+                        import_type,
+                        definitions: None,
+                    });
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected an import."));
+                }
+            }
+            _ => return Err(anyhow!("Expected definitions.")),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     element::BPMNElement,
     elements::inclusive_gateway::BPMNInclusiveGateway,
+    importer::parse_attribute,
     parser::{
         parser::NameSpace,
         parser_state::ParserState,
@@ -41,9 +42,13 @@ impl Openable for TagInclusiveGateway {
     {
         let (index, id) = state.read_and_add_id(e)?;
 
+        let default_sequence_flow = parse_attribute(e, "default");
+
         Ok(OpenedTag::InclusiveGateway {
             global_index: index,
             id,
+            name: parse_attribute(e, "name"),
+            default_sequence_flow,
         })
     }
 }
@@ -53,12 +58,20 @@ impl Closeable for TagInclusiveGateway {
         match state.open_tags.iter_mut().last() {
             Some(OpenedTag::Process { elements, .. })
             | Some(OpenedTag::SubProcess { elements, .. }) => {
-                if let OpenedTag::InclusiveGateway { global_index, id } = opened_tag {
+                if let OpenedTag::InclusiveGateway {
+                    global_index,
+                    id,
+                    name,
+                    default_sequence_flow,
+                } = opened_tag
+                {
                     let local_index = elements.len();
                     elements.push(BPMNElement::InclusiveGateway(BPMNInclusiveGateway {
                         global_index,
                         id,
                         local_index,
+                        name,
+                        default_sequence_flow,
                         incoming_sequence_flows: vec![],
                         outgoing_sequence_flows: vec![],
                     }));
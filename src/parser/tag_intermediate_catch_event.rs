@@ -2,9 +2,12 @@ use crate::{
     element::BPMNElement,
     elements::{
         intermediate_catch_event::BPMNIntermediateCatchEvent,
+        link_intermediate_catch_event::BPMNLinkIntermediateCatchEvent,
         message_intermediate_catch_event::BPMNMessageIntermediateCatchEvent,
+        signal_intermediate_catch_event::BPMNSignalIntermediateCatchEvent,
         timer_intermediate_catch_event::BPMNTimerIntermediateCatchEvent,
     },
+    importer::parse_attribute,
     parser::{
         parser::NameSpace,
         parser_state::ParserState,
@@ -48,8 +51,15 @@ impl Openable for TagIntermediateCatchEvent {
         Ok(OpenedTag::IntermediateCatchEvent {
             global_index: index,
             id,
+            name: parse_attribute(e, "name"),
+            link_marker_id: None,
+            link_name: None,
             message_marker_id: None,
+            message_ref: None,
+            signal_marker_id: None,
+            signal_ref: None,
             timer_marker_id: None,
+            timer: None,
         })
     }
 }
@@ -62,52 +72,105 @@ impl Closeable for TagIntermediateCatchEvent {
                 if let OpenedTag::IntermediateCatchEvent {
                     global_index,
                     id,
+                    name,
+                    link_marker_id,
+                    link_name,
                     message_marker_id,
+                    message_ref,
+                    signal_marker_id,
+                    signal_ref,
                     timer_marker_id,
+                    timer,
                 } = opened_tag
                 {
                     let local_index = elements.len();
-                    match (message_marker_id, timer_marker_id) {
-                        (None, None) => {
+                    match (
+                        link_marker_id,
+                        message_marker_id,
+                        signal_marker_id,
+                        timer_marker_id,
+                    ) {
+                        (None, None, None, None) => {
                             //no marker
                             elements.push(BPMNElement::IntermediateCatchEvent(
                                 BPMNIntermediateCatchEvent {
                                     global_index,
                                     id,
                                     local_index,
+                                    name,
                                     incoming_sequence_flows: vec![],
                                     outgoing_sequence_flows: vec![],
                                 },
                             ));
                         }
-                        (None, Some(timer_marker_id)) => {
+                        (None, None, None, Some(timer_marker_id)) => {
                             //timer marker
                             elements.push(BPMNElement::TimerIntermediateCatchEvent(
                                 BPMNTimerIntermediateCatchEvent {
                                     global_index,
                                     id,
                                     local_index,
+                                    name,
                                     timer_marker_id,
+                                    timer,
                                     incoming_sequence_flows: vec![],
                                     outgoing_sequence_flows: vec![],
                                 },
                             ));
                         }
-                        (Some(message_marker_id), None) => {
+                        (None, Some(message_marker_id), None, None) => {
                             //message marker
                             elements.push(BPMNElement::MessageIntermediateCatchEvent(
                                 BPMNMessageIntermediateCatchEvent {
                                     global_index,
                                     id,
                                     local_index,
+                                    name,
                                     message_marker_id,
+                                    message_ref,
                                     incoming_sequence_flows: vec![],
                                     outgoing_sequence_flows: vec![],
                                     incoming_message_flow: None,
                                 },
                             ));
                         }
-                        (Some(_), Some(_)) => todo!(),
+                        (None, None, Some(signal_marker_id), None) => {
+                            //signal marker
+                            elements.push(BPMNElement::SignalIntermediateCatchEvent(
+                                BPMNSignalIntermediateCatchEvent {
+                                    global_index,
+                                    id,
+                                    local_index,
+                                    name,
+                                    signal_marker_id,
+                                    signal_ref,
+                                    incoming_sequence_flows: vec![],
+                                    outgoing_sequence_flows: vec![],
+                                },
+                            ));
+                        }
+                        (Some(link_marker_id), None, None, None) => {
+                            //link marker
+                            let Some(link_name) = link_name else {
+                                return Err(anyhow!("Link event `{}` has no name.", id));
+                            };
+                            elements.push(BPMNElement::LinkIntermediateCatchEvent(
+                                BPMNLinkIntermediateCatchEvent {
+                                    global_index,
+                                    id,
+                                    local_index,
+                                    name,
+                                    link_marker_id,
+                                    link_name,
+                                    outgoing_sequence_flows: vec![],
+                                },
+                            ));
+                        }
+                        _ => {
+                            return Err(anyhow!(
+                                "an intermediate catch event can have only one of a timer, a message, a signal and a link event definition"
+                            ));
+                        }
                     }
                     Ok(())
                 } else {
@@ -2,8 +2,11 @@ use crate::{
     element::BPMNElement,
     elements::{
         intermediate_throw_event::BPMNIntermediateThrowEvent,
+        link_intermediate_throw_event::BPMNLinkIntermediateThrowEvent,
         message_intermediate_throw_event::BPMNMessageIntermediateThrowEvent,
+        signal_intermediate_throw_event::BPMNSignalIntermediateThrowEvent,
     },
+    importer::parse_attribute,
     parser::{
         parser::NameSpace,
         parser_state::ParserState,
@@ -47,7 +50,13 @@ impl Openable for TagIntermediateThrowEvent {
         Ok(OpenedTag::IntermediateThrowEvent {
             global_index: index,
             id,
+            name: parse_attribute(e, "name"),
+            link_marker_id: None,
+            link_name: None,
             message_marker_id: None,
+            message_ref: None,
+            signal_marker_id: None,
+            signal_ref: None,
         })
     }
 }
@@ -60,28 +69,80 @@ impl Closeable for TagIntermediateThrowEvent {
                 if let OpenedTag::IntermediateThrowEvent {
                     global_index,
                     id,
+                    name,
+                    link_marker_id,
+                    link_name,
                     message_marker_id,
+                    message_ref,
+                    signal_marker_id,
+                    signal_ref,
                 } = opened_tag
                 {
                     let local_index = elements.len();
-                    if let Some(message_marker_id) = message_marker_id {
+                    if [
+                        link_marker_id.is_some(),
+                        message_marker_id.is_some(),
+                        signal_marker_id.is_some(),
+                    ]
+                    .iter()
+                    .filter(|x| **x)
+                    .count()
+                        > 1
+                    {
+                        return Err(anyhow!(
+                            "Intermediate throw event `{}` has multiple event definitions, which is not supported.",
+                            id
+                        ));
+                    }
+                    if let Some(link_marker_id) = link_marker_id {
+                        let Some(link_name) = link_name else {
+                            return Err(anyhow!("Link event `{}` has no name.", id));
+                        };
+                        elements.push(BPMNElement::LinkIntermediateThrowEvent(
+                            BPMNLinkIntermediateThrowEvent {
+                                global_index,
+                                id,
+                                local_index,
+                                name,
+                                link_marker_id,
+                                link_name,
+                                incoming_sequence_flows: vec![],
+                            },
+                        ));
+                    } else if let Some(message_marker_id) = message_marker_id {
                         elements.push(BPMNElement::MessageIntermediateThrowEvent(
                             BPMNMessageIntermediateThrowEvent {
                                 global_index,
                                 id,
                                 local_index,
+                                name,
                                 message_marker_id,
+                                message_ref,
                                 incoming_sequence_flows: vec![],
                                 outgoing_sequence_flows: vec![],
                                 outgoing_message_flow: None,
                             },
                         ));
+                    } else if let Some(signal_marker_id) = signal_marker_id {
+                        elements.push(BPMNElement::SignalIntermediateThrowEvent(
+                            BPMNSignalIntermediateThrowEvent {
+                                global_index,
+                                id,
+                                local_index,
+                                name,
+                                signal_marker_id,
+                                signal_ref,
+                                incoming_sequence_flows: vec![],
+                                outgoing_sequence_flows: vec![],
+                            },
+                        ));
                     } else {
                         elements.push(BPMNElement::IntermediateThrowEvent(
                             BPMNIntermediateThrowEvent {
                                 global_index,
                                 id,
                                 local_index,
+                                name,
                                 incoming_sequence_flows: vec![],
                                 outgoing_sequence_flows: vec![],
                             },
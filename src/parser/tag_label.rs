@@ -0,0 +1,60 @@
+use crate::{
+    diagram::BPMNLabel,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagLabel {}
+
+impl Recognisable for TagLabel {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmndi() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Shape { .. }) | Some(OpenedTag::Edge { .. }) => {
+                    if e.local_name().as_ref() == b"BPMNLabel" {
+                        return Some(Tag::Label);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagLabel {
+    fn open_tag(_tag: Tag, _e: &BytesStart, _state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        Ok(OpenedTag::Label { bounds: None })
+    }
+}
+
+impl Closeable for TagLabel {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        if let OpenedTag::Label { bounds } = opened_tag {
+            match state.open_tags.iter_mut().last() {
+                Some(OpenedTag::Shape { label, .. }) | Some(OpenedTag::Edge { label, .. }) => {
+                    //a label without bounds is positioned by the modeller, but is kept nonetheless
+                    *label = Some(BPMNLabel { bounds });
+                    Ok(())
+                }
+                _ => Err(anyhow!("Expected a shape or an edge.")),
+            }
+        } else {
+            Err(anyhow!("Expected a label."))
+        }
+    }
+}
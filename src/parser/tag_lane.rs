@@ -0,0 +1,71 @@
+use crate::{
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tag_lane_set::DraftLane,
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagLane {}
+
+impl Recognisable for TagLane {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::LaneSet { .. }) => {
+                    if e.local_name().as_ref() == b"lane" {
+                        return Some(Tag::Lane);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagLane {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let (global_index, id) = state.read_and_add_id(e)?;
+        let name = parse_attribute(e, "name");
+
+        Ok(OpenedTag::Lane {
+            lane: DraftLane {
+                global_index,
+                id,
+                name,
+                flow_node_refs: vec![],
+                child_lane_set: None,
+            },
+        })
+    }
+}
+
+impl Closeable for TagLane {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::LaneSet { lane_set }) => {
+                if let OpenedTag::Lane { lane } = opened_tag {
+                    lane_set.lanes.push(lane);
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a lane."));
+                }
+            }
+            _ => return Err(anyhow!("Expected a lane set.")),
+        }
+    }
+}
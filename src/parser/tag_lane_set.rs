@@ -0,0 +1,163 @@
+use crate::{
+    element::BPMNElement,
+    error::BPMNError,
+    importer::parse_attribute,
+    lane::{BPMNLane, BPMNLaneSet},
+    parser::{
+        parser::NameSpace,
+        parser_state::{GlobalIndex, ParserState},
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+    traits::searchable::Searchable,
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+/// The `laneSet` tag of a process and the `childLaneSet` tag of a lane.
+pub(crate) struct TagLaneSet {}
+
+impl Recognisable for TagLaneSet {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Process { .. }) => {
+                    if e.local_name().as_ref() == b"laneSet" {
+                        return Some(Tag::LaneSet);
+                    }
+                }
+                Some(OpenedTag::Lane { .. }) => {
+                    if e.local_name().as_ref() == b"childLaneSet" {
+                        return Some(Tag::LaneSet);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagLaneSet {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let id = state.read_and_add_id_optional(e);
+        let name = parse_attribute(e, "name");
+
+        Ok(OpenedTag::LaneSet {
+            lane_set: DraftLaneSet {
+                id,
+                name,
+                lanes: vec![],
+            },
+        })
+    }
+}
+
+impl Closeable for TagLaneSet {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        if let OpenedTag::LaneSet { lane_set } = opened_tag {
+            match state.open_tags.iter_mut().last() {
+                Some(OpenedTag::Process { lane_sets, .. }) => {
+                    lane_sets.push(lane_set);
+                    Ok(())
+                }
+                Some(OpenedTag::Lane { lane }) => {
+                    if lane.child_lane_set.is_some() {
+                        return Err(anyhow!(
+                            "Lane `{}` cannot have two child lane sets.",
+                            lane.id
+                        ));
+                    }
+                    lane.child_lane_set = Some(lane_set);
+                    Ok(())
+                }
+                _ => Err(anyhow!("Expected a process or a lane.")),
+            }
+        } else {
+            Err(anyhow!("Expected a lane set."))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct DraftLaneSet {
+    pub(crate) id: Option<String>,
+    pub(crate) name: Option<String>,
+    pub(crate) lanes: Vec<DraftLane>,
+}
+
+#[derive(Debug)]
+pub(crate) struct DraftLane {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) name: Option<String>,
+    pub(crate) flow_node_refs: Vec<String>,
+    pub(crate) child_lane_set: Option<DraftLaneSet>,
+}
+
+impl DraftLaneSet {
+    /// Resolves the references of the lanes to the elements of their process.
+    /// References to elements that are not supported, and hence ignored, are dropped.
+    pub(crate) fn resolve(
+        self,
+        elements: &[BPMNElement],
+        state: &ParserState,
+    ) -> Result<BPMNLaneSet> {
+        let DraftLaneSet { id, name, lanes } = self;
+        let mut result = Vec::with_capacity(lanes.len());
+        for draft_lane in lanes {
+            let DraftLane {
+                global_index,
+                id: lane_id,
+                name: lane_name,
+                flow_node_refs: flow_node_ids,
+                child_lane_set,
+            } = draft_lane;
+
+            let mut flow_node_refs = Vec::with_capacity(flow_node_ids.len());
+            for flow_node_id in flow_node_ids {
+                if let Some((_, flow_node_ref)) = elements
+                    .iter()
+                    .find_map(|element| element.id_2_pool_and_global_index(&flow_node_id))
+                {
+                    flow_node_refs.push(flow_node_ref);
+                } else if !state.not_recognised_id_2_tag.contains_key(&flow_node_id) {
+                    return Err(BPMNError::UnknownId {
+                        message: format!(
+                            "Could not find element `{}` of lane `{}`.",
+                            flow_node_id, lane_id
+                        ),
+                        id: flow_node_id.clone(),
+                    }
+                    .into());
+                }
+            }
+
+            let child_lane_set = match child_lane_set {
+                Some(child_lane_set) => Some(child_lane_set.resolve(elements, state)?),
+                None => None,
+            };
+
+            result.push(BPMNLane {
+                global_index,
+                id: lane_id,
+                name: lane_name,
+                flow_node_refs,
+                child_lane_set,
+            });
+        }
+        Ok(BPMNLaneSet {
+            id,
+            name,
+            lanes: result,
+        })
+    }
+}
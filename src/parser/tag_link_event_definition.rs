@@ -0,0 +1,80 @@
+use crate::{
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagLinkEventDefinition {}
+
+impl Recognisable for TagLinkEventDefinition {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::IntermediateCatchEvent { .. })
+                | Some(OpenedTag::IntermediateThrowEvent { .. }) => {
+                    if e.local_name().as_ref() == b"linkEventDefinition" {
+                        return Some(Tag::LinkEventDefinition);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagLinkEventDefinition {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let id = state.read_and_add_id_optional(e);
+        let name = parse_attribute(e, "name");
+
+        Ok(OpenedTag::LinkEventDefinition { id, name })
+    }
+}
+
+impl Closeable for TagLinkEventDefinition {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        let index = state.open_tags.len() - 1;
+        match state.open_tags.get_mut(index) {
+            Some(OpenedTag::IntermediateCatchEvent {
+                id: event_id,
+                link_marker_id: marker_id,
+                link_name,
+                ..
+            })
+            | Some(OpenedTag::IntermediateThrowEvent {
+                id: event_id,
+                link_marker_id: marker_id,
+                link_name,
+                ..
+            }) => {
+                if let OpenedTag::LinkEventDefinition { id, name } = opened_tag {
+                    //links are paired by name, so a link without a name cannot be followed
+                    let name = name.filter(|name| !name.is_empty()).ok_or_else(|| {
+                        anyhow!("Link event definition of event `{}` has no name.", event_id)
+                    })?;
+                    *marker_id = Some(id);
+                    *link_name = Some(name);
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a link event definition."));
+                }
+            }
+            _ => return Err(anyhow!("Expected an event.")),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::parser::{
+    parser::NameSpace,
+    parser_state::ParserState,
+    parser_traits::{Closeable, Openable, Recognisable},
+    tags::{OpenedTag, Tag},
+};
+use anyhow::{Context, Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagLoopCardinality {}
+
+impl Recognisable for TagLoopCardinality {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::MultiInstanceLoopCharacteristics { .. }) => {
+                    if e.local_name().as_ref() == b"loopCardinality" {
+                        return Some(Tag::LoopCardinality);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagLoopCardinality {
+    fn open_tag(_tag: Tag, _e: &BytesStart, _state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        Ok(OpenedTag::LoopCardinality {
+            text: String::new(),
+        })
+    }
+}
+
+impl Closeable for TagLoopCardinality {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::MultiInstanceLoopCharacteristics {
+                loop_cardinality, ..
+            }) => {
+                if let OpenedTag::LoopCardinality { text } = opened_tag {
+                    let cardinality = text.trim().parse::<u64>().with_context(|| {
+                        anyhow!(
+                            "Parsing loop cardinality `{}` as a non-negative integer; only constant loop cardinalities are supported.",
+                            text.trim()
+                        )
+                    })?;
+                    *loop_cardinality = Some(cardinality);
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a loop cardinality."));
+                }
+            }
+            _ => return Err(anyhow!("Expected multi-instance loop characteristics.")),
+        }
+    }
+}
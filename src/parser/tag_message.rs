@@ -0,0 +1,78 @@
+use crate::{
+    importer::parse_attribute,
+    message::BPMNMessage,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagMessage {}
+
+impl Recognisable for TagMessage {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Definitions { .. }) => {
+                    if e.local_name().as_ref() == b"message" {
+                        return Some(Tag::Message);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagMessage {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let (global_index, id) = state.read_and_add_id(e)?;
+        let name = parse_attribute(e, "name");
+
+        Ok(OpenedTag::Message {
+            global_index,
+            id,
+            name,
+        })
+    }
+}
+
+impl Closeable for TagMessage {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Definitions { messages, .. }) => {
+                if let OpenedTag::Message {
+                    global_index,
+                    id,
+                    name,
+                } = opened_tag
+                {
+                    let local_index = messages.len();
+                    messages.push(BPMNMessage {
+                        global_index,
+                        id,
+                        local_index,
+                        name,
+                    });
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a message."));
+                }
+            }
+            _ => return Err(anyhow!("Expected definitions.")),
+        }
+    }
+}
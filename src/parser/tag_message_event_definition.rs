@@ -1,8 +1,11 @@
-use crate::parser::{
-    parser::NameSpace,
-    parser_state::ParserState,
-    parser_traits::{Closeable, Openable, Recognisable},
-    tags::{OpenedTag, Tag},
+use crate::{
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
 };
 use anyhow::{Result, anyhow};
 use quick_xml::events::{BytesEnd, BytesStart};
@@ -16,7 +19,8 @@ impl Recognisable for TagMessageEventDefinition {
     {
         if n.is_bpmn() {
             match state.open_tags.iter().last() {
-                Some(OpenedTag::StartEvent { .. })
+                Some(OpenedTag::BoundaryEvent { .. })
+                | Some(OpenedTag::StartEvent { .. })
                 | Some(OpenedTag::EndEvent { .. })
                 | Some(OpenedTag::IntermediateCatchEvent { .. })
                 | Some(OpenedTag::IntermediateThrowEvent { .. }) => {
@@ -39,8 +43,9 @@ impl Openable for TagMessageEventDefinition {
         Self: Sized,
     {
         let id = state.read_and_add_id_optional(e);
+        let message_ref = parse_attribute(e, "messageRef");
 
-        Ok(OpenedTag::MessageEventDefinition { id })
+        Ok(OpenedTag::MessageEventDefinition { id, message_ref })
     }
 }
 
@@ -48,24 +53,34 @@ impl Closeable for TagMessageEventDefinition {
     fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
         let index = state.open_tags.len() - 1;
         match state.open_tags.get_mut(index) {
-            Some(OpenedTag::StartEvent {
+            Some(OpenedTag::BoundaryEvent {
                 message_marker_id: message_id,
+                message_ref: event_message_ref,
+                ..
+            })
+            | Some(OpenedTag::StartEvent {
+                message_marker_id: message_id,
+                message_ref: event_message_ref,
                 ..
             })
             | Some(OpenedTag::EndEvent {
                 message_marker_id: message_id,
+                message_ref: event_message_ref,
                 ..
             })
             | Some(OpenedTag::IntermediateCatchEvent {
                 message_marker_id: message_id,
+                message_ref: event_message_ref,
                 ..
             })
             | Some(OpenedTag::IntermediateThrowEvent {
                 message_marker_id: message_id,
+                message_ref: event_message_ref,
                 ..
             }) => {
-                if let OpenedTag::MessageEventDefinition { id } = opened_tag {
+                if let OpenedTag::MessageEventDefinition { id, message_ref } = opened_tag {
                     *message_id = Some(id);
+                    *event_message_ref = message_ref;
                     Ok(())
                 } else {
                     return Err(anyhow!("Expected a message event definition."));
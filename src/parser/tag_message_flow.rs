@@ -46,8 +46,10 @@ impl Openable for TagMessageFlow {
                 Ok(OpenedTag::MessageFlow {
                     global_index: index,
                     id,
+                    name: parse_attribute(e, "name"),
                     source_id: source_ref,
                     target_id: target_ref,
+                    message_ref: parse_attribute(e, "messageRef"),
                 })
             } else {
                 Err(anyhow!("message flow must have a target"))
@@ -66,15 +68,19 @@ impl Closeable for TagMessageFlow {
             if let OpenedTag::MessageFlow {
                 global_index,
                 id,
+                name,
                 source_id,
                 target_id,
+                message_ref,
             } = opened_tag
             {
                 message_flows.push(DraftMessageFlow {
                     global_index,
                     id,
+                    name,
                     source_id,
                     target_id,
+                    message_ref,
                 });
                 Ok(())
             } else {
@@ -90,6 +96,8 @@ impl Closeable for TagMessageFlow {
 pub(crate) struct DraftMessageFlow {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
+    pub(crate) name: Option<String>,
     pub(crate) source_id: String,
     pub(crate) target_id: String,
+    pub(crate) message_ref: Option<String>,
 }
@@ -0,0 +1,151 @@
+use crate::{
+    importer::parse_attribute,
+    multi_instance::BPMNMultiInstance,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagMultiInstanceLoopCharacteristics {}
+
+impl Recognisable for TagMultiInstanceLoopCharacteristics {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Task { .. })
+                | Some(OpenedTag::UserTask { .. })
+                | Some(OpenedTag::ManualTask { .. })
+                | Some(OpenedTag::ReceiveTask { .. })
+                | Some(OpenedTag::BusinessRuleTask { .. })
+                | Some(OpenedTag::ScriptTask { .. })
+                | Some(OpenedTag::SendTask { .. })
+                | Some(OpenedTag::ServiceTask { .. })
+                | Some(OpenedTag::SubProcess { .. }) => {
+                    if e.local_name().as_ref() == b"multiInstanceLoopCharacteristics" {
+                        return Some(Tag::MultiInstanceLoopCharacteristics);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagMultiInstanceLoopCharacteristics {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let id = state.read_and_add_id_optional(e);
+        let is_sequential = match parse_attribute(e, "isSequential").as_deref() {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(value) => {
+                return Err(anyhow!(
+                    "Attribute isSequential should be `true` or `false`, but found `{}`.",
+                    value
+                ));
+            }
+        };
+
+        Ok(OpenedTag::MultiInstanceLoopCharacteristics {
+            id,
+            is_sequential,
+            loop_cardinality: None,
+        })
+    }
+}
+
+impl Closeable for TagMultiInstanceLoopCharacteristics {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Task {
+                multi_instance,
+                standard_loop,
+                ..
+            })
+            | Some(OpenedTag::UserTask {
+                multi_instance,
+                standard_loop,
+                ..
+            })
+            | Some(OpenedTag::ManualTask {
+                multi_instance,
+                standard_loop,
+                ..
+            })
+            | Some(OpenedTag::ReceiveTask {
+                multi_instance,
+                standard_loop,
+                ..
+            })
+            | Some(OpenedTag::BusinessRuleTask {
+                multi_instance,
+                standard_loop,
+                ..
+            })
+            | Some(OpenedTag::ScriptTask {
+                multi_instance,
+                standard_loop,
+                ..
+            })
+            | Some(OpenedTag::SendTask {
+                multi_instance,
+                standard_loop,
+                ..
+            })
+            | Some(OpenedTag::ServiceTask {
+                multi_instance,
+                standard_loop,
+                ..
+            })
+            | Some(OpenedTag::SubProcess {
+                multi_instance,
+                standard_loop,
+                ..
+            }) => {
+                if let OpenedTag::MultiInstanceLoopCharacteristics {
+                    id,
+                    is_sequential,
+                    loop_cardinality,
+                } = opened_tag
+                {
+                    //only a constant number of instances is supported
+                    let loop_cardinality = loop_cardinality.ok_or_else(|| {
+                        anyhow!("Multi-instance loop characteristics without a loop cardinality are not supported.")
+                    })?;
+                    if multi_instance.is_some() {
+                        return Err(anyhow!(
+                            "Cannot assign two multi-instance loop characteristics to an activity."
+                        ));
+                    }
+                    if standard_loop.is_some() {
+                        return Err(anyhow!(
+                            "Cannot assign two loop characteristics to an activity."
+                        ));
+                    }
+                    *multi_instance = Some(BPMNMultiInstance {
+                        id,
+                        is_sequential,
+                        loop_cardinality,
+                    });
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected multi-instance loop characteristics."));
+                }
+            }
+            _ => return Err(anyhow!("Expected an activity.")),
+        }
+    }
+}
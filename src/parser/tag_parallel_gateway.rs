@@ -1,6 +1,7 @@
 use crate::{
     element::BPMNElement,
     elements::parallel_gateway::BPMNParallelGateway,
+    importer::parse_attribute,
     parser::{
         parser::NameSpace,
         parser_state::ParserState,
@@ -44,6 +45,7 @@ impl Openable for TagParallelGateway {
         Ok(OpenedTag::ParallelGateway {
             global_index: index,
             id,
+            name: parse_attribute(e, "name"),
         })
     }
 }
@@ -53,12 +55,18 @@ impl Closeable for TagParallelGateway {
         match state.open_tags.iter_mut().last() {
             Some(OpenedTag::Process { elements, .. })
             | Some(OpenedTag::SubProcess { elements, .. }) => {
-                if let OpenedTag::ParallelGateway { global_index, id } = opened_tag {
+                if let OpenedTag::ParallelGateway {
+                    global_index,
+                    id,
+                    name,
+                } = opened_tag
+                {
                     let local_index = elements.len();
                     elements.push(BPMNElement::ParallelGateway(BPMNParallelGateway {
                         global_index,
                         id,
                         local_index,
+                        name,
                         incoming_sequence_flows: vec![],
                         outgoing_sequence_flows: vec![],
                     }));
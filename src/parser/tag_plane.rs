@@ -0,0 +1,79 @@
+use crate::{
+    diagram::BPMNPlane,
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagPlane {}
+
+impl Recognisable for TagPlane {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmndi() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Diagram { .. }) => {
+                    if e.local_name().as_ref() == b"BPMNPlane" {
+                        return Some(Tag::Plane);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagPlane {
+    fn open_tag(_tag: Tag, e: &BytesStart, _state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        Ok(OpenedTag::Plane {
+            id: parse_attribute(e, "id"),
+            bpmn_element: parse_attribute(e, "bpmnElement"),
+            shapes: vec![],
+            edges: vec![],
+        })
+    }
+}
+
+impl Closeable for TagPlane {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Diagram { plane, .. }) => {
+                if let OpenedTag::Plane {
+                    id,
+                    bpmn_element,
+                    shapes,
+                    edges,
+                } = opened_tag
+                {
+                    if plane.is_some() {
+                        return Err(anyhow!("A diagram can have only one plane."));
+                    }
+                    *plane = Some(BPMNPlane {
+                        id,
+                        bpmn_element,
+                        shapes,
+                        edges,
+                    });
+                    Ok(())
+                } else {
+                    Err(anyhow!("Expected a plane."))
+                }
+            }
+            _ => Err(anyhow!("Expected a diagram.")),
+        }
+    }
+}
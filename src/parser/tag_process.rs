@@ -1,11 +1,13 @@
 use crate::{
     element::{BPMNElement, BPMNElementTrait},
     elements::process::BPMNProcess,
+    error::BPMNError,
     importer::parse_attribute,
     parser::{
         parser::NameSpace,
         parser_state::ParserState,
         parser_traits::{Closeable, Openable, Recognisable},
+        tag_boundary_event::resolve_boundary_events,
         tag_sequence_flow::DraftSequenceFlow,
         tags::{OpenedTag, Tag},
     },
@@ -53,6 +55,7 @@ impl Openable for TagProcess {
             name,
             elements: vec![],
             draft_sequence_flows: vec![],
+            lane_sets: vec![],
         })
     }
 }
@@ -65,9 +68,11 @@ macro_rules! process_internal_sequence_flows {
                 let DraftSequenceFlow {
                     global_index,
                     id,
+                    name,
                     source_id,
                     target_id,
-                    weight
+                    weight,
+                    condition_expression
                 } = draft_sequence_flow;
                 let new_flow_index = sequence_flows.len();
                 let source_local_index = $sub_elements
@@ -75,18 +80,24 @@ macro_rules! process_internal_sequence_flows {
                     .ok_or_else(|| {
                         //attempt to give a more helpful error with other found tags
                         if let Some(tag) = $state.not_recognised_id_2_tag.get(&source_id) {
-                            anyhow!(
-                                "Could not find source `{}` of sequence flow `{}`.\nHowever, a tag with name `{}` was found with this id. That tag is perhaps not supported or is not in an expected location.",
-                                source_id,
-                                id,
-                                tag
-                            )
+                            BPMNError::UnknownId {
+                                message: format!(
+                                    "Could not find source `{}` of sequence flow `{}`.\nHowever, a tag with name `{}` was found with this id. That tag is perhaps not supported or is not in an expected location.",
+                                    source_id,
+                                    id,
+                                    tag
+                                ),
+                                id: source_id.clone(),
+                            }
                         } else {
-                            anyhow!(
-                                "Could not find source `{}` of sequence flow `{}`.",
-                                source_id,
-                                id
-                            )
+                            BPMNError::UnknownId {
+                                message: format!(
+                                    "Could not find source `{}` of sequence flow `{}`.",
+                                    source_id,
+                                    id
+                                ),
+                                id: source_id.clone(),
+                            }
                         }
                     })?;
                 //register the sequence flow in the source element
@@ -103,11 +114,14 @@ macro_rules! process_internal_sequence_flows {
                 source
                     .add_outgoing_sequence_flow(new_flow_index)
                     .with_context(|| {
-                        anyhow!(
-                            "Could not add sequence flow `{}` to its source element `{}`.",
-                            id,
-                            source_id,
-                        )
+                        BPMNError::StructuralViolation {
+                            message: format!(
+                                "Could not add sequence flow `{}` to its source element `{}`.",
+                                id,
+                                source_id,
+                            ),
+                            element_ids: vec![id.clone(), source_id.clone()],
+                        }
                     })?;
                 let source_global_index = source.global_index();
 
@@ -116,18 +130,24 @@ macro_rules! process_internal_sequence_flows {
                     .ok_or_else(|| {
                         //attempt to give a more helpful error message
                         if let Some(tag) = $state.not_recognised_id_2_tag.get(&target_id) {
-                            anyhow!(
-                                "Could not find target `{}` of sequence flow `{}`.\nHowever, a tag with name `{}` was found with this id. That tag is perhaps not supported or is not in an expected location.",
-                                target_id,
-                                id,
-                                tag
-                            )
+                            BPMNError::UnknownId {
+                                message: format!(
+                                    "Could not find target `{}` of sequence flow `{}`.\nHowever, a tag with name `{}` was found with this id. That tag is perhaps not supported or is not in an expected location.",
+                                    target_id,
+                                    id,
+                                    tag
+                                ),
+                                id: target_id.clone(),
+                            }
                         } else {
-                        anyhow!(
-                            "Could not find target `{}` of sequence flow `{}`.",
-                            target_id,
-                            id
-                        )
+                        BPMNError::UnknownId {
+                            message: format!(
+                                "Could not find target `{}` of sequence flow `{}`.",
+                                target_id,
+                                id
+                            ),
+                            id: target_id.clone(),
+                        }
                     }
                     })?;
                 //register the sequence flow in the target element
@@ -144,22 +164,27 @@ macro_rules! process_internal_sequence_flows {
                 target
                     .add_incoming_sequence_flow(new_flow_index)
                     .with_context(|| {
-                        anyhow!(
-                            "Could not add sequence flow `{}` to its target element `{}`",
-                            id,
-                            target_id,
-                        )
+                        BPMNError::StructuralViolation {
+                            message: format!(
+                                "Could not add sequence flow `{}` to its target element `{}`",
+                                id,
+                                target_id,
+                            ),
+                            element_ids: vec![id.clone(), target_id.clone()],
+                        }
                     })?;
 
                 sequence_flows.push(BPMNSequenceFlow {
                     global_index,
                     id,
                     local_index: new_flow_index,
+                    name,
                     source_global_index,
                     source_local_index,
                     target_global_index,
                     target_local_index,
-                    weight
+                    weight,
+                    condition_expression
                 });
             }
             sequence_flows
@@ -176,6 +201,7 @@ impl Closeable for TagProcess {
             name,
             elements: mut sub_elements,
             draft_sequence_flows,
+            lane_sets: draft_lane_sets,
         } = opened_tag
         {
             if let Some(OpenedTag::Definitions {
@@ -187,6 +213,15 @@ impl Closeable for TagProcess {
                 let sequence_flows =
                     process_internal_sequence_flows!(draft_sequence_flows, sub_elements, state);
 
+                //attach boundary events to their hosts
+                resolve_boundary_events(&mut sub_elements)?;
+
+                //assign elements to lanes
+                let mut lane_sets = Vec::with_capacity(draft_lane_sets.len());
+                for draft_lane_set in draft_lane_sets {
+                    lane_sets.push(draft_lane_set.resolve(&sub_elements, state)?);
+                }
+
                 //create a process
                 let local_index = super_elements.len();
                 super_elements.push(BPMNElement::Process(BPMNProcess {
@@ -198,6 +233,8 @@ impl Closeable for TagProcess {
                     participant_id: None,
                     elements: sub_elements,
                     sequence_flows,
+                    lane_sets,
+                    is_called: false,
                 }));
                 Ok(())
             } else {
@@ -48,6 +48,8 @@ impl Openable for TagReceiveTask {
             global_index: index,
             id,
             activity,
+            multi_instance: None,
+            standard_loop: None,
         })
     }
 }
@@ -65,6 +67,8 @@ impl Closeable for TagReceiveTask {
                     global_index,
                     id,
                     activity,
+                    multi_instance,
+                    standard_loop,
                 } = opened_tag
                 {
                     let local_index = elements.len();
@@ -76,6 +80,8 @@ impl Closeable for TagReceiveTask {
                         incoming_sequence_flows: vec![],
                         outgoing_sequence_flows: vec![],
                         incoming_message_flow: None,
+                        multi_instance,
+                        standard_loop,
                     }));
                     Ok(())
                 } else {
@@ -1,4 +1,5 @@
 use crate::{
+    expression::BPMNConditionExpression,
     importer::parse_attribute,
     parser::{
         parser::NameSpace,
@@ -46,9 +47,11 @@ impl Openable for TagSequenceFlow {
                 Ok(OpenedTag::SequenceFlow {
                     global_index: index,
                     id,
+                    name: parse_attribute(e, "name"),
                     source_ref,
                     target_ref,
                     weight: None,
+                    condition_expression: None,
                 })
             } else {
                 Err(anyhow!("Sequence flow `{}` does not have a target", id))
@@ -73,17 +76,21 @@ impl Closeable for TagSequenceFlow {
                 if let OpenedTag::SequenceFlow {
                     global_index,
                     id,
+                    name,
                     source_ref,
                     target_ref,
                     weight,
+                    condition_expression,
                 } = opened_tag
                 {
                     draft_sequence_flows.push(DraftSequenceFlow {
                         global_index,
                         id,
+                        name,
                         source_id: source_ref,
                         target_id: target_ref,
                         weight,
+                        condition_expression,
                     });
                     Ok(())
                 } else {
@@ -99,7 +106,9 @@ impl Closeable for TagSequenceFlow {
 pub(crate) struct DraftSequenceFlow {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
+    pub(crate) name: Option<String>,
     pub(crate) source_id: String,
     pub(crate) target_id: String,
     pub(crate) weight: Option<Fraction>,
+    pub(crate) condition_expression: Option<BPMNConditionExpression>,
 }
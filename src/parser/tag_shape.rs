@@ -0,0 +1,95 @@
+use crate::{
+    diagram::BPMNShape,
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagShape {}
+
+impl Recognisable for TagShape {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmndi() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Plane { .. }) => {
+                    if e.local_name().as_ref() == b"BPMNShape" {
+                        return Some(Tag::Shape);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+pub(crate) fn parse_attribute_bool(e: &BytesStart, attribute_name: &str) -> Option<bool> {
+    parse_attribute(e, attribute_name).map(|value| value == "true")
+}
+
+impl Openable for TagShape {
+    fn open_tag(_tag: Tag, e: &BytesStart, _state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let bpmn_element = parse_attribute(e, "bpmnElement")
+            .ok_or_else(|| anyhow!("A shape must have a `bpmnElement` attribute."))?;
+
+        Ok(OpenedTag::Shape {
+            id: parse_attribute(e, "id"),
+            bpmn_element,
+            bounds: None,
+            is_expanded: parse_attribute_bool(e, "isExpanded"),
+            is_horizontal: parse_attribute_bool(e, "isHorizontal"),
+            is_marker_visible: parse_attribute_bool(e, "isMarkerVisible"),
+            label: None,
+        })
+    }
+}
+
+impl Closeable for TagShape {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Plane { shapes, .. }) => {
+                if let OpenedTag::Shape {
+                    id,
+                    bpmn_element,
+                    bounds,
+                    is_expanded,
+                    is_horizontal,
+                    is_marker_visible,
+                    label,
+                } = opened_tag
+                {
+                    let bounds = bounds.ok_or_else(|| {
+                        anyhow!("The shape of element `{}` must have bounds.", bpmn_element)
+                    })?;
+                    shapes.push(BPMNShape {
+                        id,
+                        bpmn_element,
+                        bounds,
+                        is_expanded,
+                        is_horizontal,
+                        is_marker_visible,
+                        label,
+                    });
+                    Ok(())
+                } else {
+                    Err(anyhow!("Expected a shape."))
+                }
+            }
+            _ => Err(anyhow!("Expected a plane.")),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    importer::parse_attribute,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagSignalEventDefinition {}
+
+impl Recognisable for TagSignalEventDefinition {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::StartEvent { .. })
+                | Some(OpenedTag::EndEvent { .. })
+                | Some(OpenedTag::IntermediateCatchEvent { .. })
+                | Some(OpenedTag::IntermediateThrowEvent { .. }) => {
+                    if e.local_name().as_ref() == b"signalEventDefinition" {
+                        return Some(Tag::SignalEventDefinition);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagSignalEventDefinition {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let id = state.read_and_add_id_optional(e);
+        let signal_ref = parse_attribute(e, "signalRef");
+
+        Ok(OpenedTag::SignalEventDefinition { id, signal_ref })
+    }
+}
+
+impl Closeable for TagSignalEventDefinition {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        let index = state.open_tags.len() - 1;
+        match state.open_tags.get_mut(index) {
+            Some(OpenedTag::StartEvent {
+                signal_marker_id: marker_id,
+                signal_ref: event_signal_ref,
+                ..
+            })
+            | Some(OpenedTag::EndEvent {
+                signal_marker_id: marker_id,
+                signal_ref: event_signal_ref,
+                ..
+            })
+            | Some(OpenedTag::IntermediateCatchEvent {
+                signal_marker_id: marker_id,
+                signal_ref: event_signal_ref,
+                ..
+            })
+            | Some(OpenedTag::IntermediateThrowEvent {
+                signal_marker_id: marker_id,
+                signal_ref: event_signal_ref,
+                ..
+            }) => {
+                if let OpenedTag::SignalEventDefinition { id, signal_ref } = opened_tag {
+                    *marker_id = Some(id);
+                    *event_signal_ref = signal_ref;
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a signal event definition."));
+                }
+            }
+            _ => return Err(anyhow!("Expected an event.")),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{
+    importer::{parse_attribute, parse_sbpmn_attribute},
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+    standard_loop::BPMNStandardLoop,
+};
+use anyhow::{Context, Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagStandardLoopCharacteristics {}
+
+impl Recognisable for TagStandardLoopCharacteristics {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Task { .. })
+                | Some(OpenedTag::UserTask { .. })
+                | Some(OpenedTag::ManualTask { .. })
+                | Some(OpenedTag::ReceiveTask { .. })
+                | Some(OpenedTag::BusinessRuleTask { .. })
+                | Some(OpenedTag::ScriptTask { .. })
+                | Some(OpenedTag::SendTask { .. })
+                | Some(OpenedTag::ServiceTask { .. })
+                | Some(OpenedTag::SubProcess { .. }) => {
+                    if e.local_name().as_ref() == b"standardLoopCharacteristics" {
+                        return Some(Tag::StandardLoopCharacteristics);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagStandardLoopCharacteristics {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let id = state.read_and_add_id_optional(e);
+        let test_before = match parse_attribute(e, "testBefore").as_deref() {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(value) => {
+                return Err(anyhow!(
+                    "Attribute testBefore should be `true` or `false`, but found `{}`.",
+                    value
+                ));
+            }
+        };
+        let loop_maximum = match parse_attribute(e, "loopMaximum") {
+            Some(value) => Some(
+                value
+                    .trim()
+                    .parse::<u64>()
+                    .with_context(|| anyhow!("Parsing loop maximum `{}`.", value))?,
+            ),
+            None => None,
+        };
+        let repeat_probability = match parse_sbpmn_attribute(e, state, "repeatProbability") {
+            Some(value) => Some(
+                value
+                    .parse()
+                    .with_context(|| anyhow!("Parsing repeat probability as fraction."))?,
+            ),
+            None => None,
+        };
+
+        Ok(OpenedTag::StandardLoopCharacteristics {
+            standard_loop: BPMNStandardLoop {
+                id,
+                test_before,
+                loop_maximum,
+                repeat_probability,
+            },
+        })
+    }
+}
+
+impl Closeable for TagStandardLoopCharacteristics {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Task {
+                multi_instance,
+                standard_loop: activity_standard_loop,
+                ..
+            })
+            | Some(OpenedTag::UserTask {
+                multi_instance,
+                standard_loop: activity_standard_loop,
+                ..
+            })
+            | Some(OpenedTag::ManualTask {
+                multi_instance,
+                standard_loop: activity_standard_loop,
+                ..
+            })
+            | Some(OpenedTag::ReceiveTask {
+                multi_instance,
+                standard_loop: activity_standard_loop,
+                ..
+            })
+            | Some(OpenedTag::BusinessRuleTask {
+                multi_instance,
+                standard_loop: activity_standard_loop,
+                ..
+            })
+            | Some(OpenedTag::ScriptTask {
+                multi_instance,
+                standard_loop: activity_standard_loop,
+                ..
+            })
+            | Some(OpenedTag::SendTask {
+                multi_instance,
+                standard_loop: activity_standard_loop,
+                ..
+            })
+            | Some(OpenedTag::ServiceTask {
+                multi_instance,
+                standard_loop: activity_standard_loop,
+                ..
+            })
+            | Some(OpenedTag::SubProcess {
+                multi_instance,
+                standard_loop: activity_standard_loop,
+                ..
+            }) => {
+                if let OpenedTag::StandardLoopCharacteristics { standard_loop } = opened_tag {
+                    if multi_instance.is_some() || activity_standard_loop.is_some() {
+                        return Err(anyhow!(
+                            "Cannot assign two loop characteristics to an activity."
+                        ));
+                    }
+                    *activity_standard_loop = Some(standard_loop);
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected standard loop characteristics."));
+                }
+            }
+            _ => return Err(anyhow!("Expected an activity.")),
+        }
+    }
+}
@@ -1,13 +1,16 @@
 use crate::{
     element::BPMNElement,
     elements::{
-        message_start_event::BPMNMessageStartEvent, start_event::BPMNStartEvent,
+        error_start_event::BPMNErrorStartEvent, message_start_event::BPMNMessageStartEvent,
+        signal_start_event::BPMNSignalStartEvent, start_event::BPMNStartEvent,
         timer_start_event::BPMNTimerStartEvent,
     },
+    importer::parse_attribute,
     parser::{
         parser::NameSpace,
         parser_state::ParserState,
         parser_traits::{Closeable, Openable, Recognisable},
+        tag_shape::parse_attribute_bool,
         tags::{OpenedTag, Tag},
     },
 };
@@ -44,11 +47,22 @@ impl Openable for TagStartEvent {
     {
         let (index, id) = state.read_and_add_id(e)?;
 
+        //by default, start events of event sub-processes are interrupting
+        let is_interrupting = parse_attribute_bool(e, "isInterrupting").unwrap_or(true);
+
         Ok(OpenedTag::StartEvent {
             global_index: index,
             id,
+            name: parse_attribute(e, "name"),
+            error_marker_id: None,
+            error_ref: None,
+            is_interrupting,
             message_marker_id: None,
+            message_ref: None,
+            signal_marker_id: None,
+            signal_ref: None,
             timer_marker_id: None,
+            timer: None,
         })
     }
 }
@@ -61,45 +75,96 @@ impl Closeable for TagStartEvent {
                 if let OpenedTag::StartEvent {
                     global_index,
                     id,
+                    name,
+                    error_marker_id,
+                    error_ref,
+                    is_interrupting,
                     message_marker_id,
+                    message_ref,
+                    signal_marker_id,
+                    signal_ref,
                     timer_marker_id,
+                    timer,
                 } = opened_tag
                 {
                     let local_index = elements.len();
-                    match (message_marker_id, timer_marker_id) {
-                        (None, None) => {
+                    match (
+                        error_marker_id,
+                        message_marker_id,
+                        signal_marker_id,
+                        timer_marker_id,
+                    ) {
+                        (None, None, None, None) => {
                             //no marker
                             elements.push(BPMNElement::StartEvent(BPMNStartEvent {
                                 global_index,
                                 id,
                                 local_index,
+                                name,
+                                outgoing_sequence_flows: vec![],
+                            }));
+                        }
+                        (Some(error_marker_id), None, None, None) => {
+                            //error marker
+                            if !is_interrupting {
+                                return Err(anyhow!(
+                                    "Error start event `{}` must be interrupting.",
+                                    id
+                                ));
+                            }
+                            elements.push(BPMNElement::ErrorStartEvent(BPMNErrorStartEvent {
+                                global_index,
+                                id,
+                                local_index,
+                                name,
+                                error_marker_id,
+                                error_ref,
                                 outgoing_sequence_flows: vec![],
                             }));
                         }
-                        (None, Some(timer_marker_id)) => {
+                        (None, None, None, Some(timer_marker_id)) => {
                             //timer marker
                             elements.push(BPMNElement::TimerStartEvent(BPMNTimerStartEvent {
                                 global_index,
                                 id,
                                 local_index,
+                                name,
                                 timer_marker_id,
+                                timer,
                                 outgoing_sequence_flows: vec![],
+                                is_interrupting,
                             }));
                         }
-                        (Some(message_marker_id), None) => {
+                        (None, Some(message_marker_id), None, None) => {
                             //message marker
                             elements.push(BPMNElement::MessageStartEvent(BPMNMessageStartEvent {
                                 global_index,
                                 id,
                                 local_index,
+                                name,
                                 message_marker_id,
+                                message_ref,
                                 outgoing_sequence_flows: vec![],
+                                is_interrupting,
                                 incoming_message_flow: None,
                             }));
                         }
-                        (Some(_), Some(_)) => {
+                        (None, None, Some(signal_marker_id), None) => {
+                            //signal marker
+                            elements.push(BPMNElement::SignalStartEvent(BPMNSignalStartEvent {
+                                global_index,
+                                id,
+                                local_index,
+                                name,
+                                signal_marker_id,
+                                signal_ref,
+                                outgoing_sequence_flows: vec![],
+                                is_interrupting,
+                            }));
+                        }
+                        _ => {
                             return Err(anyhow!(
-                                "a start event cannot be both a timer and a message event"
+                                "a start event can have only one of an error, a timer, a message and a signal event definition"
                             ));
                         }
                     }
@@ -1,16 +1,19 @@
 use crate::{
     element::{BPMNElement, BPMNElementTrait},
     elements::{
-        collapsed_sub_process::BPMNCollapsedSubProcess,
+        collapsed_sub_process::BPMNCollapsedSubProcess, event_sub_process::BPMNEventSubProcess,
         expanded_sub_process::BPMNExpandedSubProcess,
     },
+    error::BPMNError,
     importer::parse_attribute,
     parser::{
         parser::NameSpace,
         parser_state::ParserState,
         parser_traits::{Closeable, Openable, Recognisable},
+        tag_boundary_event::resolve_boundary_events,
         tag_process::process_internal_sequence_flows,
         tag_sequence_flow::DraftSequenceFlow,
+        tag_shape::parse_attribute_bool,
         tags::{OpenedTag, Tag},
     },
     sequence_flow::BPMNSequenceFlow,
@@ -50,12 +53,16 @@ impl Openable for TagSubProcess {
         let (index, id) = state.read_and_add_id(e)?;
 
         let name = parse_attribute(e, "name");
+        let triggered_by_event = parse_attribute_bool(e, "triggeredByEvent").unwrap_or(false);
         Ok(OpenedTag::SubProcess {
             global_index: index,
             id,
             name,
             elements: vec![],
             draft_sequence_flows: vec![],
+            multi_instance: None,
+            standard_loop: None,
+            triggered_by_event,
         })
     }
 }
@@ -68,12 +75,18 @@ impl Closeable for TagSubProcess {
             name,
             mut elements,
             draft_sequence_flows,
+            multi_instance,
+            standard_loop,
+            triggered_by_event,
         } = opened_tag
         {
             //process the internal sequence flows
             let sequence_flows =
                 process_internal_sequence_flows!(draft_sequence_flows, elements, state);
 
+            //attach boundary events to their hosts
+            resolve_boundary_events(&mut elements)?;
+
             match state.open_tags.iter_mut().last() {
                 Some(OpenedTag::Process {
                     elements: super_elements,
@@ -83,7 +96,24 @@ impl Closeable for TagSubProcess {
                     elements: super_elements,
                     ..
                 }) => {
-                    if elements.is_empty() {
+                    if triggered_by_event {
+                        //create an event sub-process
+                        if multi_instance.is_some() || standard_loop.is_some() {
+                            return Err(anyhow!(
+                                "Event sub-process `{}` cannot have loop characteristics.",
+                                id
+                            ));
+                        }
+                        let local_index = super_elements.len();
+                        super_elements.push(BPMNElement::EventSubProcess(BPMNEventSubProcess {
+                            global_index,
+                            id,
+                            local_index,
+                            name,
+                            elements,
+                            sequence_flows,
+                        }));
+                    } else if elements.is_empty() {
                         //create a collapsed sub-process
                         let local_index = super_elements.len();
                         super_elements.push(BPMNElement::CollapsedSubProcess(
@@ -113,6 +143,8 @@ impl Closeable for TagSubProcess {
                                 sequence_flows,
                                 incoming_sequence_flows: vec![],
                                 outgoing_sequence_flows: vec![],
+                                multi_instance,
+                                standard_loop,
                             },
                         ));
                     }
@@ -9,7 +9,7 @@ use crate::{
         tags::{OpenedTag, Tag},
     },
 };
-use anyhow::{anyhow, Result};
+use anyhow::{Result, anyhow};
 use quick_xml::events::{BytesEnd, BytesStart};
 
 pub struct TagTask {}
@@ -48,6 +48,8 @@ impl Openable for TagTask {
             global_index: index,
             id,
             activity,
+            multi_instance: None,
+            standard_loop: None,
         })
     }
 }
@@ -61,6 +63,8 @@ impl Closeable for TagTask {
                     global_index,
                     id,
                     activity,
+                    multi_instance,
+                    standard_loop,
                 } = opened_tag
                 {
                     let local_index = elements.len();
@@ -73,6 +77,8 @@ impl Closeable for TagTask {
                         outgoing_sequence_flows: vec![],
                         incoming_message_flow: None,
                         outgoing_message_flow: None,
+                        multi_instance,
+                        standard_loop,
                     }));
                     Ok(())
                 } else {
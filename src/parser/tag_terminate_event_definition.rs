@@ -0,0 +1,62 @@
+use crate::parser::{
+    parser::NameSpace,
+    parser_state::ParserState,
+    parser_traits::{Closeable, Openable, Recognisable},
+    tags::{OpenedTag, Tag},
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagTerminateEventDefinition {}
+
+impl Recognisable for TagTerminateEventDefinition {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::EndEvent { .. }) => {
+                    if e.local_name().as_ref() == b"terminateEventDefinition" {
+                        return Some(Tag::TerminateEventDefinition);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagTerminateEventDefinition {
+    fn open_tag(_tag: Tag, e: &BytesStart, state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let id = state.read_and_add_id_optional(e);
+
+        Ok(OpenedTag::TerminateEventDefinition { id })
+    }
+}
+
+impl Closeable for TagTerminateEventDefinition {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        let index = state.open_tags.len() - 1;
+        match state.open_tags.get_mut(index) {
+            Some(OpenedTag::EndEvent {
+                terminate_marker_id: terminate_id,
+                ..
+            }) => {
+                if let OpenedTag::TerminateEventDefinition { id } = opened_tag {
+                    *terminate_id = Some(id);
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a terminate event definition."));
+                }
+            }
+            _ => return Err(anyhow!("Expected an end event.")),
+        }
+    }
+}
@@ -16,7 +16,8 @@ impl Recognisable for TagTimerEventDefinition {
     {
         if n.is_bpmn() {
             match state.open_tags.iter().last() {
-                Some(OpenedTag::StartEvent { .. })
+                Some(OpenedTag::BoundaryEvent { .. })
+                | Some(OpenedTag::StartEvent { .. })
                 | Some(OpenedTag::IntermediateCatchEvent { .. }) => {
                     if e.local_name().as_ref() == b"timerEventDefinition" {
                         return Some(Tag::TimerEventDefinition);
@@ -38,7 +39,7 @@ impl Openable for TagTimerEventDefinition {
     {
         let id = state.read_and_add_id_optional(e);
 
-        Ok(OpenedTag::TimerEventDefinition { id })
+        Ok(OpenedTag::TimerEventDefinition { id, timer: None })
     }
 }
 
@@ -46,16 +47,24 @@ impl Closeable for TagTimerEventDefinition {
     fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
         let index = state.open_tags.len() - 1;
         match state.open_tags.get_mut(index) {
-            Some(OpenedTag::StartEvent {
+            Some(OpenedTag::BoundaryEvent {
                 timer_marker_id: timer_id,
+                timer: event_timer,
+                ..
+            })
+            | Some(OpenedTag::StartEvent {
+                timer_marker_id: timer_id,
+                timer: event_timer,
                 ..
             })
             | Some(OpenedTag::IntermediateCatchEvent {
                 timer_marker_id: timer_id,
+                timer: event_timer,
                 ..
             }) => {
-                if let OpenedTag::TimerEventDefinition { id } = opened_tag {
+                if let OpenedTag::TimerEventDefinition { id, timer } = opened_tag {
                     *timer_id = Some(id);
+                    *event_timer = timer;
                     Ok(())
                 } else {
                     return Err(anyhow!("Expected a timer event definition."));
@@ -0,0 +1,77 @@
+use crate::{
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tags::{OpenedTag, Tag},
+    },
+    timer::{BPMNTimer, BPMNTimerType},
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagTimerExpression {}
+
+impl Recognisable for TagTimerExpression {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_bpmn() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::TimerEventDefinition { .. }) => {
+                    if timer_type(e).is_some() {
+                        return Some(Tag::TimerExpression);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagTimerExpression {
+    fn open_tag(_tag: Tag, e: &BytesStart, _state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        let timer_type = timer_type(e).ok_or_else(|| anyhow!("Expected a timer expression."))?;
+        Ok(OpenedTag::TimerExpression {
+            timer_type,
+            text: String::new(),
+        })
+    }
+}
+
+impl Closeable for TagTimerExpression {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::TimerEventDefinition { timer, .. }) => {
+                if let OpenedTag::TimerExpression { timer_type, text } = opened_tag {
+                    if timer.is_some() {
+                        return Err(anyhow!(
+                            "A timer event definition can have only one of a time date, a time duration and a time cycle."
+                        ));
+                    }
+                    *timer = Some(BPMNTimer::new(timer_type, text.trim().to_string()));
+                    Ok(())
+                } else {
+                    return Err(anyhow!("Expected a timer expression."));
+                }
+            }
+            _ => return Err(anyhow!("Expected a timer event definition.")),
+        }
+    }
+}
+
+fn timer_type(e: &BytesStart) -> Option<BPMNTimerType> {
+    match e.local_name().as_ref() {
+        b"timeDate" => Some(BPMNTimerType::Date),
+        b"timeDuration" => Some(BPMNTimerType::Duration),
+        b"timeCycle" => Some(BPMNTimerType::Cycle),
+        _ => None,
+    }
+}
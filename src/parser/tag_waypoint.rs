@@ -0,0 +1,65 @@
+use crate::{
+    diagram::BPMNPoint,
+    parser::{
+        parser::NameSpace,
+        parser_state::ParserState,
+        parser_traits::{Closeable, Openable, Recognisable},
+        tag_bounds::parse_coordinate,
+        tags::{OpenedTag, Tag},
+    },
+};
+use anyhow::{Result, anyhow};
+use quick_xml::events::{BytesEnd, BytesStart};
+
+pub(crate) struct TagWaypoint {}
+
+impl Recognisable for TagWaypoint {
+    fn recognise_tag(e: &BytesStart, state: &ParserState, n: NameSpace) -> Option<Tag>
+    where
+        Self: Sized,
+    {
+        if n.is_di() {
+            match state.open_tags.iter().last() {
+                Some(OpenedTag::Edge { .. }) => {
+                    if e.local_name().as_ref() == b"waypoint" {
+                        return Some(Tag::Waypoint);
+                    }
+                }
+                _ => {}
+            }
+            None
+        } else {
+            None
+        }
+    }
+}
+
+impl Openable for TagWaypoint {
+    fn open_tag(_tag: Tag, e: &BytesStart, _state: &mut ParserState) -> Result<OpenedTag>
+    where
+        Self: Sized,
+    {
+        Ok(OpenedTag::Waypoint {
+            point: BPMNPoint {
+                x: parse_coordinate(e, "x")?,
+                y: parse_coordinate(e, "y")?,
+            },
+        })
+    }
+}
+
+impl Closeable for TagWaypoint {
+    fn close_tag(opened_tag: OpenedTag, _e: &BytesEnd, state: &mut ParserState) -> Result<()> {
+        match state.open_tags.iter_mut().last() {
+            Some(OpenedTag::Edge { waypoints, .. }) => {
+                if let OpenedTag::Waypoint { point } = opened_tag {
+                    waypoints.push(point);
+                    Ok(())
+                } else {
+                    Err(anyhow!("Expected a waypoint."))
+                }
+            }
+            _ => Err(anyhow!("Expected an edge.")),
+        }
+    }
+}
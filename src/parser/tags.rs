@@ -1,33 +1,74 @@
 use crate::{
+    data::{BPMNDataElement, DataAssociationDirection},
+    diagram::{BPMNBounds, BPMNDiagram, BPMNEdge, BPMNLabel, BPMNPlane, BPMNPoint, BPMNShape},
     element::BPMNElement,
     elements::collapsed_pool::BPMNCollapsedPool,
+    expression::BPMNConditionExpression,
+    import::BPMNImport,
+    message::BPMNMessage,
+    multi_instance::BPMNMultiInstance,
     parser::{
         parser::NameSpace,
         parser_state::{GlobalIndex, ParserState},
         parser_traits::{Closeable, Openable, Recognisable},
+        tag_boundary_event::TagBoundaryEvent,
+        tag_bounds::TagBounds,
+        tag_business_rule_task::TagBusinessRuleTask,
+        tag_call_activity::TagCallActivity,
         tag_collaboration::Collaboration,
+        tag_complex_gateway::TagComplexGateway,
+        tag_condition_expression::TagConditionExpression,
+        tag_data_association::TagDataAssociation,
+        tag_data_association_reference::TagDataAssociationReference,
+        tag_data_element::TagDataElement,
         tag_definitions::Definitions,
+        tag_diagram::TagDiagram,
+        tag_edge::TagEdge,
         tag_end_event::TagEndEvent,
+        tag_error_event_definition::TagErrorEventDefinition,
         tag_event_based_gateway::TagEventBasedGateway,
         tag_exclusive_gateway::TagExclusiveGateway,
+        tag_flow_node_ref::TagFlowNodeRef,
+        tag_import::TagImport,
         tag_inclusive_gateway::TagInclusiveGateway,
         tag_intermediate_catch_event::TagIntermediateCatchEvent,
         tag_intermediate_throw_event::TagIntermediateThrowEvent,
+        tag_label::TagLabel,
+        tag_lane::TagLane,
+        tag_lane_set::{DraftLane, DraftLaneSet, TagLaneSet},
+        tag_link_event_definition::TagLinkEventDefinition,
+        tag_loop_cardinality::TagLoopCardinality,
         tag_manual_task::TagManualTask,
+        tag_message::TagMessage,
         tag_message_event_definition::TagMessageEventDefinition,
         tag_message_flow::{DraftMessageFlow, TagMessageFlow},
+        tag_multi_instance_loop_characteristics::TagMultiInstanceLoopCharacteristics,
         tag_parallel_gateway::TagParallelGateway,
         tag_participant::{DraftTagParticipant, TagParticipant},
+        tag_plane::TagPlane,
         tag_process::TagProcess,
         tag_receive_task::TagReceiveTask,
+        tag_script_task::TagScriptTask,
+        tag_send_task::TagSendTask,
         tag_sequence_flow::{DraftSequenceFlow, TagSequenceFlow},
+        tag_service_task::TagServiceTask,
+        tag_shape::TagShape,
+        tag_signal::TagSignal,
+        tag_signal_event_definition::TagSignalEventDefinition,
+        tag_standard_loop_characteristics::TagStandardLoopCharacteristics,
         tag_start_event::TagStartEvent,
         tag_subprocess::TagSubProcess,
         tag_task::TagTask,
+        tag_terminate_event_definition::TagTerminateEventDefinition,
         tag_timer_event_definition::TagTimerEventDefinition,
+        tag_timer_expression::TagTimerExpression,
         tag_user_task::TagUserTask,
+        tag_waypoint::TagWaypoint,
         tag_weight::TagWeight,
     },
+    signal::BPMNSignal,
+    standard_loop::BPMNStandardLoop,
+    timer::{BPMNTimer, BPMNTimerType},
 };
 use anyhow::Result;
 use ebi_activity_key::Activity;
@@ -38,27 +79,59 @@ use strum_macros::{Display, EnumIs, EnumIter, EnumString};
 
 #[derive(Clone, Copy, EnumString, EnumIter, Display)]
 pub(crate) enum Tag {
+    BoundaryEvent,
+    Bounds,
+    BusinessRuleTask,
+    CallActivity,
     Collaboration,
+    ComplexGateway,
+    ConditionExpression,
+    DataAssociation,
+    DataAssociationReference,
+    DataElement,
     Definitions,
+    Diagram,
+    Edge,
     EndEvent,
+    ErrorEventDefinition,
     EventBasedGateway,
     ExclusiveGateway,
+    FlowNodeRef,
+    Import,
     InclusiveGateway,
     IntermediateCatchEvent,
     IntermediateThrowEvent,
+    Label,
+    Lane,
+    LaneSet,
+    LinkEventDefinition,
+    LoopCardinality,
     ManualTask,
+    Message,
     MessageEventDefinition,
     MessageFlow,
+    MultiInstanceLoopCharacteristics,
     ParallelGateway,
     Participant,
+    Plane,
     Process,
     ReceiveTask,
+    ScriptTask,
+    SendTask,
     SequenceFlow,
+    ServiceTask,
+    Shape,
+    Signal,
+    SignalEventDefinition,
+    StandardLoopCharacteristics,
     SubProcess,
     StartEvent,
     Task,
+    TerminateEventDefinition,
     TimerEventDefinition,
+    TimerExpression,
     UserTask,
+    Waypoint,
     Weight,
 }
 
@@ -81,6 +154,7 @@ impl Recognisable for Tag {
                 Tag::SequenceFlow => TagSequenceFlow::recognise_tag(e, state, n),
                 Tag::EndEvent => TagEndEvent::recognise_tag(e, state, n),
                 Tag::ExclusiveGateway => TagExclusiveGateway::recognise_tag(e, state, n),
+                Tag::Import => TagImport::recognise_tag(e, state, n),
                 Tag::InclusiveGateway => TagInclusiveGateway::recognise_tag(e, state, n),
                 Tag::IntermediateThrowEvent => {
                     TagIntermediateThrowEvent::recognise_tag(e, state, n)
@@ -93,10 +167,49 @@ impl Recognisable for Tag {
                 Tag::SubProcess => TagSubProcess::recognise_tag(e, state, n),
                 Tag::Participant => TagParticipant::recognise_tag(e, state, n),
                 Tag::TimerEventDefinition => TagTimerEventDefinition::recognise_tag(e, state, n),
+                Tag::TimerExpression => TagTimerExpression::recognise_tag(e, state, n),
                 Tag::Weight => TagWeight::recognise_tag(e, state, n),
                 Tag::ReceiveTask => TagReceiveTask::recognise_tag(e, state, n),
                 Tag::ManualTask => TagManualTask::recognise_tag(e, state, n),
                 Tag::UserTask => TagUserTask::recognise_tag(e, state, n),
+                Tag::BusinessRuleTask => TagBusinessRuleTask::recognise_tag(e, state, n),
+                Tag::ScriptTask => TagScriptTask::recognise_tag(e, state, n),
+                Tag::SendTask => TagSendTask::recognise_tag(e, state, n),
+                Tag::ServiceTask => TagServiceTask::recognise_tag(e, state, n),
+                Tag::Diagram => TagDiagram::recognise_tag(e, state, n),
+                Tag::Plane => TagPlane::recognise_tag(e, state, n),
+                Tag::Shape => TagShape::recognise_tag(e, state, n),
+                Tag::Edge => TagEdge::recognise_tag(e, state, n),
+                Tag::Label => TagLabel::recognise_tag(e, state, n),
+                Tag::LinkEventDefinition => TagLinkEventDefinition::recognise_tag(e, state, n),
+                Tag::Bounds => TagBounds::recognise_tag(e, state, n),
+                Tag::Waypoint => TagWaypoint::recognise_tag(e, state, n),
+                Tag::BoundaryEvent => TagBoundaryEvent::recognise_tag(e, state, n),
+                Tag::CallActivity => TagCallActivity::recognise_tag(e, state, n),
+                Tag::ComplexGateway => TagComplexGateway::recognise_tag(e, state, n),
+                Tag::ErrorEventDefinition => TagErrorEventDefinition::recognise_tag(e, state, n),
+                Tag::TerminateEventDefinition => {
+                    TagTerminateEventDefinition::recognise_tag(e, state, n)
+                }
+                Tag::Message => TagMessage::recognise_tag(e, state, n),
+                Tag::Signal => TagSignal::recognise_tag(e, state, n),
+                Tag::SignalEventDefinition => TagSignalEventDefinition::recognise_tag(e, state, n),
+                Tag::MultiInstanceLoopCharacteristics => {
+                    TagMultiInstanceLoopCharacteristics::recognise_tag(e, state, n)
+                }
+                Tag::LoopCardinality => TagLoopCardinality::recognise_tag(e, state, n),
+                Tag::StandardLoopCharacteristics => {
+                    TagStandardLoopCharacteristics::recognise_tag(e, state, n)
+                }
+                Tag::ConditionExpression => TagConditionExpression::recognise_tag(e, state, n),
+                Tag::DataElement => TagDataElement::recognise_tag(e, state, n),
+                Tag::DataAssociation => TagDataAssociation::recognise_tag(e, state, n),
+                Tag::DataAssociationReference => {
+                    TagDataAssociationReference::recognise_tag(e, state, n)
+                }
+                Tag::LaneSet => TagLaneSet::recognise_tag(e, state, n),
+                Tag::Lane => TagLane::recognise_tag(e, state, n),
+                Tag::FlowNodeRef => TagFlowNodeRef::recognise_tag(e, state, n),
             };
             if x.is_some() {
                 return x;
@@ -122,6 +235,7 @@ impl Openable for Tag {
             Tag::SequenceFlow => TagSequenceFlow::open_tag(tag, e, state),
             Tag::EndEvent => TagEndEvent::open_tag(tag, e, state),
             Tag::ExclusiveGateway => TagExclusiveGateway::open_tag(tag, e, state),
+            Tag::Import => TagImport::open_tag(tag, e, state),
             Tag::InclusiveGateway => TagInclusiveGateway::open_tag(tag, e, state),
             Tag::IntermediateThrowEvent => TagIntermediateThrowEvent::open_tag(tag, e, state),
             Tag::IntermediateCatchEvent => TagIntermediateCatchEvent::open_tag(tag, e, state),
@@ -130,10 +244,45 @@ impl Openable for Tag {
             Tag::SubProcess => TagSubProcess::open_tag(tag, e, state),
             Tag::Participant => TagParticipant::open_tag(tag, e, state),
             Tag::TimerEventDefinition => TagTimerEventDefinition::open_tag(tag, e, state),
+            Tag::TimerExpression => TagTimerExpression::open_tag(tag, e, state),
             Tag::Weight => TagWeight::open_tag(tag, e, state),
             Tag::ReceiveTask => TagReceiveTask::open_tag(tag, e, state),
             Tag::ManualTask => TagManualTask::open_tag(tag, e, state),
             Tag::UserTask => TagUserTask::open_tag(tag, e, state),
+            Tag::BusinessRuleTask => TagBusinessRuleTask::open_tag(tag, e, state),
+            Tag::ScriptTask => TagScriptTask::open_tag(tag, e, state),
+            Tag::SendTask => TagSendTask::open_tag(tag, e, state),
+            Tag::ServiceTask => TagServiceTask::open_tag(tag, e, state),
+            Tag::Diagram => TagDiagram::open_tag(tag, e, state),
+            Tag::Plane => TagPlane::open_tag(tag, e, state),
+            Tag::Shape => TagShape::open_tag(tag, e, state),
+            Tag::Edge => TagEdge::open_tag(tag, e, state),
+            Tag::Label => TagLabel::open_tag(tag, e, state),
+            Tag::LinkEventDefinition => TagLinkEventDefinition::open_tag(tag, e, state),
+            Tag::Bounds => TagBounds::open_tag(tag, e, state),
+            Tag::Waypoint => TagWaypoint::open_tag(tag, e, state),
+            Tag::BoundaryEvent => TagBoundaryEvent::open_tag(tag, e, state),
+            Tag::CallActivity => TagCallActivity::open_tag(tag, e, state),
+            Tag::ComplexGateway => TagComplexGateway::open_tag(tag, e, state),
+            Tag::ErrorEventDefinition => TagErrorEventDefinition::open_tag(tag, e, state),
+            Tag::TerminateEventDefinition => TagTerminateEventDefinition::open_tag(tag, e, state),
+            Tag::Message => TagMessage::open_tag(tag, e, state),
+            Tag::Signal => TagSignal::open_tag(tag, e, state),
+            Tag::SignalEventDefinition => TagSignalEventDefinition::open_tag(tag, e, state),
+            Tag::MultiInstanceLoopCharacteristics => {
+                TagMultiInstanceLoopCharacteristics::open_tag(tag, e, state)
+            }
+            Tag::LoopCardinality => TagLoopCardinality::open_tag(tag, e, state),
+            Tag::StandardLoopCharacteristics => {
+                TagStandardLoopCharacteristics::open_tag(tag, e, state)
+            }
+            Tag::ConditionExpression => TagConditionExpression::open_tag(tag, e, state),
+            Tag::DataElement => TagDataElement::open_tag(tag, e, state),
+            Tag::DataAssociation => TagDataAssociation::open_tag(tag, e, state),
+            Tag::DataAssociationReference => TagDataAssociationReference::open_tag(tag, e, state),
+            Tag::LaneSet => TagLaneSet::open_tag(tag, e, state),
+            Tag::Lane => TagLane::open_tag(tag, e, state),
+            Tag::FlowNodeRef => TagFlowNodeRef::open_tag(tag, e, state),
         }
     }
 }
@@ -141,6 +290,35 @@ impl Openable for Tag {
 #[derive(Debug, EnumIs)]
 pub(crate) enum OpenedTag {
     Unknown,
+    BoundaryEvent {
+        global_index: GlobalIndex,
+        id: String,
+        name: Option<String>,
+        attached_to_id: String,
+        cancel_activity: bool,
+        error_marker_id: Option<Option<String>>,
+        error_ref: Option<String>,
+        message_marker_id: Option<Option<String>>,
+        message_ref: Option<String>,
+        timer_marker_id: Option<Option<String>>,
+        timer: Option<BPMNTimer>,
+    },
+    Bounds {
+        bounds: BPMNBounds,
+    },
+    BusinessRuleTask {
+        global_index: GlobalIndex,
+        id: String,
+        activity: Activity,
+        multi_instance: Option<BPMNMultiInstance>,
+        standard_loop: Option<BPMNStandardLoop>,
+    },
+    CallActivity {
+        global_index: GlobalIndex,
+        id: String,
+        name: Option<String>,
+        called_element: String,
+    },
     Collaboration {
         global_index: GlobalIndex,
         id: String,
@@ -148,6 +326,32 @@ pub(crate) enum OpenedTag {
         draft_participants: Vec<DraftTagParticipant>,
         message_flows: Vec<DraftMessageFlow>,
     },
+    ComplexGateway {
+        global_index: GlobalIndex,
+        id: String,
+        name: Option<String>,
+        activation_count: Option<u64>,
+    },
+    ConditionExpression {
+        id: Option<String>,
+        tag: String,
+        position: u64,
+        language: Option<String>,
+        text: String,
+    },
+    DataAssociation {
+        id: Option<String>,
+        direction: DataAssociationDirection,
+        source_refs: Vec<String>,
+        target_ref: Option<String>,
+    },
+    DataAssociationReference {
+        is_source: bool,
+        text: String,
+    },
+    DataElement {
+        data_element: BPMNDataElement,
+    },
     Definitions {
         global_index: GlobalIndex,
         id: String,
@@ -157,52 +361,139 @@ pub(crate) enum OpenedTag {
         draft_message_flows: Vec<DraftMessageFlow>,
         draft_participants: Vec<DraftTagParticipant>,
         elements: Vec<BPMNElement>,
+        imports: Vec<BPMNImport>,
+        messages: Vec<BPMNMessage>,
+        namespaces: Vec<(String, String)>,
+        signals: Vec<BPMNSignal>,
+        diagrams: Vec<BPMNDiagram>,
+    },
+    Diagram {
+        id: Option<String>,
+        name: Option<String>,
+        plane: Option<BPMNPlane>,
+    },
+    Edge {
+        id: Option<String>,
+        bpmn_element: String,
+        source_element: Option<String>,
+        target_element: Option<String>,
+        waypoints: Vec<BPMNPoint>,
+        label: Option<BPMNLabel>,
     },
     EndEvent {
         global_index: GlobalIndex,
         id: String,
+        name: Option<String>,
+        error_marker_id: Option<Option<String>>,
+        error_ref: Option<String>,
         message_marker_id: Option<Option<String>>,
+        message_ref: Option<String>,
+        signal_marker_id: Option<Option<String>>,
+        signal_ref: Option<String>,
+        terminate_marker_id: Option<Option<String>>,
+    },
+    ErrorEventDefinition {
+        id: Option<String>,
+        error_ref: Option<String>,
     },
     EventBasedGateway {
         global_index: GlobalIndex,
         id: String,
+        name: Option<String>,
     },
     ExclusiveGateway {
         global_index: GlobalIndex,
         id: String,
+        name: Option<String>,
+        default_sequence_flow: Option<String>,
+    },
+    FlowNodeRef {
+        text: String,
+    },
+    Import {
+        namespace: String,
+        location: String,
+        import_type: String,
     },
     InclusiveGateway {
         global_index: GlobalIndex,
         id: String,
+        name: Option<String>,
+        default_sequence_flow: Option<String>,
     },
     IntermediateCatchEvent {
         global_index: GlobalIndex,
         id: String,
+        name: Option<String>,
+        link_marker_id: Option<Option<String>>,
+        link_name: Option<String>,
         message_marker_id: Option<Option<String>>,
+        message_ref: Option<String>,
+        signal_marker_id: Option<Option<String>>,
+        signal_ref: Option<String>,
         timer_marker_id: Option<Option<String>>,
+        timer: Option<BPMNTimer>,
     },
     IntermediateThrowEvent {
         global_index: GlobalIndex,
         id: String,
+        name: Option<String>,
+        link_marker_id: Option<Option<String>>,
+        link_name: Option<String>,
         message_marker_id: Option<Option<String>>,
+        message_ref: Option<String>,
+        signal_marker_id: Option<Option<String>>,
+        signal_ref: Option<String>,
+    },
+    Label {
+        bounds: Option<BPMNBounds>,
+    },
+    Lane {
+        lane: DraftLane,
+    },
+    LaneSet {
+        lane_set: DraftLaneSet,
+    },
+    LinkEventDefinition {
+        id: Option<String>,
+        name: Option<String>,
+    },
+    LoopCardinality {
+        text: String,
     },
     ManualTask {
         global_index: GlobalIndex,
         id: String,
         activity: Activity,
+        multi_instance: Option<BPMNMultiInstance>,
+        standard_loop: Option<BPMNStandardLoop>,
+    },
+    Message {
+        global_index: GlobalIndex,
+        id: String,
+        name: Option<String>,
     },
     MessageEventDefinition {
         id: Option<String>,
+        message_ref: Option<String>,
     },
     MessageFlow {
         global_index: GlobalIndex,
         id: String,
+        name: Option<String>,
         source_id: String,
         target_id: String,
+        message_ref: Option<String>,
+    },
+    MultiInstanceLoopCharacteristics {
+        id: Option<String>,
+        is_sequential: bool,
+        loop_cardinality: Option<u64>,
     },
     ParallelGateway {
         global_index: GlobalIndex,
         id: String,
+        name: Option<String>,
     },
     Participant {
         global_index: GlobalIndex,
@@ -210,24 +501,65 @@ pub(crate) enum OpenedTag {
         name: Option<String>,
         process_id: Option<String>,
     },
+    Plane {
+        id: Option<String>,
+        bpmn_element: Option<String>,
+        shapes: Vec<BPMNShape>,
+        edges: Vec<BPMNEdge>,
+    },
     Process {
         global_index: GlobalIndex,
         id: String,
         name: Option<String>,
         elements: Vec<BPMNElement>,
         draft_sequence_flows: Vec<DraftSequenceFlow>,
+        lane_sets: Vec<DraftLaneSet>,
     },
     ReceiveTask {
         global_index: GlobalIndex,
         id: String,
         activity: Activity,
+        multi_instance: Option<BPMNMultiInstance>,
+        standard_loop: Option<BPMNStandardLoop>,
+    },
+    ScriptTask {
+        global_index: GlobalIndex,
+        id: String,
+        activity: Activity,
+        multi_instance: Option<BPMNMultiInstance>,
+        standard_loop: Option<BPMNStandardLoop>,
+    },
+    SendTask {
+        global_index: GlobalIndex,
+        id: String,
+        activity: Activity,
+        multi_instance: Option<BPMNMultiInstance>,
+        standard_loop: Option<BPMNStandardLoop>,
     },
     SequenceFlow {
         global_index: GlobalIndex,
         id: String,
+        name: Option<String>,
         source_ref: String,
         target_ref: String,
         weight: Option<Fraction>,
+        condition_expression: Option<BPMNConditionExpression>,
+    },
+    ServiceTask {
+        global_index: GlobalIndex,
+        id: String,
+        activity: Activity,
+        multi_instance: Option<BPMNMultiInstance>,
+        standard_loop: Option<BPMNStandardLoop>,
+    },
+    Shape {
+        id: Option<String>,
+        bpmn_element: String,
+        bounds: Option<BPMNBounds>,
+        is_expanded: Option<bool>,
+        is_horizontal: Option<bool>,
+        is_marker_visible: Option<bool>,
+        label: Option<BPMNLabel>,
     },
     SubProcess {
         global_index: GlobalIndex,
@@ -235,25 +567,63 @@ pub(crate) enum OpenedTag {
         name: Option<String>,
         elements: Vec<BPMNElement>,
         draft_sequence_flows: Vec<DraftSequenceFlow>,
+        multi_instance: Option<BPMNMultiInstance>,
+        standard_loop: Option<BPMNStandardLoop>,
+        triggered_by_event: bool,
+    },
+    Signal {
+        global_index: GlobalIndex,
+        id: String,
+        name: Option<String>,
+    },
+    SignalEventDefinition {
+        id: Option<String>,
+        signal_ref: Option<String>,
+    },
+    StandardLoopCharacteristics {
+        standard_loop: BPMNStandardLoop,
     },
     StartEvent {
         global_index: GlobalIndex,
         id: String,
+        name: Option<String>,
+        error_marker_id: Option<Option<String>>,
+        error_ref: Option<String>,
+        is_interrupting: bool,
         message_marker_id: Option<Option<String>>,
+        message_ref: Option<String>,
+        signal_marker_id: Option<Option<String>>,
+        signal_ref: Option<String>,
         timer_marker_id: Option<Option<String>>,
+        timer: Option<BPMNTimer>,
     },
     Task {
         global_index: GlobalIndex,
         id: String,
         activity: Activity,
+        multi_instance: Option<BPMNMultiInstance>,
+        standard_loop: Option<BPMNStandardLoop>,
+    },
+    TerminateEventDefinition {
+        id: Option<String>,
     },
     TimerEventDefinition {
         id: Option<String>,
+        timer: Option<BPMNTimer>,
+    },
+    TimerExpression {
+        timer_type: BPMNTimerType,
+        text: String,
     },
     UserTask {
         global_index: GlobalIndex,
         id: String,
         activity: Activity,
+        multi_instance: Option<BPMNMultiInstance>,
+        standard_loop: Option<BPMNStandardLoop>,
+    },
+    Waypoint {
+        point: BPMNPoint,
     },
     Weight {
         weight: Fraction,
@@ -278,6 +648,7 @@ impl Closeable for OpenedTag {
             OpenedTag::ExclusiveGateway { .. } => {
                 TagExclusiveGateway::close_tag(opened_tag, e, state)
             }
+            OpenedTag::Import { .. } => TagImport::close_tag(opened_tag, e, state),
             OpenedTag::InclusiveGateway { .. } => {
                 TagInclusiveGateway::close_tag(opened_tag, e, state)
             }
@@ -298,10 +669,65 @@ impl Closeable for OpenedTag {
             OpenedTag::TimerEventDefinition { .. } => {
                 TagTimerEventDefinition::close_tag(opened_tag, e, state)
             }
+            OpenedTag::TimerExpression { .. } => {
+                TagTimerExpression::close_tag(opened_tag, e, state)
+            }
             OpenedTag::Weight { .. } => TagWeight::close_tag(opened_tag, e, state),
             OpenedTag::ReceiveTask { .. } => TagReceiveTask::close_tag(opened_tag, e, state),
             OpenedTag::ManualTask { .. } => TagManualTask::close_tag(opened_tag, e, state),
             OpenedTag::UserTask { .. } => TagUserTask::close_tag(opened_tag, e, state),
+            OpenedTag::BusinessRuleTask { .. } => {
+                TagBusinessRuleTask::close_tag(opened_tag, e, state)
+            }
+            OpenedTag::ScriptTask { .. } => TagScriptTask::close_tag(opened_tag, e, state),
+            OpenedTag::SendTask { .. } => TagSendTask::close_tag(opened_tag, e, state),
+            OpenedTag::ServiceTask { .. } => TagServiceTask::close_tag(opened_tag, e, state),
+            OpenedTag::Diagram { .. } => TagDiagram::close_tag(opened_tag, e, state),
+            OpenedTag::Plane { .. } => TagPlane::close_tag(opened_tag, e, state),
+            OpenedTag::Shape { .. } => TagShape::close_tag(opened_tag, e, state),
+            OpenedTag::Edge { .. } => TagEdge::close_tag(opened_tag, e, state),
+            OpenedTag::Label { .. } => TagLabel::close_tag(opened_tag, e, state),
+            OpenedTag::LinkEventDefinition { .. } => {
+                TagLinkEventDefinition::close_tag(opened_tag, e, state)
+            }
+            OpenedTag::Bounds { .. } => TagBounds::close_tag(opened_tag, e, state),
+            OpenedTag::Waypoint { .. } => TagWaypoint::close_tag(opened_tag, e, state),
+            OpenedTag::BoundaryEvent { .. } => TagBoundaryEvent::close_tag(opened_tag, e, state),
+            OpenedTag::CallActivity { .. } => TagCallActivity::close_tag(opened_tag, e, state),
+            OpenedTag::ComplexGateway { .. } => TagComplexGateway::close_tag(opened_tag, e, state),
+            OpenedTag::ErrorEventDefinition { .. } => {
+                TagErrorEventDefinition::close_tag(opened_tag, e, state)
+            }
+            OpenedTag::TerminateEventDefinition { .. } => {
+                TagTerminateEventDefinition::close_tag(opened_tag, e, state)
+            }
+            OpenedTag::Message { .. } => TagMessage::close_tag(opened_tag, e, state),
+            OpenedTag::Signal { .. } => TagSignal::close_tag(opened_tag, e, state),
+            OpenedTag::SignalEventDefinition { .. } => {
+                TagSignalEventDefinition::close_tag(opened_tag, e, state)
+            }
+            OpenedTag::MultiInstanceLoopCharacteristics { .. } => {
+                TagMultiInstanceLoopCharacteristics::close_tag(opened_tag, e, state)
+            }
+            OpenedTag::LoopCardinality { .. } => {
+                TagLoopCardinality::close_tag(opened_tag, e, state)
+            }
+            OpenedTag::StandardLoopCharacteristics { .. } => {
+                TagStandardLoopCharacteristics::close_tag(opened_tag, e, state)
+            }
+            OpenedTag::ConditionExpression { .. } => {
+                TagConditionExpression::close_tag(opened_tag, e, state)
+            }
+            OpenedTag::DataElement { .. } => TagDataElement::close_tag(opened_tag, e, state),
+            OpenedTag::DataAssociation { .. } => {
+                TagDataAssociation::close_tag(opened_tag, e, state)
+            }
+            OpenedTag::DataAssociationReference { .. } => {
+                TagDataAssociationReference::close_tag(opened_tag, e, state)
+            }
+            OpenedTag::LaneSet { .. } => TagLaneSet::close_tag(opened_tag, e, state),
+            OpenedTag::Lane { .. } => TagLane::close_tag(opened_tag, e, state),
+            OpenedTag::FlowNodeRef { .. } => TagFlowNodeRef::close_tag(opened_tag, e, state),
         }
     }
 }
@@ -1,6 +1,8 @@
 use crate::{
     BPMNMarking, BusinessProcessModelAndNotation,
     element::BPMNElement,
+    elements::event_sub_process::BPMNEventSubProcess,
+    error::BPMNError,
     marking::{BPMNRootMarking, BPMNSubMarking, Token},
     stochastic_business_process_model_and_notation::StochasticBusinessProcessModelAndNotation,
     traits::{
@@ -9,7 +11,7 @@ use crate::{
         transitionable::Transitionable,
     },
 };
-use anyhow::{Result, anyhow};
+use anyhow::Result;
 use bitvec::bitvec;
 use ebi_activity_key::Activity;
 use ebi_arithmetic::Fraction;
@@ -21,7 +23,7 @@ impl BusinessProcessModelAndNotation {
     /// Additionally, if the model is empty, it does not support any trace, and this function returns Ok(None).
     /// If the model is structurally correct, this method will always return Ok(..).
     /// If not, will return Err() but will not panic.
-    pub fn get_initial_marking(&self) -> Result<Option<BPMNMarking>> {
+    pub fn get_initial_marking(&self) -> Result<Option<BPMNMarking>, BPMNError> {
         if self.elements.is_empty() {
             return Ok(None);
         }
@@ -29,21 +31,35 @@ impl BusinessProcessModelAndNotation {
         //gather the initiation mode
         let mut initiation_mode = InitiationMode::ParallelElements(vec![]);
         for element in &self.elements {
-            if let BPMNElement::Process(process) = element {
-                initiation_mode = initiation_mode + process.initiation_mode(self)?;
+            match element {
+                BPMNElement::Process(process) if !process.is_called => {
+                    initiation_mode = initiation_mode + process.initiation_mode(self)?;
+                }
+                _ => {}
             }
         }
 
         if initiation_mode.is_choice_between_start_events() {
             let root_marking = BPMNRootMarking {
                 message_flow_2_tokens: vec![0; self.message_flows.len()],
+                signal_2_tokens: vec![0; self.signals.len()],
+                error_thrown: None,
+                variables: None,
                 root_initial_choice_token: true,
             };
 
             let mut element_index_2_sub_markings = Vec::with_capacity(self.elements.len());
             for element in self.elements.iter() {
                 if let BPMNElement::Process(process) = element {
-                    element_index_2_sub_markings.push(process.to_sub_marking(&initiation_mode)?);
+                    if process.is_called {
+                        //called processes are only started by their call activities
+                        element_index_2_sub_markings.push(
+                            process.to_sub_marking(&InitiationMode::ParallelElements(vec![]))?,
+                        );
+                    } else {
+                        element_index_2_sub_markings
+                            .push(process.to_sub_marking(&initiation_mode)?);
+                    }
                 } else {
                     element_index_2_sub_markings.push(BPMNSubMarking::new_empty());
                 }
@@ -54,7 +70,36 @@ impl BusinessProcessModelAndNotation {
                 root_marking,
             }))
         } else {
-            todo!()
+            //initiation mode 2: all elements without incoming sequence flows start in parallel
+            let root_marking = BPMNRootMarking {
+                message_flow_2_tokens: vec![0; self.message_flows.len()],
+                signal_2_tokens: vec![0; self.signals.len()],
+                error_thrown: None,
+                variables: None,
+                root_initial_choice_token: false,
+            };
+
+            let mut element_index_2_sub_markings = Vec::with_capacity(self.elements.len());
+            for element in self.elements.iter() {
+                if let BPMNElement::Process(process) = element {
+                    //each process marks its own elements, as local indices are per process
+                    let process_initiation_mode = if process.is_called {
+                        //called processes are only started by their call activities
+                        InitiationMode::ParallelElements(vec![])
+                    } else {
+                        process.initiation_mode(self)?
+                    };
+                    element_index_2_sub_markings
+                        .push(process.to_sub_marking(&process_initiation_mode)?);
+                } else {
+                    element_index_2_sub_markings.push(BPMNSubMarking::new_empty());
+                }
+            }
+
+            Ok(Some(BPMNMarking {
+                element_index_2_sub_markings,
+                root_marking,
+            }))
         }
     }
 
@@ -65,12 +110,13 @@ impl BusinessProcessModelAndNotation {
         &self,
         marking: &mut BPMNMarking,
         mut transition_index: TransitionIndex,
-    ) -> Result<()> {
+    ) -> Result<(), BPMNError> {
         let transition_index_debug = transition_index;
         let BPMNMarking {
             element_index_2_sub_markings,
             root_marking,
         } = marking;
+        let mut executed = false;
         for (element, sub_marking) in self
             .elements
             .iter()
@@ -78,26 +124,59 @@ impl BusinessProcessModelAndNotation {
         {
             let number_of_transitions = element.number_of_transitions(sub_marking);
             if transition_index < number_of_transitions {
-                return element.execute_transition(
+                element.execute_transition(
                     transition_index,
                     root_marking,
                     sub_marking,
                     self,
                     self,
-                );
+                )?;
+                executed = true;
+                break;
             }
             transition_index -= number_of_transitions;
         }
-        Err(anyhow!(
-            "transition {} is not enabled, as it is unknown",
-            transition_index_debug
-        ))
+        if !executed {
+            return Err(BPMNError::SemanticMisuse(format!(
+                "transition {} is not enabled, as it is unknown",
+                transition_index_debug
+            )));
+        }
+
+        //an error that was not caught has ended its process instance, and is discarded
+        root_marking.error_thrown = None;
+
+        Ok(self.broadcast_signals(marking)?)
+    }
+
+    /// Broadcasts the signals that were thrown by the last executed transition to all signal catch events that are waiting for them, in all pools.
+    fn broadcast_signals(&self, marking: &mut BPMNMarking) -> Result<()> {
+        for (signal_index, signal) in self.signals.iter().enumerate() {
+            if marking.root_marking.signal_2_tokens[signal_index] == 0 {
+                continue;
+            }
+            marking.root_marking.signal_2_tokens[signal_index] = 0;
+
+            for (element, sub_marking) in self
+                .elements
+                .iter()
+                .zip(marking.element_index_2_sub_markings.iter_mut())
+            {
+                match element {
+                    BPMNElement::Process(process) if !process.is_called => {
+                        broadcast_signal(process, sub_marking, &signal.id)?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
     }
 
     /// Returns whether the marking is a final marking. That is, whether no transitions are enabled in it.
     /// If the model is structurally correct, this function will always return Ok().
     /// If the model is not structurally correct, this function may return Err() but will not panic.
-    pub fn is_final_marking(&self, marking: &BPMNMarking) -> Result<bool> {
+    pub fn is_final_marking(&self, marking: &BPMNMarking) -> Result<bool, BPMNError> {
         Ok(self.get_enabled_transitions(marking)?.is_empty())
     }
 
@@ -134,7 +213,10 @@ impl BusinessProcessModelAndNotation {
 
     /// Returns the transitions that are enabled in the given `marking`.
     /// By contract, will return Ok() if the model is structurally correct. Otherwise, it will return Err() but will not panic.
-    pub fn get_enabled_transitions(&self, marking: &BPMNMarking) -> Result<Vec<TransitionIndex>> {
+    pub fn get_enabled_transitions(
+        &self,
+        marking: &BPMNMarking,
+    ) -> Result<Vec<TransitionIndex>, BPMNError> {
         let mut result = bitvec![0;0];
         for (element, sub_marking) in self
             .elements
@@ -195,7 +277,7 @@ impl BusinessProcessModelAndNotation {
         &self,
         mut transition_index: TransitionIndex,
         marking: &BPMNMarking,
-    ) -> Result<Vec<Token>> {
+    ) -> Result<Vec<Token>, BPMNError> {
         for (element, sub_marking) in self
             .elements
             .iter()
@@ -203,17 +285,19 @@ impl BusinessProcessModelAndNotation {
         {
             let number_of_transitions = element.number_of_transitions(sub_marking);
             if transition_index < number_of_transitions {
-                return element.transition_2_consumed_tokens(
+                return Ok(element.transition_2_consumed_tokens(
                     transition_index,
                     &marking.root_marking,
                     sub_marking,
                     self,
                     self,
-                );
+                )?);
             }
             transition_index -= number_of_transitions;
         }
-        Err(anyhow!("Transition not found."))
+        Err(BPMNError::SemanticMisuse(
+            "Transition not found.".to_string(),
+        ))
     }
 
     /// Returns the tokens that are produced when this transition is fired, or None if the transition does not exist.
@@ -221,7 +305,7 @@ impl BusinessProcessModelAndNotation {
         &self,
         mut transition_index: TransitionIndex,
         marking: &BPMNMarking,
-    ) -> Result<Vec<Token>> {
+    ) -> Result<Vec<Token>, BPMNError> {
         for (element, sub_marking) in self
             .elements
             .iter()
@@ -229,25 +313,109 @@ impl BusinessProcessModelAndNotation {
         {
             let number_of_transitions = element.number_of_transitions(sub_marking);
             if transition_index < number_of_transitions {
-                return element.transition_2_produced_tokens(
+                return Ok(element.transition_2_produced_tokens(
                     transition_index,
                     &marking.root_marking,
                     sub_marking,
                     self,
                     self,
-                );
+                )?);
             }
             transition_index -= number_of_transitions;
         }
-        Err(anyhow!("Transition not found."))
+        Err(BPMNError::SemanticMisuse(
+            "Transition not found.".to_string(),
+        ))
+    }
+}
+
+/// Lets the signal catch events of a (sub-)process instance that are waiting for the signal catch it, including those in instances of nested sub-processes.
+fn broadcast_signal(
+    parent: &dyn Processable,
+    sub_marking: &mut BPMNSubMarking,
+    signal_id: &str,
+) -> Result<()> {
+    //gather the waiting events first, such that tokens produced by catching the signal are not considered
+    let mut waiting = vec![];
+    for element in parent.elements_non_recursive() {
+        match element {
+            BPMNElement::SignalIntermediateCatchEvent(event)
+                if event.signal_ref() == Some(signal_id) =>
+            {
+                waiting.push((element, event.number_of_waiting_tokens(sub_marking)));
+            }
+            BPMNElement::SignalStartEvent(event)
+                if event.signal_ref() == Some(signal_id) && !parent.is_event_sub_process() =>
+            {
+                //a signal start event at the top level of a process always waits
+                waiting.push((element, 1));
+            }
+            BPMNElement::EventSubProcess(event_sub_process)
+                if event_sub_process.is_triggered_by_signal(signal_id) =>
+            {
+                //an event sub-process waits while its parent instance is active
+                if BPMNEventSubProcess::instance_is_active(parent, sub_marking) {
+                    waiting.push((element, 1));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for (element, tokens) in waiting {
+        match element {
+            BPMNElement::SignalIntermediateCatchEvent(event) => {
+                for _ in 0..tokens {
+                    //a token may have been taken by another event after the same event-based gateway
+                    if event.number_of_waiting_tokens(sub_marking) == 0 {
+                        break;
+                    }
+                    event.catch_signal(sub_marking, parent)?;
+                }
+            }
+            BPMNElement::SignalStartEvent(event) => event.catch_signal(sub_marking),
+            BPMNElement::EventSubProcess(event_sub_process) => {
+                event_sub_process.catch_signal(sub_marking)?
+            }
+            _ => {}
+        }
+    }
+
+    //recurse on the instances of sub-processes
+    for (element, instances) in parent
+        .elements_non_recursive()
+        .iter()
+        .zip(sub_marking.element_index_2_sub_markings.iter_mut())
+    {
+        match element {
+            BPMNElement::EventSubProcess(event_sub_process) => {
+                for instance in instances.iter_mut() {
+                    broadcast_signal(event_sub_process, instance, signal_id)?;
+                }
+            }
+            BPMNElement::ExpandedSubProcess(sub_process) => {
+                for instance in sub_process.instances_mut(instances) {
+                    broadcast_signal(sub_process, instance, signal_id)?;
+                }
+            }
+            BPMNElement::CallActivity(call_activity) => {
+                if let Some(called_process) = &call_activity.called_process {
+                    for instance in instances.iter_mut() {
+                        broadcast_signal(called_process.as_ref(), instance, signal_id)?;
+                    }
+                }
+            }
+            _ => {}
+        }
     }
+    Ok(())
 }
 
 impl StochasticBusinessProcessModelAndNotation {
     /// BPMN 2.0.2 standard page 238
     /// By convention, if the model is empty, it does not support any trace, and this function returns Ok(None).
     /// If the model is structurally correct, this method will return Ok(..).
-    pub fn get_initial_marking(&self) -> Result<Option<BPMNMarking>> {
+    pub fn get_initial_marking(&self) -> Result<Option<BPMNMarking>, BPMNError> {
         self.bpmn.get_initial_marking()
     }
 
@@ -255,11 +423,11 @@ impl StochasticBusinessProcessModelAndNotation {
         &self,
         marking: &mut BPMNMarking,
         transition_index: TransitionIndex,
-    ) -> Result<()> {
+    ) -> Result<(), BPMNError> {
         self.bpmn.execute_transition(marking, transition_index)
     }
 
-    pub fn is_final_marking(&self, marking: &BPMNMarking) -> Result<bool> {
+    pub fn is_final_marking(&self, marking: &BPMNMarking) -> Result<bool, BPMNError> {
         self.bpmn.is_final_marking(marking)
     }
 
@@ -279,7 +447,10 @@ impl StochasticBusinessProcessModelAndNotation {
         self.bpmn.get_transition_activity(transition_index, marking)
     }
 
-    pub fn get_enabled_transitions(&self, marking: &BPMNMarking) -> Result<Vec<TransitionIndex>> {
+    pub fn get_enabled_transitions(
+        &self,
+        marking: &BPMNMarking,
+    ) -> Result<Vec<TransitionIndex>, BPMNError> {
         self.bpmn.get_enabled_transitions(marking)
     }
 
@@ -316,7 +487,7 @@ impl StochasticBusinessProcessModelAndNotation {
         &self,
         transition_index: TransitionIndex,
         marking: &BPMNMarking,
-    ) -> Result<Vec<Token>> {
+    ) -> Result<Vec<Token>, BPMNError> {
         self.bpmn
             .transition_2_consumed_tokens(transition_index, marking)
     }
@@ -326,7 +497,7 @@ impl StochasticBusinessProcessModelAndNotation {
         &self,
         transition_index: TransitionIndex,
         marking: &BPMNMarking,
-    ) -> Result<Vec<Token>> {
+    ) -> Result<Vec<Token>, BPMNError> {
         self.bpmn
             .transition_2_produced_tokens(transition_index, marking)
     }
@@ -337,7 +508,7 @@ pub(crate) mod tests {
     use ebi_arithmetic::{Fraction, One, f};
 
     use crate::{
-        BusinessProcessModelAndNotation, GlobalIndex,
+        BPMNError, BusinessProcessModelAndNotation, GlobalIndex,
         marking::Token,
         semantics::{BPMNMarking, BPMNRootMarking, BPMNSubMarking},
         stochastic_business_process_model_and_notation::StochasticBusinessProcessModelAndNotation,
@@ -370,7 +541,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: true,
-                    message_flow_2_tokens: vec![]
+                    message_flow_2_tokens: vec![],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![BPMNSubMarking {
                     sequence_flow_2_tokens: vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
@@ -390,7 +564,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![]
+                    message_flow_2_tokens: vec![],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![BPMNSubMarking {
                     sequence_flow_2_tokens: vec![0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
@@ -414,7 +591,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![]
+                    message_flow_2_tokens: vec![],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![BPMNSubMarking {
                     sequence_flow_2_tokens: vec![1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
@@ -434,7 +614,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![]
+                    message_flow_2_tokens: vec![],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![BPMNSubMarking {
                     sequence_flow_2_tokens: vec![0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
@@ -458,7 +641,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![]
+                    message_flow_2_tokens: vec![],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![BPMNSubMarking {
                     sequence_flow_2_tokens: vec![0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
@@ -478,7 +664,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![]
+                    message_flow_2_tokens: vec![],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![BPMNSubMarking {
                     sequence_flow_2_tokens: vec![0, 0, 0, 0, 0, 0, 0, 1, 0, 0],
@@ -498,7 +687,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![]
+                    message_flow_2_tokens: vec![],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![BPMNSubMarking {
                     sequence_flow_2_tokens: vec![0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
@@ -519,7 +711,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![]
+                    message_flow_2_tokens: vec![],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![BPMNSubMarking {
                     sequence_flow_2_tokens: vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
@@ -549,7 +744,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: true,
-                    message_flow_2_tokens: vec![0]
+                    message_flow_2_tokens: vec![0],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![
                     BPMNSubMarking::new_empty(),
@@ -572,7 +770,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![0]
+                    message_flow_2_tokens: vec![0],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![
                     BPMNSubMarking::new_empty(),
@@ -595,7 +796,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![0]
+                    message_flow_2_tokens: vec![0],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![
                     BPMNSubMarking::new_empty(),
@@ -633,7 +837,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![0]
+                    message_flow_2_tokens: vec![0],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![
                     BPMNSubMarking::new_empty(),
@@ -675,7 +882,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![0]
+                    message_flow_2_tokens: vec![0],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![
                     BPMNSubMarking::new_empty(),
@@ -712,7 +922,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![0]
+                    message_flow_2_tokens: vec![0],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![
                     BPMNSubMarking::new_empty(),
@@ -749,7 +962,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![0]
+                    message_flow_2_tokens: vec![0],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![
                     BPMNSubMarking::new_empty(),
@@ -786,7 +1002,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![0]
+                    message_flow_2_tokens: vec![0],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![
                     BPMNSubMarking::new_empty(),
@@ -818,7 +1037,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![0]
+                    message_flow_2_tokens: vec![0],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![
                     BPMNSubMarking::new_empty(),
@@ -854,7 +1076,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![0]
+                    message_flow_2_tokens: vec![0],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![
                     BPMNSubMarking::new_empty(),
@@ -886,7 +1111,10 @@ pub(crate) mod tests {
             BPMNMarking {
                 root_marking: BPMNRootMarking {
                     root_initial_choice_token: false,
-                    message_flow_2_tokens: vec![0]
+                    message_flow_2_tokens: vec![0],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
                 },
                 element_index_2_sub_markings: vec![
                     BPMNSubMarking::new_empty(),
@@ -1387,4 +1615,1081 @@ pub(crate) mod tests {
 
         assert!(bpmn.is_final_marking(&marking).unwrap());
     }
+
+    #[test]
+    fn bpmn_semantics_parallel_elements() {
+        let fin = fs::read_to_string("testfiles/no-start-event.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        debug_transitions(&bpmn, &marking);
+
+        assert_eq!(
+            marking,
+            BPMNMarking {
+                root_marking: BPMNRootMarking {
+                    root_initial_choice_token: false,
+                    message_flow_2_tokens: vec![],
+                    signal_2_tokens: vec![],
+                    error_thrown: None,
+                    variables: None
+                },
+                element_index_2_sub_markings: vec![BPMNSubMarking {
+                    sequence_flow_2_tokens: vec![0],
+                    initial_choice_token: false,
+                    element_index_2_tokens: vec![1, 0],
+                    element_index_2_sub_markings: vec![vec![]; 2],
+                }],
+            }
+        );
+
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![0]);
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1]);
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_semantics_boundary_event() {
+        let fin = fs::read_to_string("testfiles/boundary-event.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 6);
+        debug_transitions(&bpmn, &marking);
+
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![0]);
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+
+        //the task and the timer race
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1, 2]);
+
+        //the task wins: the timer is no longer enabled
+        let mut marking_task = marking.clone();
+        bpmn.execute_transition(&mut marking_task, 1).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking_task).unwrap(),
+            vec![4]
+        );
+
+        //the timer wins: the task is cancelled
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3]);
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![5]);
+        bpmn.execute_transition(&mut marking, 5).unwrap();
+
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_semantics_boundary_event_non_interrupting() {
+        let fin = fs::read_to_string("testfiles/boundary-event.bpmn")
+            .unwrap()
+            .replace(
+                "attachedToRef=\"task_a\"",
+                "attachedToRef=\"task_a\" cancelActivity=\"false\"",
+            );
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+
+        //the timer forks a token, and the task remains enabled; the timer fires only once per activation of the task
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1, 3]);
+
+        //once the task has been executed, the firing is forgotten
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3, 4]);
+        assert_eq!(
+            marking.element_index_2_sub_markings[0].element_index_2_tokens[2],
+            0
+        );
+    }
+
+    #[test]
+    fn bpmn_semantics_boundary_event_non_interrupting_cycle() {
+        let fin = fs::read_to_string("testfiles/boundary-event.bpmn")
+            .unwrap()
+            .replace(
+                "attachedToRef=\"task_a\"",
+                "attachedToRef=\"task_a\" cancelActivity=\"false\"",
+            )
+            .replace(
+                "<timerEventDefinition id=\"timermarker_1\"/>",
+                "<timerEventDefinition id=\"timermarker_1\"><timeCycle>R2/PT1H</timeCycle></timerEventDefinition>",
+            );
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+
+        //the cycle repeats twice, so the timer fires twice
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![1, 2, 3]
+        );
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1, 3]);
+    }
+
+    #[test]
+    fn bpmn_semantics_boundary_event_non_interrupting_unbounded_cycle() {
+        let fin = fs::read_to_string("testfiles/boundary-event.bpmn")
+            .unwrap()
+            .replace(
+                "attachedToRef=\"task_a\"",
+                "attachedToRef=\"task_a\" cancelActivity=\"false\"",
+            )
+            .replace(
+                "<timerEventDefinition id=\"timermarker_1\"/>",
+                "<timerEventDefinition id=\"timermarker_1\"><timeCycle>R/PT1H</timeCycle></timerEventDefinition>",
+            );
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+
+        //the cycle does not limit its repetitions, so the timer fires once per activation of the task
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1, 3]);
+    }
+
+    #[test]
+    fn bpmn_semantics_terminate_end_event() {
+        let fin = fs::read_to_string("testfiles/terminate-end-event.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 6);
+        debug_transitions(&bpmn, &marking);
+
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![2, 4]);
+
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3, 4]);
+
+        //the terminate end event removes the token in front of task b as well
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_semantics_terminate_end_event_nested_instance() {
+        let fin = fs::read_to_string("testfiles/terminate-end-event.bpmn")
+            .unwrap()
+            .replace(
+                "<task id=\"task_b\" name=\"b\">",
+                "<subProcess id=\"subprocess_b\">
+                    <startEvent id=\"startevent_2\"><outgoing>sequenceflow_6</outgoing></startEvent>
+                    <endEvent id=\"endevent_3\"><incoming>sequenceflow_6</incoming></endEvent>
+                    <sequenceFlow id=\"sequenceflow_6\" sourceRef=\"startevent_2\" targetRef=\"endevent_3\"/>",
+            )
+            .replace(
+                "<outgoing>sequenceflow_5</outgoing>\n\t\t</task>",
+                "<outgoing>sequenceflow_5</outgoing>\n\t\t</subProcess>",
+            )
+            .replace("targetRef=\"task_b\"", "targetRef=\"subprocess_b\"")
+            .replace("sourceRef=\"task_b\"", "sourceRef=\"subprocess_b\"");
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        debug_transitions(&bpmn, &marking);
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![2, 4]);
+
+        //start an instance of the sub-process, and execute task a
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+
+        //the terminate end event consumes the start token of the running instance as well
+        let consumed_tokens = bpmn.transition_2_consumed_tokens(3, &marking).unwrap();
+        assert_eq!(consumed_tokens.len(), 2);
+        assert!(
+            consumed_tokens
+                .iter()
+                .any(|token| matches!(token, Token::SubProcessStart { .. }))
+        );
+
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_semantics_error_end_event() {
+        let fin = fs::read_to_string("testfiles/error-end-event.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 7);
+        debug_transitions(&bpmn, &marking);
+
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3]);
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![5]);
+
+        //the error cancels the sub-process instance and continues along the boundary event
+        bpmn.execute_transition(&mut marking, 5).unwrap();
+        assert!(marking.element_index_2_sub_markings[0].element_index_2_sub_markings[1].is_empty());
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![4]);
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![6]);
+        bpmn.execute_transition(&mut marking, 6).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_semantics_error_end_event_uncaught() {
+        let fin = fs::read_to_string("testfiles/error-end-event.bpmn")
+            .unwrap()
+            .replace(
+                "<errorEventDefinition id=\"errormarker_2\"/>",
+                "<timerEventDefinition id=\"timermarker_2\"/>",
+            );
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        for transition in [0, 1, 3, 4, 5] {
+            bpmn.execute_transition(&mut marking, transition).unwrap();
+        }
+
+        //without an error boundary event, the error ends the process instance
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+        assert!(marking.element_index_2_sub_markings[0].is_empty());
+        assert!(marking.root_marking.error_thrown.is_none());
+    }
+
+    #[test]
+    fn bpmn_semantics_error_end_event_error_ref() {
+        let fin = fs::read_to_string("testfiles/error-end-event.bpmn")
+            .unwrap()
+            .replace(
+                "<errorEventDefinition id=\"errormarker_1\"/>",
+                "<errorEventDefinition id=\"errormarker_1\" errorRef=\"error_1\"/>",
+            );
+
+        //a boundary event with the same errorRef catches the error
+        let bpmn = fin
+            .replace(
+                "<errorEventDefinition id=\"errormarker_2\"/>",
+                "<errorEventDefinition id=\"errormarker_2\" errorRef=\"error_1\"/>",
+            )
+            .parse::<BusinessProcessModelAndNotation>()
+            .unwrap();
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        for transition in [0, 1, 3, 4, 5] {
+            bpmn.execute_transition(&mut marking, transition).unwrap();
+        }
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![4]);
+
+        //a boundary event without errorRef catches all errors
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        for transition in [0, 1, 3, 4, 5] {
+            bpmn.execute_transition(&mut marking, transition).unwrap();
+        }
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![4]);
+
+        //a boundary event with another errorRef does not catch the error, which ends the process instance
+        let bpmn = fin
+            .replace(
+                "<errorEventDefinition id=\"errormarker_2\"/>",
+                "<errorEventDefinition id=\"errormarker_2\" errorRef=\"error_2\"/>",
+            )
+            .parse::<BusinessProcessModelAndNotation>()
+            .unwrap();
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        for transition in [0, 1, 3, 4, 5] {
+            bpmn.execute_transition(&mut marking, transition).unwrap();
+        }
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_semantics_error_end_event_process() {
+        let fin = fs::read_to_string("testfiles/terminate-end-event.bpmn")
+            .unwrap()
+            .replace(
+                "<terminateEventDefinition id=\"terminatemarker_1\"/>",
+                "<errorEventDefinition id=\"errormarker_1\"/>",
+            );
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3, 4]);
+
+        //the uncaught error ends the process instance, including the token in front of task b
+        let consumed_tokens = bpmn.transition_2_consumed_tokens(3, &marking).unwrap();
+        assert_eq!(consumed_tokens.len(), 2);
+        assert!(
+            bpmn.transition_2_produced_tokens(3, &marking)
+                .unwrap()
+                .is_empty()
+        );
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_semantics_link_event() {
+        let fin = fs::read_to_string("testfiles/link-event.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 5);
+        debug_transitions(&bpmn, &marking);
+
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![2]);
+        assert!(bpmn.is_transition_silent(2, &marking));
+
+        //the link throw event jumps directly to the outgoing sequence flow of the link catch event
+        let produced_tokens = bpmn.transition_2_produced_tokens(2, &marking).unwrap();
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3]);
+        assert_eq!(
+            bpmn.transition_2_consumed_tokens(3, &marking).unwrap(),
+            produced_tokens
+        );
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_semantics_signal_event() {
+        let fin = fs::read_to_string("testfiles/signal-event.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 9);
+        debug_transitions(&bpmn, &marking);
+
+        //the signal start event is only triggered by the signal throw event
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![0]);
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1]);
+
+        //throwing the first signal starts the second pool, which waits for the second signal
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![2]);
+
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3]);
+
+        //throwing the second signal lets the waiting catch event continue
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![4, 7]);
+
+        bpmn.execute_transition(&mut marking, 7).unwrap();
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![8]);
+        bpmn.execute_transition(&mut marking, 8).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_semantics_call_activity() {
+        let fin = fs::read_to_string("testfiles/call-activity.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 7);
+        debug_transitions(&bpmn, &marking);
+
+        //the called process does not start by itself
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![0]);
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1]);
+
+        //the call activity starts an instance of the called process
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 11);
+        debug_transitions(&bpmn, &marking);
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3]);
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![4]);
+        assert!(!bpmn.is_transition_silent(4, &marking));
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        bpmn.execute_transition(&mut marking, 5).unwrap();
+
+        //the instance has ended, so the call activity can complete
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![2]);
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 7);
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![2]);
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_call_activity_cycle() {
+        let fin = fs::read_to_string("testfiles/call-activity.bpmn")
+            .unwrap()
+            .replace("calledElement=\"process_2\"", "calledElement=\"process_1\"");
+        assert!(fin.parse::<BusinessProcessModelAndNotation>().is_err());
+    }
+
+    #[test]
+    fn bpmn_semantics_multi_instance_and_loop_task_tokens() {
+        let fin = fs::read_to_string("testfiles/multi-instance.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+
+        //the activation keeps the number of instances that still have to be executed
+        assert!(matches!(
+            bpmn.transition_2_consumed_tokens(1, &marking).unwrap()[..],
+            [Token::SequenceFlow(_)]
+        ));
+        assert!(matches!(
+            bpmn.transition_2_produced_tokens(1, &marking).unwrap()[..],
+            [Token::Activation { count: 3, .. }]
+        ));
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        assert!(matches!(
+            bpmn.transition_2_consumed_tokens(3, &marking).unwrap()[..],
+            [Token::Activation { count: 3, .. }]
+        ));
+        assert!(matches!(
+            bpmn.transition_2_produced_tokens(3, &marking).unwrap()[..],
+            [Token::Activation { count: 2, .. }]
+        ));
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+
+        //the last instance completes the task
+        assert!(matches!(
+            bpmn.transition_2_produced_tokens(2, &marking).unwrap()[..],
+            [Token::SequenceFlow(_)]
+        ));
+
+        let fin = fs::read_to_string("testfiles/standard-loop.sbpmn").unwrap();
+        let sbpmn = fin
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+        let bpmn = &sbpmn.bpmn;
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+
+        //the activation keeps the number of executions so far
+        assert!(matches!(
+            bpmn.transition_2_produced_tokens(1, &marking).unwrap()[..],
+            [Token::Activation { count: 1, .. }]
+        ));
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+
+        //repeat
+        assert!(matches!(
+            bpmn.transition_2_consumed_tokens(2, &marking).unwrap()[..],
+            [Token::Activation { count: 1, .. }]
+        ));
+        assert!(matches!(
+            bpmn.transition_2_produced_tokens(2, &marking).unwrap()[..],
+            [Token::Activation { count: 2, .. }]
+        ));
+
+        //continue
+        assert!(matches!(
+            bpmn.transition_2_consumed_tokens(3, &marking).unwrap()[..],
+            [Token::Activation { count: 1, .. }]
+        ));
+        assert!(matches!(
+            bpmn.transition_2_produced_tokens(3, &marking).unwrap()[..],
+            [Token::SequenceFlow(_)]
+        ));
+    }
+
+    #[test]
+    fn bpmn_semantics_multi_instance() {
+        let fin = fs::read_to_string("testfiles/multi-instance.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 4);
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+
+        //activating the parallel multi-instance task is silent
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1]);
+        assert!(bpmn.is_transition_silent(1, &marking));
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+
+        //all three instances are enabled concurrently
+        assert_eq!(bpmn.number_of_transitions(&marking), 7);
+        debug_transitions(&bpmn, &marking);
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![2, 3, 4]
+        );
+        assert!(!bpmn.is_transition_silent(3, &marking));
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![2, 3]);
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+
+        //the task has completed
+        assert_eq!(bpmn.number_of_transitions(&marking), 4);
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![2]);
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+
+        //the sequential multi-instance sub-process runs one instance at a time
+        for _ in 0..2 {
+            assert_eq!(bpmn.number_of_transitions(&marking), 8);
+            debug_transitions(&bpmn, &marking);
+            assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![4]);
+            bpmn.execute_transition(&mut marking, 4).unwrap();
+            bpmn.execute_transition(&mut marking, 5).unwrap();
+            bpmn.execute_transition(&mut marking, 6).unwrap();
+            assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3]);
+            bpmn.execute_transition(&mut marking, 3).unwrap();
+        }
+
+        //the sub-process has completed
+        assert_eq!(bpmn.number_of_transitions(&marking), 4);
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3]);
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn sbpmn_semantics_standard_loop() {
+        let fin = fs::read_to_string("testfiles/standard-loop.sbpmn").unwrap();
+        let sbpmn = fin
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+        let bpmn = &sbpmn.bpmn;
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 4);
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+
+        //the first execution of the loop task is not silent
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1]);
+        assert!(!bpmn.is_transition_silent(1, &marking));
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+
+        //choose to repeat or to continue
+        assert_eq!(bpmn.number_of_transitions(&marking), 6);
+        debug_transitions(bpmn, &marking);
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![2, 3]);
+        assert!(!bpmn.is_transition_silent(2, &marking));
+        assert!(bpmn.is_transition_silent(3, &marking));
+        assert_eq!(
+            sbpmn.get_transition_probabilistic_penalty(2, &marking),
+            Some(f!(1, 4))
+        );
+        assert_eq!(
+            sbpmn.get_transition_probabilistic_penalty(3, &marking),
+            Some(f!(3, 4))
+        );
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+
+        //the loop maximum has been reached
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3]);
+        assert_eq!(
+            sbpmn.get_transition_probabilistic_penalty(3, &marking),
+            Some(Fraction::one())
+        );
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+
+        //the loop sub-process tests before its first execution
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![2]);
+        assert!(bpmn.is_transition_silent(2, &marking));
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 6);
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3, 4]);
+        assert_eq!(
+            sbpmn.get_transition_probabilistic_penalty(3, &marking),
+            Some(f!(1, 2))
+        );
+
+        //repeat: the choice is not available while the instance runs
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 10);
+        debug_transitions(bpmn, &marking);
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![6]);
+        bpmn.execute_transition(&mut marking, 6).unwrap();
+        bpmn.execute_transition(&mut marking, 7).unwrap();
+        bpmn.execute_transition(&mut marking, 8).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![5]);
+        bpmn.execute_transition(&mut marking, 5).unwrap();
+
+        //continue
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3, 4]);
+        assert!(bpmn.is_transition_silent(4, &marking));
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 4);
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3]);
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_semantics_task_types() {
+        let fin = fs::read_to_string("testfiles/task-types.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 8);
+        debug_transitions(&bpmn, &marking);
+
+        //the second process waits for the message of the send task
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![0]);
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1]);
+        assert!(!bpmn.is_transition_silent(1, &marking));
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(marking.root_marking.message_flow_2_tokens, vec![1]);
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![2, 4]);
+
+        //the other tasks behave like tasks
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        assert_eq!(marking.root_marking.message_flow_2_tokens, vec![0]);
+        for transition in [2, 3, 5, 6, 7] {
+            assert!(
+                bpmn.get_enabled_transitions(&marking)
+                    .unwrap()
+                    .contains(&transition)
+            );
+            bpmn.execute_transition(&mut marking, transition).unwrap();
+        }
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_semantics_event_sub_process() {
+        let fin = fs::read_to_string("testfiles/event-sub-process.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 5);
+        debug_transitions(&bpmn, &marking);
+
+        //the event sub-processes cannot start before the process instance is active
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![0]);
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1, 3]);
+
+        //the non-interrupting timer event sub-process runs alongside the process instance
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 9);
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![1, 3, 6]
+        );
+        bpmn.execute_transition(&mut marking, 6).unwrap();
+        bpmn.execute_transition(&mut marking, 7).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![1, 3, 4]
+        );
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1, 3]);
+
+        //the error is caught by the interrupting error event sub-process, which cancels the process instance
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![2, 3]);
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert!(marking.root_marking.error_thrown.is_none());
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![7]);
+        bpmn.execute_transition(&mut marking, 7).unwrap();
+        bpmn.execute_transition(&mut marking, 8).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![5]);
+        bpmn.execute_transition(&mut marking, 5).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn sbpmn_semantics_complex_gateway() {
+        let fin = fs::read_to_string("testfiles/complex-gateway.sbpmn").unwrap();
+        let bpmn = fin
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 16);
+        debug_transitions(&bpmn.bpmn, &marking);
+
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![2, 3, 4]
+        );
+
+        //the join needs two incoming tokens
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3, 4]);
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![4, 5, 6, 7]
+        );
+
+        //the split chooses like an inclusive gateway
+        assert_eq!(
+            bpmn.get_transition_probabilistic_penalty(5, &marking)
+                .unwrap(),
+            f!(3, 8)
+        );
+        assert_eq!(
+            bpmn.get_transition_probabilistic_penalty(6, &marking)
+                .unwrap(),
+            f!(3, 8)
+        );
+        assert_eq!(
+            bpmn.get_transition_probabilistic_penalty(7, &marking)
+                .unwrap(),
+            f!(1, 4)
+        );
+
+        //the join then waits for the token of c
+        assert!(matches!(
+            bpmn.transition_2_produced_tokens(7, &marking).unwrap()[..],
+            [
+                Token::SequenceFlow(_),
+                Token::SequenceFlow(_),
+                Token::Reset { .. }
+            ]
+        ));
+        bpmn.execute_transition(&mut marking, 7).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![4, 12, 13]
+        );
+
+        //the late token is absorbed without producing any
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![10, 12, 13]
+        );
+        assert!(matches!(
+            bpmn.transition_2_consumed_tokens(10, &marking).unwrap()[..],
+            [Token::SequenceFlow(_), Token::Reset { .. }]
+        ));
+        assert!(
+            bpmn.transition_2_produced_tokens(10, &marking)
+                .unwrap()
+                .is_empty()
+        );
+        bpmn.execute_transition(&mut marking, 10).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![12, 13]
+        );
+        bpmn.execute_transition(&mut marking, 12).unwrap();
+        bpmn.execute_transition(&mut marking, 13).unwrap();
+        bpmn.execute_transition(&mut marking, 14).unwrap();
+        bpmn.execute_transition(&mut marking, 15).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+        assert!(
+            marking
+                .element_index_2_sub_markings
+                .iter()
+                .all(|sub_marking| sub_marking.is_empty())
+        );
+    }
+
+    #[test]
+    fn sbpmn_semantics_complex_gateway_loop() {
+        let fin = fs::read_to_string("testfiles/complex-gateway-loop.sbpmn").unwrap();
+        let bpmn = fin
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 14);
+        debug_transitions(&bpmn.bpmn, &marking);
+
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![4, 5]);
+
+        //a fires the join, which then waits for b
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![5, 6]);
+        bpmn.execute_transition(&mut marking, 6).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![5, 10]
+        );
+
+        //loop back before b has finished
+        bpmn.execute_transition(&mut marking, 10).unwrap();
+        bpmn.execute_transition(&mut marking, 11).unwrap();
+        bpmn.execute_transition(&mut marking, 2).unwrap();
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![4, 5]);
+
+        //the second a does not fire the join while it waits for the first b
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![5]);
+
+        //the first b is absorbed, after which the second a fires the join
+        bpmn.execute_transition(&mut marking, 5).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![5, 8]);
+        bpmn.execute_transition(&mut marking, 8).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![5, 6]);
+        bpmn.execute_transition(&mut marking, 6).unwrap();
+
+        //the second b is absorbed as well
+        bpmn.execute_transition(&mut marking, 5).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![8, 10]
+        );
+        bpmn.execute_transition(&mut marking, 8).unwrap();
+        bpmn.execute_transition(&mut marking, 10).unwrap();
+        bpmn.execute_transition(&mut marking, 12).unwrap();
+        bpmn.execute_transition(&mut marking, 13).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn sbpmn_semantics_complex_gateway_reset_without_token() {
+        //b is not chosen, thus its token never comes to the join
+        let fin = fs::read_to_string("testfiles/complex-gateway-loop.sbpmn")
+            .unwrap()
+            .replace("parallelGateway", "exclusiveGateway")
+            .replace(
+                "targetRef=\"task_a\"/>",
+                "targetRef=\"task_a\">\n\t\t\t<sbpmn:weight constant=\"1\"/>\n\t\t</sequenceFlow>",
+            )
+            .replace(
+                "targetRef=\"task_b\"/>",
+                "targetRef=\"task_b\">\n\t\t\t<sbpmn:weight constant=\"1\"/>\n\t\t</sequenceFlow>",
+            );
+        let bpmn = fin
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 15);
+        debug_transitions(&bpmn.bpmn, &marking);
+
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        bpmn.execute_transition(&mut marking, 5).unwrap();
+        bpmn.execute_transition(&mut marking, 7).unwrap();
+
+        //while the token may still loop back to b, the join keeps waiting
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![11]);
+        bpmn.execute_transition(&mut marking, 11).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![12, 13]
+        );
+
+        //once the loop is left, the join resets
+        bpmn.execute_transition(&mut marking, 13).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![10, 14]
+        );
+        bpmn.execute_transition(&mut marking, 10).unwrap();
+        bpmn.execute_transition(&mut marking, 14).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+        assert!(
+            marking
+                .element_index_2_sub_markings
+                .iter()
+                .all(|sub_marking| sub_marking.is_empty())
+        );
+    }
+
+    #[test]
+    fn sbpmn_semantics_inclusive_gateway_default_sequence_flow() {
+        let fin = fs::read_to_string("testfiles/default-flow.sbpmn").unwrap();
+        let bpmn = fin
+            .parse::<StochasticBusinessProcessModelAndNotation>()
+            .unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![4, 5, 6, 7]
+        );
+
+        //the probabilities are normalised over the subsets that do not take the default sequence flow together with another one
+        assert_eq!(
+            bpmn.get_transition_probabilistic_penalty(4, &marking)
+                .unwrap(),
+            f!(10, 31)
+        );
+        assert_eq!(
+            bpmn.get_transition_probabilistic_penalty(5, &marking)
+                .unwrap(),
+            f!(7, 31)
+        );
+        let sum = bpmn
+            .get_enabled_transitions(&marking)
+            .unwrap()
+            .into_iter()
+            .map(|transition| {
+                bpmn.get_transition_probabilistic_penalty(transition, &marking)
+                    .unwrap()
+            })
+            .sum::<Fraction>();
+        assert_eq!(sum, Fraction::one());
+    }
+
+    #[test]
+    fn bpmn_semantics_default_sequence_flow() {
+        let fin = fs::read_to_string("testfiles/default-flow.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 17);
+        debug_transitions(&bpmn, &marking);
+
+        //the inclusive split never takes its default sequence flow together with another one
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![4, 5, 6, 7]
+        );
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![10]);
+        bpmn.execute_transition(&mut marking, 10).unwrap();
+        bpmn.execute_transition(&mut marking, 11).unwrap();
+
+        //the exclusive split may take its default sequence flow like any other
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![12, 13]
+        );
+        bpmn.execute_transition(&mut marking, 13).unwrap();
+        bpmn.execute_transition(&mut marking, 16).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+
+        //a default sequence flow must leave its gateway
+        assert!(
+            fin.replace("default=\"sequenceflow_10\"", "default=\"sequenceflow_8\"")
+                .parse::<BusinessProcessModelAndNotation>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn bpmn_semantics_condition_expressions() {
+        let fin = fs::read_to_string("testfiles/conditions.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        //without variables, the conditions are ignored
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        assert_eq!(bpmn.number_of_transitions(&marking), 21);
+        debug_transitions(&bpmn, &marking);
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(
+            bpmn.get_enabled_transitions(&marking).unwrap(),
+            vec![1, 2, 3]
+        );
+
+        //with variables, only the sequence flows of which the condition holds are taken
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        marking.set_variable("amount", 1500);
+        marking.set_variable("risk", "low");
+        assert!(marking.is_data_aware());
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1]);
+        bpmn.execute_transition(&mut marking, 1).unwrap();
+        bpmn.execute_transition(&mut marking, 4).unwrap();
+        bpmn.execute_transition(&mut marking, 7).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![15]);
+
+        //the default sequence flows are taken if no condition holds
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        marking.set_variable("amount", 100);
+        marking.set_variable("risk", "high");
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![3]);
+        bpmn.execute_transition(&mut marking, 3).unwrap();
+        bpmn.execute_transition(&mut marking, 6).unwrap();
+        bpmn.execute_transition(&mut marking, 9).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![13]);
+        bpmn.execute_transition(&mut marking, 13).unwrap();
+        bpmn.execute_transition(&mut marking, 19).unwrap();
+        bpmn.execute_transition(&mut marking, 20).unwrap();
+        assert!(bpmn.is_final_marking(&marking).unwrap());
+    }
+
+    #[test]
+    fn bpmn_semantics_unsupported_condition_expression() {
+        let fin = fs::read_to_string("testfiles/conditions.bpmn")
+            .unwrap()
+            .replace("amount &gt; 1000", "${amount &gt; 1000}");
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        //the unsupported condition always holds
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        marking.set_variable("amount", 100);
+        marking.set_variable("risk", "high");
+        bpmn.execute_transition(&mut marking, 0).unwrap();
+        assert_eq!(bpmn.get_enabled_transitions(&marking).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn bpmn_semantics_message_names() {
+        let fin = fs::read_to_string("testfiles/messages.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let marking = bpmn.get_initial_marking().unwrap().unwrap();
+        debug_transitions(&bpmn, &marking);
+        let debugs = (0..bpmn.number_of_transitions(&marking))
+            .filter_map(|transition_index| bpmn.transition_debug(transition_index, &marking))
+            .collect::<Vec<_>>();
+
+        //named messages are shown by name, others by id
+        assert!(debugs.iter().any(|debug| {
+            debug.starts_with("message intermediate throw event")
+                && debug.ends_with("; message `order`")
+        }));
+        assert!(debugs.iter().any(|debug| {
+            debug.starts_with("message intermediate catch event")
+                && debug.ends_with("; message `order`")
+        }));
+        assert!(debugs.iter().any(|debug| {
+            debug.starts_with("message end event") && debug.ends_with("; message `message_2`")
+        }));
+    }
+
+    #[test]
+    fn bpmn_semantics_unknown_transition() {
+        let fin = fs::read_to_string("testfiles/model.bpmn").unwrap();
+        let bpmn = fin.parse::<BusinessProcessModelAndNotation>().unwrap();
+
+        let mut marking = bpmn.get_initial_marking().unwrap().unwrap();
+        let transition_index = bpmn.number_of_transitions(&marking);
+        assert!(matches!(
+            bpmn.execute_transition(&mut marking, transition_index),
+            Err(BPMNError::SemanticMisuse(_))
+        ));
+        assert!(matches!(
+            bpmn.transition_2_consumed_tokens(transition_index, &marking),
+            Err(BPMNError::SemanticMisuse(_))
+        ));
+    }
 }
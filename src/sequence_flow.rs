@@ -3,6 +3,8 @@ use ebi_arithmetic::{Fraction, Signed};
 use crate::{
     BusinessProcessModelAndNotation,
     element::BPMNElement,
+    expression::BPMNConditionExpression,
+    marking::BPMNRootMarking,
     parser::parser_state::GlobalIndex,
     traits::{processable::Processable, searchable::Searchable},
 };
@@ -13,6 +15,7 @@ pub struct BPMNSequenceFlow {
     pub(crate) global_index: GlobalIndex,
     pub(crate) id: String,
     pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
     pub(crate) source_global_index: GlobalIndex,
     pub(crate) source_local_index: usize,
     pub(crate) target_global_index: GlobalIndex,
@@ -24,6 +27,8 @@ pub struct BPMNSequenceFlow {
     /// [BusinessProcessModelAndNotation]: crate::BusinessProcessModelAndNotation
     /// [StochasticBusinessProcessModelAndNotation]: crate::StochasticBusinessProcessModelAndNotation
     pub weight: Option<Fraction>,
+
+    pub(crate) condition_expression: Option<BPMNConditionExpression>,
 }
 
 impl BPMNSequenceFlow {
@@ -31,6 +36,10 @@ impl BPMNSequenceFlow {
         self.global_index
     }
 
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     pub fn source_global_index(&self) -> GlobalIndex {
         self.source_global_index
     }
@@ -39,6 +48,24 @@ impl BPMNSequenceFlow {
         self.target_global_index
     }
 
+    pub fn condition_expression(&self) -> Option<&BPMNConditionExpression> {
+        self.condition_expression.as_ref()
+    }
+
+    /// Returns whether the condition of this sequence flow holds for the variables of the marking.
+    /// The condition of a sequence flow without a condition expression always holds, as does any condition in a marking that is not data-aware.
+    /// A condition that is not supported, which is reported on import, holds as well.
+    pub(crate) fn condition_holds(&self, root_marking: &BPMNRootMarking) -> bool {
+        match (&self.condition_expression, &root_marking.variables) {
+            (Some(condition_expression), Some(variables))
+                if condition_expression.is_supported() =>
+            {
+                condition_expression.holds(variables).unwrap_or(true)
+            }
+            _ => true,
+        }
+    }
+
     pub fn has_fireable_weight(&self, bpmn: &BusinessProcessModelAndNotation) -> bool {
         if !bpmn.stochastic_namespace {
             true
@@ -0,0 +1,25 @@
+use crate::parser::parser_state::GlobalIndex;
+
+/// A struct that represents a signal definition in a BPMN model.
+/// Signal events refer to a signal by its id; a thrown signal is broadcast to all signal catch events that refer to the same signal.
+#[derive(Clone, Debug)]
+pub struct BPMNSignal {
+    pub(crate) global_index: GlobalIndex,
+    pub(crate) id: String,
+    pub(crate) local_index: usize,
+    pub(crate) name: Option<String>,
+}
+
+impl BPMNSignal {
+    pub fn global_index(&self) -> GlobalIndex {
+        self.global_index
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
@@ -0,0 +1,147 @@
+use crate::marking::BPMNSubMarking;
+use anyhow::{Result, anyhow};
+use bitvec::{bitvec, vec::BitVec};
+use ebi_arithmetic::{Fraction, One};
+
+/// The standard loop characteristics of an activity: after each execution of the activity, a silent choice is made to repeat it or to continue.
+///
+/// While a loop activity is running, its activations are kept in the sub-markings of the activity.
+/// An activation keeps the number of executions so far as its only element token.
+/// An activation of a sub-process keeps its running instance, if any, as its only list of sub-markings.
+/// Each activation has two choice transitions: one to repeat the activity and one to continue.
+#[derive(Debug, Clone)]
+pub struct BPMNStandardLoop {
+    pub(crate) id: Option<String>,
+    /// Whether the choice is also made before the first execution, such that the activity may not be executed at all.
+    pub test_before: bool,
+    /// The maximum number of executions of the activity, if any.
+    pub loop_maximum: Option<u64>,
+    /// The probability of repeating the activity after an execution (SBPMN).
+    pub repeat_probability: Option<Fraction>,
+}
+
+impl BPMNStandardLoop {
+    /// Returns whether an activation with the given number of executions may be repeated.
+    fn can_repeat(&self, executions: u64) -> bool {
+        match self.loop_maximum {
+            Some(loop_maximum) => executions < loop_maximum,
+            None => true,
+        }
+    }
+
+    /// Returns a new activation of a task: the first execution is part of the activation, unless the loop tests before.
+    pub(crate) fn new_task_activation(&self) -> BPMNSubMarking {
+        BPMNSubMarking {
+            sequence_flow_2_tokens: vec![],
+            initial_choice_token: false,
+            element_index_2_tokens: vec![if self.test_before { 0 } else { 1 }],
+            element_index_2_sub_markings: vec![],
+        }
+    }
+
+    /// Returns a new activation of a sub-process, with its first instance started, unless the loop tests before.
+    pub(crate) fn new_sub_process_activation(
+        &self,
+        start_instance: impl FnOnce() -> Result<BPMNSubMarking>,
+    ) -> Result<BPMNSubMarking> {
+        let instances = if self.test_before {
+            vec![]
+        } else {
+            vec![start_instance()?]
+        };
+        Ok(BPMNSubMarking {
+            sequence_flow_2_tokens: vec![],
+            initial_choice_token: false,
+            element_index_2_tokens: vec![instances.len() as u64],
+            element_index_2_sub_markings: vec![instances],
+        })
+    }
+
+    /// Returns the number of choice transitions of the given activations.
+    pub(crate) fn number_of_choice_transitions(&self, activations: &[BPMNSubMarking]) -> usize {
+        2 * activations.len()
+    }
+
+    /// Returns the enabledness of the choice transitions of the given activations.
+    /// The choice can only be made while no instance of the activity is running.
+    pub(crate) fn choice_enabledness(&self, activations: &[BPMNSubMarking]) -> BitVec {
+        let mut result = bitvec![0; self.number_of_choice_transitions(activations)];
+        for (activation_index, activation) in activations.iter().enumerate() {
+            if activation
+                .element_index_2_sub_markings
+                .first()
+                .is_some_and(|instances| !instances.is_empty())
+            {
+                continue;
+            }
+            result.set(
+                2 * activation_index,
+                self.can_repeat(activation.element_index_2_tokens[0]),
+            );
+            result.set(2 * activation_index + 1, true);
+        }
+        result
+    }
+
+    /// Returns whether the given choice transition is the one that continues, rather than repeats.
+    pub(crate) fn is_continue_transition(&self, transition_index: usize) -> bool {
+        transition_index % 2 == 1
+    }
+
+    /// Executes a choice transition.
+    /// Returns whether the activity has completed; otherwise, the execution count of the activation has been increased and the activity is to be repeated.
+    pub(crate) fn execute_choice_transition(
+        &self,
+        activations: &mut Vec<BPMNSubMarking>,
+        transition_index: usize,
+    ) -> Result<bool> {
+        let activation_index = transition_index / 2;
+        if activation_index >= activations.len() {
+            return Err(anyhow!("Transition does not exist."));
+        }
+        if self.is_continue_transition(transition_index) {
+            activations.remove(activation_index);
+            Ok(true)
+        } else {
+            activations[activation_index].element_index_2_tokens[0] += 1;
+            Ok(false)
+        }
+    }
+
+    /// Returns the execution count of the activation to which the given choice transition belongs, before the transition, and after it if the loop repeats.
+    pub(crate) fn choice_transition_2_executions(
+        &self,
+        activations: &[BPMNSubMarking],
+        transition_index: usize,
+    ) -> Result<(u64, Option<u64>)> {
+        let activation = activations
+            .get(transition_index / 2)
+            .ok_or_else(|| anyhow!("Transition does not exist."))?;
+        let executions = activation.element_index_2_tokens[0];
+        if self.is_continue_transition(transition_index) {
+            Ok((executions, None))
+        } else {
+            Ok((executions, Some(executions + 1)))
+        }
+    }
+
+    /// Returns the probabilistic penalty of a choice transition, if the loop has a repeat probability.
+    pub(crate) fn choice_probabilistic_penalty(
+        &self,
+        activations: &[BPMNSubMarking],
+        transition_index: usize,
+    ) -> Option<Fraction> {
+        let activation = activations.get(transition_index / 2)?;
+        if !self.can_repeat(activation.element_index_2_tokens[0]) {
+            //the maximum has been reached: continuing is certain
+            return Some(Fraction::one());
+        }
+
+        let repeat_probability = self.repeat_probability.as_ref()?;
+        if self.is_continue_transition(transition_index) {
+            Some(Fraction::one() - repeat_probability)
+        } else {
+            Some(repeat_probability.clone())
+        }
+    }
+}
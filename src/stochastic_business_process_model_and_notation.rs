@@ -1,12 +1,13 @@
 use crate::{
     BPMNMarking, BusinessProcessModelAndNotation,
     element::BPMNElement,
+    error::BPMNError,
     parser::{parser::NAMESPACE_SBPMN, parser_state::GlobalIndex},
     semantics::TransitionIndex,
     sequence_flow::BPMNSequenceFlow,
     traits::processable::Processable,
 };
-use anyhow::{Error, Result, anyhow};
+use anyhow::Result;
 #[cfg(any(test, feature = "testactivities"))]
 use ebi_activity_key::TestActivityKey;
 use ebi_activity_key::{ActivityKey, HasActivityKey, TranslateActivityKey};
@@ -23,16 +24,16 @@ pub struct StochasticBusinessProcessModelAndNotation {
 }
 
 impl StochasticBusinessProcessModelAndNotation {
-    pub fn import_from_reader(reader: &mut dyn BufRead) -> Result<Self>
+    pub fn import_from_reader(reader: &mut dyn BufRead) -> Result<Self, BPMNError>
     where
         Self: Sized,
     {
         let bpmn = BusinessProcessModelAndNotation::import_from_reader(reader, false)?;
         if !bpmn.stochastic_namespace {
-            return Err(anyhow!(
+            return Err(BPMNError::SemanticMisuse(format!(
                 "The SBPMN namespace of `{}` must be declared on the definitions tag.",
                 String::from_utf8_lossy(NAMESPACE_SBPMN)
-            ));
+            )));
         }
         let sbpmn = Self { bpmn };
         sbpmn.is_structurally_correct()?;
@@ -119,7 +120,7 @@ impl HasActivityKey for StochasticBusinessProcessModelAndNotation {
 }
 
 impl FromStr for StochasticBusinessProcessModelAndNotation {
-    type Err = Error;
+    type Err = BPMNError;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         let mut reader = std::io::Cursor::new(s);
@@ -2,14 +2,16 @@ use crate::{
     BusinessProcessModelAndNotation,
     element::{BPMNElement, BPMNElementTrait},
     elements::{
-        event_based_gateway::BPMNEventBasedGateway, exclusive_gateway::BPMNExclusiveGateway,
-        inclusive_gateway::BPMNInclusiveGateway,
+        complex_gateway::BPMNComplexGateway, event_based_gateway::BPMNEventBasedGateway,
+        exclusive_gateway::BPMNExclusiveGateway, inclusive_gateway::BPMNInclusiveGateway,
     },
+    error::BPMNError,
     stochastic_business_process_model_and_notation::StochasticBusinessProcessModelAndNotation,
-    traits::{objectable::BPMNObject, startable::Startable},
+    traits::{objectable::BPMNObject, searchable::Searchable, startable::Startable},
 };
 use anyhow::{Context, Result, anyhow};
-use ebi_arithmetic::Signed;
+use ebi_arithmetic::{Fraction, One, Signed};
+use std::collections::{HashMap, HashSet};
 
 impl BusinessProcessModelAndNotation {
     /// Verify whether the model is structurally correct using several, though not exhaustive, checks.
@@ -17,7 +19,10 @@ impl BusinessProcessModelAndNotation {
     ///
     /// [import_from_reader]: BusinessProcessModelAndNotation::import_from_reader
     /// [BPMNCreator]: crate::BPMNCreator
-    pub fn is_structurally_correct(&self) -> Result<()> {
+    pub fn is_structurally_correct(&self) -> Result<(), BPMNError> {
+        //check that call activities do not call processes recursively
+        self.verify_no_recursive_calls()?;
+
         //check elements
         for element in &self.elements {
             element
@@ -34,10 +39,57 @@ impl BusinessProcessModelAndNotation {
         for message_flow in &self.message_flows {
             //each message must connect different pools
             if message_flow.source_pool_index == message_flow.target_pool_index {
-                return Err(anyhow!(
-                    "message flow with id `{}` is intra-pool",
-                    message_flow.id
-                ));
+                return Err(BPMNError::StructuralViolation {
+                    message: format!("message flow with id `{}` is intra-pool", message_flow.id),
+                    element_ids: vec![message_flow.id.clone()],
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that no process calls itself, either directly or through other processes.
+    fn verify_no_recursive_calls(&self) -> Result<()> {
+        //gather the processes that each process calls
+        let mut process_2_called_processes = HashMap::new();
+        for element in &self.elements {
+            if let BPMNElement::Process(process) = element {
+                let called_processes = process
+                    .all_elements_ref()
+                    .into_iter()
+                    .filter_map(|element| match element {
+                        BPMNElement::CallActivity(call_activity) => {
+                            Some(call_activity.called_element.as_str())
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                process_2_called_processes.insert(process.id.as_str(), called_processes);
+            }
+        }
+
+        //depth-first search from each process
+        for process_id in process_2_called_processes.keys() {
+            let mut visited = HashSet::new();
+            let mut queue = vec![*process_id];
+            while let Some(current) = queue.pop() {
+                for called in process_2_called_processes
+                    .get(current)
+                    .map(|x| x.as_slice())
+                    .unwrap_or(&[])
+                {
+                    if called == process_id {
+                        return Err(BPMNError::Unsupported(format!(
+                            "Process `{}` calls itself, possibly through other processes, which is not supported.",
+                            process_id
+                        ))
+                        .into());
+                    }
+                    if visited.insert(*called) {
+                        queue.push(*called);
+                    }
+                }
             }
         }
 
@@ -46,7 +98,7 @@ impl BusinessProcessModelAndNotation {
 }
 
 impl StochasticBusinessProcessModelAndNotation {
-    pub fn is_structurally_correct(&self) -> Result<()> {
+    pub fn is_structurally_correct(&self) -> Result<(), BPMNError> {
         //check the bpmn itself
         self.bpmn
             .is_structurally_correct()
@@ -57,21 +109,27 @@ impl StochasticBusinessProcessModelAndNotation {
             //gather the start elements
             let mut start_elements = vec![];
             for element in &self.bpmn.elements {
-                if let BPMNElement::Process(process) = element {
-                    start_elements
-                        .extend(process.unconstrained_start_events_without_recursing(&self.bpmn)?);
+                match element {
+                    BPMNElement::Process(process) if !process.is_called => {
+                        start_elements.extend(
+                            process.unconstrained_start_events_without_recursing(&self.bpmn)?,
+                        );
+                    }
+                    _ => {}
                 }
             }
             if start_elements.len() > 1 {
-                println!("start elements {:?}", start_elements);
-                return Err(anyhow!("An SBPMN model can have at most one start event."));
+                return Err(BPMNError::Unsupported(
+                    "An SBPMN model can have at most one start event.".to_string(),
+                ));
             }
         }
 
         //choice-based sequence flows must have weights
         {
             for element in self.bpmn.elements() {
-                if element.is_event_based_gateway()
+                if element.is_complex_gateway()
+                    || element.is_event_based_gateway()
                     || element.is_exclusive_gateway()
                     || element.is_inclusive_gateway()
                 {
@@ -88,10 +146,13 @@ impl StochasticBusinessProcessModelAndNotation {
                                 .ok_or_else(|| anyhow!("sequence flow not found"))?;
 
                             if sequence_flow.weight.is_none() {
-                                return Err(anyhow!(
-                                    "Sequence flow `{}` originates from a choice-making gateway and therefore must have a weight.",
-                                    sequence_flow.id
-                                ));
+                                return Err(BPMNError::StructuralViolation {
+                                    message: format!(
+                                        "Sequence flow `{}` originates from a choice-making gateway and therefore must have a weight.",
+                                        sequence_flow.id
+                                    ),
+                                    element_ids: vec![sequence_flow.id.clone()],
+                                });
                             }
                         }
                     }
@@ -99,6 +160,37 @@ impl StochasticBusinessProcessModelAndNotation {
             }
         }
 
+        //loop activities must have a repeat probability
+        for element in self.bpmn.elements() {
+            if let Some(standard_loop) = element.standard_loop() {
+                match &standard_loop.repeat_probability {
+                    None => {
+                        return Err(BPMNError::StructuralViolation {
+                            message: format!(
+                                "Loop activity `{}` must have a repeat probability.",
+                                element.id()
+                            ),
+                            element_ids: vec![element.id().to_string()],
+                        });
+                    }
+                    Some(repeat_probability)
+                        if repeat_probability.is_negative()
+                            || repeat_probability > &Fraction::one() =>
+                    {
+                        return Err(BPMNError::StructuralViolation {
+                            message: format!(
+                                "Loop activity `{}` has repeat probability {}, which is not between 0 and 1.",
+                                element.id(),
+                                repeat_probability
+                            ),
+                            element_ids: vec![element.id().to_string()],
+                        });
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
         //we cannot handle models with steered event-based gateways
         {
             for element in self.bpmn.elements() {
@@ -118,11 +210,11 @@ impl StochasticBusinessProcessModelAndNotation {
                                 //no problem
                             } else {
                                 //otherwise, the message must be there
-                                return Err(anyhow!(
+                                return Err(BPMNError::Unsupported(format!(
                                     "Event-based gateway `{}` has an outgoing sequence flow to element `{}`, which depends on an uncertain message. This is not supported.",
                                     element.id(),
                                     target.id()
-                                ));
+                                )));
                             }
                         } else {
                             //there is no constraining message, so this message start event can start a process instance
@@ -136,12 +228,20 @@ impl StochasticBusinessProcessModelAndNotation {
         //check that outgoing sequence flows have weights
         for element in self.bpmn.elements() {
             match element {
-                BPMNElement::CollapsedPool(_)
+                BPMNElement::BoundaryEvent(_)
+                | BPMNElement::BusinessRuleTask(_)
+                | BPMNElement::CallActivity(_)
+                | BPMNElement::CollapsedPool(_)
                 | BPMNElement::CollapsedSubProcess(_)
                 | BPMNElement::EndEvent(_)
+                | BPMNElement::ErrorEndEvent(_)
+                | BPMNElement::ErrorStartEvent(_)
+                | BPMNElement::EventSubProcess(_)
                 | BPMNElement::ExpandedSubProcess(_)
                 | BPMNElement::IntermediateCatchEvent(_)
                 | BPMNElement::IntermediateThrowEvent(_)
+                | BPMNElement::LinkIntermediateCatchEvent(_)
+                | BPMNElement::LinkIntermediateThrowEvent(_)
                 | BPMNElement::ManualTask(_)
                 | BPMNElement::MessageEndEvent(_)
                 | BPMNElement::MessageIntermediateCatchEvent(_)
@@ -150,12 +250,24 @@ impl StochasticBusinessProcessModelAndNotation {
                 | BPMNElement::ParallelGateway(_)
                 | BPMNElement::Process(_)
                 | BPMNElement::ReceiveTask(_)
+                | BPMNElement::ScriptTask(_)
+                | BPMNElement::SendTask(_)
+                | BPMNElement::ServiceTask(_)
+                | BPMNElement::SignalEndEvent(_)
+                | BPMNElement::SignalIntermediateCatchEvent(_)
+                | BPMNElement::SignalIntermediateThrowEvent(_)
+                | BPMNElement::SignalStartEvent(_)
                 | BPMNElement::StartEvent(_)
                 | BPMNElement::Task(_)
+                | BPMNElement::TerminateEndEvent(_)
                 | BPMNElement::TimerIntermediateCatchEvent(_)
                 | BPMNElement::TimerStartEvent(_)
                 | BPMNElement::UserTask(_) => {}
-                BPMNElement::EventBasedGateway(BPMNEventBasedGateway {
+                BPMNElement::ComplexGateway(BPMNComplexGateway {
+                    outgoing_sequence_flows,
+                    ..
+                })
+                | BPMNElement::EventBasedGateway(BPMNEventBasedGateway {
                     outgoing_sequence_flows,
                     ..
                 })
@@ -181,16 +293,22 @@ impl StochasticBusinessProcessModelAndNotation {
 
                             if let Some(weight) = &sequence_flow.weight {
                                 if weight.is_negative() {
-                                    return Err(anyhow!(
-                                        "Sequence flow `{}` has a negative weight.",
-                                        sequence_flow.id
-                                    ));
+                                    return Err(BPMNError::StructuralViolation {
+                                        message: format!(
+                                            "Sequence flow `{}` has a negative weight.",
+                                            sequence_flow.id
+                                        ),
+                                        element_ids: vec![sequence_flow.id.clone()],
+                                    });
                                 }
                             } else {
-                                return Err(anyhow!(
-                                    "Sequence flow `{}` does not have a weight. It should have a weight as it is an outgoing sequence flow of a gateway that makes a choice.",
-                                    sequence_flow.id
-                                ));
+                                return Err(BPMNError::StructuralViolation {
+                                    message: format!(
+                                        "Sequence flow `{}` does not have a weight. It should have a weight as it is an outgoing sequence flow of a gateway that makes a choice.",
+                                        sequence_flow.id
+                                    ),
+                                    element_ids: vec![sequence_flow.id.clone()],
+                                });
                             }
                         }
                     }
@@ -211,34 +329,46 @@ macro_rules! verify_structural_correctness_initiation_mode {
         {
             //there must be end events
             if $process.end_events_without_recursing().is_empty() {
-                return Err(anyhow!(
-                    "Process `{}` has start events but no end events.",
-                    $process.id
-                ));
+                return Err(BPMNError::StructuralViolation {
+                    message: format!(
+                        "Process `{}` has start events but no end events.",
+                        $process.id
+                    ),
+                    element_ids: vec![$process.id.to_string()],
+                }
+                .into());
             }
 
             //all elements must have incoming and outgoing arcs
             for element in &$process.elements {
                 if element.can_have_incoming_sequence_flows() {
                     if element.incoming_sequence_flows().is_empty() {
-                        return Err(anyhow!(
-                            "Given that there are start events in process `{}`, element `{}` should have an incoming sequence flow.",
-                            $process.id,
-                            element.id()
-                        ));
+                        return Err(BPMNError::StructuralViolation {
+                            message: format!(
+                                "Given that there are start events in process `{}`, element `{}` should have an incoming sequence flow.",
+                                $process.id,
+                                element.id()
+                            ),
+                            element_ids: vec![element.id().to_string()],
+                        }
+                        .into());
                     }
                 }
                 if element.can_have_outgoing_sequence_flows() {
                     if element.outgoing_sequence_flows().is_empty() {
-                        return Err(anyhow!(
-                            "Given that there are start events in process `{}`, element `{}` should have an outgoing sequence flow.",
-                            $process.id,
-                            element.id()
-                        ));
+                        return Err(BPMNError::StructuralViolation {
+                            message: format!(
+                                "Given that there are start events in process `{}`, element `{}` should have an outgoing sequence flow.",
+                                $process.id,
+                                element.id()
+                            ),
+                            element_ids: vec![element.id().to_string()],
+                        }
+                        .into());
                     }
                 }
             }
         }
     };
 }
-pub(crate) use verify_structural_correctness_initiation_mode;
\ No newline at end of file
+pub(crate) use verify_structural_correctness_initiation_mode;
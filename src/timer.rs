@@ -0,0 +1,372 @@
+use anyhow::{Context, Result, anyhow};
+use std::{fmt::Display, str::FromStr};
+
+/// Which of `timeDate`, `timeDuration` and `timeCycle` defines a timer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BPMNTimerType {
+    Date,
+    Duration,
+    Cycle,
+}
+
+impl BPMNTimerType {
+    pub(crate) fn tag_name(&self) -> &'static str {
+        match self {
+            BPMNTimerType::Date => "timeDate",
+            BPMNTimerType::Duration => "timeDuration",
+            BPMNTimerType::Cycle => "timeCycle",
+        }
+    }
+}
+
+/// The timer of a timer start event, timer intermediate catch event or timer boundary event.
+///
+/// The text of a timer is an ISO-8601 date-time (`timeDate`), duration (`timeDuration`) or repeating interval (`timeCycle`).
+/// Timers do not influence behaviour: a timer event may fire at any moment.
+#[derive(Debug, Clone)]
+pub struct BPMNTimer {
+    pub(crate) timer_type: BPMNTimerType,
+    pub(crate) text: String,
+
+    ///the parsed specification; absent if the text is not in ISO-8601, such as an expression of an engine
+    pub(crate) specification: Option<BPMNTimerSpecification>,
+}
+
+impl BPMNTimer {
+    /// Creates a timer. A text that is not in ISO-8601 is kept, but has no specification.
+    pub fn new(timer_type: BPMNTimerType, text: String) -> Self {
+        let specification = BPMNTimerSpecification::parse(timer_type, &text).ok();
+        Self {
+            timer_type,
+            text,
+            specification,
+        }
+    }
+
+    pub fn timer_type(&self) -> BPMNTimerType {
+        self.timer_type
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn specification(&self) -> Option<&BPMNTimerSpecification> {
+        self.specification.as_ref()
+    }
+}
+
+impl From<BPMNDuration> for BPMNTimer {
+    fn from(value: BPMNDuration) -> Self {
+        Self::new(BPMNTimerType::Duration, value.to_string())
+    }
+}
+
+/// The parsed text of a timer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BPMNTimerSpecification {
+    Date(BPMNDateTime),
+    Duration(BPMNDuration),
+
+    /// A repeating interval `R[n]/[start/]duration[/end]`; without a number of repetitions, the interval repeats indefinitely.
+    Cycle {
+        repetitions: Option<u64>,
+        start: Option<BPMNDateTime>,
+        interval: BPMNDuration,
+        end: Option<BPMNDateTime>,
+    },
+}
+
+impl BPMNTimerSpecification {
+    pub fn parse(timer_type: BPMNTimerType, text: &str) -> Result<Self> {
+        let text = text.trim();
+        match timer_type {
+            BPMNTimerType::Date => Ok(Self::Date(text.parse()?)),
+            BPMNTimerType::Duration => Ok(Self::Duration(text.parse()?)),
+            BPMNTimerType::Cycle => {
+                let mut parts = text.split('/');
+                let repetitions = parts
+                    .next()
+                    .and_then(|part| part.strip_prefix('R'))
+                    .ok_or_else(|| anyhow!("repeating interval `{}` must start with `R`", text))?;
+                let repetitions = if repetitions.is_empty() {
+                    None
+                } else {
+                    Some(repetitions.parse::<u64>().with_context(|| {
+                        anyhow!("parsing the number of repetitions of `{}`", text)
+                    })?)
+                };
+
+                match (parts.next(), parts.next(), parts.next()) {
+                    (Some(interval), None, None) => Ok(Self::Cycle {
+                        repetitions,
+                        start: None,
+                        interval: interval.parse()?,
+                        end: None,
+                    }),
+                    (Some(first), Some(second), None) if first.starts_with('P') => {
+                        Ok(Self::Cycle {
+                            repetitions,
+                            start: None,
+                            interval: first.parse()?,
+                            end: Some(second.parse()?),
+                        })
+                    }
+                    (Some(first), Some(second), None) => Ok(Self::Cycle {
+                        repetitions,
+                        start: Some(first.parse()?),
+                        interval: second.parse()?,
+                        end: None,
+                    }),
+                    _ => Err(anyhow!(
+                        "repeating interval `{}` must have a duration, and may have a start or an end",
+                        text
+                    )),
+                }
+            }
+        }
+    }
+}
+
+/// An ISO-8601 duration, such as `P1DT12H` or `PT0.5S`.
+/// Years and months are kept as such, as their length depends on the moment at which the duration starts.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BPMNDuration {
+    pub years: u64,
+    pub months: u64,
+    pub weeks: u64,
+    pub days: u64,
+    pub hours: u64,
+    pub minutes: u64,
+    pub seconds: f64,
+}
+
+impl FromStr for BPMNDuration {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let rest = s
+            .strip_prefix('P')
+            .ok_or_else(|| anyhow!("duration `{}` must start with `P`", s))?;
+        let (date, time) = match rest.split_once('T') {
+            Some((date, time)) => (date, Some(time)),
+            None => (rest, None),
+        };
+
+        let mut result = BPMNDuration::default();
+        let mut components = 0;
+        for (value, designator) in duration_components(date, s)? {
+            match designator {
+                'Y' => result.years = parse_whole(value, s)?,
+                'M' => result.months = parse_whole(value, s)?,
+                'W' => result.weeks = parse_whole(value, s)?,
+                'D' => result.days = parse_whole(value, s)?,
+                _ => return Err(anyhow!("unexpected `{}` in duration `{}`", designator, s)),
+            }
+            components += 1;
+        }
+        if let Some(time) = time {
+            if time.is_empty() {
+                return Err(anyhow!("duration `{}` has no time after `T`", s));
+            }
+            for (value, designator) in duration_components(time, s)? {
+                match designator {
+                    'H' => result.hours = parse_whole(value, s)?,
+                    'M' => result.minutes = parse_whole(value, s)?,
+                    'S' => {
+                        result.seconds = value
+                            .replace(',', ".")
+                            .parse::<f64>()
+                            .with_context(|| anyhow!("parsing the seconds of `{}`", s))?
+                    }
+                    _ => return Err(anyhow!("unexpected `{}` in duration `{}`", designator, s)),
+                }
+                components += 1;
+            }
+        }
+
+        if components == 0 {
+            return Err(anyhow!("duration `{}` has no components", s));
+        }
+        Ok(result)
+    }
+}
+
+impl Display for BPMNDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "P")?;
+        for (value, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if value > 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+        if self.hours > 0 || self.minutes > 0 || self.seconds > 0.0 {
+            write!(f, "T")?;
+            for (value, designator) in [(self.hours, 'H'), (self.minutes, 'M')] {
+                if value > 0 {
+                    write!(f, "{}{}", value, designator)?;
+                }
+            }
+            if self.seconds > 0.0 {
+                write!(f, "{}S", self.seconds)?;
+            }
+        } else if self.years == 0 && self.months == 0 && self.weeks == 0 && self.days == 0 {
+            write!(f, "T0S")?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits a part of a duration into its numbers and designators, such as `1H30M` into `[("1", 'H'), ("30", 'M')]`.
+fn duration_components<'a>(part: &'a str, duration: &str) -> Result<Vec<(&'a str, char)>> {
+    let mut result = vec![];
+    let mut start = 0;
+    for (i, c) in part.char_indices() {
+        if c.is_ascii_alphabetic() {
+            if start == i {
+                return Err(anyhow!("`{}` in duration `{}` has no number", c, duration));
+            }
+            result.push((&part[start..i], c));
+            start = i + 1;
+        }
+    }
+    if start < part.len() {
+        return Err(anyhow!(
+            "`{}` in duration `{}` has no designator",
+            &part[start..],
+            duration
+        ));
+    }
+    Ok(result)
+}
+
+fn parse_whole(value: &str, duration: &str) -> Result<u64> {
+    value.parse::<u64>().with_context(|| {
+        anyhow!(
+            "parsing `{}` of duration `{}`; only seconds can have a fraction",
+            value,
+            duration
+        )
+    })
+}
+
+/// Returns whether the text is a non-empty sequence of ASCII digits.
+fn is_digits(text: &str) -> bool {
+    !text.is_empty() && text.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// An ISO-8601 date-time, such as `2026-03-01T09:00:00Z`, or a date, which starts at midnight.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BPMNDateTime {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: f64,
+
+    /// The offset from UTC in minutes; absent for local time.
+    pub offset_minutes: Option<i32>,
+}
+
+impl FromStr for BPMNDateTime {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || anyhow!("`{}` is not an ISO-8601 date-time", s);
+        let (date, time) = match s.split_once('T') {
+            Some((date, time)) => (date, Some(time)),
+            None => (s, None),
+        };
+
+        let mut date_parts = date.split('-');
+        let (Some(year), Some(month), Some(day), None) = (
+            date_parts.next(),
+            date_parts.next(),
+            date_parts.next(),
+            date_parts.next(),
+        ) else {
+            return Err(invalid());
+        };
+        if year.len() != 4
+            || month.len() != 2
+            || day.len() != 2
+            || !is_digits(year)
+            || !is_digits(month)
+            || !is_digits(day)
+        {
+            return Err(invalid());
+        }
+        let mut result = BPMNDateTime {
+            year: year.parse().map_err(|_| invalid())?,
+            month: month.parse().map_err(|_| invalid())?,
+            day: day.parse().map_err(|_| invalid())?,
+            ..Default::default()
+        };
+
+        if let Some(time) = time {
+            //split off the offset
+            let (time, offset) = if let Some(time) = time.strip_suffix('Z') {
+                (time, Some(0))
+            } else if let Some(i) = time.rfind(['+', '-']) {
+                let (time, offset) = time.split_at(i);
+                let sign = if offset.starts_with('-') { -1 } else { 1 };
+                let offset = offset[1..].replace(':', "");
+                if (offset.len() != 2 && offset.len() != 4) || !is_digits(&offset) {
+                    return Err(invalid());
+                }
+                let hours = offset[..2].parse::<i32>().map_err(|_| invalid())?;
+                let minutes = if offset.len() == 4 {
+                    offset[2..].parse::<i32>().map_err(|_| invalid())?
+                } else {
+                    0
+                };
+                (time, Some(sign * (hours * 60 + minutes)))
+            } else {
+                (time, None)
+            };
+            result.offset_minutes = offset;
+
+            let mut time_parts = time.split(':');
+            let (Some(hour), Some(minute), second, None) = (
+                time_parts.next(),
+                time_parts.next(),
+                time_parts.next(),
+                time_parts.next(),
+            ) else {
+                return Err(invalid());
+            };
+            if hour.len() != 2 || minute.len() != 2 || !is_digits(hour) || !is_digits(minute) {
+                return Err(invalid());
+            }
+            result.hour = hour.parse().map_err(|_| invalid())?;
+            result.minute = minute.parse().map_err(|_| invalid())?;
+            if let Some(second) = second {
+                //whole seconds with an optional fraction; no sign, exponent or special values
+                let second = second.replace(',', ".");
+                let (whole, fraction) = second.split_once('.').unwrap_or((&second, "0"));
+                if whole.len() != 2 || !is_digits(whole) || !is_digits(fraction) {
+                    return Err(invalid());
+                }
+                result.second = second.parse().map_err(|_| invalid())?;
+            }
+        }
+
+        if result.month == 0
+            || result.month > 12
+            || result.day == 0
+            || result.day > 31
+            || result.hour > 24
+            || result.minute > 59
+            || result.second >= 61.0
+        {
+            return Err(invalid());
+        }
+        Ok(result)
+    }
+}
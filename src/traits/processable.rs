@@ -19,9 +19,14 @@ pub trait Processable: BPMNObject + Debug {
     /// return a initial marking
     fn to_sub_marking(&self, initiation_mode: &InitiationMode) -> Result<BPMNSubMarking>;
 
-    /// return whether this is a sub-process, i.e. not a pool or a root of the model.
+    /// return whether this is a sub-process, i.e. not a pool or a root of the model. A process that is called by a call activity is a sub-process.
     fn is_sub_process(&self) -> bool;
 
+    /// return whether this is an event sub-process, which is started by the trigger of its start event rather than by a sequence flow
+    fn is_event_sub_process(&self) -> bool {
+        false
+    }
+
     /// return the element that is the target of the given sequence flow
     fn sequence_flow_index_2_source(&self, sequence_flow_index: usize) -> Result<&BPMNElement> {
         let sequence_flow = self
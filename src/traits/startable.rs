@@ -68,14 +68,22 @@ impl Startable for Vec<BPMNElement> {
     fn end_events_without_recursing(&self) -> Vec<&BPMNElement> {
         self.iter()
             .filter(|element| match element {
-                BPMNElement::CollapsedPool(_)
+                BPMNElement::BoundaryEvent(_)
+                | BPMNElement::BusinessRuleTask(_)
+                | BPMNElement::CallActivity(_)
+                | BPMNElement::CollapsedPool(_)
                 | BPMNElement::CollapsedSubProcess(_)
+                | BPMNElement::ComplexGateway(_)
+                | BPMNElement::ErrorStartEvent(_)
                 | BPMNElement::EventBasedGateway(_)
+                | BPMNElement::EventSubProcess(_)
                 | BPMNElement::ExclusiveGateway(_)
                 | BPMNElement::ExpandedSubProcess(_)
                 | BPMNElement::InclusiveGateway(_)
                 | BPMNElement::IntermediateCatchEvent(_)
                 | BPMNElement::IntermediateThrowEvent(_)
+                | BPMNElement::LinkIntermediateCatchEvent(_)
+                | BPMNElement::LinkIntermediateThrowEvent(_)
                 | BPMNElement::ManualTask(_)
                 | BPMNElement::MessageIntermediateCatchEvent(_)
                 | BPMNElement::MessageIntermediateThrowEvent(_)
@@ -83,13 +91,23 @@ impl Startable for Vec<BPMNElement> {
                 | BPMNElement::ParallelGateway(_)
                 | BPMNElement::Process(_)
                 | BPMNElement::ReceiveTask(_)
+                | BPMNElement::ScriptTask(_)
+                | BPMNElement::SendTask(_)
+                | BPMNElement::ServiceTask(_)
+                | BPMNElement::SignalIntermediateCatchEvent(_)
+                | BPMNElement::SignalIntermediateThrowEvent(_)
+                | BPMNElement::SignalStartEvent(_)
                 | BPMNElement::StartEvent(_)
                 | BPMNElement::Task(_)
                 | BPMNElement::TimerIntermediateCatchEvent(_)
                 | BPMNElement::TimerStartEvent(_)
                 | BPMNElement::UserTask(_) => false,
 
-                BPMNElement::EndEvent(_) | BPMNElement::MessageEndEvent(_) => true,
+                BPMNElement::EndEvent(_)
+                | BPMNElement::ErrorEndEvent(_)
+                | BPMNElement::MessageEndEvent(_)
+                | BPMNElement::SignalEndEvent(_)
+                | BPMNElement::TerminateEndEvent(_) => true,
             })
             .collect()
     }
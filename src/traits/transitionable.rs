@@ -1,6 +1,7 @@
 use crate::{
     BusinessProcessModelAndNotation,
     element::BPMNElement,
+    elements::{boundary_event::BPMNBoundaryEvent, event_sub_process::BPMNEventSubProcess},
     marking::{BPMNRootMarking, BPMNSubMarking, Token},
     semantics::TransitionIndex,
     traits::processable::Processable,
@@ -110,13 +111,27 @@ impl Transitionable for Vec<BPMNElement> {
         for element in self.iter() {
             let number_of_transitions = element.number_of_transitions(sub_marking);
             if transition_index < number_of_transitions {
-                return element.execute_transition(
+                element.execute_transition(
                     transition_index,
                     root_marking,
                     sub_marking,
                     parent,
                     bpmn,
-                );
+                )?;
+
+                //an error event sub-process of this process or sub-process instance catches the error
+                if root_marking.error_thrown.is_some() && !element.is_event_sub_process() {
+                    BPMNEventSubProcess::catch_error(self, root_marking, sub_marking)?;
+                }
+
+                //an error that is not caught ends this instance, and propagates to the enclosing instance
+                if root_marking.error_thrown.is_some() {
+                    sub_marking.clear();
+                }
+
+                //forget the firings of non-interrupting boundary events whose host activation has ended
+                BPMNBoundaryEvent::bound_firings(self, sub_marking);
+                return Ok(());
             }
             transition_index -= number_of_transitions;
         }
@@ -289,6 +304,18 @@ macro_rules! execute_transition_message_produce {
 }
 pub(crate) use execute_transition_message_produce;
 
+macro_rules! execute_transition_signal_throw {
+    ($self:ident, $root_marking:ident, $bpmn:ident) => {
+        if let Some(signal_ref) = &$self.signal_ref {
+            if let Some(signal_index) = $bpmn.signal_id_2_local_index(signal_ref) {
+                //the signal is broadcast to the waiting catch events once this transition has been executed
+                $root_marking.signal_2_tokens[signal_index] += 1;
+            }
+        }
+    };
+}
+pub(crate) use execute_transition_signal_throw;
+
 macro_rules! transition_2_consumed_tokens_xor_join {
     ($self:ident, $transition_index:expr, $parent:ident) => {
         if $self.incoming_sequence_flows.len() >= 1 {
@@ -369,3 +396,199 @@ macro_rules! transition_2_produced_tokens_message {
     };
 }
 pub(crate) use transition_2_produced_tokens_message;
+
+macro_rules! number_of_transitions_task_activations {
+    ($self:ident, $sub_marking:ident) => {{
+        let activations = &$sub_marking.element_index_2_sub_markings[$self.local_index];
+        if let Some(multi_instance) = &$self.multi_instance {
+            multi_instance.number_of_task_transitions(activations)
+        } else if let Some(standard_loop) = &$self.standard_loop {
+            standard_loop.number_of_choice_transitions(activations)
+        } else {
+            0
+        }
+    }};
+}
+pub(crate) use number_of_transitions_task_activations;
+
+macro_rules! enabledness_task_activations {
+    ($self:ident, $sub_marking:ident) => {{
+        let activations = &$sub_marking.element_index_2_sub_markings[$self.local_index];
+        if let Some(multi_instance) = &$self.multi_instance {
+            //every instance that can be executed is enabled
+            bitvec![1; multi_instance.number_of_task_transitions(activations)]
+        } else if let Some(standard_loop) = &$self.standard_loop {
+            standard_loop.choice_enabledness(activations)
+        } else {
+            bitvec![0; 0]
+        }
+    }};
+}
+pub(crate) use enabledness_task_activations;
+
+macro_rules! execute_transition_task_activate {
+    ($self:ident, $sub_marking:ident) => {{
+        let activation = if let Some(multi_instance) = &$self.multi_instance {
+            if multi_instance.loop_cardinality > 0 {
+                Some(multi_instance.new_task_activation())
+            } else {
+                //there are no instances to execute
+                None
+            }
+        } else if let Some(standard_loop) = &$self.standard_loop {
+            Some(standard_loop.new_task_activation())
+        } else {
+            None
+        };
+        match activation {
+            Some(activation) => {
+                $sub_marking.element_index_2_sub_markings[$self.local_index].push(activation);
+                true
+            }
+            None => false,
+        }
+    }};
+}
+pub(crate) use execute_transition_task_activate;
+
+macro_rules! execute_transition_task_activation {
+    ($self:ident, $sub_marking:ident, $transition_index:expr) => {{
+        let activations = &mut $sub_marking.element_index_2_sub_markings[$self.local_index];
+        if let Some(multi_instance) = &$self.multi_instance {
+            multi_instance.execute_task_transition(activations, $transition_index)?
+        } else if let Some(standard_loop) = &$self.standard_loop {
+            standard_loop.execute_choice_transition(activations, $transition_index)?
+        } else {
+            return Err(anyhow!("Transition does not exist."));
+        }
+    }};
+}
+pub(crate) use execute_transition_task_activation;
+
+macro_rules! task_activation_counts {
+    ($self:ident, $sub_marking:ident, $transition_index:expr) => {{
+        let activations = &$sub_marking.element_index_2_sub_markings[$self.local_index];
+        if let Some(multi_instance) = &$self.multi_instance {
+            //the activation completes with its last instance
+            let (remaining, remaining_after) =
+                multi_instance.task_transition_2_remaining(activations, $transition_index)?;
+            (
+                remaining,
+                Some(remaining_after).filter(|remaining| *remaining > 0),
+            )
+        } else if let Some(standard_loop) = &$self.standard_loop {
+            standard_loop.choice_transition_2_executions(activations, $transition_index)?
+        } else {
+            return Err(anyhow!("Transition does not exist."));
+        }
+    }};
+}
+pub(crate) use task_activation_counts;
+
+macro_rules! task_activation_initial_count {
+    ($self:ident) => {
+        if let Some(multi_instance) = &$self.multi_instance {
+            Some(multi_instance.loop_cardinality).filter(|cardinality| *cardinality > 0)
+        } else if let Some(standard_loop) = &$self.standard_loop {
+            Some(standard_loop.new_task_activation().element_index_2_tokens[0])
+        } else {
+            None
+        }
+    };
+}
+pub(crate) use task_activation_initial_count;
+
+macro_rules! transition_2_consumed_tokens_task {
+    ($self:ident, $transition_index:ident, $sub_marking:ident, $parent:ident $(, $bpmn:ident)?) => {{
+        let number_of_start_transitions = number_of_transitions_xor_join_only!($self);
+        if $transition_index < number_of_start_transitions {
+            //a start transition consumes the incoming token and message
+            let result = task_consumed_tokens!($self, $transition_index, $parent);
+            $(let result = [result, transition_2_consumed_tokens_message!($self, $bpmn)].concat();)?
+            result
+        } else {
+            //a transition of an activation consumes the activation
+            let (count, _) = task_activation_counts!(
+                $self,
+                $sub_marking,
+                $transition_index - number_of_start_transitions
+            );
+            vec![Token::Activation {
+                of: $self.global_index,
+                count,
+            }]
+        }
+    }};
+}
+pub(crate) use transition_2_consumed_tokens_task;
+
+macro_rules! transition_2_produced_tokens_task {
+    ($self:ident, $transition_index:ident, $sub_marking:ident, $parent:ident $(, $bpmn:ident)?) => {{
+        let number_of_start_transitions = number_of_transitions_xor_join_only!($self);
+        let count_after = if $transition_index < number_of_start_transitions {
+            task_activation_initial_count!($self)
+        } else {
+            task_activation_counts!(
+                $self,
+                $sub_marking,
+                $transition_index - number_of_start_transitions
+            )
+            .1
+        };
+        match count_after {
+            //the task is activated, or the activation continues
+            Some(count) => vec![Token::Activation {
+                of: $self.global_index,
+                count,
+            }],
+            None => {
+                //the task completes
+                let result = transition_2_produced_tokens_concurrent_split!($self, $parent);
+                $(let result = [result, transition_2_produced_tokens_message!($self, $bpmn)].concat();)?
+                result
+            }
+        }
+    }};
+}
+pub(crate) use transition_2_produced_tokens_task;
+
+macro_rules! transition_activity_task {
+    ($self:ident, $transition_index:ident) => {{
+        let number_of_start_transitions = number_of_transitions_xor_join_only!($self);
+        let is_silent = if $transition_index < number_of_start_transitions {
+            //activating a multi-instance task, or a loop task that chooses before its first execution, is silent
+            $self.multi_instance.is_some()
+                || $self
+                    .standard_loop
+                    .as_ref()
+                    .is_some_and(|standard_loop| standard_loop.test_before)
+        } else if let Some(standard_loop) = &$self.standard_loop {
+            //repeating a loop task executes it, while continuing is silent
+            standard_loop.is_continue_transition($transition_index - number_of_start_transitions)
+        } else {
+            false
+        };
+        if is_silent {
+            None
+        } else {
+            Some($self.activity)
+        }
+    }};
+}
+pub(crate) use transition_activity_task;
+
+macro_rules! transition_probabilistic_penalty_task {
+    ($self:ident, $transition_index:ident, $marking:ident) => {{
+        let number_of_start_transitions = number_of_transitions_xor_join_only!($self);
+        match &$self.standard_loop {
+            Some(standard_loop) if $transition_index >= number_of_start_transitions => {
+                standard_loop.choice_probabilistic_penalty(
+                    &$marking.element_index_2_sub_markings[$self.local_index],
+                    $transition_index - number_of_start_transitions,
+                )
+            }
+            _ => Some(Fraction::one()),
+        }
+    }};
+}
+pub(crate) use transition_probabilistic_penalty_task;
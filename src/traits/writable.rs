@@ -1,7 +1,7 @@
 use crate::{
-    BusinessProcessModelAndNotation, element::BPMNElement,
-    elements::collapsed_pool::BPMNCollapsedPool, message_flow::BPMNMessageFlow,
-    sequence_flow::BPMNSequenceFlow, traits::processable::Processable,
+    BusinessProcessModelAndNotation, diagram::BPMNDiagram, element::BPMNElement,
+    elements::collapsed_pool::BPMNCollapsedPool, lane::BPMNLaneSet, message_flow::BPMNMessageFlow,
+    sequence_flow::BPMNSequenceFlow, signal::BPMNSignal, traits::processable::Processable,
 };
 use anyhow::Result;
 use quick_xml::Writer;
@@ -39,6 +39,9 @@ vec_writable!(Vec<BPMNElement>);
 vec_writable!(Vec<BPMNMessageFlow>);
 vec_writable!(Vec<&BPMNCollapsedPool>);
 vec_writable!(Vec<BPMNSequenceFlow>);
+vec_writable!(Vec<BPMNDiagram>);
+vec_writable!(Vec<BPMNSignal>);
+vec_writable!(Vec<BPMNLaneSet>);
 
 macro_rules! write_external_incoming {
     ($x: ident, $self:ident, $parent:ident) => {
@@ -0,0 +1,64 @@
+use crate::BusinessProcessModelAndNotation;
+
+/// A node of XML that this crate does not interpret, such as documentation, text annotations or extension elements.
+/// It is kept such that it can be written again when the model is exported.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BPMNXmlNode {
+    Element {
+        /// The qualified name of the tag, such as `documentation` or `camunda:properties`.
+        name: String,
+        attributes: Vec<(String, String)>,
+        children: Vec<BPMNXmlNode>,
+    },
+    Text(String),
+    CData(String),
+    Comment(String),
+}
+
+/// The XML of a recognised element that this crate does not interpret: its unknown attributes and its unknown child nodes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BPMNUnrecognisedXml {
+    pub(crate) attributes: Vec<(String, String)>,
+
+    /// Each child is stored with the id of the recognised sibling that it follows, if any.
+    pub(crate) children: Vec<(Option<String>, BPMNXmlNode)>,
+
+    /// The unrecognised XML of recognised descendants that have no id, such as a `standardLoopCharacteristics`.
+    /// Each is stored with its path from this element: the local names of the tags, with their occurrence among the siblings of the same name that have no id.
+    pub(crate) descendants: Vec<(Vec<(String, usize)>, BPMNUnrecognisedXml)>,
+}
+
+impl BPMNUnrecognisedXml {
+    pub fn attributes(&self) -> &[(String, String)] {
+        &self.attributes
+    }
+
+    pub fn children(&self) -> Vec<&BPMNXmlNode> {
+        self.children.iter().map(|(_, node)| node).collect()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.children.is_empty() && self.descendants.is_empty()
+    }
+
+    /// Returns the unrecognised XML of the recognised descendant without an id at the given path.
+    pub(crate) fn descendant(&self, path: &[(String, usize)]) -> Option<&BPMNUnrecognisedXml> {
+        self.descendants
+            .iter()
+            .find(|(descendant_path, _)| descendant_path == path)
+            .map(|(_, xml)| xml)
+    }
+}
+
+impl BusinessProcessModelAndNotation {
+    /// Returns the XML of the element with the given id that was imported but is not interpreted by this crate, if any.
+    /// This XML is written again on export.
+    pub fn unrecognised_xml_of(&self, id: &str) -> Option<&BPMNUnrecognisedXml> {
+        self.unrecognised_xml.get(id)
+    }
+
+    /// Returns the XML outside of the definitions that was imported, such as comments, which is written again on export.
+    pub fn unrecognised_document_xml(&self) -> &BPMNUnrecognisedXml {
+        &self.unrecognised_document_xml
+    }
+}
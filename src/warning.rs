@@ -0,0 +1,130 @@
+use std::fmt::Display;
+
+/// The tags of the BPMN 2.0.2 standard that are flow nodes, regardless of whether this crate supports them.
+const FLOW_NODE_TAGS: &[&str] = &[
+    "adHocSubProcess",
+    "boundaryEvent",
+    "businessRuleTask",
+    "callActivity",
+    "callChoreography",
+    "choreographyTask",
+    "complexGateway",
+    "endEvent",
+    "eventBasedGateway",
+    "exclusiveGateway",
+    "implicitThrowEvent",
+    "inclusiveGateway",
+    "intermediateCatchEvent",
+    "intermediateThrowEvent",
+    "manualTask",
+    "parallelGateway",
+    "receiveTask",
+    "scriptTask",
+    "sendTask",
+    "serviceTask",
+    "startEvent",
+    "subChoreography",
+    "subProcess",
+    "task",
+    "transaction",
+    "userTask",
+];
+
+/// Why a tag was ignored on import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BPMNImportWarningReason {
+    /// A flow node that is not supported, or that is not in a location where it is supported.
+    /// It does not take part in the behaviour of the model.
+    IgnoredFlowNode,
+
+    /// An event definition that is not supported. Its event behaves as if it has no event definition.
+    IgnoredEventDefinition,
+
+    /// A tag that is not interpreted, such as documentation or a text annotation. It is written again on export.
+    NotInterpreted,
+
+    /// A condition expression that is not in the supported subset of FEEL, or that is in another language.
+    /// It is written again on export, but it always holds when the model is executed with data.
+    UnsupportedCondition,
+}
+
+impl BPMNImportWarningReason {
+    pub(crate) fn of(local_name: &str) -> Self {
+        if FLOW_NODE_TAGS.contains(&local_name) {
+            Self::IgnoredFlowNode
+        } else if local_name.ends_with("EventDefinition") {
+            Self::IgnoredEventDefinition
+        } else {
+            Self::NotInterpreted
+        }
+    }
+}
+
+impl Display for BPMNImportWarningReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BPMNImportWarningReason::IgnoredFlowNode => write!(
+                f,
+                "the flow node is not supported or not in an expected location, and does not take part in the behaviour of the model"
+            ),
+            BPMNImportWarningReason::IgnoredEventDefinition => write!(
+                f,
+                "the event definition is not supported, and its event behaves as if it has no event definition"
+            ),
+            BPMNImportWarningReason::NotInterpreted => write!(
+                f,
+                "the tag is not interpreted, but is written again on export"
+            ),
+            BPMNImportWarningReason::UnsupportedCondition => write!(
+                f,
+                "the condition is not in the supported subset of FEEL, and always holds when executed with data"
+            ),
+        }
+    }
+}
+
+/// A tag that was ignored when importing a model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BPMNImportWarning {
+    pub(crate) tag: String,
+    pub(crate) id: Option<String>,
+    pub(crate) position: u64,
+    pub(crate) reason: BPMNImportWarningReason,
+}
+
+impl BPMNImportWarning {
+    /// The qualified name of the tag, such as `bpmn:transaction`.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// The byte position of the start of the tag in the imported file.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn reason(&self) -> BPMNImportWarningReason {
+        self.reason
+    }
+}
+
+impl Display for BPMNImportWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.id {
+            Some(id) => write!(
+                f,
+                "Tag `{}` with id `{}` at position {} is ignored: {}.",
+                self.tag, id, self.position, self.reason
+            ),
+            None => write!(
+                f,
+                "Tag `{}` at position {} is ignored: {}.",
+                self.tag, self.position, self.reason
+            ),
+        }
+    }
+}
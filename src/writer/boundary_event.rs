@@ -0,0 +1,54 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    elements::boundary_event::{BPMNBoundaryEvent, BoundaryEventType},
+    traits::{
+        processable::Processable,
+        writable::{Writable, write_external_outgoing},
+    },
+    writer::timer::write_timer_event_definition,
+};
+use quick_xml::events::{BytesStart, BytesText, Event};
+
+impl Writable for BPMNBoundaryEvent {
+    fn write<W: std::io::Write>(
+        &self,
+        x: &mut quick_xml::Writer<W>,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> anyhow::Result<()> {
+        let mut element = x
+            .create_element("boundaryEvent")
+            .with_attribute(("id", self.id.as_str()))
+            .with_attribute(("attachedToRef", self.attached_to_id.as_str()));
+        if let Some(name) = &self.name {
+            element = element.with_attribute(("name", name.as_str()));
+        }
+        if !self.cancel_activity {
+            element = element.with_attribute(("cancelActivity", "false"));
+        }
+        element.write_inner_content(|x| {
+            write_external_outgoing!(x, self, parent);
+            if self.event_type.is_timer() {
+                write_timer_event_definition!(x, &self.marker_id, &self.timer);
+                return Ok(());
+            }
+            let mut b = BytesStart::new(match self.event_type {
+                BoundaryEventType::Error => "errorEventDefinition",
+                BoundaryEventType::Message => "messageEventDefinition",
+                BoundaryEventType::Timer => "timerEventDefinition",
+            });
+            if let Some(id) = &self.marker_id {
+                b = b.with_attributes([("id", id.as_str())]);
+            }
+            if let Some(error_ref) = &self.error_ref {
+                b = b.with_attributes([("errorRef", error_ref.as_str())]);
+            }
+            if let Some(message_ref) = &self.message_ref {
+                b = b.with_attributes([("messageRef", message_ref.as_str())]);
+            }
+            x.write_event(Event::Empty(b))?;
+            Ok(())
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    elements::business_rule_task::BPMNBusinessRuleTask,
+    traits::{
+        processable::Processable,
+        writable::{Writable, write_external_sequence_flows},
+    },
+    writer::{
+        data::write_data_associations, multi_instance::write_multi_instance,
+        standard_loop::write_standard_loop,
+    },
+};
+use quick_xml::events::BytesText;
+
+impl Writable for BPMNBusinessRuleTask {
+    fn write<W: std::io::Write>(
+        &self,
+        x: &mut quick_xml::Writer<W>,
+        parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> anyhow::Result<()> {
+        x.create_element("businessRuleTask")
+            .with_attribute(("id", self.id.as_str()))
+            .with_attribute(("name", bpmn.activity_key.deprocess_activity(&self.activity)))
+            .write_inner_content(|x| {
+                write_external_sequence_flows!(x, self, parent);
+                write_data_associations(x, self.global_index, bpmn)?;
+                write_multi_instance!(x, self);
+                write_standard_loop!(x, self);
+                Ok(())
+            })?;
+        Ok(())
+    }
+}
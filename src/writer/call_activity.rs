@@ -0,0 +1,34 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    elements::call_activity::BPMNCallActivity,
+    traits::{
+        processable::Processable,
+        writable::{Writable, write_external_sequence_flows},
+    },
+    writer::data::write_data_associations,
+};
+use quick_xml::events::BytesText;
+
+impl Writable for BPMNCallActivity {
+    fn write<W: std::io::Write>(
+        &self,
+        x: &mut quick_xml::Writer<W>,
+        parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+        attributes.push(("calledElement", self.called_element.as_str()));
+
+        x.create_element("callActivity")
+            .with_attributes(attributes)
+            .write_inner_content(|x| {
+                write_external_sequence_flows!(x, self, parent);
+                write_data_associations(x, self.global_index, bpmn)?;
+                Ok(())
+            })?;
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
         processable::Processable,
         writable::{Writable, write_external_sequence_flows},
     },
+    writer::data::{write_data_associations, write_data_elements},
 };
 use quick_xml::events::BytesText;
 
@@ -22,6 +23,8 @@ impl Writable for BPMNCollapsedSubProcess {
             ])
             .write_inner_content(|x| {
                 write_external_sequence_flows!(x, self, parent);
+                write_data_associations(x, self.global_index, bpmn)?;
+                write_data_elements(x, self.global_index, bpmn)?;
                 Ok(())
             })?;
         Ok(())
@@ -0,0 +1,38 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    elements::complex_gateway::BPMNComplexGateway,
+    traits::{
+        processable::Processable,
+        writable::{Writable, write_external_sequence_flows},
+    },
+};
+use quick_xml::events::BytesText;
+
+impl Writable for BPMNComplexGateway {
+    fn write<W: std::io::Write>(
+        &self,
+        x: &mut quick_xml::Writer<W>,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> anyhow::Result<()> {
+        let activation_count = self
+            .activation_count
+            .map(|activation_count| activation_count.to_string());
+
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+        if let Some(activation_count) = &activation_count {
+            attributes.push(("sbpmn:activationCount", activation_count.as_str()));
+        }
+
+        x.create_element("complexGateway")
+            .with_attributes(attributes)
+            .write_inner_content(|x| {
+                write_external_sequence_flows!(x, self, parent);
+                Ok(())
+            })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    data::{BPMNDataAssociation, BPMNDataElement, DataAssociationDirection, DataElementType},
+    parser::parser_state::GlobalIndex,
+};
+use quick_xml::{
+    Writer,
+    events::{BytesEnd, BytesStart, BytesText, Event},
+};
+use std::io::{Result, Write};
+
+/// Writes the data objects, data stores and references to them that are declared in the given process, sub-process or definitions.
+pub(crate) fn write_data_elements<W: Write>(
+    x: &mut Writer<W>,
+    parent_global_index: GlobalIndex,
+    bpmn: &BusinessProcessModelAndNotation,
+) -> Result<()> {
+    for data_element in &bpmn.data_elements {
+        if data_element.parent_global_index == parent_global_index
+            && data_element.element_type != DataElementType::Property
+        {
+            write_data_element(x, data_element)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the properties and the data associations of the given activity.
+pub(crate) fn write_data_associations<W: Write>(
+    x: &mut Writer<W>,
+    activity_global_index: GlobalIndex,
+    bpmn: &BusinessProcessModelAndNotation,
+) -> Result<()> {
+    for data_element in &bpmn.data_elements {
+        if data_element.parent_global_index == activity_global_index
+            && data_element.element_type == DataElementType::Property
+        {
+            write_data_element(x, data_element)?;
+        }
+    }
+
+    //input associations precede output associations
+    let associations = bpmn.data_associations_of(activity_global_index);
+    for direction in [
+        DataAssociationDirection::Input,
+        DataAssociationDirection::Output,
+    ] {
+        for association in &associations {
+            if association.direction == direction {
+                write_data_association(x, association)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_data_element<W: Write>(x: &mut Writer<W>, data_element: &BPMNDataElement) -> Result<()> {
+    let mut attributes = vec![("id", data_element.id.as_str())];
+    if let Some(name) = &data_element.name {
+        attributes.push(("name", name.as_str()));
+    }
+    if let (Some(attribute), Some(data_ref)) = (
+        data_element.element_type.data_ref_attribute(),
+        &data_element.data_ref,
+    ) {
+        attributes.push((attribute, data_ref.as_str()));
+    }
+    if data_element.is_collection {
+        attributes.push(("isCollection", "true"));
+    }
+    x.write_event(Event::Empty(
+        BytesStart::new(data_element.element_type.tag_name()).with_attributes(attributes),
+    ))?;
+    Ok(())
+}
+
+fn write_data_association<W: Write>(
+    x: &mut Writer<W>,
+    association: &BPMNDataAssociation,
+) -> Result<()> {
+    let tag_name = match association.direction {
+        DataAssociationDirection::Input => "dataInputAssociation",
+        DataAssociationDirection::Output => "dataOutputAssociation",
+    };
+    let mut b = BytesStart::new(tag_name);
+    if let Some(id) = &association.id {
+        b = b.with_attributes([("id", id.as_str())]);
+    }
+    x.write_event(Event::Start(b))?;
+    for source_ref in &association.source_refs {
+        x.create_element("sourceRef")
+            .write_text_content(BytesText::new(source_ref))?;
+    }
+    if let Some(target_ref) = &association.target_ref {
+        x.create_element("targetRef")
+            .write_text_content(BytesText::new(target_ref))?;
+    }
+    x.write_event(Event::End(BytesEnd::new(tag_name)))?;
+    Ok(())
+}
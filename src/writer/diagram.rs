@@ -0,0 +1,126 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    diagram::{BPMNBounds, BPMNDiagram, BPMNEdge, BPMNLabel, BPMNShape},
+    traits::{processable::Processable, writable::Writable},
+};
+use anyhow::Result;
+use quick_xml::{
+    Writer,
+    events::{BytesEnd, BytesStart, Event},
+};
+use std::io::Write;
+
+impl Writable for BPMNDiagram {
+    fn write<W: std::io::Write>(
+        &self,
+        x: &mut quick_xml::Writer<W>,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> anyhow::Result<()> {
+        let mut diagram = BytesStart::new("bpmndi:BPMNDiagram");
+        if let Some(id) = &self.id {
+            diagram.push_attribute(("id", id.as_str()));
+        }
+        if let Some(name) = &self.name {
+            diagram.push_attribute(("name", name.as_str()));
+        }
+        x.write_event(Event::Start(diagram))?;
+
+        let mut plane = BytesStart::new("bpmndi:BPMNPlane");
+        if let Some(id) = &self.plane.id {
+            plane.push_attribute(("id", id.as_str()));
+        }
+        if let Some(bpmn_element) = &self.plane.bpmn_element {
+            plane.push_attribute(("bpmnElement", bpmn_element.as_str()));
+        }
+        x.write_event(Event::Start(plane))?;
+
+        for shape in &self.plane.shapes {
+            write_shape(x, shape)?;
+        }
+        for edge in &self.plane.edges {
+            write_edge(x, edge)?;
+        }
+
+        x.write_event(Event::End(BytesEnd::new("bpmndi:BPMNPlane")))?;
+        x.write_event(Event::End(BytesEnd::new("bpmndi:BPMNDiagram")))?;
+        Ok(())
+    }
+}
+
+fn write_shape<W: Write>(x: &mut Writer<W>, shape: &BPMNShape) -> Result<()> {
+    let mut bytes_start = BytesStart::new("bpmndi:BPMNShape");
+    if let Some(id) = &shape.id {
+        bytes_start.push_attribute(("id", id.as_str()));
+    }
+    bytes_start.push_attribute(("bpmnElement", shape.bpmn_element.as_str()));
+    for (attribute, value) in [
+        ("isExpanded", shape.is_expanded),
+        ("isHorizontal", shape.is_horizontal),
+        ("isMarkerVisible", shape.is_marker_visible),
+    ] {
+        if let Some(value) = value {
+            bytes_start.push_attribute((attribute, if value { "true" } else { "false" }));
+        }
+    }
+    x.write_event(Event::Start(bytes_start))?;
+
+    write_bounds(x, &shape.bounds)?;
+    write_label(x, &shape.label)?;
+
+    x.write_event(Event::End(BytesEnd::new("bpmndi:BPMNShape")))?;
+    Ok(())
+}
+
+fn write_edge<W: Write>(x: &mut Writer<W>, edge: &BPMNEdge) -> Result<()> {
+    let mut bytes_start = BytesStart::new("bpmndi:BPMNEdge");
+    if let Some(id) = &edge.id {
+        bytes_start.push_attribute(("id", id.as_str()));
+    }
+    bytes_start.push_attribute(("bpmnElement", edge.bpmn_element.as_str()));
+    if let Some(source_element) = &edge.source_element {
+        bytes_start.push_attribute(("sourceElement", source_element.as_str()));
+    }
+    if let Some(target_element) = &edge.target_element {
+        bytes_start.push_attribute(("targetElement", target_element.as_str()));
+    }
+    x.write_event(Event::Start(bytes_start))?;
+
+    for waypoint in &edge.waypoints {
+        x.write_event(Event::Empty(BytesStart::new("di:waypoint").with_attributes([
+            ("x", waypoint.x.to_string().as_str()),
+            ("y", waypoint.y.to_string().as_str()),
+        ])))?;
+    }
+    write_label(x, &edge.label)?;
+
+    x.write_event(Event::End(BytesEnd::new("bpmndi:BPMNEdge")))?;
+    Ok(())
+}
+
+fn write_label<W: Write>(x: &mut Writer<W>, label: &Option<BPMNLabel>) -> Result<()> {
+    match label {
+        Some(BPMNLabel {
+            bounds: Some(bounds),
+        }) => {
+            x.write_event(Event::Start(BytesStart::new("bpmndi:BPMNLabel")))?;
+            write_bounds(x, bounds)?;
+            x.write_event(Event::End(BytesEnd::new("bpmndi:BPMNLabel")))?;
+        }
+        Some(BPMNLabel { bounds: None }) => {
+            x.write_event(Event::Empty(BytesStart::new("bpmndi:BPMNLabel")))?;
+        }
+        None => {}
+    }
+    Ok(())
+}
+
+fn write_bounds<W: Write>(x: &mut Writer<W>, bounds: &BPMNBounds) -> Result<()> {
+    x.write_event(Event::Empty(BytesStart::new("dc:Bounds").with_attributes([
+        ("x", bounds.x.to_string().as_str()),
+        ("y", bounds.y.to_string().as_str()),
+        ("width", bounds.width.to_string().as_str()),
+        ("height", bounds.height.to_string().as_str()),
+    ])))?;
+    Ok(())
+}
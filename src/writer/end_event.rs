@@ -15,8 +15,13 @@ impl Writable for BPMNEndEvent {
         parent: &dyn Processable,
         _bpmn: &BusinessProcessModelAndNotation,
     ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+
         x.create_element("endEvent")
-            .with_attributes([("id", self.id.as_str())])
+            .with_attributes(attributes)
             .write_inner_content(|x| {
                 write_external_incoming!(x, self, parent);
                 Ok(())
@@ -0,0 +1,39 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    elements::error_end_event::BPMNErrorEndEvent,
+    traits::{
+        processable::Processable,
+        writable::{Writable, write_external_incoming},
+    },
+};
+use quick_xml::events::{BytesStart, BytesText, Event};
+
+impl Writable for BPMNErrorEndEvent {
+    fn write<W: std::io::Write>(
+        &self,
+        x: &mut quick_xml::Writer<W>,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+
+        x.create_element("endEvent")
+            .with_attributes(attributes)
+            .write_inner_content(|x| {
+                write_external_incoming!(x, self, parent);
+                let mut b = BytesStart::new("errorEventDefinition");
+                if let Some(id) = &self.error_marker_id {
+                    b = b.with_attributes([("id", id.as_str())]);
+                }
+                if let Some(error_ref) = &self.error_ref {
+                    b = b.with_attributes([("errorRef", error_ref.as_str())]);
+                }
+                x.write_event(Event::Empty(b))?;
+                Ok(())
+            })?;
+        Ok(())
+    }
+}
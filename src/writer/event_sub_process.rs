@@ -0,0 +1,41 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    elements::event_sub_process::BPMNEventSubProcess,
+    traits::{processable::Processable, writable::Writable},
+    writer::data::{write_data_associations, write_data_elements},
+};
+use quick_xml::events::{BytesEnd, BytesStart, Event};
+
+impl Writable for BPMNEventSubProcess {
+    fn write<W: std::io::Write>(
+        &self,
+        x: &mut quick_xml::Writer<W>,
+        _parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+        attributes.push(("triggeredByEvent", "true"));
+
+        x.write_event(Event::Start(
+            BytesStart::new("subProcess").with_attributes(attributes),
+        ))?;
+
+        //properties and data associations
+        write_data_associations(x, self.global_index, bpmn)?;
+
+        //data objects and data stores
+        write_data_elements(x, self.global_index, bpmn)?;
+
+        //internal sequence flows
+        self.sequence_flows.write(x, self, bpmn)?;
+
+        //recursive elements
+        self.elements.write(x, self, bpmn)?;
+
+        x.write_event(Event::End(BytesEnd::new("subProcess")))?;
+        Ok(())
+    }
+}
@@ -15,8 +15,16 @@ impl Writable for BPMNExclusiveGateway {
         parent: &dyn Processable,
         _bpmn: &BusinessProcessModelAndNotation,
     ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+        if let Some(default_sequence_flow) = &self.default_sequence_flow {
+            attributes.push(("default", default_sequence_flow.as_str()));
+        }
+
         x.create_element("exclusiveGateway")
-            .with_attribute(("id", self.id.as_str()))
+            .with_attributes(attributes)
             .write_inner_content(|x| {
                 write_external_sequence_flows!(x, self, parent);
                 Ok(())
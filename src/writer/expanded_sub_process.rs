@@ -2,6 +2,11 @@ use crate::{
     BusinessProcessModelAndNotation,
     elements::expanded_sub_process::BPMNExpandedSubProcess,
     traits::{processable::Processable, writable::{Writable, write_external_sequence_flows}},
+    writer::{
+        data::{write_data_associations, write_data_elements},
+        multi_instance::write_multi_instance,
+        standard_loop::write_standard_loop,
+    },
 };
 use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
 
@@ -24,6 +29,16 @@ impl Writable for BPMNExpandedSubProcess {
         //external sequence flows
         write_external_sequence_flows!(x, self, parent);
 
+        //properties and data associations
+        write_data_associations(x, self.global_index, bpmn)?;
+
+        //multi-instance or loop marker
+        write_multi_instance!(x, self);
+        write_standard_loop!(x, self);
+
+        //data objects and data stores
+        write_data_elements(x, self.global_index, bpmn)?;
+
         //internal sequence flows
         self.sequence_flows.write(x, self, bpmn)?;
 
@@ -0,0 +1,22 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    import::BPMNImport,
+    traits::{processable::Processable, writable::Writable},
+};
+use quick_xml::events::{BytesStart, Event};
+
+impl Writable for BPMNImport {
+    fn write<W: std::io::Write>(
+        &self,
+        x: &mut quick_xml::Writer<W>,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> anyhow::Result<()> {
+        x.write_event(Event::Empty(BytesStart::new("import").with_attributes([
+            ("namespace", self.namespace.as_str()),
+            ("location", self.location.as_str()),
+            ("importType", self.import_type.as_str()),
+        ])))?;
+        Ok(())
+    }
+}
@@ -12,8 +12,16 @@ impl Writable for BPMNInclusiveGateway {
         parent: &dyn Processable,
         _bpmn: &BusinessProcessModelAndNotation,
     ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+        if let Some(default_sequence_flow) = &self.default_sequence_flow {
+            attributes.push(("default", default_sequence_flow.as_str()));
+        }
+
         x.create_element("inclusiveGateway")
-            .with_attribute(("id", self.id.as_str()))
+            .with_attributes(attributes)
             .write_inner_content(|x| {
                 write_external_sequence_flows!(x, self, parent);
                 Ok(())
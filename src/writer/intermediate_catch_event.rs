@@ -15,8 +15,13 @@ impl Writable for BPMNIntermediateCatchEvent {
         parent: &dyn Processable,
         _bpmn: &BusinessProcessModelAndNotation,
     ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+
         x.create_element("intermediateCatchEvent")
-            .with_attribute(("id", self.id.as_str()))
+            .with_attributes(attributes)
             .write_inner_content(|x| {
                 write_external_sequence_flows!(x, self, parent);
                 Ok(())
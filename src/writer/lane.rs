@@ -0,0 +1,67 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    lane::BPMNLaneSet,
+    traits::{objectable::BPMNObject, processable::Processable, writable::Writable},
+};
+use anyhow::{Result, anyhow};
+use quick_xml::{
+    Writer,
+    events::{BytesEnd, BytesStart, BytesText, Event},
+};
+use std::io::Write;
+
+impl Writable for BPMNLaneSet {
+    fn write<W: Write>(
+        &self,
+        x: &mut Writer<W>,
+        _parent: &dyn Processable,
+        bpmn: &BusinessProcessModelAndNotation,
+    ) -> Result<()> {
+        write_lane_set(x, self, "laneSet", bpmn)
+    }
+}
+
+fn write_lane_set<W: Write>(
+    x: &mut Writer<W>,
+    lane_set: &BPMNLaneSet,
+    tag_name: &str,
+    bpmn: &BusinessProcessModelAndNotation,
+) -> Result<()> {
+    let mut attributes = vec![];
+    if let Some(id) = &lane_set.id {
+        attributes.push(("id", id.as_str()));
+    }
+    if let Some(name) = &lane_set.name {
+        attributes.push(("name", name.as_str()));
+    }
+    x.write_event(Event::Start(
+        BytesStart::new(tag_name).with_attributes(attributes),
+    ))?;
+
+    for lane in &lane_set.lanes {
+        let mut attributes = vec![("id", lane.id.as_str())];
+        if let Some(name) = &lane.name {
+            attributes.push(("name", name.as_str()));
+        }
+        x.write_event(Event::Start(
+            BytesStart::new("lane").with_attributes(attributes),
+        ))?;
+
+        for flow_node_ref in &lane.flow_node_refs {
+            let element = bpmn
+                .global_index_2_element(*flow_node_ref)
+                .ok_or_else(|| anyhow!("Could not find an element of lane `{}`.", lane.id))?;
+            x.create_element("flowNodeRef")
+                .write_text_content(BytesText::new(element.id()))?;
+        }
+
+        if let Some(child_lane_set) = &lane.child_lane_set {
+            write_lane_set(x, child_lane_set, "childLaneSet", bpmn)?;
+        }
+
+        x.write_event(Event::End(BytesEnd::new("lane")))?;
+    }
+
+    x.write_event(Event::End(BytesEnd::new(tag_name)))?;
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    elements::link_intermediate_throw_event::BPMNLinkIntermediateThrowEvent,
+    traits::{
+        processable::Processable,
+        writable::{Writable, write_external_incoming},
+    },
+};
+use quick_xml::events::{BytesStart, BytesText, Event};
+
+impl Writable for BPMNLinkIntermediateThrowEvent {
+    fn write<W: std::io::Write>(
+        &self,
+        x: &mut quick_xml::Writer<W>,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+
+        x.create_element("intermediateThrowEvent")
+            .with_attributes(attributes)
+            .write_inner_content(|x| {
+                write_external_incoming!(x, self, parent);
+                let mut b = BytesStart::new("linkEventDefinition");
+                if let Some(id) = &self.link_marker_id {
+                    b = b.with_attributes([("id", id.as_str())]);
+                }
+                b = b.with_attributes([("name", self.link_name.as_str())]);
+                x.write_event(Event::Empty(b))?;
+                Ok(())
+            })?;
+        Ok(())
+    }
+}
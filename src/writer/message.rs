@@ -0,0 +1,22 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    message::BPMNMessage,
+    traits::{processable::Processable, writable::Writable},
+};
+use quick_xml::events::{BytesStart, Event};
+
+impl Writable for BPMNMessage {
+    fn write<W: std::io::Write>(
+        &self,
+        x: &mut quick_xml::Writer<W>,
+        _parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> anyhow::Result<()> {
+        let mut b = BytesStart::new("message").with_attributes([("id", self.id.as_str())]);
+        if let Some(name) = &self.name {
+            b = b.with_attributes([("name", name.as_str())]);
+        }
+        x.write_event(Event::Empty(b))?;
+        Ok(())
+    }
+}
@@ -15,14 +15,22 @@ impl Writable for BPMNMessageEndEvent {
         parent: &dyn Processable,
         _bpmn: &BusinessProcessModelAndNotation,
     ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+
         x.create_element("endEvent")
-            .with_attributes([("id", self.id.as_str())])
+            .with_attributes(attributes)
             .write_inner_content(|x| {
                 write_external_incoming!(x, self, parent);
                 let mut b = BytesStart::new("messageEventDefinition");
                 if let Some(id) = &self.message_marker_id {
                     b = b.with_attributes([("id", id.as_str())]);
                 }
+                if let Some(message_ref) = &self.message_ref {
+                    b = b.with_attributes([("messageRef", message_ref.as_str())]);
+                }
                 x.write_event(Event::Empty(b))?;
                 Ok(())
             })?;
@@ -22,12 +22,18 @@ impl Writable for BPMNMessageFlow {
             .ok_or_else(|| anyhow!("target not found"))?
             .id();
 
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+        attributes.push(("sourceRef", source_id));
+        attributes.push(("targetRef", target_id));
+        if let Some(message_ref) = &self.message_ref {
+            attributes.push(("messageRef", message_ref.as_str()));
+        }
+
         x.write_event(Event::Empty(
-            BytesStart::new("messageFlow").with_attributes([
-                ("id", self.id.as_str()),
-                ("sourceRef", source_id),
-                ("targetRef", target_id),
-            ]),
+            BytesStart::new("messageFlow").with_attributes(attributes),
         ))?;
         Ok(())
     }
@@ -0,0 +1,24 @@
+macro_rules! write_multi_instance {
+    ($x: ident, $self:ident) => {
+        if let Some(multi_instance) = &$self.multi_instance {
+            let mut attributes = vec![];
+            if let Some(id) = &multi_instance.id {
+                attributes.push(("id", id.as_str()));
+            }
+            if multi_instance.is_sequential {
+                attributes.push(("isSequential", "true"));
+            }
+            $x.write_event(quick_xml::events::Event::Start(
+                quick_xml::events::BytesStart::new("multiInstanceLoopCharacteristics")
+                    .with_attributes(attributes),
+            ))?;
+            $x.create_element("loopCardinality").write_text_content(
+                quick_xml::events::BytesText::new(&multi_instance.loop_cardinality.to_string()),
+            )?;
+            $x.write_event(quick_xml::events::Event::End(
+                quick_xml::events::BytesEnd::new("multiInstanceLoopCharacteristics"),
+            ))?;
+        }
+    };
+}
+pub(crate) use write_multi_instance;
@@ -2,6 +2,7 @@ use crate::{
     BusinessProcessModelAndNotation,
     elements::process::BPMNProcess,
     traits::{processable::Processable, writable::Writable},
+    writer::data::write_data_elements,
 };
 use quick_xml::events::{BytesEnd, BytesStart, Event};
 
@@ -12,10 +13,21 @@ impl Writable for BPMNProcess {
         _parent: &dyn Processable,
         bpmn: &BusinessProcessModelAndNotation,
     ) -> anyhow::Result<()> {
-        x.write_event(Event::Start(BytesStart::new("process").with_attributes([
-            ("id", self.id.as_str()),
-            ("isExecutable", "true"),
-        ])))?;
+        let mut attributes = vec![("id", self.id.as_str())];
+        if self.participant_global_index.is_none() {
+            //in a collaboration, the name belongs to the participant
+            if let Some(name) = &self.name {
+                attributes.push(("name", name.as_str()));
+            }
+        }
+        attributes.push(("isExecutable", "true"));
+        x.write_event(Event::Start(
+            BytesStart::new("process").with_attributes(attributes),
+        ))?;
+
+        self.lane_sets.write(x, self, bpmn)?;
+
+        write_data_elements(x, self.global_index, bpmn)?;
 
         self.elements.write(x, self, bpmn)?;
 
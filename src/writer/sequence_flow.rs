@@ -3,7 +3,7 @@ use crate::{
     sequence_flow::BPMNSequenceFlow,
     traits::{objectable::BPMNObject, processable::Processable, writable::Writable},
 };
-use quick_xml::events::{BytesEnd, BytesStart, Event};
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
 
 impl Writable for BPMNSequenceFlow {
     fn write<W: std::io::Write>(
@@ -15,24 +15,38 @@ impl Writable for BPMNSequenceFlow {
         let source_id = parent.elements_non_recursive()[self.source_local_index].id();
         let target_id = parent.elements_non_recursive()[self.target_local_index].id();
 
-        let bytesstart = BytesStart::new("sequenceFlow").with_attributes([
-            ("id", self.id.as_str()),
-            ("sourceRef", source_id),
-            ("targetRef", target_id),
-        ]);
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+        attributes.push(("sourceRef", source_id));
+        attributes.push(("targetRef", target_id));
+        let bytesstart = BytesStart::new("sequenceFlow").with_attributes(attributes);
 
-        if let Some(weight) = &self.weight {
-            //with weight
+        if self.weight.is_some() || self.condition_expression.is_some() {
+            //with weight or condition
             x.write_event(Event::Start(bytesstart))?;
 
-            x.write_event(Event::Empty(
-                BytesStart::new("sbpmn:weight")
-                    .with_attributes([("constant", weight.to_string().as_str())]),
-            ))?;
+            if let Some(weight) = &self.weight {
+                x.write_event(Event::Empty(
+                    BytesStart::new("sbpmn:weight")
+                        .with_attributes([("constant", weight.to_string().as_str())]),
+                ))?;
+            }
+
+            if let Some(condition_expression) = &self.condition_expression {
+                let mut attributes = vec![("xsi:type", "tFormalExpression")];
+                if let Some(language) = &condition_expression.language {
+                    attributes.push(("language", language.as_str()));
+                }
+                x.create_element("conditionExpression")
+                    .with_attributes(attributes)
+                    .write_text_content(BytesText::new(&condition_expression.text))?;
+            }
 
             x.write_event(Event::End(BytesEnd::new("sequenceFlow")))?;
         } else {
-            //without weight
+            //without weight or condition
             x.write_event(Event::Empty(bytesstart))?;
         }
 
@@ -0,0 +1,39 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    elements::signal_intermediate_catch_event::BPMNSignalIntermediateCatchEvent,
+    traits::{
+        processable::Processable,
+        writable::{Writable, write_external_sequence_flows},
+    },
+};
+use quick_xml::events::{BytesStart, BytesText, Event};
+
+impl Writable for BPMNSignalIntermediateCatchEvent {
+    fn write<W: std::io::Write>(
+        &self,
+        x: &mut quick_xml::Writer<W>,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+
+        x.create_element("intermediateCatchEvent")
+            .with_attributes(attributes)
+            .write_inner_content(|x| {
+                write_external_sequence_flows!(x, self, parent);
+                let mut b = BytesStart::new("signalEventDefinition");
+                if let Some(id) = &self.signal_marker_id {
+                    b = b.with_attributes([("id", id.as_str())]);
+                }
+                if let Some(signal_ref) = &self.signal_ref {
+                    b = b.with_attributes([("signalRef", signal_ref.as_str())]);
+                }
+                x.write_event(Event::Empty(b))?;
+                Ok(())
+            })?;
+        Ok(())
+    }
+}
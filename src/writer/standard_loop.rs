@@ -0,0 +1,32 @@
+macro_rules! write_standard_loop {
+    ($x: ident, $self:ident) => {
+        if let Some(standard_loop) = &$self.standard_loop {
+            let loop_maximum = standard_loop
+                .loop_maximum
+                .map(|loop_maximum| loop_maximum.to_string());
+            let repeat_probability = standard_loop
+                .repeat_probability
+                .as_ref()
+                .map(|repeat_probability| repeat_probability.to_string());
+
+            let mut attributes = vec![];
+            if let Some(id) = &standard_loop.id {
+                attributes.push(("id", id.as_str()));
+            }
+            if standard_loop.test_before {
+                attributes.push(("testBefore", "true"));
+            }
+            if let Some(loop_maximum) = &loop_maximum {
+                attributes.push(("loopMaximum", loop_maximum.as_str()));
+            }
+            if let Some(repeat_probability) = &repeat_probability {
+                attributes.push(("sbpmn:repeatProbability", repeat_probability.as_str()));
+            }
+            $x.write_event(quick_xml::events::Event::Empty(
+                quick_xml::events::BytesStart::new("standardLoopCharacteristics")
+                    .with_attributes(attributes),
+            ))?;
+        }
+    };
+}
+pub(crate) use write_standard_loop;
@@ -11,8 +11,13 @@ impl Writable for BPMNStartEvent {
         parent: &dyn Processable,
         _bpmn: &crate::BusinessProcessModelAndNotation,
     ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+
         x.create_element("startEvent")
-            .with_attributes([("id", self.id.as_str())])
+            .with_attributes(attributes)
             .write_inner_content(|x| {
                 for outgoing_sequence_flow in &self.outgoing_sequence_flows {
                     x.create_element("outgoing")
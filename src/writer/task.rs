@@ -5,6 +5,10 @@ use crate::{
         processable::Processable,
         writable::{Writable, write_external_sequence_flows},
     },
+    writer::{
+        data::write_data_associations, multi_instance::write_multi_instance,
+        standard_loop::write_standard_loop,
+    },
 };
 use quick_xml::events::BytesText;
 
@@ -20,6 +24,9 @@ impl Writable for BPMNTask {
             .with_attribute(("name", bpmn.activity_key.deprocess_activity(&self.activity)))
             .write_inner_content(|x| {
                 write_external_sequence_flows!(x, self, parent);
+                write_data_associations(x, self.global_index, bpmn)?;
+                write_multi_instance!(x, self);
+                write_standard_loop!(x, self);
                 Ok(())
             })?;
         Ok(())
@@ -0,0 +1,36 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    elements::terminate_end_event::BPMNTerminateEndEvent,
+    traits::{
+        processable::Processable,
+        writable::{Writable, write_external_incoming},
+    },
+};
+use quick_xml::events::{BytesStart, BytesText, Event};
+
+impl Writable for BPMNTerminateEndEvent {
+    fn write<W: std::io::Write>(
+        &self,
+        x: &mut quick_xml::Writer<W>,
+        parent: &dyn Processable,
+        _bpmn: &BusinessProcessModelAndNotation,
+    ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+
+        x.create_element("endEvent")
+            .with_attributes(attributes)
+            .write_inner_content(|x| {
+                write_external_incoming!(x, self, parent);
+                let mut b = BytesStart::new("terminateEventDefinition");
+                if let Some(id) = &self.terminate_marker_id {
+                    b = b.with_attributes([("id", id.as_str())]);
+                }
+                x.write_event(Event::Empty(b))?;
+                Ok(())
+            })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+macro_rules! write_timer_event_definition {
+    ($x: ident, $marker_id: expr, $timer: expr) => {
+        let mut b = quick_xml::events::BytesStart::new("timerEventDefinition");
+        if let Some(id) = $marker_id {
+            b = b.with_attributes([("id", id.as_str())]);
+        }
+        match $timer {
+            Some(timer) => {
+                let end = b.to_end().into_owned();
+                $x.write_event(quick_xml::events::Event::Start(b))?;
+                $x.create_element(timer.timer_type.tag_name())
+                    .with_attribute(("xsi:type", "tFormalExpression"))
+                    .write_text_content(quick_xml::events::BytesText::new(&timer.text))?;
+                $x.write_event(quick_xml::events::Event::End(end))?;
+            }
+            None => $x.write_event(quick_xml::events::Event::Empty(b))?,
+        }
+    };
+}
+pub(crate) use write_timer_event_definition;
@@ -5,8 +5,9 @@ use crate::{
         processable::Processable,
         writable::{Writable, write_external_outgoing},
     },
+    writer::timer::write_timer_event_definition,
 };
-use quick_xml::events::{BytesStart, BytesText, Event};
+use quick_xml::events::BytesText;
 
 impl Writable for BPMNTimerStartEvent {
     fn write<W: std::io::Write>(
@@ -15,15 +16,19 @@ impl Writable for BPMNTimerStartEvent {
         parent: &dyn Processable,
         _bpmn: &BusinessProcessModelAndNotation,
     ) -> anyhow::Result<()> {
+        let mut attributes = vec![("id", self.id.as_str())];
+        if let Some(name) = &self.name {
+            attributes.push(("name", name.as_str()));
+        }
+        if !self.is_interrupting {
+            attributes.push(("isInterrupting", "false"));
+        }
+
         x.create_element("startEvent")
-            .with_attributes([("id", self.id.as_str())])
+            .with_attributes(attributes)
             .write_inner_content(|x| {
                 write_external_outgoing!(x, self, parent);
-                let mut b = BytesStart::new("timerEventDefinition");
-                if let Some(id) = &self.timer_marker_id {
-                    b = b.with_attributes([("id", id.as_str())]);
-                }
-                x.write_event(Event::Empty(b))?;
+                write_timer_event_definition!(x, &self.timer_marker_id, &self.timer);
                 Ok(())
             })?;
         Ok(())
@@ -0,0 +1,251 @@
+use crate::{
+    BusinessProcessModelAndNotation,
+    importer::parse_attribute,
+    unrecognised_xml::{BPMNUnrecognisedXml, BPMNXmlNode},
+};
+use anyhow::Result;
+use quick_xml::{
+    Reader, Writer,
+    events::{BytesCData, BytesEnd, BytesStart, BytesText, Event},
+};
+use std::io::Write;
+
+struct OpenElement<'a> {
+    id: Option<String>,
+    xml: Option<&'a BPMNUnrecognisedXml>,
+
+    /// The unrecognised XML of the nearest element with an id, which holds that of the elements without an id below it.
+    owner: Option<&'a BPMNUnrecognisedXml>,
+    /// The path from the nearest element with an id to this element.
+    path: Vec<(String, usize)>,
+
+    /// The ids of the recognised children that have been written.
+    written_anchors: Vec<String>,
+    /// The local names of the children without an id that have been written.
+    id_less_children: Vec<String>,
+}
+
+impl<'a> OpenElement<'a> {
+    fn new(id: Option<String>, owner: Option<&'a BPMNUnrecognisedXml>) -> Self {
+        Self {
+            id,
+            xml: owner,
+            owner,
+            path: vec![],
+            written_anchors: vec![],
+            id_less_children: vec![],
+        }
+    }
+}
+
+/// Rewrites an exported model, given as XML, such that the unrecognised XML of the model is woven back in at the elements it belongs to.
+pub(crate) fn write_unrecognised_xml(
+    input: &[u8],
+    f: &mut dyn Write,
+    bpmn: &BusinessProcessModelAndNotation,
+) -> Result<()> {
+    let mut reader = Reader::from_reader(input);
+    let mut x = Writer::new_with_indent(f, b'\t', 1);
+
+    let mut buf = vec![];
+    //the document is the parent of the definitions
+    let mut open_elements = vec![OpenElement::new(
+        None,
+        Some(&bpmn.unrecognised_document_xml),
+    )];
+    let mut pending_text = String::new();
+    loop {
+        buf.clear();
+        let event = reader.read_event_into(&mut buf)?;
+
+        //text and references are collected, such that the indentation can be recomputed
+        match &event {
+            Event::Text(e) => {
+                pending_text.push_str(&String::from_utf8_lossy(e));
+                continue;
+            }
+            Event::GeneralRef(e) => {
+                pending_text.push_str(&format!("&{};", String::from_utf8_lossy(e)));
+                continue;
+            }
+            _ => {
+                if !pending_text.trim().is_empty() {
+                    x.write_event(Event::Text(BytesText::from_escaped(pending_text.as_str())))?;
+                }
+                pending_text.clear();
+            }
+        }
+
+        match event {
+            Event::Decl(e) => {
+                //the children of the document that precede the definitions
+                x.write_event(Event::Decl(e))?;
+                for (anchor, node) in &bpmn.unrecognised_document_xml.children {
+                    if anchor.is_none() {
+                        write_node(&mut x, node)?;
+                    }
+                }
+            }
+            Event::Start(e) => {
+                let open_element = new_open_element(&e, &mut open_elements, bpmn);
+                x.write_event(Event::Start(with_attributes(e, open_element.xml)))?;
+                if let Some(xml) = open_element.xml {
+                    for (anchor, node) in &xml.children {
+                        if anchor.is_none() {
+                            write_node(&mut x, node)?;
+                        }
+                    }
+                }
+                open_elements.push(open_element);
+            }
+            Event::Empty(e) => {
+                let open_element = new_open_element(&e, &mut open_elements, bpmn);
+                match open_element.xml {
+                    Some(xml) if !xml.children.is_empty() => {
+                        let end = e.to_end().into_owned();
+                        x.write_event(Event::Start(with_attributes(e, Some(xml))))?;
+                        for (_, node) in &xml.children {
+                            write_node(&mut x, node)?;
+                        }
+                        x.write_event(Event::End(end))?;
+                    }
+                    xml => x.write_event(Event::Empty(with_attributes(e, xml)))?,
+                }
+                write_anchored_children(&mut x, &mut open_elements, open_element.id)?;
+            }
+            Event::End(e) => {
+                if let Some(open_element) = open_elements.pop() {
+                    write_unwritten_children(&mut x, &open_element)?;
+                    x.write_event(Event::End(e))?;
+                    write_anchored_children(&mut x, &mut open_elements, open_element.id)?;
+                } else {
+                    x.write_event(Event::End(e))?;
+                }
+            }
+            Event::Eof => {
+                for open_element in &open_elements {
+                    write_unwritten_children(&mut x, open_element)?;
+                }
+                break;
+            }
+            event => x.write_event(event)?,
+        }
+    }
+    Ok(())
+}
+
+/// Finds the unrecognised XML of an element that is written: by its id, or otherwise by its path from the nearest element with an id.
+fn new_open_element<'a>(
+    e: &BytesStart,
+    open_elements: &mut [OpenElement<'a>],
+    bpmn: &'a BusinessProcessModelAndNotation,
+) -> OpenElement<'a> {
+    if let Some(id) = parse_attribute(e, "id") {
+        let xml = bpmn.unrecognised_xml.get(&id);
+        return OpenElement::new(Some(id), xml);
+    }
+
+    let local_name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+    let mut result = OpenElement::new(None, None);
+    if let Some(parent) = open_elements.last_mut() {
+        let occurrence = parent
+            .id_less_children
+            .iter()
+            .filter(|name| **name == local_name)
+            .count();
+        parent.id_less_children.push(local_name.clone());
+
+        result.owner = parent.owner;
+        result.path = parent.path.clone();
+        result.path.push((local_name, occurrence));
+        result.xml = result
+            .owner
+            .and_then(|owner| owner.descendant(&result.path));
+    }
+    result
+}
+
+/// Writes the children whose recognised sibling was not written, at the end of their parent.
+fn write_unwritten_children<W: Write>(x: &mut Writer<W>, open_element: &OpenElement) -> Result<()> {
+    if let Some(xml) = open_element.xml {
+        for (anchor, node) in &xml.children {
+            if let Some(anchor) = anchor {
+                if !open_element.written_anchors.contains(anchor) {
+                    write_node(x, node)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Adds the unrecognised attributes, unless the exporter wrote them already.
+fn with_attributes<'a>(e: BytesStart<'a>, xml: Option<&BPMNUnrecognisedXml>) -> BytesStart<'a> {
+    let mut e = e;
+    if let Some(xml) = xml {
+        let present = e
+            .attributes()
+            .flatten()
+            .map(|attribute| attribute.key.as_ref().to_vec())
+            .collect::<Vec<_>>();
+        for (key, value) in &xml.attributes {
+            if !present
+                .iter()
+                .any(|present_key| present_key == key.as_bytes())
+            {
+                e.push_attribute((key.as_str(), value.as_str()));
+            }
+        }
+    }
+    e
+}
+
+/// Writes the unrecognised children of the parent that follow the recognised element with the given id.
+fn write_anchored_children<W: Write>(
+    x: &mut Writer<W>,
+    open_elements: &mut [OpenElement],
+    id: Option<String>,
+) -> Result<()> {
+    if let (Some(parent), Some(id)) = (open_elements.last_mut(), id) {
+        if let Some(xml) = parent.xml {
+            for (anchor, node) in &xml.children {
+                if anchor.as_ref() == Some(&id) {
+                    write_node(x, node)?;
+                }
+            }
+        }
+        parent.written_anchors.push(id);
+    }
+    Ok(())
+}
+
+fn write_node<W: Write>(x: &mut Writer<W>, node: &BPMNXmlNode) -> Result<()> {
+    match node {
+        BPMNXmlNode::Element {
+            name,
+            attributes,
+            children,
+        } => {
+            let bytes_start = BytesStart::new(name.as_str()).with_attributes(
+                attributes
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value.as_str())),
+            );
+            if children.is_empty() {
+                x.write_event(Event::Empty(bytes_start))?;
+            } else {
+                x.write_event(Event::Start(bytes_start))?;
+                for child in children {
+                    write_node(x, child)?;
+                }
+                x.write_event(Event::End(BytesEnd::new(name.as_str())))?;
+            }
+        }
+        BPMNXmlNode::Text(text) => x.write_event(Event::Text(BytesText::new(text)))?,
+        BPMNXmlNode::CData(text) => x.write_event(Event::CData(BytesCData::new(text)))?,
+        BPMNXmlNode::Comment(text) => {
+            x.write_event(Event::Comment(BytesText::from_escaped(text.as_str())))?
+        }
+    }
+    Ok(())
+}